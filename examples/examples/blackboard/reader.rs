@@ -0,0 +1,45 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_millis(500);
+const KEY_A: u64 = 0;
+const KEY_B: u64 = 1;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/Blackboard")?;
+    let initial_value = TransmissionData {
+        x: 0,
+        y: 0,
+        funky: 0.0,
+    };
+
+    let service = zero_copy::Service::new(&service_name)
+        .blackboard::<TransmissionData>()
+        .add(KEY_A, initial_value)
+        .add(KEY_B, initial_value)
+        .open_or_create()?;
+
+    let reader = service.reader().create()?;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        println!("entry A: {:?}", reader.read(KEY_A)?);
+        println!("entry B: {:?}", reader.read(KEY_B)?);
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
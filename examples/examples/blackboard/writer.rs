@@ -0,0 +1,68 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+const KEY_A: u64 = 0;
+const KEY_B: u64 = 1;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/Blackboard")?;
+    let initial_value = TransmissionData {
+        x: 0,
+        y: 0,
+        funky: 0.0,
+    };
+
+    let service = zero_copy::Service::new(&service_name)
+        .blackboard::<TransmissionData>()
+        .add(KEY_A, initial_value)
+        .add(KEY_B, initial_value)
+        .open_or_create()?;
+
+    let writer = service.writer().create()?;
+
+    let mut counter: u64 = 0;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+
+        writer.write(
+            KEY_A,
+            TransmissionData {
+                x: counter as i32,
+                y: counter as i32 * 3,
+                funky: counter as f64 * 812.12,
+            },
+        )?;
+
+        if counter % 5 == 0 {
+            writer.write(
+                KEY_B,
+                TransmissionData {
+                    x: counter as i32 * 5,
+                    y: counter as i32 * 7,
+                    funky: counter as f64 * 0.5,
+                },
+            )?;
+        }
+
+        println!("Update entries {} times", counter);
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let event_a = zero_copy::Service::new(&ServiceName::new("MyEventA")?)
+        .event()
+        .open_or_create()?;
+    let event_b = zero_copy::Service::new(&ServiceName::new("MyEventB")?)
+        .event()
+        .open_or_create()?;
+
+    let notifier_a = event_a.notifier().create()?;
+    let notifier_b = event_b.notifier().create()?;
+
+    let mut counter: u64 = 0;
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        counter += 1;
+        if counter % 2 == 0 {
+            notifier_a.notify_with_custom_event_id(EventId::new(counter))?;
+            println!("Trigger MyEventA with id {} ...", counter);
+        } else {
+            notifier_b.notify_with_custom_event_id(EventId::new(counter))?;
+            println!("Trigger MyEventB with id {} ...", counter);
+        }
+    }
+
+    println!("exit ... ");
+
+    Ok(())
+}
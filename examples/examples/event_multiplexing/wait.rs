@@ -0,0 +1,54 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use iceoryx2::waitset::{WaitSetAttachmentId, WaitSetRunResult};
+
+const STATUS_INTERVAL: Duration = Duration::from_secs(5);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let event_a = zero_copy::Service::new(&ServiceName::new("MyEventA")?)
+        .event()
+        .open_or_create()?;
+    let event_b = zero_copy::Service::new(&ServiceName::new("MyEventB")?)
+        .event()
+        .open_or_create()?;
+
+    let listener_a = event_a.listener().create()?;
+    let listener_b = event_b.listener().create()?;
+
+    let waitset = WaitSetBuilder::new().create()?;
+    let guard_a = waitset.attach_notification(&listener_a)?;
+    let guard_b = waitset.attach_notification(&listener_b)?;
+    let status_guard = waitset.attach_interval(STATUS_INTERVAL);
+
+    let on_event = |attachment_id: WaitSetAttachmentId| {
+        if attachment_id.has_event_from(&guard_a) {
+            listener_a
+                .try_wait_all(|event_id| println!("MyEventA triggered with id: {:?}", event_id))
+                .ok();
+        } else if attachment_id.has_event_from(&guard_b) {
+            listener_b
+                .try_wait_all(|event_id| println!("MyEventB triggered with id: {:?}", event_id))
+                .ok();
+        } else if attachment_id.has_event_from(&status_guard) {
+            println!("still waiting for events ...");
+        }
+    };
+
+    while let Ok(WaitSetRunResult::AllEventsHandled) = waitset.wait_and_process(on_event) {}
+
+    println!("exit ...");
+
+    Ok(())
+}
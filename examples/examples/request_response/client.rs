@@ -0,0 +1,53 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_secs(1);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/RequestResponse")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .request_response()
+        .open_or_create::<u64, TransmissionData>()?;
+
+    let client = service.client().create()?;
+
+    let mut counter: u64 = 0;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        while let Some(response) = client.receive()? {
+            println!(
+                "received response {:?} for request {}",
+                *response,
+                response.header().request_id()
+            );
+        }
+
+        counter += 1;
+        let request = client.loan_uninit()?;
+        let request = request.write_payload(counter);
+        let number_of_servers = client.send(request)?;
+
+        println!(
+            "Send request {} to {} server(s) ...",
+            counter, number_of_servers
+        );
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::*;
+use transmission_data::TransmissionData;
+
+const CYCLE_TIME: Duration = Duration::from_millis(100);
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new("My/Funk/RequestResponse")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .request_response()
+        .open_or_create::<u64, TransmissionData>()?;
+
+    let server = service.server().create()?;
+
+    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+        while let Some(request) = server.receive()? {
+            println!("received request: {:?}", *request);
+
+            let response = server.loan_uninit(&request)?;
+            let response = response.write_payload(TransmissionData {
+                x: *request as i32,
+                y: *request as i32 * 3,
+                funky: *request as f64 * 812.12,
+            });
+
+            server.send(response)?;
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
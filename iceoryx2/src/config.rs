@@ -73,6 +73,64 @@
 //! # }
 //! ```
 //!
+//! ## Layered Config
+//!
+//! [`Config::get_global_config()`] loads the config from multiple layers, see
+//! [`Config::from_default_layers()`]. Every layer overrides the entries of the previous ones:
+//!
+//!  1. the built-in defaults, [`Config::default()`]
+//!  2. the system wide config file, [`SYSTEM_CONFIG_FILE`]
+//!  3. the config file of the user, [`Config::user_config_file()`]
+//!  4. the config file in the working directory, [`DEFAULT_CONFIG_FILE`]
+//!  5. environment variables that start with [`ENVIRONMENT_VARIABLE_PREFIX`], like
+//!     `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS=4` or `IOX2_GLOBAL_ROOT_PATH=/tmp/iox2/`
+//!
+//! A config file only needs to contain the entries it overrides. Unknown entries and
+//! out-of-range values are reported as [`ConfigCreationError`].
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let custom_config = Config::from_layers(
+//!     &[FilePath::new(b"vehicle/base.toml")?, FilePath::new(b"vehicle/variant.toml")?],
+//!     std::env::vars())?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Per Service Overrides
+//!
+//! The `services` table overrides the [`Defaults`] of all services whose name matches the
+//! pattern. A `*` matches any sequence of characters. When multiple patterns match, the
+//! most specific one, the one with the most characters that are not `*`, is used.
+//!
+//! ```toml
+//! [services."camera/*".publish_subscribe]
+//! max_subscribers = 2
+//! subscriber_max_buffer_size = 8
+//! ```
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::Config;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut custom_config = Config::default();
+//! let mut camera_defaults = custom_config.defaults.clone();
+//! camera_defaults.publish_subscribe.max_subscribers = 2;
+//! custom_config.services.insert("camera/*".to_string(), camera_defaults);
+//!
+//! let service = zero_copy::Service::new(&ServiceName::new("camera/front")?)
+//!     .publish_subscribe_with_custom_config(&custom_config)
+//!     .open_or_create::<u64>()?;
+//!
+//! assert_eq!(service.static_config().max_supported_subscribers(), 2);
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Generate Config From Custom File
 //!
 //! ```no_run
@@ -90,15 +148,20 @@
 use iceoryx2_bb_container::byte_string::FixedSizeByteString;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::lazy_singleton::*;
-use iceoryx2_bb_posix::{file::FileBuilder, shared_memory::AccessMode};
+use iceoryx2_bb_posix::{
+    file::{File, FileBuilder},
+    shared_memory::AccessMode,
+};
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use iceoryx2_bb_log::{fail, trace, warn};
 
 use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::service_name::ServiceName;
 
 /// Path to the default config file
 #[cfg(target_os = "windows")]
@@ -110,6 +173,24 @@ pub const DEFAULT_CONFIG_FILE: FilePath =
 pub const DEFAULT_CONFIG_FILE: FilePath =
     unsafe { FilePath::new_unchecked(b"config/iceoryx2.toml") };
 
+/// Path to the system wide config file
+#[cfg(target_os = "windows")]
+pub const SYSTEM_CONFIG_FILE: FilePath =
+    unsafe { FilePath::new_unchecked(b"C:\\ProgramData\\iceoryx2\\iceoryx2.toml") };
+
+/// Path to the system wide config file
+#[cfg(not(target_os = "windows"))]
+pub const SYSTEM_CONFIG_FILE: FilePath =
+    unsafe { FilePath::new_unchecked(b"/etc/iceoryx2/iceoryx2.toml") };
+
+/// Every environment variable that starts with this prefix overrides a config entry. The rest
+/// of the variable name is the path of the entry where every `.` is replaced with `_`,
+/// for instance `IOX2_GLOBAL_SERVICE_DIRECTORY` overrides `global.service.directory`.
+pub const ENVIRONMENT_VARIABLE_PREFIX: &str = "IOX2_";
+
+const SERVICES_KEY: &str = "services";
+const DEFAULTS_KEY: &str = "defaults";
+
 /// Failures occurring while creating a new [`Config`] object with [`Config::from_file()`] or
 /// [`Config::setup_global_config_from_file()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -117,6 +198,9 @@ pub enum ConfigCreationError {
     FailedToOpenConfigFile,
     FailedToReadConfigFileContents,
     UnableToDeserializeContents,
+    UnknownKey,
+    ValueOutOfRange,
+    InvalidEnvironmentVariable,
 }
 
 impl std::fmt::Display for ConfigCreationError {
@@ -144,6 +228,24 @@ pub struct Service {
     pub creation_timeout: Duration,
     /// The suffix of a one-to-one connection
     pub connection_suffix: String,
+    /// The suffix of the channel a subscriber receives copies of samples from, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_copy_transfer()`]
+    pub copy_channel_suffix: String,
+    /// The suffix of the event a [`crate::service::discovery::ServiceDiscovery`] is notified
+    /// with whenever a service is created or removed
+    pub discovery_listener_suffix: String,
+}
+
+/// All configurable settings of a [`crate::node::Node`].
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct Node {
+    /// The directory in which the node registry is stored
+    pub directory: String,
+    /// The suffix of the file that contains the details of a node
+    pub details_suffix: String,
+    /// The suffix of the file that tags a port as owned by a node
+    pub port_tag_suffix: String,
 }
 
 /// The global settings
@@ -156,6 +258,8 @@ pub struct Global {
     pub prefix: String,
     /// [`crate::service::Service`] settings
     pub service: Service,
+    /// [`crate::node::Node`] settings
+    pub node: Node,
 }
 
 impl Global {
@@ -178,6 +282,12 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern request-response
+    pub request_response: RequestResponse,
+    /// Default settings for the messaging pattern blackboard
+    pub blackboard: Blackboard,
+    /// Default settings for the messaging pattern pipeline
+    pub pipeline: Pipeline,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -221,10 +331,73 @@ pub struct Event {
     pub max_notifiers: usize,
 }
 
-/// Represents the configuration that Iceoryx2 will utilize. It is divided into two sections:
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestResponse {
+    /// The maximum amount of supported [`crate::port::client::Client`]
+    pub max_clients: usize,
+    /// The maximum amount of supported [`crate::port::server::Server`]
+    pub max_servers: usize,
+    /// The maximum amount of requests of a single [`crate::port::client::Client`] a
+    /// [`crate::port::server::Server`] can store in its buffer
+    pub server_max_buffer_size: usize,
+    /// The maximum amount of [`crate::request::Request`]s of a single
+    /// [`crate::port::client::Client`] a [`crate::port::server::Server`] can hold in parallel.
+    pub server_max_borrowed_requests: usize,
+    /// The maximum amount of responses of a single [`crate::port::server::Server`] a
+    /// [`crate::port::client::Client`] can store in its buffer
+    pub client_max_buffer_size: usize,
+    /// The maximum amount of [`crate::response::Response`]s of a single
+    /// [`crate::port::server::Server`] a [`crate::port::client::Client`] can hold in parallel.
+    pub client_max_borrowed_responses: usize,
+    /// The maximum amount of [`crate::request_mut::RequestMut`]s a
+    /// [`crate::port::client::Client`] can loan in parallel.
+    pub client_max_loaned_requests: usize,
+    /// The maximum amount of [`crate::response_mut::ResponseMut`]s a
+    /// [`crate::port::server::Server`] can loan in parallel.
+    pub server_max_loaned_responses: usize,
+}
+
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+}
+
+/// Default settings for the pipeline messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Pipeline {
+    /// The maximum amount of supported [`crate::port::producer::Producer`]
+    pub max_producers: usize,
+    /// The maximum amount of supported [`crate::port::consumer::Consumer`]
+    pub max_consumers: usize,
+    /// The maximum amount of samples of a single [`crate::port::producer::Producer`] a
+    /// [`crate::port::consumer::Consumer`] can store in its buffer
+    pub consumer_max_buffer_size: usize,
+    /// The maximum amount of [`crate::work_item::WorkItem`]s of a single
+    /// [`crate::port::producer::Producer`] a [`crate::port::consumer::Consumer`] can hold in
+    /// parallel.
+    pub consumer_max_borrowed_samples: usize,
+    /// The maximum amount of [`crate::work_item_mut::WorkItemMut`]s a
+    /// [`crate::port::producer::Producer`] can loan in parallel.
+    pub producer_max_loaned_samples: usize,
+    /// Defines the strategy of the [`crate::port::producer::Producer`] when the buffers of all
+    /// [`crate::port::consumer::Consumer`]s are full.
+    pub unable_to_deliver_strategy: UnableToDeliverStrategy,
+}
+
+/// Represents the configuration that Iceoryx2 will utilize. It is divided into three sections:
 /// the [Global] settings, which must align with the Iceoryx2 instance the application intends to
-/// join, and the [Defaults] for communication within that Iceoryx2 instance. The user has the
-/// flexibility to override both sections.
+/// join, the [Defaults] for communication within that Iceoryx2 instance and the [Defaults] of
+/// the services whose name matches a pattern. The user has the flexibility to override all
+/// sections.
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
@@ -232,6 +405,10 @@ pub struct Config {
     pub global: Global,
     /// Default settings
     pub defaults: Defaults,
+    /// Default settings of all services whose name matches the pattern, see
+    /// [`Config::defaults_for()`]
+    #[serde(default)]
+    pub services: BTreeMap<String, Defaults>,
 }
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
@@ -252,6 +429,13 @@ impl Default for Config {
                     dynamic_config_storage_suffix: ".dynamic".to_string(),
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: ".connection".to_string(),
+                    copy_channel_suffix: ".copy_channel".to_string(),
+                    discovery_listener_suffix: ".discovery".to_string(),
+                },
+                node: Node {
+                    directory: "nodes".to_string(),
+                    details_suffix: ".node".to_string(),
+                    port_tag_suffix: ".port_tag".to_string(),
                 },
             },
             defaults: Defaults {
@@ -269,39 +453,336 @@ impl Default for Config {
                     max_listeners: 1,
                     max_notifiers: 16,
                 },
+                request_response: RequestResponse {
+                    max_clients: 8,
+                    max_servers: 2,
+                    server_max_buffer_size: 4,
+                    server_max_borrowed_requests: 2,
+                    client_max_buffer_size: 4,
+                    client_max_borrowed_responses: 2,
+                    client_max_loaned_requests: 2,
+                    server_max_loaned_responses: 2,
+                },
+                blackboard: Blackboard { max_readers: 8 },
+                pipeline: Pipeline {
+                    max_producers: 2,
+                    max_consumers: 8,
+                    consumer_max_buffer_size: 2,
+                    consumer_max_borrowed_samples: 2,
+                    producer_max_loaned_samples: 2,
+                    unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
+                },
             },
+            services: BTreeMap::new(),
         }
     }
 }
 
+// Returns true when the name matches the pattern where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &[u8], name: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some((b'*', rest)) => (0..=name.len()).any(|n| matches_pattern(rest, &name[n..])),
+        Some((c, rest)) => name.first() == Some(c) && matches_pattern(rest, &name[1..]),
+    }
+}
+
+fn pattern_specificity(pattern: &str) -> usize {
+    pattern.bytes().filter(|c| *c != b'*').count()
+}
+
+fn key_path(path: &str, key: &str) -> String {
+    match path.is_empty() {
+        true => key.to_string(),
+        false => format!("{}.{}", path, key),
+    }
+}
+
+// Returns the path of the first key in the layer that is not part of the reference.
+fn find_unknown_key(
+    layer: &toml::value::Table,
+    reference: &toml::value::Table,
+    path: &str,
+) -> Option<String> {
+    for (key, value) in layer {
+        match (reference.get(key), value) {
+            (None, _) => return Some(key_path(path, key)),
+            (Some(toml::Value::Table(reference_entry)), toml::Value::Table(entry)) => {
+                if let Some(unknown_key) =
+                    find_unknown_key(entry, reference_entry, &key_path(path, key))
+                {
+                    return Some(unknown_key);
+                }
+            }
+            // a type mismatch is reported when the config is deserialized
+            (Some(_), _) => (),
+        }
+    }
+
+    None
+}
+
+fn merge_tables(target: &mut toml::value::Table, layer: toml::value::Table) {
+    for (key, value) in layer {
+        match (target.get_mut(&key), value) {
+            (Some(toml::Value::Table(target_entry)), toml::Value::Table(entry)) => {
+                merge_tables(target_entry, entry)
+            }
+            (_, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+// Returns the entry whose path, joined with `_`, is equal to the key.
+fn find_entry_mut<'a>(table: &'a mut toml::value::Table, key: &str) -> Option<&'a mut toml::Value> {
+    for (entry_name, value) in table.iter_mut() {
+        if key == entry_name {
+            return Some(value);
+        }
+
+        if let (Some(rest), toml::Value::Table(entry)) = (
+            key.strip_prefix(entry_name.as_str())
+                .and_then(|rest| rest.strip_prefix('_')),
+            value,
+        ) {
+            if let Some(value) = find_entry_mut(entry, rest) {
+                return Some(value);
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_environment_value(current: &toml::Value, value: &str) -> Option<toml::Value> {
+    match current {
+        toml::Value::String(_) => Some(toml::Value::String(value.to_string())),
+        toml::Value::Integer(_) => value.parse::<i64>().ok().map(toml::Value::Integer),
+        toml::Value::Float(_) => value.parse::<f64>().ok().map(toml::Value::Float),
+        toml::Value::Boolean(_) => value.parse::<bool>().ok().map(toml::Value::Boolean),
+        _ => None,
+    }
+}
+
+fn ensure_at_least_one(origin: &str, key: &str, value: usize) -> Result<(), ConfigCreationError> {
+    if value == 0 {
+        fail!(from origin, with ConfigCreationError::ValueOutOfRange,
+            "The config entry \"{}\" is out of range, it must be at least 1.", key);
+    }
+
+    Ok(())
+}
+
+impl Defaults {
+    fn validate(&self, path: &str) -> Result<(), ConfigCreationError> {
+        let origin = "Defaults::validate()";
+        let pubsub = &self.publish_subscribe;
+        for (key, value) in [
+            ("publish_subscribe.max_subscribers", pubsub.max_subscribers),
+            ("publish_subscribe.max_publishers", pubsub.max_publishers),
+            (
+                "publish_subscribe.subscriber_max_buffer_size",
+                pubsub.subscriber_max_buffer_size,
+            ),
+            (
+                "publish_subscribe.subscriber_max_borrowed_samples",
+                pubsub.subscriber_max_borrowed_samples,
+            ),
+            (
+                "publish_subscribe.publisher_max_loaned_samples",
+                pubsub.publisher_max_loaned_samples,
+            ),
+            ("event.max_listeners", self.event.max_listeners),
+            ("event.max_notifiers", self.event.max_notifiers),
+            (
+                "request_response.max_clients",
+                self.request_response.max_clients,
+            ),
+            (
+                "request_response.max_servers",
+                self.request_response.max_servers,
+            ),
+            (
+                "request_response.server_max_buffer_size",
+                self.request_response.server_max_buffer_size,
+            ),
+            (
+                "request_response.server_max_borrowed_requests",
+                self.request_response.server_max_borrowed_requests,
+            ),
+            (
+                "request_response.client_max_buffer_size",
+                self.request_response.client_max_buffer_size,
+            ),
+            (
+                "request_response.client_max_borrowed_responses",
+                self.request_response.client_max_borrowed_responses,
+            ),
+            (
+                "request_response.client_max_loaned_requests",
+                self.request_response.client_max_loaned_requests,
+            ),
+            (
+                "request_response.server_max_loaned_responses",
+                self.request_response.server_max_loaned_responses,
+            ),
+            ("blackboard.max_readers", self.blackboard.max_readers),
+            ("pipeline.max_producers", self.pipeline.max_producers),
+            ("pipeline.max_consumers", self.pipeline.max_consumers),
+            (
+                "pipeline.consumer_max_buffer_size",
+                self.pipeline.consumer_max_buffer_size,
+            ),
+            (
+                "pipeline.consumer_max_borrowed_samples",
+                self.pipeline.consumer_max_borrowed_samples,
+            ),
+            (
+                "pipeline.producer_max_loaned_samples",
+                self.pipeline.producer_max_loaned_samples,
+            ),
+        ] {
+            ensure_at_least_one(origin, &key_path(path, key), value)?;
+        }
+
+        if !pubsub.enable_safe_overflow
+            && pubsub.subscriber_max_buffer_size < pubsub.publisher_history_size
+        {
+            fail!(from origin, with ConfigCreationError::ValueOutOfRange,
+                "The config entry \"{}\" is out of range, without safe overflow it must not exceed the subscriber buffer size of {}.",
+                key_path(path, "publish_subscribe.publisher_history_size"), pubsub.subscriber_max_buffer_size);
+        }
+
+        Ok(())
+    }
+}
+
 impl Config {
-    /// Loads a configuration from a file. On success it returns a [`Config`] object otherwise a
+    /// Loads a configuration from a file. Entries that are not contained in the file are set to
+    /// their default value. On success it returns a [`Config`] object otherwise a
     /// [`ConfigCreationError`] describing the failure.
     pub fn from_file(config_file: &FilePath) -> Result<Config, ConfigCreationError> {
         let msg = "Failed to create config";
-        let mut new_config = Self::default();
+        let origin = "Config::from_file()";
+        let mut layers = LayeredConfig::new(origin)?;
+        fail!(from origin, when layers.add_file(config_file),
+            "{} since the config file \"{}\" could not be loaded.", msg, config_file);
+        let new_config = fail!(from origin, when layers.finalize(),
+            "{} from the config file \"{}\".", msg, config_file);
 
-        let file = fail!(from new_config, when FileBuilder::new(config_file).open_existing(AccessMode::Read),
-                with ConfigCreationError::FailedToOpenConfigFile,
-                "{} since the config file could not be opened.", msg);
+        trace!(from new_config, "Loaded.");
+        Ok(new_config)
+    }
 
-        let mut contents = String::new();
-        fail!(from new_config, when file.read_to_string(&mut contents),
-                with ConfigCreationError::FailedToReadConfigFileContents,
-                "{} since the config file contents could not be read.", msg);
+    /// Loads a configuration from multiple layers. It starts with the [`Config::default()`]
+    /// values that are overridden by the entries of every existing config file in the given
+    /// order. Config files that do not exist are skipped. Finally, all environment variables
+    /// that start with [`ENVIRONMENT_VARIABLE_PREFIX`] override the corresponding entries.
+    /// Environment variables with the prefix that do not correspond to an entry are ignored
+    /// with a warning.
+    /// On success it returns a [`Config`] object otherwise a [`ConfigCreationError`] describing
+    /// the failure.
+    pub fn from_layers<I: IntoIterator<Item = (String, String)>>(
+        config_files: &[FilePath],
+        environment: I,
+    ) -> Result<Config, ConfigCreationError> {
+        let msg = "Failed to create layered config";
+        let origin = "Config::from_layers()";
+        let mut layers = LayeredConfig::new(origin)?;
 
-        match toml::from_str(&contents) {
-            Ok(v) => new_config = v,
-            Err(e) => {
-                fail!(from new_config, with ConfigCreationError::UnableToDeserializeContents,
-                                "{} since the contents could not be deserialized ({}).", msg, e);
+        for config_file in config_files {
+            match File::does_exist(config_file) {
+                Ok(true) => {
+                    fail!(from origin, when layers.add_file(config_file),
+                        "{} since the config file \"{}\" could not be loaded.", msg, config_file);
+                }
+                Ok(false) => {
+                    trace!(from origin, "Skip config file \"{}\" since it does not exist.", config_file);
+                }
+                Err(e) => {
+                    fail!(from origin, with ConfigCreationError::FailedToOpenConfigFile,
+                        "{} since the existance of the config file \"{}\" could not be verified ({:?}).",
+                        msg, config_file, e);
+                }
             }
         }
 
+        for (name, value) in environment {
+            fail!(from origin, when layers.add_environment_variable(&name, &value),
+                "{} since the environment variable \"{}\" could not be applied.", msg, name);
+        }
+
+        let new_config = fail!(from origin, when layers.finalize(), "{}.", msg);
+
         trace!(from new_config, "Loaded.");
         Ok(new_config)
     }
 
+    /// Loads the configuration from the default layers: the [`SYSTEM_CONFIG_FILE`], the
+    /// [`Config::user_config_file()`], the [`DEFAULT_CONFIG_FILE`] and the environment variables
+    /// of the process. See [`Config::from_layers()`].
+    pub fn from_default_layers() -> Result<Config, ConfigCreationError> {
+        let mut config_files = vec![SYSTEM_CONFIG_FILE];
+        if let Some(user_config_file) = Self::user_config_file() {
+            config_files.push(user_config_file);
+        }
+        config_files.push(DEFAULT_CONFIG_FILE);
+
+        Self::from_layers(&config_files, std::env::vars())
+    }
+
+    /// Returns the path to the config file of the current user,
+    /// `$XDG_CONFIG_HOME/iceoryx2/iceoryx2.toml` or `$HOME/.config/iceoryx2/iceoryx2.toml`
+    /// (`%APPDATA%\\iceoryx2\\iceoryx2.toml` on windows). Returns [`None`] when the
+    /// location of the users config directory is unknown.
+    pub fn user_config_file() -> Option<FilePath> {
+        #[cfg(target_os = "windows")]
+        let config_file = std::env::var("APPDATA")
+            .ok()
+            .map(|dir| format!("{}\\iceoryx2\\iceoryx2.toml", dir));
+
+        #[cfg(not(target_os = "windows"))]
+        let config_file = match std::env::var("XDG_CONFIG_HOME") {
+            Ok(dir) if !dir.is_empty() => Some(format!("{}/iceoryx2/iceoryx2.toml", dir)),
+            _ => std::env::var("HOME")
+                .ok()
+                .map(|dir| format!("{}/.config/iceoryx2/iceoryx2.toml", dir)),
+        };
+
+        match FilePath::new(config_file?.as_bytes()) {
+            Ok(config_file) => Some(config_file),
+            Err(e) => {
+                warn!(from "Config::user_config_file()", "The users config file is ignored since its path is not a valid file path ({:?}).", e);
+                None
+            }
+        }
+    }
+
+    /// Returns the [`Defaults`] of the service with the given name. If the name matches one or
+    /// more patterns of [`Config::services`] the [`Defaults`] of the most specific pattern are
+    /// returned, otherwise [`Config::defaults`].
+    pub fn defaults_for(&self, service_name: &ServiceName) -> &Defaults {
+        let name = service_name.as_str().as_bytes();
+        self.services
+            .iter()
+            .filter(|(pattern, _)| matches_pattern(pattern.as_bytes(), name))
+            .max_by_key(|(pattern, _)| pattern_specificity(pattern))
+            .map(|(_, defaults)| defaults)
+            .unwrap_or(&self.defaults)
+    }
+
+    /// Verifies that all values of the [`Config`] are in their valid range.
+    pub fn validate(&self) -> Result<(), ConfigCreationError> {
+        self.defaults.validate(DEFAULTS_KEY)?;
+        for (pattern, defaults) in &self.services {
+            defaults.validate(&format!("{}.\"{}\"", SERVICES_KEY, pattern))?;
+        }
+
+        Ok(())
+    }
+
     /// Sets up the global configuration from a file. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
@@ -325,18 +806,203 @@ impl Config {
     }
 
     /// Returns the global configuration. If the global configuration was not
-    /// [`Config::setup_global_config_from_file()`] it will load a default config. If
+    /// [`Config::setup_global_config_from_file()`] it will load the config from the default
+    /// layers, see [`Config::from_default_layers()`]. If
     /// [`Config::setup_global_config_from_file()`]
     /// is called after this function was called, no file will be loaded since the global default
     /// config was already populated.
     pub fn get_global_config() -> &'static Config {
-        if !ICEORYX2_CONFIG.is_initialized()
-            && Config::setup_global_config_from_file(&DEFAULT_CONFIG_FILE).is_err()
-        {
-            warn!(from "Config::get_global_config()", "Unable to load default config file, populate config with default values.");
-            ICEORYX2_CONFIG.set_value(Config::default());
+        if !ICEORYX2_CONFIG.is_initialized() {
+            match Config::from_default_layers() {
+                Ok(config) => {
+                    ICEORYX2_CONFIG.set_value(config);
+                }
+                Err(e) => {
+                    warn!(from "Config::get_global_config()", "Unable to load the layered config ({:?}), populate config with default values.", e);
+                    ICEORYX2_CONFIG.set_value(Config::default());
+                }
+            }
         }
 
         ICEORYX2_CONFIG.get()
     }
 }
+
+/// Merges the layers of a [`Config`]. The entries of the `services` table are stored
+/// separately and are merged onto the final [`Defaults`] when the [`Config`] is finalized, so
+/// that they are not affected by the order of the layers.
+struct LayeredConfig {
+    reference: toml::value::Table,
+    merged: toml::value::Table,
+    service_overrides: toml::value::Table,
+    origin: &'static str,
+}
+
+impl LayeredConfig {
+    fn new(origin: &'static str) -> Result<Self, ConfigCreationError> {
+        let reference = match toml::Value::try_from(Config::default()) {
+            Ok(toml::Value::Table(reference)) => reference,
+            v => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "This should never happen! Unable to serialize the default config ({:?}).", v);
+            }
+        };
+
+        Ok(Self {
+            merged: reference.clone(),
+            reference,
+            service_overrides: toml::value::Table::new(),
+            origin,
+        })
+    }
+
+    fn add_file(&mut self, config_file: &FilePath) -> Result<(), ConfigCreationError> {
+        let msg = "Unable to add config file";
+        let origin = self.origin;
+
+        let file = fail!(from origin, when FileBuilder::new(config_file).open_existing(AccessMode::Read),
+                with ConfigCreationError::FailedToOpenConfigFile,
+                "{} since the config file could not be opened.", msg);
+
+        let mut contents = String::new();
+        fail!(from origin, when file.read_to_string(&mut contents),
+                with ConfigCreationError::FailedToReadConfigFileContents,
+                "{} since the config file contents could not be read.", msg);
+
+        let mut layer: toml::value::Table = match toml::from_str(&contents) {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                                "{} since the contents could not be deserialized ({}).", msg, e);
+            }
+        };
+
+        if let Some(services) = layer.remove(SERVICES_KEY) {
+            self.add_service_overrides(services)?;
+        }
+
+        if let Some(unknown_key) = find_unknown_key(&layer, &self.reference, "") {
+            fail!(from origin, with ConfigCreationError::UnknownKey,
+                "{} since it contains the unknown entry \"{}\".", msg, unknown_key);
+        }
+
+        merge_tables(&mut self.merged, layer);
+        Ok(())
+    }
+
+    fn add_service_overrides(&mut self, services: toml::Value) -> Result<(), ConfigCreationError> {
+        let msg = "Unable to add service overrides";
+        let origin = self.origin;
+
+        let services = match services {
+            toml::Value::Table(services) => services,
+            _ => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "{} since the entry \"{}\" is not a table.", msg, SERVICES_KEY);
+            }
+        };
+
+        let reference = match self.reference.get(DEFAULTS_KEY) {
+            Some(toml::Value::Table(reference)) => reference,
+            _ => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "This should never happen! The default config does not contain the \"{}\" table.", DEFAULTS_KEY);
+            }
+        };
+
+        for (pattern, overrides) in services {
+            let path = format!("{}.\"{}\"", SERVICES_KEY, pattern);
+            let overrides = match overrides {
+                toml::Value::Table(overrides) => overrides,
+                _ => {
+                    fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                        "{} since the entry \"{}\" is not a table.", msg, path);
+                }
+            };
+
+            if let Some(unknown_key) = find_unknown_key(&overrides, reference, &path) {
+                fail!(from origin, with ConfigCreationError::UnknownKey,
+                    "{} since it contains the unknown entry \"{}\".", msg, unknown_key);
+            }
+
+            match self.service_overrides.get_mut(&pattern) {
+                Some(toml::Value::Table(existing)) => merge_tables(existing, overrides),
+                _ => {
+                    self.service_overrides
+                        .insert(pattern, toml::Value::Table(overrides));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_environment_variable(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<(), ConfigCreationError> {
+        let origin = self.origin;
+        let key = match name.strip_prefix(ENVIRONMENT_VARIABLE_PREFIX) {
+            Some(key) => key.to_lowercase(),
+            None => return Ok(()),
+        };
+
+        let entry = match find_entry_mut(&mut self.merged, &key) {
+            Some(entry) => entry,
+            None => {
+                warn!(from origin, "The environment variable \"{}\" is ignored since it does not correspond to a config entry.", name);
+                return Ok(());
+            }
+        };
+
+        match parse_environment_value(entry, value) {
+            Some(value) => {
+                *entry = value;
+                Ok(())
+            }
+            None => {
+                fail!(from origin, with ConfigCreationError::InvalidEnvironmentVariable,
+                    "Unable to apply the environment variable \"{}\" since the value \"{}\" is not compatible with the type of the config entry.",
+                    name, value);
+            }
+        }
+    }
+
+    fn finalize(mut self) -> Result<Config, ConfigCreationError> {
+        let msg = "Unable to finalize config";
+        let origin = self.origin;
+
+        let defaults = match self.merged.get(DEFAULTS_KEY) {
+            Some(toml::Value::Table(defaults)) => defaults.clone(),
+            _ => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "{} since the \"{}\" entry is not a table.", msg, DEFAULTS_KEY);
+            }
+        };
+
+        let mut services = toml::value::Table::new();
+        for (pattern, overrides) in self.service_overrides {
+            let mut service_defaults = defaults.clone();
+            if let toml::Value::Table(overrides) = overrides {
+                merge_tables(&mut service_defaults, overrides);
+            }
+            services.insert(pattern, toml::Value::Table(service_defaults));
+        }
+        self.merged
+            .insert(SERVICES_KEY.to_string(), toml::Value::Table(services));
+
+        let config: Config = match toml::Value::Table(self.merged).try_into() {
+            Ok(config) => config,
+            Err(e) => {
+                fail!(from origin, with ConfigCreationError::UnableToDeserializeContents,
+                    "{} since the merged contents could not be deserialized ({}).", msg, e);
+            }
+        };
+
+        fail!(from origin, when config.validate(),
+            "{} since it contains invalid values.", msg);
+
+        Ok(config)
+    }
+}
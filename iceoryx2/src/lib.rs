@@ -20,9 +20,9 @@
 //!
 //! - Publish-Subscribe
 //! - Events
-//! - Request-Response (planned)
-//! - Pipeline (planned)
-//! - Blackboard (planned)
+//! - Request-Response
+//! - Pipeline
+//! - Blackboard
 //!
 //! For a comprehensive list of all planned features, please refer to the
 //! [GitHub Roadmap](https://github.com/iceoryx2/iceoryx2/ROADMAP.md).
@@ -170,6 +170,209 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! Explore a simple request-response setup where the client sends a request every second to all
+//! servers and every server answers it. The response is delivered only to the client that sent
+//! the request.
+//!
+//! **Server (Process 1)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_millis(100);
+//! let service_name = ServiceName::new("My/Funk/RequestResponse")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = service.server().create()?;
+//!
+//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//!     while let Some(request) = server.receive()? {
+//!         let response = server.loan_uninit(&request)?;
+//!         let response = response.write_payload(*request * 2);
+//!         server.send(response)?;
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Client (Process 2)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//! let service_name = ServiceName::new("My/Funk/RequestResponse")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let client = service.client().create()?;
+//!
+//! let mut counter: u64 = 0;
+//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//!     while let Some(response) = client.receive()? {
+//!         println!("received response: {:?}", *response);
+//!     }
+//!
+//!     counter += 1;
+//!     client.send_copy(counter)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Pipeline
+//!
+//! Explore a simple pipeline setup where the producer sends a work item every second and every
+//! work item is processed by exactly one of the consumers.
+//!
+//! **Consumer (Process 1)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_millis(100);
+//! let service_name = ServiceName::new("My/Funk/Pipeline")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let consumer = service.consumer().create()?;
+//!
+//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//!     while let Some(work_item) = consumer.receive()? {
+//!         println!("processing work item: {:?}", *work_item);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Producer (Process 2)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//! let service_name = ServiceName::new("My/Funk/Pipeline")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let producer = service.producer().create()?;
+//!
+//! let mut counter: u64 = 0;
+//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//!     counter += 1;
+//!     let consumer_id = producer.send_copy(counter)?;
+//!     println!("work item {} was sent to {:?}", counter, consumer_id);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! Explore a simple blackboard setup where the writer updates an entry every second and the
+//! reader prints the most recent value of the entry every 500 milliseconds.
+//!
+//! **Writer (Process 1)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//! let service_name = ServiceName::new("My/Funk/Blackboard")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! let writer = service.writer().create()?;
+//!
+//! let mut counter: u64 = 0;
+//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//!     counter += 1;
+//!     writer.write(0, counter)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Reader (Process 2)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_millis(500);
+//! let service_name = ServiceName::new("My/Funk/Blackboard")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! let reader = service.reader().create()?;
+//!
+//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//!     println!("entry 0: {}", reader.read(0)?);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Nodes
+//!
+//! A [`Node`](crate::node::Node) represents the application in the system. It is registered
+//! in a node registry, so that tools can list all nodes, their process ids and the ports they
+//! hold. All services created via the node use its config and their ports are owned by the node.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new(&NodeName::new("My/Funk/Application")?)
+//!     .create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//!
+//! for node in Node::<zero_copy::Service>::list()? {
+//!     println!("{} (pid {}) holds {} ports", node.name(), node.pid(), node.ports().len());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Quality Of Services
 //!
 //! Quality of service settings, or service settings, play a crucial role in determining memory
@@ -225,6 +428,79 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("ReqResQos")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     // the maximum amount of clients of this service
+//!     .max_clients(4)
+//!     // the maximum amount of servers of this service
+//!     .max_servers(2)
+//!     // how many requests of a client a server can hold in its buffer
+//!     .server_max_buffer_size(4)
+//!     // how many requests a server can borrow in parallel from a client
+//!     .server_max_borrowed_requests(2)
+//!     // how many responses of a server a client can hold in its buffer
+//!     .client_max_buffer_size(4)
+//!     // how many responses a client can borrow in parallel from a server
+//!     .client_max_borrowed_responses(2)
+//!     .create::<u64, u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Pipeline
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("PipelineQos")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     // the maximum amount of producers of this service
+//!     .max_producers(2)
+//!     // the maximum amount of consumers of this service
+//!     .max_consumers(8)
+//!     // how many work items of a producer a consumer can hold in its buffer
+//!     .consumer_max_buffer_size(4)
+//!     // how many work items a consumer can borrow in parallel from a producer
+//!     .consumer_max_borrowed_samples(2)
+//!     .create::<u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("BlackboardQos")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     // the maximum amount of readers of this service
+//!     .max_readers(4)
+//!     // the entries of the blackboard with their initial values
+//!     .add(0, 0)
+//!     .add(1, 0)
+//!     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Port Behavior
 //!
 //! Certain ports in iceoryx2 provide users with the flexibility to define custom behaviors in
@@ -262,6 +538,9 @@
 //!  * `logger_log` - Uses the [log crate](https://crates.io/crates/log) as default log backend
 //!  * `logger_tracing` - Uses the [tracing crate](https://crates.io/crates/tracing) as default log
 //!     backend
+//!  * `async` - Provides [`crate::port::async_listener::AsyncListener`] and
+//!     [`crate::port::async_subscriber::AsyncSubscriber`] which can be awaited in any async
+//!     executor that can wait on a file descriptor, see [`crate::reactor`]
 //!
 //! # Custom Configuration
 //!
@@ -282,11 +561,33 @@ pub mod iox2;
 
 pub(crate) mod message;
 
+/// The node that owns services and ports and registers the application in the node registry
+pub mod node;
+
+/// The types that can be used as payload, either fixed size types or slices
+pub mod payload_type;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
 pub(crate) mod raw_sample;
 
+/// The interface to integrate the ports into the reactor of an async executor
+#[cfg(feature = "async")]
+pub mod reactor;
+
+/// The request that is received by a [`crate::port::server::Server`].
+pub mod request;
+
+/// The request that is sent by a [`crate::port::client::Client`].
+pub mod request_mut;
+
+/// The response that is received by a [`crate::port::client::Client`].
+pub mod response;
+
+/// The response that is sent by a [`crate::port::server::Server`].
+pub mod response_mut;
+
 /// The payload that is received by a [`crate::port::subscriber::Subscriber`].
 pub mod sample;
 
@@ -297,5 +598,19 @@ pub mod sample_mut;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// The payload as raw bytes that is received by a
+/// [`crate::port::type_erased_subscriber::TypeErasedSubscriber`].
+pub mod type_erased_sample;
+
+/// The work item that is received by a [`crate::port::consumer::Consumer`].
+pub mod work_item;
+
+/// The work item that is sent by a [`crate::port::producer::Producer`].
+pub mod work_item_mut;
+
+/// The event multiplexer that waits on multiple [`crate::port::listener::Listener`]s, timers
+/// and intervals at once
+pub mod waitset;
+
 /// Loads a meaninful subset to cover 90% of the iceoryx2 communication use cases.
 pub mod prelude;
@@ -0,0 +1,622 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Create Services And Ports Through A Node
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new(&NodeName::new("My/Funk/Application")?)
+//!     .create::<zero_copy::Service>()?;
+//!
+//! let service = node.service_builder(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! // the publisher is listed as port of the node
+//! let publisher = service.publisher().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## List All Nodes
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! for node in Node::<zero_copy::Service>::list()? {
+//!     println!("{} (pid: {}, alive: {})", node.name(), node.pid(), node.is_alive());
+//!     for port in node.ports() {
+//!         println!("  {:?} of \"{}\"", port.port_type(), port.service_name());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Remove The Resources Of Dead Nodes
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let number_of_dead_nodes = Node::<zero_copy::Service>::cleanup_dead_nodes()?;
+//! # Ok(())
+//! # }
+//! ```
+
+/// Represents the name of a [`Node`]
+pub mod node_name;
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
+use iceoryx2_cal::named_concept::*;
+use iceoryx2_cal::serialize::Serialize as _;
+use iceoryx2_cal::static_storage::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::config;
+use crate::service::builder::Builder;
+use crate::service::config_scheme::{
+    dynamic_config_storage_config, node_details_storage_config, node_port_tag_config,
+};
+use crate::service::dead_port_cleanup::cleanup_dead_ports_of_service;
+use crate::service::dynamic_config::DynamicConfig;
+use crate::service::naming_scheme::{
+    dynamic_config_storage_name, extract_ids_from_node_port_tag_name, node_details_storage_name,
+    node_port_tag_name,
+};
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::StaticConfig;
+use crate::service::{self, Service};
+
+use self::node_name::NodeName;
+
+/// Failures that can occur when a [`Node`] is created with [`NodeBuilder::create()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeCreationFailure {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeCreationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeCreationFailure {}
+
+/// Failures that can occur when the node registry is read with [`Node::list()`] or cleaned up
+/// with [`Node::cleanup_dead_nodes()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum NodeListFailure {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeListFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for NodeListFailure {}
+
+/// The system wide unique id of a [`Node`]. It contains the process id of the process that
+/// created the [`Node`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(UniqueSystemId);
+
+impl NodeId {
+    /// Returns the underlying value of the system wide unique id
+    pub fn value(&self) -> u128 {
+        self.0.value()
+    }
+
+    /// Returns the [`ProcessId`] of the process that created the [`Node`]
+    pub fn pid(&self) -> ProcessId {
+        self.0.pid()
+    }
+
+    fn is_owner_alive(&self) -> bool {
+        Process::from_pid(self.0.pid()).is_alive()
+    }
+}
+
+/// Defines the type of a port that was created via a [`Node`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PortType {
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Client,
+    Server,
+    Reader,
+    Writer,
+    Producer,
+    Consumer,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NodeDetails {
+    name: NodeName,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NodePortDetails {
+    service_name: ServiceName,
+    service_uuid: String,
+    port_type: PortType,
+}
+
+/// Describes a port that is owned by a [`Node`], acquired via [`NodeView::ports()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodePortView {
+    service_name: ServiceName,
+    service_uuid: String,
+    port_type: PortType,
+    port_id: u128,
+}
+
+impl NodePortView {
+    /// Returns the name of the [`crate::service::Service`] the port belongs to
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`] the port belongs to
+    pub fn service_uuid(&self) -> &str {
+        &self.service_uuid
+    }
+
+    /// Returns the [`PortType`] of the port
+    pub fn port_type(&self) -> PortType {
+        self.port_type
+    }
+
+    /// Returns the value of the unique port id, for instance
+    /// [`crate::port::port_identifiers::UniquePublisherId::value()`]
+    pub fn port_id(&self) -> u128 {
+        self.port_id
+    }
+}
+
+/// Describes a [`Node`] of the node registry, acquired via [`Node::list()`].
+#[derive(Debug, Clone)]
+pub struct NodeView {
+    id: NodeId,
+    name: NodeName,
+    ports: Vec<NodePortView>,
+}
+
+impl NodeView {
+    /// Returns the [`NodeId`] of the [`Node`]
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the [`NodeName`] of the [`Node`]
+    pub fn name(&self) -> &NodeName {
+        &self.name
+    }
+
+    /// Returns the [`ProcessId`] of the process that owns the [`Node`]
+    pub fn pid(&self) -> ProcessId {
+        self.id.pid()
+    }
+
+    /// Returns true when the process that owns the [`Node`] is still alive. The resources of
+    /// dead nodes can be removed with [`Node::cleanup_dead_nodes()`].
+    pub fn is_alive(&self) -> bool {
+        self.id.is_owner_alive()
+    }
+
+    /// Returns all ports the [`Node`] holds
+    pub fn ports(&self) -> &[NodePortView] {
+        &self.ports
+    }
+}
+
+/// Creates a new [`Node`] and registers it in the node registry.
+///
+/// # Example
+///
+/// See [`crate::node`]
+#[derive(Debug)]
+pub struct NodeBuilder<'config> {
+    name: NodeName,
+    config: &'config config::Config,
+}
+
+impl<'config> NodeBuilder<'config> {
+    /// Creates a new [`NodeBuilder`] for a [`Node`] with the given name
+    pub fn new(name: &NodeName) -> Self {
+        Self {
+            name: *name,
+            config: config::Config::get_global_config(),
+        }
+    }
+
+    /// Uses a custom [`config::Config`] for the [`Node`] and all services that are created
+    /// via the [`Node`]
+    pub fn config(mut self, value: &'config config::Config) -> Self {
+        self.config = value;
+        self
+    }
+
+    /// Creates the [`Node`] and registers it under
+    /// [`config::Global::root_path`] in the node registry.
+    pub fn create<S: Service>(self) -> Result<Node<'config, S>, NodeCreationFailure> {
+        let msg = "Unable to create node";
+        let id = NodeId(fail!(from self, when UniqueSystemId::new(),
+                    with NodeCreationFailure::InternalError,
+                    "{} since the node id could not be generated.", msg));
+
+        let details_storage = fail!(from self, when write_storage::<S::Type<'config>, _>(
+                        &node_details_storage_name(&id),
+                        &node_details_storage_config::<S::Type<'config>>(self.config),
+                        &NodeDetails { name: self.name }),
+                    "{} since the node could not be registered in the node registry.", msg);
+
+        Ok(Node {
+            id,
+            name: self.name,
+            config: self.config,
+            _details_storage: details_storage,
+        })
+    }
+}
+
+/// The central entity of an application. It is registered in the node registry as long as it
+/// exists and every port that is created from a service that was built via
+/// [`Node::service_builder()`] is listed as port of the [`Node`].
+///
+/// # Example
+///
+/// See [`crate::node`]
+#[derive(Debug)]
+pub struct Node<'config, S: Service> {
+    id: NodeId,
+    name: NodeName,
+    config: &'config config::Config,
+    _details_storage: <S::Type<'config> as service::Details<'config>>::StaticStorage,
+}
+
+impl<'config, S: Service> Node<'config, S> {
+    /// Returns the [`NodeId`] of the [`Node`]
+    pub fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Returns the [`NodeName`] of the [`Node`]
+    pub fn name(&self) -> &NodeName {
+        &self.name
+    }
+
+    /// Returns the [`config::Config`] the [`Node`] and its services use
+    pub fn config(&self) -> &'config config::Config {
+        self.config
+    }
+
+    /// Creates a new [`Builder`] for a service with the given name. The service uses the
+    /// [`config::Config`] of the [`Node`] and all of its ports are owned by the [`Node`].
+    pub fn service_builder<'node>(&'node self, name: &ServiceName) -> Builder<'node, S> {
+        Builder::new_with_node(name, self.id, self.config)
+    }
+
+    /// Returns all nodes of the node registry, including the ones whose process is no longer
+    /// alive.
+    pub fn list() -> Result<Vec<NodeView>, NodeListFailure> {
+        Self::list_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Returns all nodes of the node registry of a given [`config::Config`], including the ones
+    /// whose process is no longer alive.
+    pub fn list_with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<Vec<NodeView>, NodeListFailure> {
+        let msg = "Unable to list all nodes";
+        let origin = "Node::list_with_custom_config()";
+        let details_config = node_details_storage_config::<S::Type<'config>>(config);
+
+        let nodes = fail!(from origin,
+                when <<S::Type<'config> as service::Details<'config>>::StaticStorage as NamedConceptMgmt>::list_cfg(&details_config),
+                map NamedConceptListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+                unmatched NodeListFailure::InternalError,
+                "{} due to a failure while reading the node registry.", msg);
+        let port_tags = list_port_tags::<S::Type<'config>>(config)?;
+
+        let mut node_vec = vec![];
+        for node in nodes {
+            let id = match extract_node_id(&node) {
+                Some(id) => id,
+                None => {
+                    warn!(from origin, "Skipping the node \"{}\" since its name is not a valid node id.", node);
+                    continue;
+                }
+            };
+
+            let details =
+                match read_storage::<S::Type<'config>, NodeDetails>(&node, &details_config) {
+                    Some(details) => details,
+                    None => continue,
+                };
+
+            node_vec.push(NodeView {
+                id,
+                name: details.name,
+                ports: port_tags
+                    .iter()
+                    .filter(|(node_id, _)| *node_id == id)
+                    .map(|(_, port)| port.clone())
+                    .collect(),
+            });
+        }
+
+        Ok(node_vec)
+    }
+
+    /// Removes all nodes whose process is no longer alive from the node registry together with
+    /// the resources their ports left behind in the services. Returns the number of removed
+    /// nodes.
+    pub fn cleanup_dead_nodes() -> Result<usize, NodeListFailure> {
+        Self::cleanup_dead_nodes_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Removes all nodes of a given [`config::Config`] whose process is no longer alive from
+    /// the node registry together with the resources their ports left behind in the services.
+    /// Returns the number of removed nodes.
+    pub fn cleanup_dead_nodes_with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<usize, NodeListFailure> {
+        let msg = "Unable to cleanup dead nodes";
+        let origin = "Node::cleanup_dead_nodes_with_custom_config()";
+        let details_config = node_details_storage_config::<S::Type<'config>>(config);
+        let tag_config = node_port_tag_config::<S::Type<'config>>(config);
+
+        let mut affected_services = vec![];
+        for (node_id, port) in list_port_tags::<S::Type<'config>>(config)? {
+            if node_id.is_owner_alive() {
+                continue;
+            }
+
+            if !affected_services.contains(&port.service_uuid) {
+                affected_services.push(port.service_uuid.clone());
+            }
+
+            remove_storage::<S::Type<'config>>(
+                &node_port_tag_name(&node_id, port.port_id),
+                &tag_config,
+            );
+        }
+
+        if !affected_services.is_empty() {
+            match <S::Type<'config> as service::Details<'config>>::list_with_custom_config(config) {
+                Ok(services) => {
+                    for static_config in services
+                        .iter()
+                        .filter(|s| affected_services.iter().any(|uuid| uuid == s.uuid()))
+                    {
+                        cleanup_service::<S::Type<'config>>(static_config, config);
+                    }
+                }
+                Err(e) => {
+                    warn!(from origin, "The resources of the ports of dead nodes are not removed since the services could not be listed ({:?}).", e);
+                }
+            }
+        }
+
+        let nodes = fail!(from origin,
+                when <<S::Type<'config> as service::Details<'config>>::StaticStorage as NamedConceptMgmt>::list_cfg(&details_config),
+                map NamedConceptListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+                unmatched NodeListFailure::InternalError,
+                "{} due to a failure while reading the node registry.", msg);
+
+        let mut number_of_dead_nodes = 0;
+        for node in nodes {
+            if let Some(id) = extract_node_id(&node) {
+                if !id.is_owner_alive()
+                    && remove_storage::<S::Type<'config>>(&node, &details_config)
+                {
+                    number_of_dead_nodes += 1;
+                }
+            }
+        }
+
+        Ok(number_of_dead_nodes)
+    }
+}
+
+/// Tags the port as owned by the [`Node`] the service was created with. The port is listed
+/// in the node registry as long as the returned tag exists. Returns [`None`] when the service
+/// was not created via a [`Node`] or the tag could not be created.
+pub(crate) fn tag_port<'config, Service: service::Details<'config>>(
+    service: &Service,
+    port_type: PortType,
+    port_id: u128,
+) -> Option<Service::StaticStorage> {
+    let node_id = service.state().node_id?;
+    let static_config = &service.state().static_config;
+
+    match write_storage::<Service, _>(
+        &node_port_tag_name(&node_id, port_id),
+        &node_port_tag_config::<Service>(service.state().global_config),
+        &NodePortDetails {
+            service_name: *static_config.service_name(),
+            service_uuid: static_config.uuid().to_string(),
+            port_type,
+        },
+    ) {
+        Ok(tag) => Some(tag),
+        Err(e) => {
+            warn!(from "tag_port()", "The {:?} port {} is not listed as port of the node {} since the port tag could not be created ({:?}).",
+                port_type, port_id, node_id.value(), e);
+            None
+        }
+    }
+}
+
+fn extract_node_id(name: &FileName) -> Option<NodeId> {
+    let value = std::str::from_utf8(name.as_bytes()).ok()?.parse().ok()?;
+    Some(NodeId(UniqueSystemId::from(value)))
+}
+
+fn list_port_tags<'config, Service: service::Details<'config>>(
+    config: &'config config::Config,
+) -> Result<Vec<(NodeId, NodePortView)>, NodeListFailure> {
+    let origin = "list_port_tags()";
+    let tag_config = node_port_tag_config::<Service>(config);
+
+    let tags = fail!(from origin,
+            when <Service::StaticStorage as NamedConceptMgmt>::list_cfg(&tag_config),
+            map NamedConceptListError::InsufficientPermissions => NodeListFailure::InsufficientPermissions,
+            unmatched NodeListFailure::InternalError,
+            "Unable to list the ports of all nodes due to a failure while reading the node registry.");
+
+    let mut port_vec = vec![];
+    for tag in tags {
+        let (node_id, port_id) = match extract_ids_from_node_port_tag_name(&tag) {
+            Some(ids) => ids,
+            None => {
+                warn!(from origin, "Skipping the port tag \"{}\" since its name does not contain a node and port id.", tag);
+                continue;
+            }
+        };
+
+        if let Some(details) = read_storage::<Service, NodePortDetails>(&tag, &tag_config) {
+            port_vec.push((
+                NodeId(UniqueSystemId::from(node_id)),
+                NodePortView {
+                    service_name: details.service_name,
+                    service_uuid: details.service_uuid,
+                    port_type: details.port_type,
+                    port_id,
+                },
+            ));
+        }
+    }
+
+    Ok(port_vec)
+}
+
+fn cleanup_service<'config, Service: service::Details<'config>>(
+    static_config: &StaticConfig,
+    config: &'config config::Config,
+) {
+    let dynamic_storage = match <<Service::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder as NamedConceptBuilder<
+                    Service::DynamicStorage,
+                >>::new(&dynamic_config_storage_name(static_config))
+                .config(&dynamic_config_storage_config::<Service>(config))
+                .has_ownership(false)
+                .open()
+    {
+        Ok(dynamic_storage) => dynamic_storage,
+        Err(e) => {
+            debug!(from "cleanup_service()", "Skip the cleanup of the service \"{}\" since it could not be opened ({:?}).",
+                static_config.service_name(), e);
+            return;
+        }
+    };
+
+    cleanup_dead_ports_of_service::<Service>(static_config, dynamic_storage.get(), config);
+}
+
+fn write_storage<'config, Service: service::Details<'config>, T: Serialize>(
+    name: &FileName,
+    config: &<Service::StaticStorage as NamedConceptMgmt>::Configuration,
+    content: &T,
+) -> Result<Service::StaticStorage, NodeCreationFailure> {
+    let msg = "Unable to write to the node registry";
+    let origin = "write_storage()";
+
+    let content = fail!(from origin, when Service::ConfigSerializer::serialize(content),
+            with NodeCreationFailure::InternalError,
+            "{} since the content of \"{}\" could not be serialized.", msg, name);
+
+    let storage = fail!(from origin,
+            when <<Service::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+                    Service::StaticStorage,
+                >>::new(name)
+                .config(config)
+                .has_ownership(true)
+                .create_locked(),
+            map StaticStorageCreateError::InsufficientPermissions => NodeCreationFailure::InsufficientPermissions,
+            unmatched NodeCreationFailure::InternalError,
+            "{} since the storage \"{}\" could not be created.", msg, name);
+
+    Ok(fail!(from origin, when storage.unlock(content.as_slice()),
+            map StaticStorageUnlockError::InsufficientPermissions => NodeCreationFailure::InsufficientPermissions,
+            unmatched NodeCreationFailure::InternalError,
+            "{} since the content could not be written to the storage \"{}\".", msg, name))
+}
+
+fn read_storage<'config, Service: service::Details<'config>, T: DeserializeOwned>(
+    name: &FileName,
+    config: &<Service::StaticStorage as NamedConceptMgmt>::Configuration,
+) -> Option<T> {
+    let origin = "read_storage()";
+
+    // the owner may remove the storage at any time, therefore this is not an error
+    let reader = match <<Service::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        Service::StaticStorage,
+    >>::new(name)
+    .config(config)
+    .has_ownership(false)
+    .open()
+    {
+        Ok(reader) => reader,
+        Err(e) => {
+            debug!(from origin, "Unable to open \"{}\" of the node registry ({:?}).", name, e);
+            return None;
+        }
+    };
+
+    let mut content = vec![0u8; reader.len() as usize];
+    if let Err(e) = reader.read(content.as_mut_slice()) {
+        debug!(from origin, "Unable to read \"{}\" of the node registry ({:?}).", name, e);
+        return None;
+    }
+
+    match Service::ConfigSerializer::deserialize::<T>(&content) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            warn!(from origin, "Unable to deserialize \"{}\" of the node registry ({:?}).", name, e);
+            None
+        }
+    }
+}
+
+fn remove_storage<'config, Service: service::Details<'config>>(
+    name: &FileName,
+    config: &<Service::StaticStorage as NamedConceptMgmt>::Configuration,
+) -> bool {
+    match unsafe { <Service::StaticStorage as NamedConceptMgmt>::remove_cfg(name, config) } {
+        Ok(has_removed) => has_removed,
+        Err(e) => {
+            warn!(from "remove_storage()", "Unable to remove \"{}\" from the node registry ({:?}).", name, e);
+            false
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! // a fixed size payload
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! // a dynamically sized payload with up to 128 elements
+//! # let service_name = ServiceName::new("My/Funk/ServiceName2")?;
+//! let slice_service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .max_slice_len(128)
+//!     .open_or_create::<[u64]>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fatal_panic;
+
+use crate::message::Message;
+
+/// Every type that can be transmitted via
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
+/// It is implemented for every fixed size type `T` and for slices `[T]`. A slice payload can
+/// contain up to [`crate::service::static_config::publish_subscribe::StaticConfig::max_slice_len()`]
+/// elements and every [`crate::sample::Sample`] knows the actual number of its elements.
+pub trait PayloadType: Debug + details::PayloadTypeDetails {}
+
+impl<T: Debug> PayloadType for T {}
+
+impl<T: Debug> PayloadType for [T] {}
+
+pub(crate) mod details {
+    use super::*;
+
+    pub trait PayloadTypeDetails {
+        /// Returns the layout of a single payload element. For fixed size payloads it is the
+        /// layout of the whole payload, for slices it is the layout of one slice element.
+        fn element_layout() -> Layout;
+
+        /// Returns true when the payload is a slice, otherwise false.
+        fn is_slice() -> bool;
+
+        /// Returns the layout of a single chunk that can hold the header and up to
+        /// `max_number_of_elements` elements.
+        fn message_layout<Header>(max_number_of_elements: usize) -> Layout;
+
+        /// Creates the pointer to the message that is stored at `address` and contains
+        /// `number_of_elements` elements.
+        ///
+        /// # Safety
+        ///
+        /// * `address` must point to a chunk that was allocated with the layout of
+        ///   [`PayloadTypeDetails::message_layout()`]
+        /// * `number_of_elements` must not exceed the maximum number of elements of the layout
+        unsafe fn message_ptr<Header>(
+            address: usize,
+            number_of_elements: usize,
+        ) -> *mut Message<Header, Self>;
+    }
+
+    impl<T> PayloadTypeDetails for T {
+        fn element_layout() -> Layout {
+            Layout::new::<T>()
+        }
+
+        fn is_slice() -> bool {
+            false
+        }
+
+        fn message_layout<Header>(_max_number_of_elements: usize) -> Layout {
+            Layout::new::<Message<Header, T>>()
+        }
+
+        unsafe fn message_ptr<Header>(
+            address: usize,
+            _number_of_elements: usize,
+        ) -> *mut Message<Header, Self> {
+            address as *mut Message<Header, T>
+        }
+    }
+
+    impl<T> PayloadTypeDetails for [T] {
+        fn element_layout() -> Layout {
+            Layout::new::<T>()
+        }
+
+        fn is_slice() -> bool {
+            true
+        }
+
+        fn message_layout<Header>(max_number_of_elements: usize) -> Layout {
+            let layout = Layout::array::<T>(max_number_of_elements)
+                .and_then(|payload| Layout::new::<Header>().extend(payload));
+
+            fatal_panic!(from "PayloadTypeDetails::message_layout()", when layout,
+                "The slice with {} elements of type \"{}\" exceeds the maximum supported memory size.",
+                max_number_of_elements, std::any::type_name::<T>())
+            .0
+            .pad_to_align()
+        }
+
+        unsafe fn message_ptr<Header>(
+            address: usize,
+            number_of_elements: usize,
+        ) -> *mut Message<Header, Self> {
+            std::ptr::slice_from_raw_parts_mut(address as *mut T, number_of_elements)
+                as *mut Message<Header, [T]>
+        }
+    }
+}
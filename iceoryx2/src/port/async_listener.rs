@@ -0,0 +1,139 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```ignore
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::async_listener::AsyncListener;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let event_name = ServiceName::new("MyEventName")?;
+//! let event = zero_copy::Service::new(&event_name)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! // see [`iceoryx2::reactor`] for an example of a reactor
+//! let mut listener = AsyncListener::new(event.listener().create()?, &TokioReactor)?;
+//!
+//! loop {
+//!     for event_id in listener.wait().await? {
+//!         println!("event was triggered with id: {:?}", event_id);
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::future::poll_fn;
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_cal::event::ListenerWaitError;
+
+use super::event_id::EventId;
+use super::listener::Listener;
+use crate::reactor::{Reactor, Readiness};
+use crate::service;
+
+/// Defines the failures that can occur when an [`AsyncListener`] is created with
+/// [`AsyncListener::new()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncListenerCreateError {
+    UnableToRegisterAtReactor,
+}
+
+impl std::fmt::Display for AsyncListenerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AsyncListenerCreateError {}
+
+enum_gen! {
+    /// Defines the failures that can occur when the [`AsyncListener`] waits for new
+    /// [`EventId`]s with [`AsyncListener::wait()`].
+    AsyncListenerWaitError
+  entry:
+    ReactorFailure
+  mapping:
+    ListenerWaitError
+}
+
+impl std::fmt::Display for AsyncListenerWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AsyncListenerWaitError {}
+
+/// A [`Listener`] whose file descriptor is registered at the [`Reactor`] of an async executor.
+/// Waiting for new [`EventId`]s suspends the task instead of blocking the thread.
+pub struct AsyncListener<'a, 'config: 'a, Service: service::Details<'config>, R: Reactor> {
+    // declared first so that the file descriptor is removed from the reactor before the
+    // listener closes it
+    readiness: R::Readiness,
+    listener: Listener<'a, 'config, Service>,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, R: Reactor> std::fmt::Debug
+    for AsyncListener<'a, 'config, Service, R>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AsyncListener {{ listener: {:?} }}", self.listener)
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, R: Reactor>
+    AsyncListener<'a, 'config, Service, R>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    /// Registers the file descriptor of the [`Listener`] at the [`Reactor`].
+    pub fn new(
+        listener: Listener<'a, 'config, Service>,
+        reactor: &R,
+    ) -> Result<Self, AsyncListenerCreateError> {
+        let file_descriptor = unsafe { listener.file_descriptor().native_handle() };
+        let readiness = fail!(from "AsyncListener::new()", when reactor.register(file_descriptor),
+                with AsyncListenerCreateError::UnableToRegisterAtReactor,
+                "Unable to create async listener since the file descriptor {} could not be registered at the reactor.",
+                file_descriptor);
+
+        Ok(Self {
+            readiness,
+            listener,
+        })
+    }
+
+    /// Returns a reference to the underlying [`Listener`].
+    pub fn listener(&self) -> &Listener<'a, 'config, Service> {
+        &self.listener
+    }
+
+    /// Waits until at least one [`EventId`] was received without blocking the thread. The
+    /// returned slice contains all [`EventId`]s that were notified since the last call, see
+    /// [`Listener::cache()`]. On error it returns [`AsyncListenerWaitError`] which describes
+    /// the error in detail.
+    pub async fn wait(&mut self) -> Result<&[EventId], AsyncListenerWaitError> {
+        while self.listener.try_wait()?.is_empty() {
+            let readiness = &self.readiness;
+            fail!(from "AsyncListener::wait()", when poll_fn(|cx| readiness.poll_readable(cx)).await,
+                with AsyncListenerWaitError::ReactorFailure,
+                "Unable to wait for new events since the reactor failed.");
+        }
+
+        Ok(self.listener.cache())
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```ignore
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::async_subscriber::AsyncSubscriber;
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .enable_notifications(true)
+//!     .open_or_create::<u64>()?;
+//!
+//! // see [`iceoryx2::reactor`] for an example of a reactor
+//! let subscriber = AsyncSubscriber::new(service.subscriber().create()?, &TokioReactor)?;
+//!
+//! loop {
+//!     let sample = subscriber.receive().await?;
+//!     println!("received: {:?}", *sample);
+//! }
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::future::poll_fn;
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+
+use super::event_id::EventId;
+use super::subscriber::{ReceiveError, Subscriber};
+use crate::payload_type::PayloadType;
+use crate::reactor::{Reactor, Readiness};
+use crate::sample::Sample;
+use crate::service;
+use crate::service::header::publish_subscribe::Header;
+
+/// Defines the failures that can occur when an [`AsyncSubscriber`] is created with
+/// [`AsyncSubscriber::new()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AsyncSubscriberCreateError {
+    NotificationsNotEnabled,
+    UnableToRegisterAtReactor,
+}
+
+impl std::fmt::Display for AsyncSubscriberCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AsyncSubscriberCreateError {}
+
+enum_gen! {
+    /// Defines the failures that can occur when the [`AsyncSubscriber`] waits for new data
+    /// with [`AsyncSubscriber::receive()`].
+    AsyncReceiveError
+  entry:
+    ReactorFailure
+  mapping:
+    ReceiveError
+}
+
+impl std::fmt::Display for AsyncReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for AsyncReceiveError {}
+
+/// A [`Subscriber`] whose notifications are registered at the [`Reactor`] of an async
+/// executor. Waiting for new [`Sample`]s suspends the task instead of blocking the thread.
+/// It requires a service with
+/// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+pub struct AsyncSubscriber<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+    R: Reactor,
+> {
+    // declared first so that the file descriptor is removed from the reactor before the
+    // subscriber closes it
+    readiness: R::Readiness,
+    subscriber: Subscriber<'a, 'config, Service, MessageType>,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        R: Reactor,
+    > Debug for AsyncSubscriber<'a, 'config, Service, MessageType, R>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AsyncSubscriber {{ subscriber: {:?} }}", self.subscriber)
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        MessageType: PayloadType + ?Sized,
+        R: Reactor,
+    > AsyncSubscriber<'a, 'config, Service, MessageType, R>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    /// Registers the notifications of the [`Subscriber`] at the [`Reactor`].
+    pub fn new(
+        subscriber: Subscriber<'a, 'config, Service, MessageType>,
+        reactor: &R,
+    ) -> Result<Self, AsyncSubscriberCreateError> {
+        let msg = "Unable to create async subscriber";
+        let origin = "AsyncSubscriber::new()";
+        let file_descriptor = match subscriber.notification_file_descriptor() {
+            Some(fd) => unsafe { fd.native_handle() },
+            None => {
+                fail!(from origin, with AsyncSubscriberCreateError::NotificationsNotEnabled,
+                    "{} since the service does not support notifications.", msg);
+            }
+        };
+
+        let readiness = fail!(from origin, when reactor.register(file_descriptor),
+                with AsyncSubscriberCreateError::UnableToRegisterAtReactor,
+                "{} since the file descriptor {} could not be registered at the reactor.",
+                msg, file_descriptor);
+
+        Ok(Self {
+            readiness,
+            subscriber,
+        })
+    }
+
+    /// Returns a reference to the underlying [`Subscriber`].
+    pub fn subscriber(&self) -> &Subscriber<'a, 'config, Service, MessageType> {
+        &self.subscriber
+    }
+
+    /// Waits until a [`Sample`] was received without blocking the thread. On error it
+    /// returns [`AsyncReceiveError`] which describes the error in detail.
+    pub async fn receive<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>, AsyncReceiveError>
+    {
+        loop {
+            if let Some(sample) = self.subscriber.receive()? {
+                return Ok(sample);
+            }
+
+            fail!(from self, when poll_fn(|cx| self.readiness.poll_readable(cx)).await,
+                with AsyncReceiveError::ReactorFailure,
+                "Unable to receive a sample since the reactor failed.");
+        }
+    }
+}
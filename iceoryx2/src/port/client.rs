@@ -0,0 +1,609 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let client = service
+//!     .client()
+//!     // defines how many requests can be loaned in parallel
+//!     .max_loaned_requests(5)
+//!     .create()?;
+//!
+//! // loan some uninitialized memory and send it as request to all connected servers
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1337);
+//! client.send(request)?;
+//!
+//! // send a copy of the value
+//! client.send_copy(313)?;
+//!
+//! // receive the responses of the servers
+//! while let Some(response) = client.receive()? {
+//!     println!("received response {:?} for request {}",
+//!         *response, response.header().request_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+
+use super::details::publisher_connections::ConnectionFailure;
+use super::details::server_connections::{Connection, ServerConnections};
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::publisher::LoanError;
+use super::subscriber::ReceiveError;
+use crate::config;
+use crate::message::Message;
+use crate::node::{tag_port, PortType};
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::request_mut::RequestMut;
+use crate::response::Response;
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::client_data_segment_name;
+use crate::service::port_factory::client::LocalClientConfig;
+use crate::service::static_config::request_response;
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopyReleaseError,
+    ZeroCopySendError, ZeroCopySender,
+};
+
+/// Defines a failure that can occur when a [`Client`] is created with
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    ExceedsMaxSupportedClients,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`crate::request_mut::RequestMut`] is sent via [`Client::send_copy()`].
+    RequestSendCopyError
+  mapping:
+    LoanError to LoanError,
+    ConnectionFailure to ConnectionError
+}
+
+impl std::fmt::Display for RequestSendCopyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestSendCopyError {}
+
+/// Sending endpoint of a request-response based communication. Sends
+/// [`crate::request_mut::RequestMut`]s to all connected [`crate::port::server::Server`]s and
+/// receives their [`crate::response::Response`]s.
+#[derive(Debug)]
+pub struct Client<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    port_id: UniqueClientId,
+    pub(crate) request_reference_counter: Vec<AtomicU64>,
+    pub(crate) data_segment: Service::SharedMemory,
+    config: LocalClientConfig,
+
+    server_connections: ServerConnections<'config, Service>,
+    server_list_state: UnsafeCell<ContainerState<'a, UniqueServerId>>,
+    pub(crate) loan_counter: AtomicUsize,
+    request_id_counter: AtomicU64,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _node_port_tag: Option<Service::StaticStorage>,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Client<'a, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &request_response::StaticConfig,
+        config: &LocalClientConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let port_id = UniqueClientId::new();
+        let server_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests =
+            static_config.required_amount_of_requests_per_data_segment(config.max_loaned_requests);
+
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_requests),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let _dynamic_config_guard = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(port_id)
+        {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                            "{} since it would exceed the maximum supported amount of clients of {}.",
+                            msg, static_config.max_clients);
+            }
+        };
+
+        let new_self = Self {
+            port_id,
+            server_connections: ServerConnections::new(
+                server_list.capacity(),
+                port_id,
+                service.state().global_config,
+                static_config,
+                number_of_requests,
+                std::mem::size_of::<Message<RequestHeader, RequestType>>(),
+            ),
+            data_segment,
+            config: *config,
+            request_reference_counter: {
+                let mut v = Vec::with_capacity(number_of_requests);
+                for _ in 0..number_of_requests {
+                    v.push(AtomicU64::new(0));
+                }
+                v
+            },
+            server_list_state: unsafe { UnsafeCell::new(server_list.get_state()) },
+            loan_counter: AtomicUsize::new(0),
+            request_id_counter: AtomicU64::new(0),
+            _dynamic_config_guard,
+            _node_port_tag: tag_port(service, PortType::Client, port_id.value()),
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.capacity(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|index, server_id| {
+                visited_indices[index as usize] = Some(*server_id);
+            })
+        };
+
+        // retrieve requests before destroying channel
+        self.retrieve_returned_requests();
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(server_id) => {
+                    if let Err(e) = self.server_connections.create(i, *server_id) {
+                        warn!(from self, "Unable to establish connection to new server {:?}.", server_id);
+                        result = Err(e);
+                    }
+                }
+                None => self.server_connections.remove(i),
+            }
+        }
+
+        result
+    }
+
+    fn request_index(distance_to_chunk: usize) -> usize {
+        distance_to_chunk / std::mem::size_of::<Message<RequestHeader, RequestType>>()
+    }
+
+    fn create_data_segment(
+        port_id: UniqueClientId,
+        global_config: &'config config::Config,
+        number_of_requests: usize,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: Layout::new::<Message<RequestHeader, RequestType>>(),
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        Ok(fail!(from "Client::create_data_segment()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&client_data_segment_name(port_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(chunk_size * number_of_requests + chunk_align - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment."))
+    }
+
+    fn send_impl(&self, address_to_chunk: usize) -> Result<usize, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Unable to send request since the connections could not be updated.");
+
+        Ok(self.deliver_request(address_to_chunk))
+    }
+
+    fn deliver_request(&self, address_to_chunk: usize) -> usize {
+        let mut number_of_recipients = 0;
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                match connection
+                    .request_sender
+                    .try_send(PointerOffset::new(address_to_chunk))
+                {
+                    Err(ZeroCopySendError::ReceiveBufferFull) => {
+                        warn!(from self, "Unable to send request to server {:?} since its buffer is full.", connection.server_id);
+                    }
+                    Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                        warn!(from self, "Unable to send request via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection);
+                    }
+                    Ok(overflow) => {
+                        self.request_reference_counter[Self::request_index(address_to_chunk)]
+                            .fetch_add(1, Ordering::Relaxed);
+                        number_of_recipients += 1;
+
+                        if let Some(old) = overflow {
+                            self.release_request(old)
+                        }
+                    }
+                }
+            }
+        }
+        number_of_recipients
+    }
+
+    pub(crate) fn release_request(&self, distance_to_chunk: PointerOffset) {
+        if self.request_reference_counter[Self::request_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment
+                .deallocate(
+                    distance_to_chunk,
+                    Layout::new::<Message<RequestHeader, RequestType>>(),
+                ), "Internal logic error. The request should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    pub(crate) fn release_response(
+        &self,
+        channel_id: usize,
+        response: RawSample<ResponseHeader, ResponseType>,
+    ) {
+        match self.server_connections.get(channel_id) {
+            Some(c) => {
+                let distance =
+                    response.as_ptr() as usize - c.data_segment.allocator_data_start_address();
+                match c.response_receiver.release(PointerOffset::new(distance)) {
+                    Ok(()) => (),
+                    Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                        fatal_panic!(from self, when c.response_receiver.release(PointerOffset::new(distance)),
+                                    "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release response since the connection is broken. The response will be discarded and has to be reclaimed manually by the server.");
+            }
+        }
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.release_request(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn receive_from_connection<'client>(
+        &'client self,
+        channel_id: usize,
+        connection: &Connection<'config, Service>,
+    ) -> Result<
+        Option<Response<'a, 'client, 'config, Service, RequestType, ResponseType>>,
+        ReceiveError,
+    > {
+        let msg = "Unable to receive another response";
+        match connection.response_receiver.receive() {
+            Ok(data) => match data {
+                None => Ok(None),
+                Some(relative_addr) => {
+                    let absolute_address = relative_addr.value()
+                        + connection.data_segment.allocator_data_start_address();
+                    Ok(Some(Response {
+                        client: self,
+                        channel_id,
+                        ptr: unsafe {
+                            RawSample::new_unchecked(
+                                absolute_address as *mut Message<ResponseHeader, ResponseType>,
+                            )
+                        },
+                    }))
+                }
+            },
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
+                    "{} since it would exceed the maximum {} of borrowed responses.",
+                    msg, connection.response_receiver.max_borrowed_samples());
+            }
+        }
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.port_id
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::server::Server`]s. This is
+    /// required to be called whenever a new [`crate::port::server::Server`] connected to
+    /// the service. It is done implicitly whenever [`Client::send()`], [`Client::send_copy()`]
+    /// or [`Client::receive()`] is called.
+    pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.server_list_state.get()).update() } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a Server port failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Send a previously loaned [`Client::loan_uninit()`] [`RequestMut`] to all connected
+    /// [`crate::port::server::Server`]s of the service.
+    ///
+    /// The payload of the [`RequestMut`] must be initialized before it can be sent. Have a look
+    /// at [`RequestMut::write_payload()`] and [`RequestMut::assume_init()`] for more details.
+    ///
+    /// On success the number of [`crate::port::server::Server`]s that received
+    /// the request is returned, otherwise a [`ConnectionFailure`] describing the failure.
+    pub fn send<'client>(
+        &'client self,
+        request: RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType>,
+    ) -> Result<usize, ConnectionFailure> {
+        Ok(
+            fail!(from self, when self.send_impl(request.offset_to_chunk().value()),
+            "Unable to send request since the underlying send failed."),
+        )
+    }
+
+    /// Copies the input `value` into a [`RequestMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::server::Server`]s that received
+    /// the request, otherwise a [`RequestSendCopyError`] describing the failure.
+    pub fn send_copy(&self, value: RequestType) -> Result<usize, RequestSendCopyError> {
+        let msg = "Unable to send copy of request";
+        let mut request = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a request failed.", msg);
+
+        request.payload_mut().write(value);
+        Ok(
+            fail!(from self, when self.send_impl(request.offset_to_chunk().value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`].
+    /// Every loaned request acquires a new request id which is stored in its
+    /// [`RequestHeader`]. The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(42); // alternatively `request.payload_mut()` can be use to access the `MaybeUninit<RequestType>`
+    ///
+    /// client.send(request)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit<'client>(
+        &'client self,
+    ) -> Result<
+        RequestMut<'a, 'client, 'config, Service, MaybeUninit<RequestType>, ResponseType>,
+        LoanError,
+    > {
+        self.retrieve_returned_requests();
+        let msg = "Unable to loan Request";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_requests {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} requests were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned request to loan another request.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_requests);
+        }
+
+        match self
+            .data_segment
+            .allocate(Layout::new::<Message<RequestHeader, RequestType>>())
+        {
+            Ok(chunk) => {
+                if self.request_reference_counter[Self::request_index(chunk.offset.value())]
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated request is already in use! This should never happen!", msg);
+                }
+
+                let message = chunk.data_ptr
+                    as *mut MaybeUninit<Message<RequestHeader, MaybeUninit<RequestType>>>;
+
+                let request_id = self.request_id_counter.fetch_add(1, Ordering::Relaxed);
+                let request = unsafe {
+                    (*message).write(Message {
+                        header: RequestHeader::new(self.port_id, request_id),
+                        data: MaybeUninit::uninit(),
+                    });
+                    RawSampleMut::new_unchecked(
+                        message as *mut Message<RequestHeader, MaybeUninit<RequestType>>,
+                    )
+                };
+
+                Ok(RequestMut::new(self, request, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    /// Receives a [`Response`] from a [`crate::port::server::Server`]. Only responses to
+    /// requests of this [`Client`] are received. If no response could be
+    /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive<'client>(
+        &'client self,
+    ) -> Result<
+        Option<Response<'a, 'client, 'config, Service, RequestType, ResponseType>>,
+        ReceiveError,
+    > {
+        if let Err(e) = self.update_connections() {
+            fail!(from self,
+                with ReceiveError::ConnectionFailure(e),
+                "Some responses are not being received since not all connections to servers could be established.");
+        }
+
+        for id in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(id) {
+                if let Some(response) = self.receive_from_connection(id, connection)? {
+                    return Ok(Some(response));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestType: Default + Debug,
+        ResponseType: Debug,
+    > Client<'a, 'config, Service, RequestType, ResponseType>
+{
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`]
+    /// and initialize it with the default value. This can be a performance hit and [`Client::loan_uninit`]
+    /// can be used to loan a [`core::mem::MaybeUninit<RequestType>`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 42;
+    ///
+    /// client.send(request)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan<'client>(
+        &'client self,
+    ) -> Result<RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType>, LoanError>
+    {
+        Ok(self.loan_uninit()?.write_payload(RequestType::default()))
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let consumer = service.consumer().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
+
+use crate::node::{tag_port, PortType};
+use crate::service::static_config::pipeline::StaticConfig;
+use crate::{
+    message::Message, raw_sample::RawSample, service, service::header::pipeline::Header,
+    work_item::WorkItem,
+};
+
+use super::details::producer_connections::{Connection, ProducerConnections};
+use super::details::publisher_connections::ConnectionFailure;
+use super::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use super::subscriber::ReceiveError;
+
+/// Describes the failures when a new [`Consumer`] is created via the
+/// [`crate::service::port_factory::consumer::PortFactoryConsumer`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConsumerCreateError {
+    ExceedsMaxSupportedConsumers,
+}
+
+impl std::fmt::Display for ConsumerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ConsumerCreateError {}
+
+/// Receiving endpoint of a pipeline based communication. Every
+/// [`crate::work_item::WorkItem`] it receives was delivered to this [`Consumer`] only.
+#[derive(Debug)]
+pub struct Consumer<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+    dynamic_config_guard: Option<UniqueIndex<'a>>,
+    _node_port_tag: Option<Service::StaticStorage>,
+    producer_connections: ProducerConnections<'config, Service>,
+    producer_list_state: UnsafeCell<ContainerState<'a, UniqueProducerId>>,
+    port_id: UniqueConsumerId,
+    _phantom_message_type: PhantomData<MessageType>,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+    Consumer<'a, 'config, Service, MessageType>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &StaticConfig,
+    ) -> Result<Self, ConsumerCreateError> {
+        let msg = "Failed to create Consumer port";
+        let origin = "Consumer::new()";
+        let port_id = UniqueConsumerId::new();
+
+        let producer_list = &service.state().dynamic_storage.get().pipeline().producers;
+
+        let mut new_self = Self {
+            producer_connections: ProducerConnections::new(
+                producer_list.capacity(),
+                port_id,
+                service.state().global_config,
+                static_config,
+            ),
+            producer_list_state: UnsafeCell::new(unsafe { producer_list.get_state() }),
+            dynamic_config_guard: None,
+            _node_port_tag: tag_port(service, PortType::Consumer, port_id.value()),
+            port_id,
+            _phantom_message_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_producer_channels() {
+            warn!(from new_self, "The new consumer is unable to connect to every producer, caused by {:?}.", e);
+        }
+
+        // !MUST! be the last task otherwise a consumer is added to the dynamic config without
+        // the creation of all required channels
+        new_self.dynamic_config_guard = Some(
+            match service
+                .state()
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .add_consumer_id(port_id)
+            {
+                Some(unique_index) => unique_index,
+                None => {
+                    fail!(from origin, with ConsumerCreateError::ExceedsMaxSupportedConsumers,
+                                "{} since it would exceed the maximum supported amount of consumers of {}.",
+                                msg, static_config.max_consumers);
+                }
+            },
+        );
+
+        Ok(new_self)
+    }
+
+    pub(crate) fn release_work_item(
+        &self,
+        channel_id: usize,
+        work_item: RawSample<Header, MessageType>,
+    ) {
+        match self.producer_connections.get(channel_id) {
+            Some(c) => {
+                let distance =
+                    work_item.as_ptr() as usize - c.data_segment.allocator_data_start_address();
+                match c.receiver.release(PointerOffset::new(distance)) {
+                    Ok(()) => (),
+                    Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                        fatal_panic!(from self, when c.receiver.release(PointerOffset::new(distance)),
+                                    "This should never happen! The producers retrieve channel is full and the work item cannot be returned.");
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release work item since the connection is broken. The work item will be discarded and has to be reclaimed manually by the producer.");
+            }
+        }
+    }
+
+    fn populate_producer_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.producer_connections.capacity(), None);
+
+        unsafe {
+            (*self.producer_list_state.get()).for_each(|index, producer_id| {
+                visited_indices[index as usize] = Some(*producer_id);
+            })
+        };
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(producer_id) => {
+                    if let Err(e) = self.producer_connections.create(i, *producer_id) {
+                        warn!(from self, "Unable to establish connection to new producer {:?}.", producer_id);
+                        result = Err(e);
+                    }
+                }
+                None => self.producer_connections.remove(i),
+            }
+        }
+
+        result
+    }
+
+    fn receive_from_connection<'consumer>(
+        &'consumer self,
+        channel_id: usize,
+        connection: &Connection<'config, Service>,
+    ) -> Result<Option<WorkItem<'a, 'consumer, 'config, Service, MessageType>>, ReceiveError> {
+        let msg = "Unable to receive another work item";
+        match connection.receiver.receive() {
+            Ok(data) => match data {
+                None => Ok(None),
+                Some(relative_addr) => {
+                    let absolute_address = relative_addr.value()
+                        + connection.data_segment.allocator_data_start_address();
+                    Ok(Some(WorkItem {
+                        consumer: self,
+                        channel_id,
+                        ptr: unsafe {
+                            RawSample::new_unchecked(
+                                absolute_address as *mut Message<Header, MessageType>,
+                            )
+                        },
+                    }))
+                }
+            },
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
+                    "{} since it would exceed the maximum {} of borrowed work items.",
+                    msg, connection.receiver.max_borrowed_samples());
+            }
+        }
+    }
+
+    /// Returns the [`UniqueConsumerId`] of the [`Consumer`]
+    pub fn id(&self) -> UniqueConsumerId {
+        self.port_id
+    }
+
+    /// Receives a [`WorkItem`] from a [`crate::port::producer::Producer`]. If no work item
+    /// could be received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive<'consumer>(
+        &'consumer self,
+    ) -> Result<Option<WorkItem<'a, 'consumer, 'config, Service, MessageType>>, ReceiveError> {
+        if let Err(e) = self.update_connections() {
+            fail!(from self,
+                with ReceiveError::ConnectionFailure(e),
+                "Some work items are not being received since not all connections to producers could be established.");
+        }
+
+        for id in 0..self.producer_connections.len() {
+            if let Some(ref connection) = self.producer_connections.get(id) {
+                if let Some(work_item) = self.receive_from_connection(id, connection)? {
+                    return Ok(Some(work_item));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::producer::Producer`]s. This is
+    /// required to be called whenever a new [`crate::port::producer::Producer`] connected to
+    /// the service. It is done implicitly whenever [`Consumer::receive()`] is called.
+    pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.producer_list_state.get()).update() } {
+            fail!(from self, when self.populate_producer_channels(),
+                "Connections were updated only partially since at least one connection to a producer failed.");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fmt::Debug;
+
+/// The number of message bytes a single [`CopyFrame`] can transfer.
+pub const COPY_FRAME_DATA_SIZE: usize = 4064;
+
+/// A fixed size fragment of a message that is transferred via the
+/// [`crate::service::Details::CopyChannel`] when a service uses
+/// [`crate::service::builder::publish_subscribe::Builder::enable_copy_transfer()`].
+/// Messages that are larger than [`COPY_FRAME_DATA_SIZE`] are split into multiple frames which
+/// are reassembled by the [`crate::port::subscriber::Subscriber`].
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct CopyFrame {
+    pub(crate) publisher_id: u128,
+    pub(crate) sequence_number: u64,
+    pub(crate) message_size: u32,
+    pub(crate) offset: u32,
+    pub(crate) data: [u8; COPY_FRAME_DATA_SIZE],
+}
+
+impl Debug for CopyFrame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CopyFrame {{ publisher_id: {}, sequence_number: {}, message_size: {}, offset: {} }}",
+            self.publisher_id, self.sequence_number, self.message_size, self.offset
+        )
+    }
+}
+
+impl CopyFrame {
+    pub(crate) fn new(
+        publisher_id: u128,
+        sequence_number: u64,
+        message: &[u8],
+        offset: usize,
+    ) -> Self {
+        let len = std::cmp::min(COPY_FRAME_DATA_SIZE, message.len() - offset);
+        let mut data = [0u8; COPY_FRAME_DATA_SIZE];
+        data[..len].copy_from_slice(&message[offset..offset + len]);
+
+        Self {
+            publisher_id,
+            sequence_number,
+            message_size: message.len() as u32,
+            offset: offset as u32,
+            data,
+        }
+    }
+
+    /// Returns the number of message bytes that are stored in the frame.
+    pub(crate) fn data_len(&self) -> usize {
+        std::cmp::min(
+            COPY_FRAME_DATA_SIZE,
+            (self.message_size as usize).saturating_sub(self.offset as usize),
+        )
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::cell::UnsafeCell;
+
+use crate::{
+    config,
+    port::port_identifiers::{UniqueClientId, UniqueServerId},
+    service::{
+        self,
+        config_scheme::{connection_config, data_segment_config},
+        naming_scheme::{
+            client_data_segment_name, request_connection_name, response_connection_name,
+        },
+        static_config::request_response::StaticConfig,
+    },
+};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::{
+    shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
+    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
+};
+
+use super::publisher_connections::ConnectionFailure;
+
+#[derive(Debug)]
+pub(crate) struct Connection<'config, Service: service::Details<'config>> {
+    pub(crate) request_receiver:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) response_sender:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) client_id: UniqueClientId,
+}
+
+impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
+    fn new(
+        this: &ClientConnections<'config, Service>,
+        client_id: UniqueClientId,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to client {:?} from server {:?}.",
+            client_id, this.server_id
+        );
+
+        let request_receiver = fail!(from this,
+                        when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                            Builder::new( &request_connection_name(client_id, this.server_id))
+                                    .config(&connection_config::<Service>(this.config))
+                                    .buffer_size(this.static_config.server_max_buffer_size)
+                                    .receiver_max_borrowed_samples(this.static_config.server_max_borrowed_requests)
+                                    .enable_safe_overflow(false)
+                                    .create_receiver(),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_sender = fail!(from this,
+                        when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                            Builder::new( &response_connection_name(this.server_id, client_id))
+                                    .config(&connection_config::<Service>(this.config))
+                                    .buffer_size(this.static_config.client_max_buffer_size)
+                                    .receiver_max_borrowed_samples(this.static_config.client_max_borrowed_responses)
+                                    .enable_safe_overflow(false)
+                                    .number_of_samples(this.number_of_samples)
+                                    .sample_size(this.sample_size)
+                                    .create_sender(),
+                        "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from this,
+                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                                Builder::new(&client_data_segment_name(client_id))
+                                .config(&data_segment_config::<Service>(this.config))
+                                .open(),
+                            "{} since the clients data segment could not be mapped into the process.", msg);
+
+        Ok(Self {
+            request_receiver,
+            response_sender,
+            data_segment,
+            client_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientConnections<'config, Service: service::Details<'config>> {
+    connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    server_id: UniqueServerId,
+    config: &'config config::Config,
+    static_config: StaticConfig,
+    number_of_samples: usize,
+    sample_size: usize,
+}
+
+impl<'config, Service: service::Details<'config>> ClientConnections<'config, Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        server_id: UniqueServerId,
+        config: &'config config::Config,
+        static_config: &StaticConfig,
+        number_of_samples: usize,
+        sample_size: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            server_id,
+            config,
+            number_of_samples,
+            sample_size,
+            static_config: static_config.clone(),
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Connection<'config, Service>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Connection<'config, Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        client_id: UniqueClientId,
+    ) -> Result<(), ConnectionFailure> {
+        if self.get(index).is_none() {
+            *self.get_mut(index) = Some(Connection::new(self, client_id)?);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::cell::UnsafeCell;
+
+use crate::{
+    config,
+    port::port_identifiers::{UniqueConsumerId, UniqueProducerId},
+    service::{
+        self, config_scheme::connection_config, naming_scheme::pipeline_connection_name,
+        static_config::pipeline::StaticConfig,
+    },
+};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::zero_copy_connection::*;
+
+#[derive(Debug)]
+pub(crate) struct Connection<'config, Service: service::Details<'config>> {
+    pub(crate) sender:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) consumer_id: UniqueConsumerId,
+}
+
+impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
+    fn new(
+        this: &ConsumerConnections<'config, Service>,
+        consumer_id: UniqueConsumerId,
+    ) -> Result<Self, ZeroCopyCreationError> {
+        let sender = fail!(from this,
+                        when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                            Builder::new( &pipeline_connection_name(this.producer_id, consumer_id))
+                                    .config(&connection_config::<Service>(this.config))
+                                    .buffer_size(this.static_config.consumer_max_buffer_size)
+                                    .receiver_max_borrowed_samples(this.static_config.consumer_max_borrowed_samples)
+                                    .enable_safe_overflow(false)
+                                    .number_of_samples(this.number_of_samples)
+                                    .sample_size(this.sample_size)
+                                    .create_sender(),
+                        "Unable to establish connection to consumer {:?} from producer {:?}.",
+                        consumer_id, this.producer_id);
+
+        Ok(Self {
+            sender,
+            consumer_id,
+        })
+    }
+
+    /// Returns true if the process that owns the [`crate::port::consumer::Consumer`] is
+    /// still alive, otherwise false.
+    pub(crate) fn is_consumer_alive(&self) -> bool {
+        self.consumer_id.is_owner_alive()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ConsumerConnections<'config, Service: service::Details<'config>> {
+    connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    dead_consumers: Vec<UnsafeCell<Option<UniqueConsumerId>>>,
+    producer_id: UniqueProducerId,
+    config: &'config config::Config,
+    static_config: StaticConfig,
+    number_of_samples: usize,
+    sample_size: usize,
+}
+
+impl<'config, Service: service::Details<'config>> ConsumerConnections<'config, Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        producer_id: UniqueProducerId,
+        config: &'config config::Config,
+        static_config: &StaticConfig,
+        number_of_samples: usize,
+        sample_size: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            dead_consumers: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            producer_id,
+            config,
+            number_of_samples,
+            sample_size,
+            static_config: static_config.clone(),
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Connection<'config, Service>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Connection<'config, Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        consumer_id: UniqueConsumerId,
+    ) -> Result<(), ZeroCopyCreationError> {
+        if unsafe { *self.dead_consumers[index].get() } == Some(consumer_id) {
+            return Ok(());
+        }
+
+        if self.get(index).is_none() {
+            *self.get_mut(index) = Some(Connection::new(self, consumer_id)?);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+        unsafe { *self.dead_consumers[index].get() = None };
+    }
+
+    /// Removes the connection to a [`crate::port::consumer::Consumer`] whose process died.
+    /// The connection is not reestablished until the consumer is removed from the service.
+    pub(crate) fn remove_dead(&self, index: usize) {
+        if let Some(connection) = self.get_mut(index).take() {
+            unsafe { *self.dead_consumers[index].get() = Some(connection.consumer_id) };
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
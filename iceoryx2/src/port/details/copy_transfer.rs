@@ -0,0 +1,299 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::communication_channel::*;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+
+use crate::{
+    config,
+    port::copy_frame::{CopyFrame, COPY_FRAME_DATA_SIZE},
+    port::port_identifiers::UniqueSubscriberId,
+    service,
+    service::{
+        config_scheme::copy_channel_config, header::publish_subscribe::Header,
+        naming_scheme::copy_channel_name, port_factory::publisher::UnableToDeliverStrategy,
+        static_config::publish_subscribe::StaticConfig,
+    },
+};
+
+/// Returns the number of [`CopyFrame`]s that are required to transfer a message of the given
+/// size.
+pub(crate) fn number_of_frames(message_size: usize) -> usize {
+    std::cmp::max(
+        1,
+        (message_size + COPY_FRAME_DATA_SIZE - 1) / COPY_FRAME_DATA_SIZE,
+    )
+}
+
+/// Sends the message as a sequence of [`CopyFrame`]s. Returns true when the whole message was
+/// delivered and false when it was discarded since the buffer of the receiver was full.
+pub(crate) fn send_message<Sender: CommunicationChannelSender<CopyFrame>>(
+    sender: &Sender,
+    publisher_id: u128,
+    sequence_number: u64,
+    message: &[u8],
+    strategy: UnableToDeliverStrategy,
+) -> Result<bool, CommunicationChannelSendError> {
+    let msg = "Unable to send copy of message";
+    let mut adaptive_wait = None;
+    let mut offset = 0;
+
+    while offset < message.len() {
+        let frame = CopyFrame::new(publisher_id, sequence_number, message, offset);
+        match sender.try_send(&frame) {
+            Ok(_) => offset += frame.data_len(),
+            Err(CommunicationChannelSendError::ReceiverCacheIsFull) => match strategy {
+                // an incomplete message is discarded by the receiver
+                UnableToDeliverStrategy::DiscardSample => return Ok(false),
+                UnableToDeliverStrategy::Block => {
+                    if adaptive_wait.is_none() {
+                        adaptive_wait = Some(fail!(from "copy_transfer::send_message()",
+                            when AdaptiveWaitBuilder::new().create(),
+                            with CommunicationChannelSendError::InternalFailure,
+                            "{} since the adaptive wait could not be created.", msg));
+                    }
+
+                    if let Some(adaptive_wait) = &mut adaptive_wait {
+                        fail!(from "copy_transfer::send_message()", when adaptive_wait.wait(),
+                            with CommunicationChannelSendError::InternalFailure,
+                            "{} since the wait for a free receive buffer failed.", msg);
+                    }
+                }
+            },
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(true)
+}
+
+#[derive(Debug)]
+struct PendingMessage {
+    publisher_id: u128,
+    sequence_number: u64,
+    message_size: usize,
+    received_bytes: usize,
+    message: *mut u8,
+}
+
+// the message buffer is owned exclusively by the pending message
+unsafe impl Send for PendingMessage {}
+
+#[derive(Debug)]
+struct CompletedMessage(*mut u8);
+
+// the message buffer is owned exclusively by the completed message
+unsafe impl Send for CompletedMessage {}
+
+/// The receiving side of the copy transfer. Every [`crate::port::subscriber::Subscriber`] of a
+/// service with copy transfer owns one channel that all publishers send their
+/// [`CopyFrame`]s to. The frames are reassembled into messages that are stored in
+/// heap memory owned by the subscriber, so that no publisher can ever modify a received message.
+#[derive(Debug)]
+pub(crate) struct CopyReceiver<'config, Service: service::Details<'config>> {
+    receiver: <Service::CopyChannel as CommunicationChannel<CopyFrame>>::Receiver,
+    message_layout: Layout,
+    max_pending_messages: usize,
+    pending_messages: RefCell<Vec<PendingMessage>>,
+    max_completed_messages: usize,
+    completed_messages: RefCell<Vec<CompletedMessage>>,
+}
+
+impl<'config, Service: service::Details<'config>> CopyReceiver<'config, Service> {
+    pub(crate) fn create(
+        subscriber_id: UniqueSubscriberId,
+        config: &'config config::Config,
+        static_config: &StaticConfig,
+    ) -> Result<Self, CommunicationChannelCreateError> {
+        let (message_layout, _) = static_config.message_layout::<Header>();
+        let max_messages = std::cmp::max(static_config.subscriber_max_buffer_size, 1)
+            * std::cmp::max(static_config.max_publishers, 1);
+        let buffer_size = max_messages * number_of_frames(message_layout.size());
+
+        let receiver = fail!(from "CopyReceiver::create()",
+                when <Service::CopyChannel as CommunicationChannel<CopyFrame>>::Creator::new(&copy_channel_name(subscriber_id))
+                    .config(&copy_channel_config::<Service>(config))
+                    .buffer_size(buffer_size)
+                    .create_receiver(),
+                "Unable to create the copy channel of the subscriber {:?}.", subscriber_id);
+
+        Ok(Self {
+            receiver,
+            message_layout,
+            max_pending_messages: std::cmp::max(static_config.max_publishers, 1),
+            pending_messages: RefCell::new(vec![]),
+            max_completed_messages: max_messages,
+            completed_messages: RefCell::new(vec![]),
+        })
+    }
+
+    /// Receives frames until a message is complete. The returned message is owned by the
+    /// caller and must be released with [`CopyReceiver::release()`].
+    pub(crate) fn receive(&self) -> Result<Option<*mut u8>, CommunicationChannelReceiveError> {
+        while let Some(frame) = self.receiver.receive()? {
+            if let Some(message) = self.add_frame(&frame) {
+                return Ok(Some(message));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Receives all available messages and returns the one that precedes all others. The
+    /// remaining messages are kept for the next call. The returned message is owned by the
+    /// caller and must be released with [`CopyReceiver::release()`].
+    pub(crate) fn receive_ordered<F: Fn(&Header, &Header) -> bool>(
+        &self,
+        precedes: F,
+    ) -> Result<Option<*mut u8>, CommunicationChannelReceiveError> {
+        let mut completed_messages = self.completed_messages.borrow_mut();
+
+        // the remaining frames stay in the channel so that the publishers cannot exhaust the
+        // memory of a subscriber that receives slower than they send
+        while completed_messages.len() < self.max_completed_messages {
+            match self.receiver.receive()? {
+                Some(frame) => {
+                    if let Some(message) = self.add_frame(&frame) {
+                        completed_messages.push(CompletedMessage(message));
+                    }
+                }
+                None => break,
+            }
+        }
+
+        let header = |index: usize| unsafe { &*(completed_messages[index].0 as *const Header) };
+        let mut preferred = None;
+        for index in 0..completed_messages.len() {
+            match preferred {
+                Some(p) if !precedes(header(index), header(p)) => (),
+                _ => preferred = Some(index),
+            }
+        }
+
+        Ok(preferred.map(|index| completed_messages.remove(index).0))
+    }
+
+    /// Releases a message that was acquired with [`CopyReceiver::receive()`] or
+    /// [`CopyReceiver::receive_ordered()`].
+    ///
+    /// # Safety
+    ///
+    ///  * the message must be acquired from this instance
+    ///  * the message must not be used after this call
+    pub(crate) unsafe fn release(&self, message: *const u8) {
+        std::alloc::dealloc(message as *mut u8, self.message_layout);
+    }
+
+    fn discard(&self, pending_message: PendingMessage) {
+        unsafe { self.release(pending_message.message) };
+    }
+
+    fn add_frame(&self, frame: &CopyFrame) -> Option<*mut u8> {
+        let message_size = frame.message_size as usize;
+        let offset = frame.offset as usize;
+        let mut pending_messages = self.pending_messages.borrow_mut();
+        let index = pending_messages
+            .iter()
+            .position(|m| m.publisher_id == frame.publisher_id);
+
+        if message_size < std::mem::size_of::<Header>()
+            || self.message_layout.size() < message_size
+            || message_size <= offset
+        {
+            warn!(from self, "Discarding the corrupted frame {:?} since it does not fit into a message of the service.", frame);
+            if let Some(i) = index {
+                self.discard(pending_messages.swap_remove(i));
+            }
+            return None;
+        }
+
+        let index = match index {
+            Some(i)
+                if offset != 0
+                    && pending_messages[i].sequence_number == frame.sequence_number
+                    && pending_messages[i].message_size == message_size
+                    && pending_messages[i].received_bytes == offset =>
+            {
+                i
+            }
+            // the frames of the message are incomplete, the remaining ones are useless
+            Some(i) => {
+                self.discard(pending_messages.swap_remove(i));
+                if offset != 0 {
+                    return None;
+                }
+                self.start_message(&mut pending_messages, frame)
+            }
+            None if offset == 0 => self.start_message(&mut pending_messages, frame),
+            None => return None,
+        };
+
+        let pending_message = &mut pending_messages[index];
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                frame.data.as_ptr(),
+                pending_message.message.add(offset),
+                frame.data_len(),
+            )
+        };
+        pending_message.received_bytes += frame.data_len();
+
+        if pending_message.received_bytes == message_size {
+            Some(pending_messages.swap_remove(index).message)
+        } else {
+            None
+        }
+    }
+
+    fn start_message(
+        &self,
+        pending_messages: &mut Vec<PendingMessage>,
+        frame: &CopyFrame,
+    ) -> usize {
+        // a publisher that sends frames with arbitrary ids shall not be able to exhaust the memory
+        if self.max_pending_messages <= pending_messages.len() {
+            self.discard(pending_messages.remove(0));
+        }
+
+        let message = unsafe { std::alloc::alloc_zeroed(self.message_layout) };
+        if message.is_null() {
+            std::alloc::handle_alloc_error(self.message_layout);
+        }
+
+        pending_messages.push(PendingMessage {
+            publisher_id: frame.publisher_id,
+            sequence_number: frame.sequence_number,
+            message_size: frame.message_size as usize,
+            received_bytes: 0,
+            message,
+        });
+
+        pending_messages.len() - 1
+    }
+}
+
+impl<'config, Service: service::Details<'config>> Drop for CopyReceiver<'config, Service> {
+    fn drop(&mut self) {
+        for pending_message in self.pending_messages.get_mut().drain(..) {
+            unsafe { std::alloc::dealloc(pending_message.message, self.message_layout) };
+        }
+
+        for message in self.completed_messages.get_mut().drain(..) {
+            unsafe { std::alloc::dealloc(message.0, self.message_layout) };
+        }
+    }
+}
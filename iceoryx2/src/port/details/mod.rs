@@ -10,5 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub(crate) mod client_connections;
+pub(crate) mod consumer_connections;
+pub(crate) mod copy_transfer;
+pub(crate) mod producer_connections;
 pub(crate) mod publisher_connections;
+pub(crate) mod server_connections;
 pub(crate) mod subscriber_connections;
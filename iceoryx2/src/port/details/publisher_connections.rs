@@ -27,7 +27,7 @@ use crate::{
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::named_concept::{AccessRights, NamedConceptBuilder, NamedConceptConfiguration};
 use iceoryx2_cal::{
     shared_memory::SharedMemory,
     shared_memory::{SharedMemoryBuilder, SharedMemoryOpenError},
@@ -69,7 +69,7 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
         let receiver = fail!(from this,
                         when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
                             Builder::new( &connection_name(publisher_id, this.subscriber_id))
-                                    .config(&connection_config::<Service>(this.config))
+                                    .config(&connection_config::<Service>(this.config).access_rights(this.access_rights))
                                     .buffer_size(this.static_config.subscriber_max_buffer_size)
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                     .enable_safe_overflow(this.static_config.enable_safe_overflow)
@@ -80,7 +80,7 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
                             when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
                                 Builder::new(&data_segment_name(publisher_id))
                                 .config(&data_segment_config::<Service>(this.config))
-                                .open(),
+                                .open_read_only(),
                             "{} since the publishers data segment could not be mapped into the process.", msg);
 
         Ok(Self {
@@ -95,6 +95,7 @@ pub(crate) struct PublisherConnections<'config, Service: service::Details<'confi
     subscriber_id: UniqueSubscriberId,
     config: &'config config::Config,
     static_config: StaticConfig,
+    access_rights: AccessRights,
 }
 
 impl<'config, Service: service::Details<'config>> PublisherConnections<'config, Service> {
@@ -103,12 +104,14 @@ impl<'config, Service: service::Details<'config>> PublisherConnections<'config,
         subscriber_id: UniqueSubscriberId,
         config: &'config config::Config,
         static_config: &StaticConfig,
+        access_rights: AccessRights,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             subscriber_id,
             config,
             static_config: static_config.clone(),
+            access_rights,
         }
     }
 
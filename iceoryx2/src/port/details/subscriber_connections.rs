@@ -12,24 +12,42 @@
 
 use std::cell::UnsafeCell;
 
-use iceoryx2_bb_log::fail;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::communication_channel::{CommunicationChannel, CommunicationChannelConnector};
+use iceoryx2_cal::event::NotifierBuilder;
+use iceoryx2_cal::named_concept::{AccessRights, NamedConceptBuilder, NamedConceptConfiguration};
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
-use crate::service::config_scheme::connection_config;
+use crate::service::config_scheme::{connection_config, copy_channel_config};
 use crate::{
     config,
+    port::copy_frame::CopyFrame,
+    port::event_id::EventId,
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service,
-    service::{naming_scheme::connection_name, static_config::publish_subscribe::StaticConfig},
+    service::{
+        naming_scheme::{connection_name, copy_channel_name, subscriber_event_concept_name},
+        static_config::publish_subscribe::StaticConfig,
+    },
 };
 
+/// The channel a [`crate::port::publisher::Publisher`] uses to deliver samples to a
+/// [`crate::port::subscriber::Subscriber`].
+#[derive(Debug)]
+pub(crate) enum SampleSender<'config, Service: service::Details<'config>> {
+    /// Sends the offsets of the samples in the publishers data segment
+    ZeroCopy(<<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender),
+    /// Sends copies of the samples
+    Copy(<Service::CopyChannel as CommunicationChannel<CopyFrame>>::Sender),
+}
+
 #[derive(Debug)]
 pub(crate) struct Connection<'config, Service: service::Details<'config>> {
-    pub(crate) sender:
-        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) sender: SampleSender<'config, Service>,
+    pub(crate) notifier: Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Notifier>,
+    pub(crate) subscriber_id: UniqueSubscriberId,
 }
 
 impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
@@ -37,26 +55,72 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
         this: &SubscriberConnections<'config, Service>,
         subscriber_id: UniqueSubscriberId,
     ) -> Result<Self, ZeroCopyCreationError> {
-        let sender = fail!(from this, when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+        let sender = match this.static_config.enable_copy_transfer {
+            true => SampleSender::Copy(fail!(from this,
+                        when <Service::CopyChannel as CommunicationChannel<CopyFrame>>::
+                            Connector::new(&copy_channel_name(subscriber_id))
+                                .config(&copy_channel_config::<Service>(this.config))
+                                .open_sender(),
+                        with ZeroCopyCreationError::InternalError,
+                        "Unable to open the copy channel of subscriber {:?} from publisher {:?}.",
+                        subscriber_id, this.port_id)),
+            false => SampleSender::ZeroCopy(
+                fail!(from this, when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.port_id, subscriber_id))
-                                .config(&connection_config::<Service>(this.config))
+                                .config(&connection_config::<Service>(this.config).access_rights(this.access_rights))
                                 .buffer_size(this.static_config.subscriber_max_buffer_size)
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                 .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                .number_of_samples(this.number_of_samples)
+                                .sample_size(this.sample_size)
                                 .create_sender(),
                         "Unable to establish connection to subscriber {:?} from publisher {:?}.",
-                        subscriber_id, this.port_id);
+                        subscriber_id, this.port_id),
+            ),
+        };
+
+        let notifier = match this.static_config.enable_notifications {
+            false => None,
+            true => {
+                match <Service::Event as iceoryx2_cal::event::Event<EventId>>::NotifierBuilder::new(
+                    &subscriber_event_concept_name(&subscriber_id),
+                )
+                .open()
+                {
+                    Ok(notifier) => Some(notifier),
+                    Err(e) => {
+                        warn!(from this, "Unable to establish notification channel to subscriber {:?} ({:?}). The subscriber receives samples but will not be woken up.",
+                            subscriber_id, e);
+                        None
+                    }
+                }
+            }
+        };
+
+        Ok(Self {
+            sender,
+            notifier,
+            subscriber_id,
+        })
+    }
 
-        Ok(Self { sender })
+    /// Returns true if the process that owns the [`crate::port::subscriber::Subscriber`] is
+    /// still alive, otherwise false.
+    pub(crate) fn is_subscriber_alive(&self) -> bool {
+        self.subscriber_id.is_owner_alive()
     }
 }
 
 #[derive(Debug)]
 pub(crate) struct SubscriberConnections<'config, Service: service::Details<'config>> {
     connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    dead_subscribers: Vec<UnsafeCell<Option<UniqueSubscriberId>>>,
     port_id: UniquePublisherId,
     config: &'config config::Config,
     static_config: StaticConfig,
+    access_rights: AccessRights,
+    number_of_samples: usize,
+    sample_size: usize,
 }
 
 impl<'config, Service: service::Details<'config>> SubscriberConnections<'config, Service> {
@@ -65,12 +129,19 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         config: &'config config::Config,
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
+        access_rights: AccessRights,
+        number_of_samples: usize,
+        sample_size: usize,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            dead_subscribers: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             config,
             port_id,
+            number_of_samples,
+            sample_size,
             static_config: static_config.clone(),
+            access_rights,
         }
     }
 
@@ -88,7 +159,16 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
     }
 
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None
+        *self.get_mut(index) = None;
+        unsafe { *self.dead_subscribers[index].get() = None };
+    }
+
+    /// Removes the connection to a [`crate::port::subscriber::Subscriber`] whose process died.
+    /// The connection is not reestablished until the subscriber is removed from the service.
+    pub(crate) fn remove_dead(&self, index: usize) {
+        if let Some(connection) = self.get_mut(index).take() {
+            unsafe { *self.dead_subscribers[index].get() = Some(connection.subscriber_id) };
+        }
     }
 
     pub(crate) fn create(
@@ -96,6 +176,10 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         index: usize,
         subscriber_id: UniqueSubscriberId,
     ) -> Result<bool, ZeroCopyCreationError> {
+        if unsafe { *self.dead_subscribers[index].get() } == Some(subscriber_id) {
+            return Ok(false);
+        }
+
         if self.get(index).is_none() {
             *self.get_mut(index) = Some(Connection::new(self, subscriber_id)?);
             Ok(true)
@@ -32,10 +32,16 @@
 
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError};
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+};
 
+use crate::node::{tag_port, PortType};
+use crate::service::access_control::{Operation, Resource};
 use crate::service::naming_scheme::event_concept_name;
 use crate::{port::port_identifiers::UniqueListenerId, service};
 use std::{marker::PhantomData, time::Duration};
@@ -48,6 +54,7 @@ use super::event_id::EventId;
 pub enum ListenerCreateError {
     ExceedsMaxSupportedListeners,
     ResourceCreationFailed,
+    PermissionDenied,
 }
 
 impl std::fmt::Display for ListenerCreateError {
@@ -62,6 +69,7 @@ impl std::error::Error for ListenerCreateError {}
 #[derive(Debug)]
 pub struct Listener<'a, 'config: 'a, Service: service::Details<'config>> {
     _dynamic_config_guard: Option<UniqueIndex<'a>>,
+    _node_port_tag: Option<Service::StaticStorage>,
     listener: <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener,
     cache: Vec<EventId>,
     _phantom_a: PhantomData<&'a Service>,
@@ -72,16 +80,38 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
     pub(crate) fn new(service: &'a Service) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
+
+        if !service
+            .state()
+            .static_config
+            .access_control()
+            .is_permitted(Operation::Listen)
+        {
+            fail!(from origin, with ListenerCreateError::PermissionDenied,
+                "{} since the process is not permitted to listen on the service.", msg);
+        }
+
         let port_id = UniqueListenerId::new();
 
         let event_name = event_concept_name(&port_id);
+        let event_config = <Service::Event as NamedConceptMgmt>::Configuration::default()
+            .access_rights(
+                service
+                    .state()
+                    .static_config
+                    .access_control()
+                    .access_rights(Resource::EventConnection),
+            );
         let listener = fail!(from origin,
-                             when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name).create(),
+                             when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name)
+                                .config(&event_config)
+                                .create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
 
         let mut new_self = Self {
             _dynamic_config_guard: None,
+            _node_port_tag: tag_port(service, PortType::Listener, port_id.value()),
             listener,
             cache: vec![],
             _phantom_a: PhantomData,
@@ -159,6 +189,25 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
         Ok(self.cache())
     }
 
+    /// Non-blocking wait for new [`EventId`]s. Instead of filling the cache, every received
+    /// [`EventId`] is handed to the provided callback. Since it does not require a mutable
+    /// reference it can be used while the [`Listener`] is attached to a
+    /// [`crate::waitset::WaitSet`].
+    pub fn try_wait_all<F: FnMut(EventId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        while let Some(id) = fail!(from self,
+                when self.listener.try_wait(),
+                "Failed to try_wait_all on Listener port since the underlying Listener concept failed.")
+        {
+            callback(id);
+        }
+
+        Ok(())
+    }
+
     /// Blocking wait for new [`EventId`]s until either an [`EventId`].
     /// Sporadic wakeups can occur and if no [`EventId`]s were notified the returned slice
     /// is empty. On error it returns [`ListenerWaitError`] is returned which describes the error
@@ -178,3 +227,20 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
         Ok(self.cache())
     }
 }
+
+impl<'a, 'config: 'a, Service: service::Details<'config>> FileDescriptorBased
+    for Listener<'a, 'config, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>> SynchronousMultiplexing
+    for Listener<'a, 'config, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener: SynchronousMultiplexing,
+{
+}
@@ -16,6 +16,20 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Receiving endpoint (port) for event based communication that can be awaited in an async
+/// executor
+#[cfg(feature = "async")]
+pub mod async_listener;
+/// Receiving endpoint (port) for publish-subscribe based communication that can be awaited in
+/// an async executor
+#[cfg(feature = "async")]
+pub mod async_subscriber;
+/// Sending endpoint (port) for request-response based communication
+pub mod client;
+/// Receiving endpoint (port) for pipeline based communication
+pub mod consumer;
+/// Defines the frame that transfers copies of samples in services with copy transfer.
+pub mod copy_frame;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
@@ -24,10 +38,24 @@ pub mod listener;
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
+/// Sending endpoint (port) for pipeline based communication
+pub mod producer;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Reading endpoint (port) for blackboard based communication
+pub mod reader;
+/// Receiving endpoint (port) for request-response based communication
+pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
+/// Sending endpoint (port) for publish-subscribe based communication that does not know the
+/// payload type
+pub mod type_erased_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication that does not know the
+/// payload type
+pub mod type_erased_subscriber;
+/// Writing endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
 use crate::service;
@@ -35,6 +35,8 @@
 //! # }
 //! ```
 
+use crate::node::{tag_port, PortType};
+use crate::service::access_control::Operation;
 use crate::{
     port::port_identifiers::UniqueNotifierId,
     service::{self, naming_scheme::event_concept_name},
@@ -52,6 +54,7 @@ use super::{event_id::EventId, port_identifiers::UniqueListenerId};
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum NotifierCreateError {
     ExceedsMaxSupportedNotifiers,
+    PermissionDenied,
 }
 
 impl std::fmt::Display for NotifierCreateError {
@@ -140,6 +143,7 @@ pub struct Notifier<'a, 'config: 'a, Service: service::Details<'config>> {
     listener_list_state: UnsafeCell<ContainerState<'a, UniqueListenerId>>,
     default_event_id: EventId,
     _dynamic_config_guard: Option<UniqueIndex<'a>>,
+    _node_port_tag: Option<Service::StaticStorage>,
     _phantom_a: PhantomData<&'a Service>,
     _phantom_b: PhantomData<&'config ()>,
 }
@@ -151,6 +155,17 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
     ) -> Result<Self, NotifierCreateError> {
         let msg = "Unable to create Notifier port";
         let origin = "Notifier::new()";
+
+        if !service
+            .state()
+            .static_config
+            .access_control()
+            .is_permitted(Operation::Notify)
+        {
+            fail!(from origin, with NotifierCreateError::PermissionDenied,
+                "{} since the process is not permitted to notify on the service.", msg);
+        }
+
         let port_id = UniqueNotifierId::new();
 
         let listener_list = &service.state().dynamic_storage.get().event().listeners;
@@ -160,6 +175,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
             default_event_id,
             listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
             _dynamic_config_guard: None,
+            _node_port_tag: tag_port(service, PortType::Notifier, port_id.value()),
             _phantom_a: PhantomData,
             _phantom_b: PhantomData,
         };
@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::process::Process;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 
 macro_rules! generate_id {
@@ -31,6 +32,16 @@ macro_rules! generate_id {
             pub fn new() -> Self {
                 Self::default()
             }
+
+            /// Returns the underlying value of the system wide unique id
+            pub fn value(&self) -> u128 {
+                self.0.value()
+            }
+
+            /// Returns true if the process that owns the port is still alive, otherwise false.
+            pub(crate) fn is_owner_alive(&self) -> bool {
+                Process::from_pid(self.0.pid()).is_alive()
+            }
         }
     };
 }
@@ -39,3 +50,9 @@ generate_id! { UniquePublisherId }
 generate_id! { UniqueSubscriberId }
 generate_id! { UniqueNotifierId }
 generate_id! { UniqueListenerId }
+generate_id! { UniqueClientId }
+generate_id! { UniqueServerId }
+generate_id! { UniqueReaderId }
+generate_id! { UniqueWriterId }
+generate_id! { UniqueProducerId }
+generate_id! { UniqueConsumerId }
@@ -0,0 +1,653 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::port_factory::producer::DistributionStrategy;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let producer = service
+//!     .producer()
+//!     // defines how many work items can be loaned in parallel
+//!     .max_loaned_samples(5)
+//!     // defines which consumer receives the next work item
+//!     .distribution_strategy(DistributionStrategy::RoundRobin)
+//!     .create()?;
+//!
+//! let consumer = service.consumer().create()?;
+//!
+//! // loan some uninitialized memory and send it to exactly one consumer
+//! let work_item = producer.loan_uninit()?;
+//! let work_item = work_item.write_payload(1337);
+//! let receiver = producer.send(work_item)?;
+//! println!("work item was delivered to {:?}", receiver);
+//!
+//! // send a copy of the value
+//! producer.send_copy(313)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::{Cell, UnsafeCell};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+
+use super::details::consumer_connections::ConsumerConnections;
+use super::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use super::publisher::LoanError;
+use crate::config;
+use crate::message::Message;
+use crate::node::{tag_port, PortType};
+use crate::raw_sample::RawSampleMut;
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+use crate::service::header::pipeline::Header;
+use crate::service::naming_scheme::producer_data_segment_name;
+use crate::service::port_factory::producer::{DistributionStrategy, LocalProducerConfig};
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::static_config::pipeline;
+use crate::work_item_mut::WorkItemMut;
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{
+    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
+};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
+};
+
+/// Defines a failure that can occur when a [`Producer`] is created with
+/// [`crate::service::port_factory::producer::PortFactoryProducer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ProducerCreateError {
+    ExceedsMaxSupportedProducers,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ProducerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerCreateError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`crate::work_item_mut::WorkItemMut`] is sent via
+    /// [`Producer::send()`].
+    ProducerSendError
+  entry:
+    NoConsumerConnected,
+    AllConsumersAreBusy,
+    InternalFailure
+  mapping:
+    ZeroCopyCreationError to ConnectionError
+}
+
+impl std::fmt::Display for ProducerSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerSendError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`crate::work_item_mut::WorkItemMut`] is sent via
+    /// [`Producer::send_copy()`].
+    ProducerSendCopyError
+  mapping:
+    LoanError to LoanError,
+    ProducerSendError to SendError
+}
+
+impl std::fmt::Display for ProducerSendCopyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerSendCopyError {}
+
+/// Sending endpoint of a pipeline based communication. Every
+/// [`crate::work_item_mut::WorkItemMut`] is delivered to exactly one of the connected
+/// [`crate::port::consumer::Consumer`]s, selected by the configured [`DistributionStrategy`].
+#[derive(Debug)]
+pub struct Producer<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+    port_id: UniqueProducerId,
+    pub(crate) sample_reference_counter: Vec<AtomicU64>,
+    pub(crate) data_segment: Service::SharedMemory,
+    config: LocalProducerConfig,
+
+    consumer_connections: ConsumerConnections<'config, Service>,
+    consumer_list_state: UnsafeCell<ContainerState<'a, UniqueConsumerId>>,
+    next_consumer: Cell<usize>,
+    pub(crate) loan_counter: AtomicUsize,
+    work_item_id_counter: AtomicU64,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _node_port_tag: Option<Service::StaticStorage>,
+    _phantom_message_type: PhantomData<MessageType>,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+    Producer<'a, 'config, Service, MessageType>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &pipeline::StaticConfig,
+        config: &LocalProducerConfig,
+    ) -> Result<Self, ProducerCreateError> {
+        let msg = "Unable to create Producer port";
+        let origin = "Producer::new()";
+        let port_id = UniqueProducerId::new();
+        let consumer_list = &service.state().dynamic_storage.get().pipeline().consumers;
+
+        let number_of_samples =
+            static_config.required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_samples),
+                with ProducerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a producer is added to the dynamic config without
+        // the creation of all required resources
+        let _dynamic_config_guard = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_producer_id(port_id)
+        {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ProducerCreateError::ExceedsMaxSupportedProducers,
+                            "{} since it would exceed the maximum supported amount of producers of {}.",
+                            msg, static_config.max_producers);
+            }
+        };
+
+        let new_self = Self {
+            port_id,
+            consumer_connections: ConsumerConnections::new(
+                consumer_list.capacity(),
+                port_id,
+                service.state().global_config,
+                static_config,
+                number_of_samples,
+                std::mem::size_of::<Message<Header, MessageType>>(),
+            ),
+            data_segment,
+            config: *config,
+            sample_reference_counter: {
+                let mut v = Vec::with_capacity(number_of_samples);
+                for _ in 0..number_of_samples {
+                    v.push(AtomicU64::new(0));
+                }
+                v
+            },
+            consumer_list_state: unsafe { UnsafeCell::new(consumer_list.get_state()) },
+            next_consumer: Cell::new(0),
+            loan_counter: AtomicUsize::new(0),
+            work_item_id_counter: AtomicU64::new(0),
+            _dynamic_config_guard,
+            _node_port_tag: tag_port(service, PortType::Producer, port_id.value()),
+            _phantom_message_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_consumer_channels() {
+            warn!(from new_self, "The new Producer port is unable to connect to every Consumer port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn populate_consumer_channels(&self) -> Result<(), ZeroCopyCreationError> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.consumer_connections.capacity(), None);
+
+        unsafe {
+            (*self.consumer_list_state.get()).for_each(|index, consumer_id| {
+                visited_indices[index as usize] = Some(*consumer_id);
+            })
+        };
+
+        // retrieve samples before destroying channel
+        self.retrieve_returned_samples();
+
+        let mut orphaned_samples = vec![];
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(consumer_id) => {
+                    // the consumer was replaced by another one before the connections were
+                    // updated
+                    let is_replaced = self
+                        .consumer_connections
+                        .get(i)
+                        .as_ref()
+                        .map_or(false, |c| c.consumer_id != *consumer_id);
+                    if is_replaced {
+                        self.acquire_undelivered_samples(i, &mut orphaned_samples);
+                        self.consumer_connections.remove(i);
+                    }
+
+                    if let Err(e) = self.consumer_connections.create(i, *consumer_id) {
+                        warn!(from self, "Unable to establish connection to new consumer {:?}.", consumer_id);
+                        result = Err(e);
+                    }
+                }
+                None => {
+                    self.acquire_undelivered_samples(i, &mut orphaned_samples);
+                    self.consumer_connections.remove(i);
+                }
+            }
+        }
+
+        self.hand_over_samples(orphaned_samples);
+
+        result
+    }
+
+    fn sample_index(distance_to_chunk: usize) -> usize {
+        distance_to_chunk / std::mem::size_of::<Message<Header, MessageType>>()
+    }
+
+    fn create_data_segment(
+        port_id: UniqueProducerId,
+        global_config: &'config config::Config,
+        number_of_samples: usize,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: Layout::new::<Message<Header, MessageType>>(),
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        Ok(fail!(from "Producer::create_data_segment()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&producer_data_segment_name(port_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(chunk_size * number_of_samples + chunk_align - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment."))
+    }
+
+    /// Collects all samples that were sent to the consumer of the connection but were not
+    /// yet returned. Every collected sample keeps the reference of the connection.
+    fn acquire_undelivered_samples(&self, index: usize, samples: &mut Vec<PointerOffset>) {
+        if let Some(connection) = self.consumer_connections.get(index) {
+            unsafe {
+                connection
+                    .sender
+                    .acquire_used_offsets(|offset| samples.push(offset))
+            };
+        }
+    }
+
+    /// Delivers samples whose consumer is gone to the remaining consumers. The reference of
+    /// the old connection is transferred to the new one, samples that cannot be delivered
+    /// are released.
+    fn hand_over_samples(&self, samples: Vec<PointerOffset>) {
+        for offset in samples {
+            if self.try_deliver(offset.value()).is_none() {
+                warn!(from self, "Unable to hand over the work item {:?} of a disconnected consumer since no other consumer is able to receive it. The work item is discarded.", offset);
+                self.release_sample(offset);
+            }
+        }
+    }
+
+    fn has_connected_consumers(&self) -> bool {
+        (0..self.consumer_connections.len()).any(|i| self.consumer_connections.get(i).is_some())
+    }
+
+    fn try_deliver(&self, address_to_chunk: usize) -> Option<UniqueConsumerId> {
+        let number_of_connections = self.consumer_connections.len();
+        let start = match self.config.distribution_strategy {
+            DistributionStrategy::RoundRobin => self.next_consumer.get(),
+            DistributionStrategy::FirstFree => 0,
+        };
+
+        for n in 0..number_of_connections {
+            let i = (start + n) % number_of_connections;
+            if let Some(ref connection) = self.consumer_connections.get(i) {
+                match connection
+                    .sender
+                    .try_send(PointerOffset::new(address_to_chunk))
+                {
+                    Ok(_) => {
+                        self.next_consumer.set((i + 1) % number_of_connections);
+                        return Some(connection.consumer_id);
+                    }
+                    Err(ZeroCopySendError::ReceiveBufferFull) => (),
+                    Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                        warn!(from self, "Unable to send work item via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn send_impl(&self, address_to_chunk: usize) -> Result<UniqueConsumerId, ProducerSendError> {
+        let msg = "Unable to send work item";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        let mut adaptive_wait = None;
+        loop {
+            if !self.has_connected_consumers() {
+                fail!(from self, with ProducerSendError::NoConsumerConnected,
+                    "{} since no consumer is connected.", msg);
+            }
+
+            if let Some(consumer_id) = self.try_deliver(address_to_chunk) {
+                self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                    .fetch_add(1, Ordering::Relaxed);
+                return Ok(consumer_id);
+            }
+
+            match self.config.unable_to_deliver_strategy {
+                UnableToDeliverStrategy::DiscardSample => {
+                    fail!(from self, with ProducerSendError::AllConsumersAreBusy,
+                        "{} since the buffers of all consumers are full.", msg);
+                }
+                UnableToDeliverStrategy::Block => {
+                    if adaptive_wait.is_none() {
+                        adaptive_wait =
+                            Some(fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                            with ProducerSendError::InternalFailure,
+                            "{} since the adaptive wait could not be created.", msg));
+                    }
+
+                    if let Some(adaptive_wait) = &mut adaptive_wait {
+                        fail!(from self, when adaptive_wait.wait(),
+                            with ProducerSendError::InternalFailure,
+                            "{} since the wait for a free consumer buffer failed.", msg);
+                    }
+
+                    // consumers may leave or die while we are waiting for them
+                    self.retrieve_returned_samples();
+                    self.recover_samples_from_dead_consumers();
+                    fail!(from self, when self.update_connections(),
+                        "{} since the connections could not be updated.", msg);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn release_sample(&self, distance_to_chunk: PointerOffset) {
+        if self.sample_reference_counter[Self::sample_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment
+                .deallocate(
+                    distance_to_chunk,
+                    Layout::new::<Message<Header, MessageType>>(),
+                ), "Internal logic error. The work item should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    fn retrieve_returned_samples(&self) {
+        for i in 0..self.consumer_connections.len() {
+            if let Some(ref connection) = self.consumer_connections.get(i) {
+                loop {
+                    match connection.sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.release_sample(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim work items from connection {:?} due to {:?}. This may lead to a situation where no more work items will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`]
+    pub fn id(&self) -> UniqueProducerId {
+        self.port_id
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::consumer::Consumer`]s. This is
+    /// required to be called whenever a new [`crate::port::consumer::Consumer`] connected to
+    /// the service. It is done implicitly whenever [`Producer::send()`] or
+    /// [`Producer::send_copy()`] is called.
+    /// Work items that were delivered to a [`crate::port::consumer::Consumer`] that
+    /// disconnected before receiving them are handed over to the remaining consumers.
+    pub fn update_connections(&self) -> Result<(), ZeroCopyCreationError> {
+        if unsafe { (*self.consumer_list_state.get()).update() } {
+            fail!(from self, when self.populate_consumer_channels(),
+                "Connections were updated only partially since at least one connection to a Consumer port failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the processes of all connected [`crate::port::consumer::Consumer`]s are still
+    /// alive. All work items that were delivered to a dead consumer but not yet received are
+    /// handed over to the remaining consumers. Returns the number of recovered work items.
+    ///
+    /// It is called implicitly when the [`Producer`] is out of memory or blocks since the
+    /// buffers of all consumers are full.
+    pub fn recover_samples_from_dead_consumers(&self) -> usize {
+        let mut orphaned_samples = vec![];
+        for i in 0..self.consumer_connections.len() {
+            let dead_consumer = match self.consumer_connections.get(i) {
+                Some(connection) if !connection.is_consumer_alive() => Some(connection.consumer_id),
+                _ => None,
+            };
+
+            if let Some(consumer_id) = dead_consumer {
+                warn!(from self, "The consumer {:?} died. All work items that were delivered to it are handed over to the remaining consumers.", consumer_id);
+                self.acquire_undelivered_samples(i, &mut orphaned_samples);
+                self.consumer_connections.remove_dead(i);
+            }
+        }
+
+        let number_of_recovered_samples = orphaned_samples.len();
+        self.hand_over_samples(orphaned_samples);
+        number_of_recovered_samples
+    }
+
+    /// Sends a previously loaned [`Producer::loan_uninit()`] [`WorkItemMut`] to exactly one
+    /// connected [`crate::port::consumer::Consumer`] of the service.
+    ///
+    /// The payload of the [`WorkItemMut`] must be initialized before it can be sent. Have a look
+    /// at [`WorkItemMut::write_payload()`] and [`WorkItemMut::assume_init()`] for more details.
+    ///
+    /// On success the [`UniqueConsumerId`] of the [`crate::port::consumer::Consumer`] that
+    /// received the work item is returned, otherwise a [`ProducerSendError`] describing the
+    /// failure.
+    pub fn send<'producer>(
+        &'producer self,
+        work_item: WorkItemMut<'a, 'producer, 'config, Service, MessageType>,
+    ) -> Result<UniqueConsumerId, ProducerSendError> {
+        Ok(
+            fail!(from self, when self.send_impl(work_item.offset_to_chunk().value()),
+            "Unable to send work item since the underlying send failed."),
+        )
+    }
+
+    /// Copies the input `value` into a [`WorkItemMut`] and delivers it.
+    /// On success it returns the [`UniqueConsumerId`] of the
+    /// [`crate::port::consumer::Consumer`] that received the work item, otherwise a
+    /// [`ProducerSendCopyError`] describing the failure.
+    pub fn send_copy(&self, value: MessageType) -> Result<UniqueConsumerId, ProducerSendCopyError> {
+        let msg = "Unable to send copy of work item";
+        let mut work_item = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a work item failed.", msg);
+
+        work_item.payload_mut().write(value);
+        Ok(
+            fail!(from self, when self.send_impl(work_item.offset_to_chunk().value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Loans/allocates a [`WorkItemMut`] from the underlying data segment of the [`Producer`].
+    /// Every loaned work item acquires a new work item id which is stored in its [`Header`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .pipeline()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    /// # let consumer = service.consumer().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(42); // alternatively `work_item.payload_mut()` can be use to access the `MaybeUninit<MessageType>`
+    ///
+    /// producer.send(work_item)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit<'producer>(
+        &'producer self,
+    ) -> Result<WorkItemMut<'a, 'producer, 'config, Service, MaybeUninit<MessageType>>, LoanError>
+    {
+        self.retrieve_returned_samples();
+        let msg = "Unable to loan work item";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_samples {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} work items were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned work item to loan another work item.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_samples);
+        }
+
+        match self.allocate_chunk() {
+            Ok(chunk) => {
+                if self.sample_reference_counter[Self::sample_index(chunk.offset.value())]
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated work item is already in use! This should never happen!", msg);
+                }
+
+                let message =
+                    chunk.data_ptr as *mut MaybeUninit<Message<Header, MaybeUninit<MessageType>>>;
+
+                let work_item_id = self.work_item_id_counter.fetch_add(1, Ordering::Relaxed);
+                let work_item = unsafe {
+                    (*message).write(Message {
+                        header: Header::new(self.port_id, work_item_id),
+                        data: MaybeUninit::uninit(),
+                    });
+                    RawSampleMut::new_unchecked(
+                        message as *mut Message<Header, MaybeUninit<MessageType>>,
+                    )
+                };
+
+                Ok(WorkItemMut::new(self, work_item, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn allocate_chunk(&self) -> Result<ShmPointer, ShmAllocationError> {
+        let layout = Layout::new::<Message<Header, MessageType>>();
+        match self.data_segment.allocate(layout) {
+            // work items that are stuck in the buffer of a dead consumer are returned only
+            // after they were handed over to another consumer
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory))
+                if self.recover_samples_from_dead_consumers() != 0 =>
+            {
+                self.retrieve_returned_samples();
+                self.data_segment.allocate(layout)
+            }
+            v => v,
+        }
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Default + Debug>
+    Producer<'a, 'config, Service, MessageType>
+{
+    /// Loans/allocates a [`WorkItemMut`] from the underlying data segment of the [`Producer`]
+    /// and initialize it with the default value. This can be a performance hit and
+    /// [`Producer::loan_uninit`] can be used to loan a [`core::mem::MaybeUninit<MessageType>`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .pipeline()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    /// # let consumer = service.consumer().create()?;
+    ///
+    /// let mut work_item = producer.loan()?;
+    /// *work_item.payload_mut() = 42;
+    ///
+    /// producer.send(work_item)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan<'producer>(
+        &'producer self,
+    ) -> Result<WorkItemMut<'a, 'producer, 'config, Service, MessageType>, LoanError> {
+        Ok(self.loan_uninit()?.write_payload(MessageType::default()))
+    }
+}
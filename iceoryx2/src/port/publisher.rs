@@ -58,12 +58,18 @@ use std::fmt::Debug;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 
+use super::event_id::EventId;
 use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use crate::message::Message;
+use crate::node::{tag_port, PortType};
+use crate::payload_type::{details::PayloadTypeDetails, PayloadType};
+use crate::port::copy_frame::CopyFrame;
+use crate::port::details::copy_transfer;
 use crate::port::details::subscriber_connections::*;
 use crate::port::{DegrationAction, DegrationCallback};
 use crate::raw_sample::RawSampleMut;
 use crate::service;
+use crate::service::access_control::{Operation, Resource};
 use crate::service::config_scheme::data_segment_config;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
@@ -76,8 +82,10 @@ use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::communication_channel::{CommunicationChannel, CommunicationChannelSendError};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::event::NotifierNotifyError;
+use iceoryx2_cal::named_concept::{AccessRights, NamedConceptBuilder, NamedConceptConfiguration};
 use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError};
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
@@ -91,6 +99,7 @@ use iceoryx2_cal::zero_copy_connection::{
 pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     UnableToCreateDataSegment,
+    PermissionDenied,
 }
 
 impl std::fmt::Display for PublisherCreateError {
@@ -103,10 +112,13 @@ impl std::error::Error for PublisherCreateError {}
 
 /// Defines a failure that can occur in [`Publisher::loan()`] and [`Publisher::loan_uninit()`] or is part of [`SendCopyError`]
 /// emitted in [`Publisher::send_copy()`].
+/// It is also emitted when a [`crate::port::client::Client`] loans a request or a
+/// [`crate::port::server::Server`] loans a response.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum LoanError {
     OutOfMemory,
     ExceedsMaxLoanedChunks,
+    ExceedsMaxLoanSize,
     InternalFailure,
 }
 
@@ -136,10 +148,16 @@ impl std::error::Error for SendCopyError {}
 
 /// Sending endpoint of a publish-subscriber based communication.
 #[derive(Debug)]
-pub struct Publisher<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+pub struct Publisher<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+> {
     port_id: UniquePublisherId,
     pub(crate) sample_reference_counter: Vec<AtomicU64>,
     pub(crate) data_segment: Service::SharedMemory,
+    sample_layout: Layout,
     config: LocalPublisherConfig,
 
     subscriber_connections: SubscriberConnections<'config, Service>,
@@ -148,20 +166,44 @@ pub struct Publisher<'a, 'config: 'a, Service: service::Details<'config>, Messag
     service: &'a Service,
     degration_callback: Option<DegrationCallback<'a>>,
     pub(crate) loan_counter: AtomicUsize,
+    copy_sequence_number: AtomicU64,
+    sequence_number: AtomicU64,
     _dynamic_config_guard: UniqueIndex<'a>,
+    _node_port_tag: Option<Service::StaticStorage>,
     _phantom_message_type: PhantomData<MessageType>,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     Publisher<'a, 'config, Service, MessageType>
 {
     pub(crate) fn new(
         service: &'a Service,
         static_config: &publish_subscribe::StaticConfig,
         config: &LocalPublisherConfig,
+    ) -> Result<Self, PublisherCreateError> {
+        Self::new_with_sample_layout(
+            service,
+            static_config,
+            config,
+            MessageType::message_layout::<Header>(static_config.max_slice_len),
+        )
+    }
+
+    pub(crate) fn new_with_sample_layout(
+        service: &'a Service,
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        sample_layout: Layout,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
+
+        let access_control = service.state().static_config.access_control();
+        if !access_control.is_permitted(Operation::Publish) {
+            fail!(from origin, with PublisherCreateError::PermissionDenied,
+                "{} since the process is not permitted to publish on the service.", msg);
+        }
+
         let port_id = UniquePublisherId::new();
         let subscriber_list = &service
             .state()
@@ -176,7 +218,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .messaging_pattern
             .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
 
-        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_samples),
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, access_control.access_rights(Resource::DataSegment), number_of_samples, sample_layout),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
@@ -204,8 +246,12 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 service.state().global_config,
                 port_id,
                 static_config,
+                access_control.access_rights(Resource::Connection),
+                number_of_samples,
+                sample_layout.size(),
             ),
             data_segment,
+            sample_layout,
             config: *config,
             sample_reference_counter: {
                 let mut v = Vec::with_capacity(number_of_samples);
@@ -222,7 +268,10 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             service,
             degration_callback: None,
             loan_counter: AtomicUsize::new(0),
+            copy_sequence_number: AtomicU64::new(0),
+            sequence_number: AtomicU64::new(0),
             _dynamic_config_guard,
+            _node_port_tag: tag_port(service, PortType::Publisher, port_id.value()),
             _phantom_message_type: PhantomData,
         };
 
@@ -278,13 +327,63 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                         },
                     }
                 }
-                None => self.subscriber_connections.remove(i),
+                None => {
+                    // the subscriber is gone, samples it has not received are no longer required
+                    self.release_used_samples(i);
+                    self.subscriber_connections.remove(i)
+                }
             }
         }
 
         Ok(())
     }
 
+    fn release_used_samples(&self, index: usize) -> usize {
+        let mut number_of_released_samples = 0;
+        if let Some(Connection {
+            sender: SampleSender::ZeroCopy(sender),
+            ..
+        }) = self.subscriber_connections.get(index)
+        {
+            unsafe {
+                sender.acquire_used_offsets(|offset| {
+                    self.release_sample(offset);
+                    number_of_released_samples += 1;
+                })
+            };
+        }
+
+        number_of_released_samples
+    }
+
+    /// Checks if the processes of all connected [`crate::port::subscriber::Subscriber`]s are
+    /// still alive. Every [`crate::sample::Sample`] that was delivered to a dead
+    /// [`crate::port::subscriber::Subscriber`] is reclaimed and the connection is removed. It is
+    /// done implicitly whenever the data segment is out of memory while loaning a [`SampleMut`].
+    ///
+    /// Only the death of a [`crate::port::subscriber::Subscriber`] is handled. When a
+    /// [`Publisher`] dies its samples vanish with its data segment and nothing has to be
+    /// recovered, its stale resources are removed with
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::cleanup_dead_ports()`].
+    ///
+    /// Returns the number of reclaimed [`crate::sample::Sample`]s.
+    pub fn recover_samples_from_dead_subscribers(&self) -> usize {
+        let mut number_of_recovered_samples = 0;
+        for i in 0..self.subscriber_connections.len() {
+            match self.subscriber_connections.get(i) {
+                Some(connection) if !connection.is_subscriber_alive() => {
+                    warn!(from self, "The subscriber {:?} died. Reclaiming all samples that were delivered to it.",
+                        connection.subscriber_id);
+                    number_of_recovered_samples += self.release_used_samples(i);
+                    self.subscriber_connections.remove_dead(i);
+                }
+                _ => (),
+            }
+        }
+
+        number_of_recovered_samples
+    }
+
     fn deliver_history(&self, connection: &Connection<'config, Service>) {
         match &self.history {
             None => (),
@@ -293,13 +392,32 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 for i in 0..history.len() {
                     let ptr_distance = unsafe { history.get_unchecked(i) };
 
-                    match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => {
-                            self.sample_reference_counter[Self::sample_index(ptr_distance)]
-                                .fetch_add(1, Ordering::Relaxed);
+                    match &connection.sender {
+                        SampleSender::ZeroCopy(sender) => {
+                            match sender.try_send(PointerOffset::new(ptr_distance)) {
+                                Ok(_) => {
+                                    self.sample_reference_counter[self.sample_index(ptr_distance)]
+                                        .fetch_add(1, Ordering::Relaxed);
+                                }
+                                Err(e) => {
+                                    warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
+                                }
+                            }
                         }
-                        Err(e) => {
-                            warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
+                        SampleSender::Copy(sender) => {
+                            match self.send_copy_of_sample(
+                                sender,
+                                ptr_distance,
+                                UnableToDeliverStrategy::DiscardSample,
+                            ) {
+                                Ok(true) => (),
+                                Ok(false) => {
+                                    warn!(from self, "Failed to deliver history to new subscriber via {:?} since its buffer is full.", connection);
+                                }
+                                Err(e) => {
+                                    warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
+                                }
+                            }
                         }
                     }
                 }
@@ -307,17 +425,49 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
-    fn sample_index(distance_to_chunk: usize) -> usize {
-        distance_to_chunk / std::mem::size_of::<Message<Header, MessageType>>()
+    /// Copies the sample into the copy channel of a [`crate::port::subscriber::Subscriber`]. Only
+    /// the header and the used part of a slice payload are transferred.
+    fn send_copy_of_sample(
+        &self,
+        sender: &<Service::CopyChannel as CommunicationChannel<CopyFrame>>::Sender,
+        distance_to_chunk: usize,
+        strategy: UnableToDeliverStrategy,
+    ) -> Result<bool, CommunicationChannelSendError> {
+        let static_config = self.service.state().static_config.publish_subscribe();
+        let (_, payload_offset) = static_config.message_layout::<Header>();
+        let address = self.data_segment.allocator_data_start_address() + distance_to_chunk;
+        let number_of_elements = std::cmp::min(
+            unsafe { (*(address as *const Header)).number_of_elements() } as usize,
+            static_config.max_slice_len,
+        );
+        let message_size = std::cmp::min(
+            payload_offset + number_of_elements * static_config.payload_size,
+            self.sample_layout.size(),
+        );
+        let message = unsafe { std::slice::from_raw_parts(address as *const u8, message_size) };
+
+        copy_transfer::send_message(
+            sender,
+            self.port_id.value(),
+            self.copy_sequence_number.fetch_add(1, Ordering::Relaxed),
+            message,
+            strategy,
+        )
+    }
+
+    fn sample_index(&self, distance_to_chunk: usize) -> usize {
+        distance_to_chunk / self.sample_layout.size()
     }
 
     fn create_data_segment(
         port_id: UniquePublisherId,
         global_config: &'config config::Config,
+        access_rights: AccessRights,
         number_of_samples: usize,
+        sample_layout: Layout,
     ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
-            bucket_layout: Layout::new::<Message<Header, MessageType>>(),
+            bucket_layout: sample_layout,
         };
         let chunk_size = allocator_config.bucket_layout.size();
         let chunk_align = allocator_config.bucket_layout.align();
@@ -326,16 +476,25 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
             Service::SharedMemory,
                 >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
+                .config(&data_segment_config::<Service>(global_config).access_rights(access_rights))
                 .size(chunk_size * number_of_samples + chunk_align - 1)
                 .create(&allocator_config),
             "Unable to create the data segment."))
     }
 
-    fn send_impl(&self, address_to_chunk: usize) -> Result<usize, ZeroCopyCreationError> {
+    pub(crate) fn send_impl(
+        &self,
+        address_to_chunk: usize,
+    ) -> Result<usize, ZeroCopyCreationError> {
         fail!(from self, when self.update_connections(),
             "Unable to send sample since the connections could not be updated.");
 
+        // the sequence number is assigned on send, loaned samples that are dropped shall not
+        // appear as lost samples and the time stamp shall reflect the send order
+        let header =
+            (self.data_segment.allocator_data_start_address() + address_to_chunk) as *mut Header;
+        unsafe { (*header).stamp(self.sequence_number.fetch_add(1, Ordering::Relaxed)) };
+
         self.add_to_history(address_to_chunk);
         Ok(self.deliver_sample(address_to_chunk))
     }
@@ -345,7 +504,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                self.sample_reference_counter[self.sample_index(address_to_chunk)]
                     .fetch_add(1, Ordering::Relaxed);
                 match unsafe { history.push_with_overflow(address_to_chunk) } {
                     None => (),
@@ -364,8 +523,35 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         let mut number_of_recipients = 0;
         for i in 0..self.subscriber_connections.len() {
             match self.subscriber_connections.get(i) {
-                Some(ref connection) => {
-                    match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
+                Some(
+                    ref connection @ Connection {
+                        sender: SampleSender::Copy(sender),
+                        ..
+                    },
+                ) => {
+                    match self.send_copy_of_sample(
+                        sender,
+                        address_to_chunk,
+                        self.config.unable_to_deliver_strategy,
+                    ) {
+                        Ok(true) => {
+                            number_of_recipients += 1;
+                            self.notify_subscriber(connection);
+                        }
+                        // the subscriber buffer is full and the sample is discarded
+                        Ok(false) => (),
+                        Err(e) => {
+                            warn!(from self, "Unable to send copy of sample via connection {:?} due to {:?}.", connection, e);
+                        }
+                    }
+                }
+                Some(
+                    ref connection @ Connection {
+                        sender: SampleSender::ZeroCopy(sender),
+                        ..
+                    },
+                ) => {
+                    match deliver_call(sender, PointerOffset::new(address_to_chunk)) {
                         Err(ZeroCopySendError::ReceiveBufferFull) => {
                             /* causes no problem
                              *   blocking_send => can never happen
@@ -376,13 +562,15 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                             warn!(from self, "Unable to send sample via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection);
                         }
                         Ok(overflow) => {
-                            self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                            self.sample_reference_counter[self.sample_index(address_to_chunk)]
                                 .fetch_add(1, Ordering::Relaxed);
                             number_of_recipients += 1;
 
                             if let Some(old) = overflow {
                                 self.release_sample(old)
                             }
+
+                            self.notify_subscriber(connection);
                         }
                     }
                 }
@@ -392,8 +580,21 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         number_of_recipients
     }
 
+    fn notify_subscriber(&self, connection: &Connection<'config, Service>) {
+        use iceoryx2_cal::event::Notifier;
+        if let Some(ref notifier) = connection.notifier {
+            match notifier.notify(EventId::default()) {
+                // the subscriber has still pending notifications and will be woken up anyway
+                Ok(()) | Err(NotifierNotifyError::FailedToDeliverSignal) => (),
+                Err(e) => {
+                    warn!(from self, "Unable to notify subscriber via connection {:?} due to {:?}.", connection, e)
+                }
+            }
+        }
+    }
+
     pub(crate) fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[Self::sample_index(distance_to_chunk.value())]
+        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
             .fetch_sub(1, Ordering::Relaxed)
             == 1
         {
@@ -401,7 +602,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 fatal_panic!(from self, when self.data_segment
                 .deallocate(
                     distance_to_chunk,
-                    Layout::new::<MessageType>(),
+                    self.sample_layout,
                 ), "Internal logic error. The sample should always contain a valid memory chunk from the provided allocator.");
             };
         }
@@ -410,10 +611,15 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
     fn retrieve_returned_samples(&self) {
         for i in 0..self.subscriber_connections.len() {
             match self.subscriber_connections.get(i) {
-                Some(ref connection) => loop {
-                    match connection.sender.reclaim() {
+                Some(
+                    ref connection @ Connection {
+                        sender: SampleSender::ZeroCopy(sender),
+                        ..
+                    },
+                ) => loop {
+                    match sender.reclaim() {
                         Ok(Some(ptr_dist)) => {
-                            let sample_index = Self::sample_index(ptr_dist.value());
+                            let sample_index = self.sample_index(ptr_dist.value());
 
                             if self.sample_reference_counter[sample_index]
                                 .fetch_sub(1, Ordering::Relaxed)
@@ -423,7 +629,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                                     fatal_panic!(from self, when self.data_segment
                                     .deallocate(
                                         ptr_dist,
-                                        Layout::new::<Message<Header, MessageType>>(),
+                                        self.sample_layout,
                                     ), "This should never happen! Failed to deallocate the reclaimed ptr. Either the data was corrupted or an invalid ptr was returned.")
                                 };
                             }
@@ -434,7 +640,8 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                         }
                     }
                 },
-                None => (),
+                // copies of samples are never returned
+                Some(_) | None => (),
             }
         }
     }
@@ -459,6 +666,11 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.port_id
+    }
+
     /// Explicitly updates all connections to the [`crate::port::subscriber::Subscriber`]s. This is
     /// required to be called whenever a new [`crate::port::subscriber::Subscriber`] connected to
     /// the service. It is done implicitly whenever [`Publisher::send()`] or [`Publisher::send_copy()`]
@@ -472,8 +684,8 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         Ok(())
     }
 
-    /// Send a previously loaned [`Publisher::loan_uninit()`] [`SampleMut`] to all connected
-    /// [`crate::port::subscriber::Subscriber`]s of the service.
+    /// Send a previously loaned [`Publisher::loan_uninit()`] or [`Publisher::loan_uninit_slice()`]
+    /// [`SampleMut`] to all connected [`crate::port::subscriber::Subscriber`]s of the service.
     ///
     /// The payload of the [`SampleMut`] must be initialized before it can be sent. Have a look
     /// at [`SampleMut::write_payload()`] and [`SampleMut::assume_init()`] for more details.
@@ -490,6 +702,66 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         )
     }
 
+    pub(crate) fn loan_chunk(
+        &self,
+        number_of_elements: usize,
+    ) -> Result<(usize, PointerOffset), LoanError> {
+        self.retrieve_returned_samples();
+        let msg = "Unable to loan Sample";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_samples {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_samples);
+        }
+
+        let mut allocation = self.data_segment.allocate(self.sample_layout);
+        if let Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) = allocation {
+            // samples may be owned by a subscriber that died, recover them and try again
+            if self.recover_samples_from_dead_subscribers() != 0 {
+                allocation = self.data_segment.allocate(self.sample_layout);
+            }
+        }
+
+        match allocation {
+            Ok(chunk) => {
+                if self.sample_reference_counter[self.sample_index(chunk.offset.value())]
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated sample is already in use! This should never happen!", msg);
+                }
+
+                unsafe {
+                    (chunk.data_ptr as *mut Header).write(Header::new(
+                        self.port_id,
+                        number_of_elements as u64,
+                        self.config.priority,
+                    ))
+                };
+
+                Ok((chunk.data_ptr as usize, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+    Publisher<'a, 'config, Service, MessageType>
+{
     /// Copies the input `value` into a [`SampleMut`] and delivers it.
     /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
     /// the data, otherwise a [`SendCopyError`] describing the failure.
@@ -537,59 +809,14 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         SampleMut<'a, 'publisher, 'config, Service, Header, MaybeUninit<MessageType>>,
         LoanError,
     > {
-        self.retrieve_returned_samples();
-        let msg = "Unable to loan Sample";
-
-        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_samples {
-            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
-                "{} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
-                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_samples);
-        }
-
-        match self
-            .data_segment
-            .allocate(Layout::new::<Message<Header, MessageType>>())
-        {
-            Ok(chunk) => {
-                if self.sample_reference_counter[Self::sample_index(chunk.offset.value())]
-                    .fetch_add(1, Ordering::Relaxed)
-                    != 0
-                {
-                    fatal_panic!(from self,
-                                "{} since the allocated sample is already in use! This should never happen!", msg);
-                }
-
-                let message =
-                    chunk.data_ptr as *mut MaybeUninit<Message<Header, MaybeUninit<MessageType>>>;
-
-                let sample = unsafe {
-                    (*message).write(Message {
-                        header: Header::new(self.port_id),
-                        data: MaybeUninit::uninit(),
-                    });
-                    RawSampleMut::new_unchecked(
-                        message as *mut Message<Header, MaybeUninit<MessageType>>,
-                    )
-                };
+        let (address, offset) = self.loan_chunk(1)?;
+        let sample = unsafe {
+            RawSampleMut::new_unchecked(address as *mut Message<Header, MaybeUninit<MessageType>>)
+        };
 
-                Ok(SampleMut::new(self, sample, chunk.offset))
-            }
-            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
-                fail!(from self, with LoanError::OutOfMemory,
-                    "{} since the underlying shared memory is out of memory.", msg);
-            }
-            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
-            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
-                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
-            }
-            Err(v) => {
-                fail!(from self, with LoanError::InternalFailure,
-                    "{} since an internal failure occurred ({:?}).", msg, v);
-            }
-        }
+        Ok(SampleMut::new(self, sample, offset))
     }
 }
-
 impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Default + Debug>
     Publisher<'a, 'config, Service, MessageType>
 {
@@ -626,3 +853,106 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Default +
         Ok(self.loan_uninit()?.write_payload(MessageType::default()))
     }
 }
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, T: Debug>
+    Publisher<'a, 'config, Service, [T]>
+{
+    /// Loans/allocates a [`SampleMut`] with a slice payload of `slice_len` elements from the
+    /// underlying data segment of the [`Publisher`]. The user has to initialize the payload
+    /// before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure. When `slice_len` exceeds
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::max_slice_len()`]
+    /// it returns [`LoanError::ExceedsMaxLoanSize`].
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_uninit_slice(4)?;
+    /// let sample = sample.write_from_fn(|i| i as u64 * 2);
+    ///
+    /// publisher.send(sample)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit_slice<'publisher>(
+        &'publisher self,
+        slice_len: usize,
+    ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, [MaybeUninit<T>]>, LoanError>
+    {
+        let max_slice_len = self
+            .service
+            .state()
+            .static_config
+            .publish_subscribe()
+            .max_slice_len;
+        if max_slice_len < slice_len {
+            fail!(from self, with LoanError::ExceedsMaxLoanSize,
+                "Unable to loan Sample since the requested slice length of {} exceeds the maximum slice length of {}.",
+                slice_len, max_slice_len);
+        }
+
+        let (address, offset) = self.loan_chunk(slice_len)?;
+        let sample = unsafe {
+            RawSampleMut::new_unchecked(<[MaybeUninit<T>]>::message_ptr::<Header>(
+                address, slice_len,
+            ))
+        };
+
+        Ok(SampleMut::new_slice(self, sample, offset))
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, T: Default + Debug>
+    Publisher<'a, 'config, Service, [T]>
+{
+    /// Loans/allocates a [`SampleMut`] with a slice payload of `slice_len` elements from the
+    /// underlying data segment of the [`Publisher`] and initializes every element with the
+    /// default value. This can be a performance hit and [`Publisher::loan_uninit_slice`] can be
+    /// used to loan a slice of [`core::mem::MaybeUninit<T>`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let mut sample = publisher.loan_slice(4)?;
+    /// sample.payload_mut()[2] = 42;
+    ///
+    /// publisher.send(sample)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_slice<'publisher>(
+        &'publisher self,
+        slice_len: usize,
+    ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, [T]>, LoanError> {
+        Ok(self
+            .loan_uninit_slice(slice_len)?
+            .write_from_fn(|_| T::default()))
+    }
+}
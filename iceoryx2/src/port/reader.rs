@@ -0,0 +1,150 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 1234)
+//!     .open_or_create()?;
+//!
+//! let reader = blackboard.reader().create()?;
+//!
+//! println!("entry 0: {}", reader.read(0)?);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::{tag_port, PortType};
+use crate::port::port_identifiers::UniqueReaderId;
+use crate::service;
+
+/// Failures that can occur when a new [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderCreateError {
+    ExceedsMaxSupportedReaders,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// Defines the failures that can occur while a [`Reader::read()`] call.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderReadError {
+    EntryDoesNotExist,
+}
+
+impl std::fmt::Display for ReaderReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReaderReadError {}
+
+/// The reading endpoint of a blackboard based communication. It reads the most recent value of
+/// an entry without any queue in between.
+#[derive(Debug)]
+pub struct Reader<'a, 'config: 'a, Service: service::Details<'config>, ValueType: Copy + Debug> {
+    service: &'a Service,
+    port_id: UniqueReaderId,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _node_port_tag: Option<Service::StaticStorage>,
+    _phantom_value_type: PhantomData<ValueType>,
+    _phantom_b: PhantomData<&'config ()>,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, ValueType: Copy + Debug>
+    Reader<'a, 'config, Service, ValueType>
+{
+    pub(crate) fn new(service: &'a Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Unable to create Reader port";
+        let origin = "Reader::new()";
+        let port_id = UniqueReaderId::new();
+
+        let _dynamic_config_guard = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_reader_id(port_id)
+        {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                            "{} since it would exceed the maximum supported amount of readers of {}.",
+                            msg, service.state().static_config.blackboard().max_readers);
+            }
+        };
+
+        Ok(Self {
+            service,
+            port_id,
+            _dynamic_config_guard,
+            _node_port_tag: tag_port(service, PortType::Reader, port_id.value()),
+            _phantom_value_type: PhantomData,
+            _phantom_b: PhantomData,
+        })
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.port_id
+    }
+
+    /// Reads the most recent value of the entry identified by `key`. If the
+    /// [`crate::service::Service`] does not provide an entry with that `key` it returns
+    /// [`ReaderReadError::EntryDoesNotExist`].
+    pub fn read(&self, key: u64) -> Result<ValueType, ReaderReadError> {
+        let index = match self
+            .service
+            .state()
+            .static_config
+            .blackboard()
+            .entry_index(key)
+        {
+            Some(index) => index,
+            None => {
+                fail!(from self, with ReaderReadError::EntryDoesNotExist,
+                    "Unable to read entry since the blackboard does not contain the key {}.", key);
+            }
+        };
+
+        Ok(unsafe {
+            self.service
+                .state()
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .entry::<ValueType>(index)
+                .load()
+        })
+    }
+}
@@ -0,0 +1,647 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = service
+//!     .server()
+//!     // defines how many responses can be loaned in parallel
+//!     .max_loaned_responses(5)
+//!     .create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     // loan some uninitialized memory and send it back to the client of the request
+//!     let response = server.loan_uninit(&request)?;
+//!     let response = response.write_payload(*request + 1);
+//!     server.send(response)?;
+//!
+//!     // or send a copy of the value
+//!     server.send_copy(&request, *request + 2)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
+
+use super::details::client_connections::{ClientConnections, Connection};
+use super::details::publisher_connections::ConnectionFailure;
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::publisher::LoanError;
+use super::subscriber::ReceiveError;
+use crate::config;
+use crate::message::Message;
+use crate::node::{tag_port, PortType};
+use crate::raw_sample::{RawSample, RawSampleMut};
+use crate::request::Request;
+use crate::response_mut::ResponseMut;
+use crate::service;
+use crate::service::config_scheme::data_segment_config;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::server_data_segment_name;
+use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::static_config::request_response;
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError};
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopyReleaseError,
+    ZeroCopySendError, ZeroCopySender,
+};
+
+/// Defines a failure that can occur when a [`Server`] is created with
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    ExceedsMaxSupportedServers,
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`crate::response_mut::ResponseMut`] is sent via
+    /// [`Server::send()`].
+    ResponseSendError
+  entry:
+    ClientNotConnected,
+    ReceiveBufferFull,
+    ConnectionCorrupted
+  mapping:
+    ConnectionFailure
+}
+
+impl std::fmt::Display for ResponseSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseSendError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`crate::response_mut::ResponseMut`] is sent via
+    /// [`Server::send_copy()`].
+    ResponseSendCopyError
+  mapping:
+    LoanError to LoanError,
+    ResponseSendError to SendError
+}
+
+impl std::fmt::Display for ResponseSendCopyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseSendCopyError {}
+
+/// Receiving endpoint of a request-response based communication. Receives
+/// [`crate::request::Request`]s from all connected [`crate::port::client::Client`]s and
+/// sends the [`crate::response_mut::ResponseMut`] only to the client that sent the
+/// corresponding request.
+#[derive(Debug)]
+pub struct Server<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    port_id: UniqueServerId,
+    pub(crate) response_reference_counter: Vec<AtomicU64>,
+    pub(crate) data_segment: Service::SharedMemory,
+    config: LocalServerConfig,
+
+    client_connections: ClientConnections<'config, Service>,
+    client_list_state: UnsafeCell<ContainerState<'a, UniqueClientId>>,
+    pub(crate) loan_counter: AtomicUsize,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _node_port_tag: Option<Service::StaticStorage>,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Server<'a, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &request_response::StaticConfig,
+        config: &LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let port_id = UniqueServerId::new();
+        let client_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let number_of_responses = static_config
+            .required_amount_of_responses_per_data_segment(config.max_loaned_responses);
+
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_responses),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let _dynamic_config_guard = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(port_id)
+        {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                            "{} since it would exceed the maximum supported amount of servers of {}.",
+                            msg, static_config.max_servers);
+            }
+        };
+
+        let new_self = Self {
+            port_id,
+            client_connections: ClientConnections::new(
+                client_list.capacity(),
+                port_id,
+                service.state().global_config,
+                static_config,
+                number_of_responses,
+                std::mem::size_of::<Message<ResponseHeader, ResponseType>>(),
+            ),
+            data_segment,
+            config: *config,
+            response_reference_counter: {
+                let mut v = Vec::with_capacity(number_of_responses);
+                for _ in 0..number_of_responses {
+                    v.push(AtomicU64::new(0));
+                }
+                v
+            },
+            client_list_state: unsafe { UnsafeCell::new(client_list.get_state()) },
+            loan_counter: AtomicUsize::new(0),
+            _dynamic_config_guard,
+            _node_port_tag: tag_port(service, PortType::Server, port_id.value()),
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.capacity(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|index, client_id| {
+                visited_indices[index as usize] = Some(*client_id);
+            })
+        };
+
+        // retrieve responses before destroying channel
+        self.retrieve_returned_responses();
+
+        let mut result = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(client_id) => {
+                    if let Err(e) = self.client_connections.create(i, *client_id) {
+                        warn!(from self, "Unable to establish connection to new client {:?}.", client_id);
+                        result = Err(e);
+                    }
+                }
+                None => self.client_connections.remove(i),
+            }
+        }
+
+        result
+    }
+
+    fn response_index(distance_to_chunk: usize) -> usize {
+        distance_to_chunk / std::mem::size_of::<Message<ResponseHeader, ResponseType>>()
+    }
+
+    fn create_data_segment(
+        port_id: UniqueServerId,
+        global_config: &'config config::Config,
+        number_of_responses: usize,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: Layout::new::<Message<ResponseHeader, ResponseType>>(),
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        Ok(fail!(from "Server::create_data_segment()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&server_data_segment_name(port_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(chunk_size * number_of_responses + chunk_align - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment."))
+    }
+
+    fn send_impl(
+        &self,
+        address_to_chunk: usize,
+        client_id: UniqueClientId,
+    ) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send response";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        let connection = match (0..self.client_connections.len())
+            .filter_map(|i| self.client_connections.get(i).as_ref())
+            .find(|connection| connection.client_id == client_id)
+        {
+            Some(connection) => connection,
+            None => {
+                fail!(from self, with ResponseSendError::ClientNotConnected,
+                    "{} since the client {:?} is no longer connected.", msg, client_id);
+            }
+        };
+
+        match connection
+            .response_sender
+            .try_send(PointerOffset::new(address_to_chunk))
+        {
+            Err(ZeroCopySendError::ReceiveBufferFull) => {
+                fail!(from self, with ResponseSendError::ReceiveBufferFull,
+                    "{} since the buffer of the client {:?} is full.", msg, client_id);
+            }
+            Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                fail!(from self, with ResponseSendError::ConnectionCorrupted,
+                    "{} via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.",
+                    msg, connection);
+            }
+            Ok(overflow) => {
+                self.response_reference_counter[Self::response_index(address_to_chunk)]
+                    .fetch_add(1, Ordering::Relaxed);
+
+                if let Some(old) = overflow {
+                    self.release_response(old)
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn release_response(&self, distance_to_chunk: PointerOffset) {
+        if self.response_reference_counter[Self::response_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment
+                .deallocate(
+                    distance_to_chunk,
+                    Layout::new::<Message<ResponseHeader, ResponseType>>(),
+                ), "Internal logic error. The response should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    pub(crate) fn release_request(
+        &self,
+        channel_id: usize,
+        request: RawSample<RequestHeader, RequestType>,
+    ) {
+        match self.client_connections.get(channel_id) {
+            Some(c) => {
+                let distance =
+                    request.as_ptr() as usize - c.data_segment.allocator_data_start_address();
+                match c.request_receiver.release(PointerOffset::new(distance)) {
+                    Ok(()) => (),
+                    Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                        fatal_panic!(from self, when c.request_receiver.release(PointerOffset::new(distance)),
+                                    "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release request since the connection is broken. The request will be discarded and has to be reclaimed manually by the client.");
+            }
+        }
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.release_response(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn receive_from_connection<'server>(
+        &'server self,
+        channel_id: usize,
+        connection: &Connection<'config, Service>,
+    ) -> Result<
+        Option<Request<'a, 'server, 'config, Service, RequestType, ResponseType>>,
+        ReceiveError,
+    > {
+        let msg = "Unable to receive another request";
+        match connection.request_receiver.receive() {
+            Ok(data) => match data {
+                None => Ok(None),
+                Some(relative_addr) => {
+                    let absolute_address = relative_addr.value()
+                        + connection.data_segment.allocator_data_start_address();
+                    Ok(Some(Request {
+                        server: self,
+                        channel_id,
+                        ptr: unsafe {
+                            RawSample::new_unchecked(
+                                absolute_address as *mut Message<RequestHeader, RequestType>,
+                            )
+                        },
+                    }))
+                }
+            },
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
+                    "{} since it would exceed the maximum {} of borrowed requests.",
+                    msg, connection.request_receiver.max_borrowed_samples());
+            }
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.port_id
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::client::Client`]s. This is
+    /// required to be called whenever a new [`crate::port::client::Client`] connected to
+    /// the service. It is done implicitly whenever [`Server::receive()`], [`Server::send()`]
+    /// or [`Server::send_copy()`] is called.
+    pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.client_list_state.get()).update() } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a Client port failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Receives a [`Request`] from a [`crate::port::client::Client`]. If no request could be
+    /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive<'server>(
+        &'server self,
+    ) -> Result<
+        Option<Request<'a, 'server, 'config, Service, RequestType, ResponseType>>,
+        ReceiveError,
+    > {
+        if let Err(e) = self.update_connections() {
+            fail!(from self,
+                with ReceiveError::ConnectionFailure(e),
+                "Some requests are not being received since not all connections to clients could be established.");
+        }
+
+        for id in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(id) {
+                if let Some(request) = self.receive_from_connection(id, connection)? {
+                    return Ok(Some(request));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Sends a previously loaned [`Server::loan_uninit()`] [`ResponseMut`] to the
+    /// [`crate::port::client::Client`] that sent the corresponding [`Request`].
+    ///
+    /// The payload of the [`ResponseMut`] must be initialized before it can be sent. Have a look
+    /// at [`ResponseMut::write_payload()`] and [`ResponseMut::assume_init()`] for more details.
+    ///
+    /// On failure a [`ResponseSendError`] describing the failure is returned.
+    pub fn send<'server>(
+        &'server self,
+        response: ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType>,
+    ) -> Result<(), ResponseSendError> {
+        fail!(from self, when self.send_impl(response.offset_to_chunk().value(), response.header().client_id()),
+            "Unable to send response since the underlying send failed.");
+
+        Ok(())
+    }
+
+    /// Copies the input `value` into a [`ResponseMut`] and sends it to the
+    /// [`crate::port::client::Client`] that sent the provided [`Request`].
+    /// On failure it returns a [`ResponseSendCopyError`] describing the failure.
+    pub fn send_copy(
+        &self,
+        request: &Request<'_, '_, 'config, Service, RequestType, ResponseType>,
+        value: ResponseType,
+    ) -> Result<(), ResponseSendCopyError> {
+        let msg = "Unable to send copy of response";
+        let mut response = fail!(from self, when self.loan_uninit(request),
+                                    "{} since the loan of a response failed.", msg);
+
+        response.payload_mut().write(value);
+        fail!(from self, when self.send_impl(response.offset_to_chunk().value(), request.header().client_id()),
+            "{} since the underlying send operation failed.", msg);
+
+        Ok(())
+    }
+
+    /// Loans/allocates a [`ResponseMut`] from the underlying data segment of the [`Server`]
+    /// for the provided [`Request`]. The [`ResponseHeader`] of the [`ResponseMut`] contains
+    /// the request id and the [`UniqueClientId`] of the [`Request`] so that the response is
+    /// delivered only to the requesting [`crate::port::client::Client`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let server = service.server().create()?;
+    ///
+    /// while let Some(request) = server.receive()? {
+    ///     let response = server.loan_uninit(&request)?;
+    ///     let response = response.write_payload(*request * 2);
+    ///
+    ///     server.send(response)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit<'server>(
+        &'server self,
+        request: &Request<'_, '_, 'config, Service, RequestType, ResponseType>,
+    ) -> Result<
+        ResponseMut<'a, 'server, 'config, Service, RequestType, MaybeUninit<ResponseType>>,
+        LoanError,
+    > {
+        self.retrieve_returned_responses();
+        let msg = "Unable to loan Response";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_responses {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_responses);
+        }
+
+        match self
+            .data_segment
+            .allocate(Layout::new::<Message<ResponseHeader, ResponseType>>())
+        {
+            Ok(chunk) => {
+                if self.response_reference_counter[Self::response_index(chunk.offset.value())]
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated response is already in use! This should never happen!", msg);
+                }
+
+                let message = chunk.data_ptr
+                    as *mut MaybeUninit<Message<ResponseHeader, MaybeUninit<ResponseType>>>;
+
+                let response = unsafe {
+                    (*message).write(Message {
+                        header: ResponseHeader::new(self.port_id, request.header()),
+                        data: MaybeUninit::uninit(),
+                    });
+                    RawSampleMut::new_unchecked(
+                        message as *mut Message<ResponseHeader, MaybeUninit<ResponseType>>,
+                    )
+                };
+
+                Ok(ResponseMut::new(self, response, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Default + Debug,
+    > Server<'a, 'config, Service, RequestType, ResponseType>
+{
+    /// Loans/allocates a [`ResponseMut`] for the provided [`Request`] from the underlying data
+    /// segment of the [`Server`] and initialize it with the default value. This can be a
+    /// performance hit and [`Server::loan_uninit`] can be used to loan a
+    /// [`core::mem::MaybeUninit<ResponseType>`].
+    ///
+    /// On failure it returns [`LoanError`] describing the failure.
+    ///
+    /// Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let server = service.server().create()?;
+    ///
+    /// while let Some(request) = server.receive()? {
+    ///     let mut response = server.loan(&request)?;
+    ///     *response.payload_mut() = 42;
+    ///
+    ///     server.send(response)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan<'server>(
+        &'server self,
+        request: &Request<'_, '_, 'config, Service, RequestType, ResponseType>,
+    ) -> Result<ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType>, LoanError>
+    {
+        Ok(self
+            .loan_uninit(request)?
+            .write_payload(ResponseType::default()))
+    }
+}
@@ -31,32 +31,44 @@
 //! # }
 //! ```
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell, UnsafeCell};
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 
+use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError};
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
 
+use crate::node::{tag_port, PortType};
+use crate::port::event_id::EventId;
 use crate::port::DegrationAction;
+use crate::service::access_control::{Operation, Resource};
+use crate::service::naming_scheme::subscriber_event_concept_name;
+use crate::service::port_factory::subscriber::{LocalSubscriberConfig, ReceiveOrder};
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{
-    message::Message, raw_sample::RawSample, sample::Sample, service,
+    payload_type::PayloadType, raw_sample::RawSample, sample::Sample, service,
     service::header::publish_subscribe::Header,
 };
 
+use super::details::copy_transfer::CopyReceiver;
 use super::details::publisher_connections::{Connection, ConnectionFailure, PublisherConnections};
 use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::DegrationCallback;
 
-/// Defines the failure that can occur when receiving data with [`Subscriber::receive()`].
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// Defines the failure that can occur when receiving data with [`Subscriber::receive()`],
+/// [`crate::port::client::Client::receive()`] or [`crate::port::server::Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum ReceiveError {
     ExceedsMaxBorrowedSamples,
     ConnectionFailure(ConnectionFailure),
+    CorruptedCopyChannel,
 }
 
 impl std::fmt::Display for ReceiveError {
@@ -67,11 +79,32 @@ impl std::fmt::Display for ReceiveError {
 
 impl std::error::Error for ReceiveError {}
 
+enum_gen! {
+    /// Defines the failures that can occur when the [`Subscriber`] waits for new data with
+    /// [`Subscriber::timed_receive()`] or [`Subscriber::blocking_receive()`].
+    SubscriberWaitError
+  entry:
+    NotificationsNotEnabled
+  mapping:
+    ReceiveError,
+    ListenerWaitError
+}
+
+impl std::fmt::Display for SubscriberWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for SubscriberWaitError {}
+
 /// Describes the failures when a new [`Subscriber`] is created via the
 /// [`crate::service::port_factory::subscriber::PortFactorySubscriber`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum SubscriberCreateError {
     ExceedsMaxSupportedSubscribers,
+    ResourceCreationFailed,
+    PermissionDenied,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
@@ -82,27 +115,58 @@ impl std::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+#[derive(Debug, Clone, Copy)]
+struct SequenceTracker {
+    publisher_id: UniquePublisherId,
+    last_sequence_number: u64,
+    number_of_lost_samples: u64,
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
-pub struct Subscriber<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+pub struct Subscriber<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+> {
     dynamic_config_guard: Option<UniqueIndex<'a>>,
+    _node_port_tag: Option<Service::StaticStorage>,
     publisher_connections: PublisherConnections<'config, Service>,
     service: &'a Service,
     degration_callback: Option<DegrationCallback<'a>>,
+    listener: Option<<Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener>,
+    copy_receiver: Option<CopyReceiver<'config, Service>>,
+    number_of_borrowed_copies: Cell<usize>,
+    max_slice_len: usize,
+    max_borrowed_samples: usize,
+    sequence_trackers: RefCell<Vec<SequenceTracker>>,
+    max_sequence_trackers: usize,
+    lost_samples_since_last_receive: Cell<u64>,
+    receive_order: ReceiveOrder,
+    pending_samples: RefCell<Vec<Option<PointerOffset>>>,
 
     publisher_list_state: UnsafeCell<ContainerState<'a, UniquePublisherId>>,
     _phantom_message_type: PhantomData<MessageType>,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     Subscriber<'a, 'config, Service, MessageType>
 {
     pub(crate) fn new(
         service: &'a Service,
         static_config: &StaticConfig,
+        config: &LocalSubscriberConfig,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
         let origin = "Subscriber::new()";
+
+        let access_control = service.state().static_config.access_control();
+        if !access_control.is_permitted(Operation::Subscribe) {
+            fail!(from origin, with SubscriberCreateError::PermissionDenied,
+                "{} since the process is not permitted to subscribe to the service.", msg);
+        }
+
         let port_id = UniqueSubscriberId::new();
 
         let publisher_list = &service
@@ -112,22 +176,58 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .publish_subscribe()
             .publishers;
 
+        let listener = match static_config.enable_notifications {
+            false => None,
+            true => {
+                let event_name = subscriber_event_concept_name(&port_id);
+                Some(fail!(from origin,
+                        when <Service::Event as iceoryx2_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name).create(),
+                        with SubscriberCreateError::ResourceCreationFailed,
+                        "{} since the underlying event concept \"{}\" for the notifications could not be created.", msg, event_name))
+            }
+        };
+
+        // the channel must exist before the subscriber is registered since the publishers
+        // connect to it as soon as they discover the subscriber
+        let copy_receiver = match static_config.enable_copy_transfer {
+            false => None,
+            true => Some(fail!(from origin,
+                    when CopyReceiver::create(port_id, service.state().global_config, static_config),
+                    with SubscriberCreateError::ResourceCreationFailed,
+                    "{} since the copy channel could not be created.", msg)),
+        };
+
         let mut new_self = Self {
             publisher_connections: PublisherConnections::new(
                 publisher_list.capacity(),
                 port_id,
                 service.state().global_config,
                 static_config,
+                access_control.access_rights(Resource::Connection),
             ),
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_config_guard: None,
+            _node_port_tag: tag_port(service, PortType::Subscriber, port_id.value()),
             service,
             degration_callback: None,
+            listener,
+            copy_receiver,
+            number_of_borrowed_copies: Cell::new(0),
+            max_slice_len: static_config.max_slice_len,
+            max_borrowed_samples: static_config.subscriber_max_borrowed_samples,
+            sequence_trackers: RefCell::new(vec![]),
+            max_sequence_trackers: std::cmp::max(static_config.max_publishers, 1),
+            lost_samples_since_last_receive: Cell::new(0),
+            receive_order: config.receive_order,
+            pending_samples: RefCell::new(vec![None; publisher_list.capacity()]),
             _phantom_message_type: PhantomData,
         };
 
-        if let Err(e) = new_self.populate_publisher_channels() {
-            warn!(from new_self, "The new subscriber is unable to connect to every publisher, caused by {:?}.", e);
+        // with copy transfer the publishers connect to the subscriber
+        if new_self.copy_receiver.is_none() {
+            if let Err(e) = new_self.populate_publisher_channels() {
+                warn!(from new_self, "The new subscriber is unable to connect to every publisher, caused by {:?}.", e);
+            }
         }
 
         // !MUST! be the last task otherwise a subscriber is added to the dynamic config without
@@ -157,10 +257,17 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         channel_id: usize,
         sample: RawSample<Header, MessageType>,
     ) {
+        if let Some(copy_receiver) = &self.copy_receiver {
+            unsafe { copy_receiver.release(sample.as_ptr() as *const u8) };
+            self.number_of_borrowed_copies
+                .set(self.number_of_borrowed_copies.get() - 1);
+            return;
+        }
+
         match self.publisher_connections.get(channel_id) {
             Some(c) => {
-                let distance =
-                    sample.as_ptr() as usize - c.data_segment.allocator_data_start_address();
+                let distance = sample.as_ptr() as *const u8 as usize
+                    - c.data_segment.allocator_data_start_address();
                 match c.receiver.release(PointerOffset::new(distance)) {
                     Ok(()) => (),
                     Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
@@ -211,7 +318,10 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                         }
                     },
                 },
-                None => self.publisher_connections.remove(i),
+                None => {
+                    self.publisher_connections.remove(i);
+                    self.pending_samples.borrow_mut()[i] = None;
+                }
             }
         }
 
@@ -221,27 +331,21 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
     fn receive_from_connection<'subscriber>(
         &'subscriber self,
         channel_id: usize,
-        connection: &mut Connection<'config, Service>,
+        connection: &Connection<'config, Service>,
     ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
     {
+        Ok(self
+            .receive_offset(connection)?
+            .map(|offset| self.create_sample(channel_id, connection, offset)))
+    }
+
+    fn receive_offset(
+        &self,
+        connection: &Connection<'config, Service>,
+    ) -> Result<Option<PointerOffset>, ReceiveError> {
         let msg = "Unable to receive another sample";
         match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(relative_addr) => {
-                    let absolute_address = relative_addr.value()
-                        + connection.data_segment.allocator_data_start_address();
-                    Ok(Some(Sample {
-                        subscriber: self,
-                        channel_id,
-                        ptr: unsafe {
-                            RawSample::new_unchecked(
-                                absolute_address as *mut Message<Header, MessageType>,
-                            )
-                        },
-                    }))
-                }
-            },
+            Ok(data) => Ok(data),
             Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
                 fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
                     "{} since it would exceed the maximum {} of borrowed samples.",
@@ -250,6 +354,194 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
+    fn create_sample<'subscriber>(
+        &'subscriber self,
+        channel_id: usize,
+        connection: &Connection<'config, Service>,
+        offset: PointerOffset,
+    ) -> Sample<'a, 'subscriber, 'config, Service, Header, MessageType> {
+        let absolute_address =
+            offset.value() + connection.data_segment.allocator_data_start_address();
+        // the header is written by the publisher and must not lead to an access beyond the
+        // chunk
+        let number_of_elements = std::cmp::min(
+            unsafe { (*(absolute_address as *const Header)).number_of_elements() } as usize,
+            self.max_slice_len,
+        );
+        Sample {
+            subscriber: self,
+            channel_id,
+            ptr: unsafe {
+                RawSample::new_unchecked(MessageType::message_ptr::<Header>(
+                    absolute_address,
+                    number_of_elements,
+                ))
+            },
+        }
+    }
+
+    fn precedes(&self, lhs: &Header, rhs: &Header) -> bool {
+        if self.receive_order == ReceiveOrder::Priority && lhs.priority() != rhs.priority() {
+            return lhs.priority() > rhs.priority();
+        }
+
+        lhs.time_stamp().as_duration() < rhs.time_stamp().as_duration()
+    }
+
+    fn receive_ordered<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        // every connection provides its oldest sample, the one that precedes all others is
+        // returned and the remaining ones are kept for the next receive call
+        let mut pending_samples = self.pending_samples.borrow_mut();
+        let mut preferred: Option<(usize, &Header)> = None;
+        let mut exceeds_max_borrowed_samples = false;
+        for id in 0..self.publisher_connections.len() {
+            if let Some(connection) = self.publisher_connections.get(id) {
+                if pending_samples[id].is_none() {
+                    // a connection whose samples are all borrowed is skipped, the samples of
+                    // the other connections can still be received
+                    pending_samples[id] = match connection.receiver.receive() {
+                        Ok(data) => data,
+                        Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                            exceeds_max_borrowed_samples = true;
+                            None
+                        }
+                    };
+                }
+
+                if let Some(offset) = pending_samples[id] {
+                    let header = unsafe {
+                        &*((offset.value() + connection.data_segment.allocator_data_start_address())
+                            as *const Header)
+                    };
+                    if preferred.map_or(true, |(_, p)| self.precedes(header, p)) {
+                        preferred = Some((id, header));
+                    }
+                }
+            }
+        }
+
+        if preferred.is_none() && exceeds_max_borrowed_samples {
+            fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
+                "Unable to receive another sample since it would exceed the maximum {} of borrowed samples.",
+                self.max_borrowed_samples);
+        }
+
+        Ok(preferred.and_then(|(id, _)| {
+            let offset = pending_samples[id].take()?;
+            let connection = self.publisher_connections.get(id).as_ref()?;
+            Some(self.create_sample(id, connection, offset))
+        }))
+    }
+
+    fn receive_copy<'subscriber>(
+        &'subscriber self,
+        copy_receiver: &CopyReceiver<'config, Service>,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        let msg = "Unable to receive another sample";
+        if self.max_borrowed_samples <= self.number_of_borrowed_copies.get() {
+            fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
+                "{} since it would exceed the maximum {} of borrowed samples.",
+                msg, self.max_borrowed_samples);
+        }
+
+        let message = match self.receive_order {
+            ReceiveOrder::ByPublisher => copy_receiver.receive(),
+            _ => copy_receiver.receive_ordered(|lhs, rhs| self.precedes(lhs, rhs)),
+        };
+
+        let message = match message {
+            Ok(Some(message)) => message as usize,
+            Ok(None) => return Ok(None),
+            Err(e) => {
+                fail!(from self, with ReceiveError::CorruptedCopyChannel,
+                    "{} since the copy channel is corrupted ({:?}).", msg, e);
+            }
+        };
+
+        // the message was written by the publisher and must not lead to an access beyond the
+        // received copy
+        let number_of_elements = std::cmp::min(
+            unsafe { (*(message as *const Header)).number_of_elements() } as usize,
+            self.max_slice_len,
+        );
+        self.number_of_borrowed_copies
+            .set(self.number_of_borrowed_copies.get() + 1);
+
+        Ok(Some(Sample {
+            subscriber: self,
+            channel_id: 0,
+            ptr: unsafe {
+                RawSample::new_unchecked(MessageType::message_ptr::<Header>(
+                    message,
+                    number_of_elements,
+                ))
+            },
+        }))
+    }
+
+    fn track_sequence_number(&self, header: &Header) {
+        let mut trackers = self.sequence_trackers.borrow_mut();
+        let lost_samples = match trackers
+            .iter()
+            .position(|t| t.publisher_id == header.publisher_id())
+        {
+            Some(index) => {
+                // the tracker of the least recently received publisher is always in front
+                let mut tracker = trackers.remove(index);
+                // samples from the history can be older than the last received one
+                let lost_samples = if tracker.last_sequence_number < header.sequence_number() {
+                    let lost_samples = header.sequence_number() - tracker.last_sequence_number - 1;
+                    tracker.last_sequence_number = header.sequence_number();
+                    tracker.number_of_lost_samples += lost_samples;
+                    lost_samples
+                } else {
+                    0
+                };
+                trackers.push(tracker);
+                lost_samples
+            }
+            None => {
+                if self.max_sequence_trackers <= trackers.len() {
+                    trackers.remove(0);
+                }
+                trackers.push(SequenceTracker {
+                    publisher_id: header.publisher_id(),
+                    last_sequence_number: header.sequence_number(),
+                    number_of_lost_samples: 0,
+                });
+                0
+            }
+        };
+
+        self.lost_samples_since_last_receive.set(lost_samples);
+    }
+
+    /// Returns the number of [`Sample`]s of the same [`crate::port::publisher::Publisher`] that
+    /// were lost right before the [`Sample`] that was acquired by the last successful
+    /// [`Subscriber::receive()`] call. Samples are lost when the buffer of the [`Subscriber`]
+    /// overflows or when the publisher discards them since the buffer is full.
+    /// The first [`Sample`] of every publisher defines the starting point, samples that were
+    /// sent before are not counted.
+    pub fn lost_samples_since_last_receive(&self) -> u64 {
+        self.lost_samples_since_last_receive.get()
+    }
+
+    /// Returns the total number of [`Sample`]s the [`crate::port::publisher::Publisher`] with
+    /// the given [`UniquePublisherId`] has sent but that were never received. The
+    /// [`Subscriber`] tracks at most as many publishers as the service supports, the least
+    /// recently received one is forgotten when a new publisher exceeds this limit.
+    pub fn number_of_lost_samples(&self, publisher_id: UniquePublisherId) -> u64 {
+        self.sequence_trackers
+            .borrow()
+            .iter()
+            .find(|t| t.publisher_id == publisher_id)
+            .map_or(0, |t| t.number_of_lost_samples)
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::publisher::Publisher`] is corrupted or a seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -282,25 +574,150 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 "Some samples are not being received since not all connections to publishers could be established.");
         }
 
-        for id in 0..self.publisher_connections.len() {
-            match &mut self.publisher_connections.get_mut(id) {
-                Some(ref mut connection) => {
+        // the pending notifications are discarded before the connections are scanned, a
+        // notification that arrives during the scan stays pending and wakes up the next wait
+        if let Err(e) = self.drain_notifications() {
+            warn!(from self, "Unable to discard the obsolete notifications ({:?}).", e);
+        }
+
+        if let Some(copy_receiver) = &self.copy_receiver {
+            if let Some(sample) = self.receive_copy(copy_receiver)? {
+                self.track_sequence_number(sample.header());
+                return Ok(Some(sample));
+            }
+        }
+
+        if self.receive_order == ReceiveOrder::ByPublisher {
+            for id in 0..self.publisher_connections.len() {
+                if let Some(connection) = self.publisher_connections.get(id) {
                     if let Some(sample) = self.receive_from_connection(id, connection)? {
+                        self.track_sequence_number(sample.header());
                         return Ok(Some(sample));
                     }
                 }
-                None => (),
             }
+        } else if let Some(sample) = self.receive_ordered()? {
+            self.track_sequence_number(sample.header());
+            return Ok(Some(sample));
         }
 
         Ok(None)
     }
 
+    /// Receives a [`Sample`] from [`crate::port::publisher::Publisher`]. If no sample is
+    /// available it blocks until a [`crate::port::publisher::Publisher`] delivers a new
+    /// [`Sample`] or the timeout has passed. When the timeout has passed [`None`] is returned.
+    /// It requires a service with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`],
+    /// otherwise [`SubscriberWaitError::NotificationsNotEnabled`] is returned.
+    pub fn timed_receive<'subscriber>(
+        &'subscriber self,
+        timeout: Duration,
+    ) -> Result<
+        Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>,
+        SubscriberWaitError,
+    > {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to timed_receive a sample";
+        let listener = self.notification_listener(msg)?;
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(sample) = self.receive()? {
+                return Ok(Some(sample));
+            }
+
+            let remaining_time = deadline.saturating_duration_since(Instant::now());
+            if remaining_time.is_zero()
+                || fail!(from self, when listener.timed_wait(remaining_time),
+                    "{} since the wait on the underlying event concept failed.", msg)
+                .is_none()
+            {
+                return Ok(self.receive()?);
+            }
+        }
+    }
+
+    /// Receives a [`Sample`] from [`crate::port::publisher::Publisher`]. If no sample is
+    /// available it blocks until a [`crate::port::publisher::Publisher`] delivers a new
+    /// [`Sample`]. Sporadic wakeups, for instance caused by a signal, can occur and in this
+    /// case [`None`] is returned.
+    /// It requires a service with
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`],
+    /// otherwise [`SubscriberWaitError::NotificationsNotEnabled`] is returned.
+    pub fn blocking_receive<'subscriber>(
+        &'subscriber self,
+    ) -> Result<
+        Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>,
+        SubscriberWaitError,
+    > {
+        use iceoryx2_cal::event::Listener;
+        let msg = "Unable to blocking_receive a sample";
+        let listener = self.notification_listener(msg)?;
+
+        loop {
+            if let Some(sample) = self.receive()? {
+                return Ok(Some(sample));
+            }
+
+            if fail!(from self, when listener.blocking_wait(),
+                "{} since the wait on the underlying event concept failed.", msg)
+            .is_none()
+            {
+                return Ok(self.receive()?);
+            }
+        }
+    }
+
+    fn notification_listener(
+        &self,
+        msg: &str,
+    ) -> Result<
+        &<Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener,
+        SubscriberWaitError,
+    > {
+        match &self.listener {
+            Some(listener) => Ok(listener),
+            None => {
+                fail!(from self, with SubscriberWaitError::NotificationsNotEnabled,
+                    "{} since the service does not support notifications.", msg);
+            }
+        }
+    }
+
+    /// Returns the file descriptor that becomes readable whenever a
+    /// [`crate::port::publisher::Publisher`] delivered a new [`Sample`]. Returns [`None`] when
+    /// the service does not support notifications.
+    #[cfg(feature = "async")]
+    pub(crate) fn notification_file_descriptor(
+        &self,
+    ) -> Option<&iceoryx2_bb_posix::file_descriptor::FileDescriptor>
+    where
+        <Service::Event as iceoryx2_cal::event::Event<EventId>>::Listener:
+            iceoryx2_bb_posix::file_descriptor::FileDescriptorBased,
+    {
+        use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+        self.listener.as_ref().map(|l| l.file_descriptor())
+    }
+
+    fn drain_notifications(&self) -> Result<(), ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        if let Some(listener) = &self.listener {
+            while listener.try_wait()?.is_some() {}
+        }
+
+        Ok(())
+    }
+
     /// Explicitly updates all connections to the [`crate::port::publisher::Publisher`]s. This is
     /// required to be called whenever a new [`crate::port::publisher::Publisher`] connected to
     /// the service. It is done implicitly whenever [`Subscriber::receive()`]
     /// is called.
     pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if self.copy_receiver.is_some() {
+            return Ok(());
+        }
+
         if unsafe { (*self.publisher_list_state.get()).update() } {
             fail!(from self, when self.populate_publisher_channels(),
                 "Connections were updated only partially since at least one connection to a publisher failed.");
@@ -0,0 +1,223 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let typed_service = zero_copy::Service::new(&service_name)
+//! #     .publish_subscribe()
+//! #     .open_or_create::<u64>()?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_type_erased()?;
+//!
+//! let publisher = service.publisher().create()?;
+//!
+//! // SAFETY: the service transmits an u64, every 8 byte pattern is a valid u64
+//! unsafe { publisher.send_bytes(&1337u64.to_ne_bytes())? };
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::zero_copy_connection::ZeroCopyCreationError;
+use ouroboros::self_referencing;
+
+use super::port_identifiers::UniquePublisherId;
+use super::publisher::{LoanError, Publisher, PublisherCreateError};
+use crate::service;
+use crate::service::header::publish_subscribe::Header;
+use crate::service::port_factory::publisher::LocalPublisherConfig;
+use crate::service::port_factory::type_erased::PortFactory;
+use crate::service::static_config::publish_subscribe::StaticConfig;
+
+enum_gen! {
+    /// Failure that can be emitted when raw bytes are sent via
+    /// [`TypeErasedPublisher::send_bytes()`].
+    TypeErasedSendError
+  entry:
+    PayloadSizeMismatch
+  mapping:
+    LoanError,
+    ZeroCopyCreationError
+}
+
+impl std::fmt::Display for TypeErasedSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for TypeErasedSendError {}
+
+/// Sending endpoint of a publish-subscribe communication that does not know the payload type
+/// of the [`crate::service::Service`]. It sends the payload as raw bytes.
+#[derive(Debug)]
+pub struct TypeErasedPublisher<'a, 'config: 'a, Service: service::Details<'config>> {
+    publisher: Publisher<'a, 'config, Service, [u8]>,
+    payload_offset: usize,
+    payload_size: usize,
+    max_slice_len: usize,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>>
+    TypeErasedPublisher<'a, 'config, Service>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &StaticConfig,
+        config: &LocalPublisherConfig,
+    ) -> Result<Self, PublisherCreateError> {
+        let (sample_layout, payload_offset) = static_config.message_layout::<Header>();
+
+        Ok(Self {
+            publisher: fail!(from "TypeErasedPublisher::new()", when Publisher::new_with_sample_layout(service, static_config, config, sample_layout),
+                "Unable to create TypeErasedPublisher port since the underlying publisher could not be created."),
+            payload_offset,
+            payload_size: static_config.payload_size(),
+            max_slice_len: static_config.max_slice_len(),
+        })
+    }
+
+    fn number_of_elements(&self, payload_len: usize) -> Option<usize> {
+        if self.payload_size == 0 {
+            return match payload_len {
+                0 => Some(1),
+                _ => None,
+            };
+        }
+
+        let number_of_elements = payload_len / self.payload_size;
+        match payload_len % self.payload_size == 0
+            && (1..=self.max_slice_len).contains(&number_of_elements)
+        {
+            true => Some(number_of_elements),
+            false => None,
+        }
+    }
+
+    /// Copies the raw bytes of `payload` into a sample and delivers it to all connected
+    /// [`crate::port::subscriber::Subscriber`]s. The length of the payload must be a multiple
+    /// of [`StaticConfig::payload_size()`] and cover at least one and at most
+    /// [`StaticConfig::max_slice_len()`] elements, otherwise
+    /// [`TypeErasedSendError::PayloadSizeMismatch`] is returned.
+    ///
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received the data.
+    ///
+    /// # Safety
+    ///
+    ///  * `payload` must contain a valid representation of the payload type of the
+    ///    [`crate::service::Service`], see [`StaticConfig::type_name()`]
+    ///
+    pub unsafe fn send_bytes(&self, payload: &[u8]) -> Result<usize, TypeErasedSendError> {
+        let msg = "Unable to send bytes";
+        let number_of_elements = match self.number_of_elements(payload.len()) {
+            Some(n) => n,
+            None => {
+                fail!(from self, with TypeErasedSendError::PayloadSizeMismatch,
+                    "{} since the payload of {} bytes is not a multiple of the payload size of {} bytes with at most {} elements.",
+                    msg, payload.len(), self.payload_size, self.max_slice_len);
+            }
+        };
+
+        let (address, offset) = fail!(from self, when self.publisher.loan_chunk(number_of_elements),
+                                    "{} since the loan of a sample failed.", msg);
+
+        std::ptr::copy_nonoverlapping(
+            payload.as_ptr(),
+            (address + self.payload_offset) as *mut u8,
+            payload.len(),
+        );
+
+        let number_of_recipients = self.publisher.send_impl(offset.value());
+        // the subscribers hold their own reference, the one of the loan is no longer required
+        self.publisher.release_sample(offset);
+
+        Ok(fail!(from self, when number_of_recipients,
+            "{} since the underlying send operation failed.", msg))
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`TypeErasedPublisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::subscriber::Subscriber`]s. It
+    /// is done implicitly whenever [`TypeErasedPublisher::send_bytes()`] is called.
+    pub fn update_connections(&self) -> Result<(), ZeroCopyCreationError> {
+        self.publisher.update_connections()
+    }
+}
+
+#[self_referencing]
+struct PublisherPorts<Service: service::Details<'static> + 'static> {
+    factory: PortFactory<'static, Service>,
+    #[borrows(factory)]
+    #[not_covariant]
+    publisher: TypeErasedPublisher<'this, 'static, Service>,
+}
+
+/// A [`TypeErasedPublisher`] that owns the [`PortFactory`] of its [`crate::service::Service`].
+/// Since it does not borrow the factory, it can be stored next to the publishers of other
+/// services, like in tools that handle a dynamic set of services.
+pub struct OwningTypeErasedPublisher<Service: service::Details<'static> + 'static> {
+    ports: PublisherPorts<Service>,
+}
+
+impl<Service: service::Details<'static> + 'static> Debug for OwningTypeErasedPublisher<Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.ports.with_publisher(|publisher| {
+            write!(
+                f,
+                "OwningTypeErasedPublisher {{ publisher: {:?} }}",
+                publisher
+            )
+        })
+    }
+}
+
+impl<Service: service::Details<'static> + 'static> OwningTypeErasedPublisher<Service> {
+    /// Creates a [`TypeErasedPublisher`] with the default settings and takes the ownership of
+    /// the [`PortFactory`]. On failure it returns a [`PublisherCreateError`].
+    pub fn create(factory: PortFactory<'static, Service>) -> Result<Self, PublisherCreateError> {
+        let ports = fail!(from "OwningTypeErasedPublisher::create()", when (PublisherPortsTryBuilder {
+                factory,
+                publisher_builder: |factory: &PortFactory<'static, Service>| factory.publisher().create(),
+            })
+            .try_build(),
+            "Unable to create the owning type erased publisher since the publisher could not be created.");
+
+        Ok(Self { ports })
+    }
+
+    /// Returns the [`PortFactory`] of the [`crate::service::Service`]
+    pub fn factory(&self) -> &PortFactory<'static, Service> {
+        self.ports.borrow_factory()
+    }
+
+    /// Calls `f` with the owned [`TypeErasedPublisher`] and returns its result.
+    pub fn with_publisher<R, F: FnOnce(&TypeErasedPublisher<'_, 'static, Service>) -> R>(
+        &self,
+        f: F,
+    ) -> R {
+        self.ports.with_publisher(|publisher| f(publisher))
+    }
+}
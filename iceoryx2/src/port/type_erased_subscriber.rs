@@ -0,0 +1,202 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let typed_service = zero_copy::Service::new(&service_name)
+//! #     .publish_subscribe()
+//! #     .open_or_create::<u64>()?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_type_erased()?;
+//!
+//! let subscriber = service.subscriber().create()?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received {} bytes: {:?}", sample.payload().len(), sample.payload());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use iceoryx2_bb_log::fail;
+use ouroboros::self_referencing;
+
+use super::details::publisher_connections::ConnectionFailure;
+use super::port_identifiers::UniquePublisherId;
+use super::subscriber::{ReceiveError, Subscriber, SubscriberCreateError, SubscriberWaitError};
+use crate::sample::Sample;
+use crate::service;
+use crate::service::header::publish_subscribe::Header;
+use crate::service::port_factory::subscriber::LocalSubscriberConfig;
+use crate::service::port_factory::type_erased::PortFactory;
+use crate::service::static_config::publish_subscribe::StaticConfig;
+use crate::type_erased_sample::TypeErasedSample;
+
+/// The receiving endpoint of a publish-subscribe communication that does not know the payload
+/// type of the [`crate::service::Service`]. It receives the payload as raw bytes.
+#[derive(Debug)]
+pub struct TypeErasedSubscriber<'a, 'config: 'a, Service: service::Details<'config>> {
+    subscriber: Subscriber<'a, 'config, Service, [u8]>,
+    payload_offset: usize,
+    payload_size: usize,
+    max_slice_len: usize,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>>
+    TypeErasedSubscriber<'a, 'config, Service>
+{
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &StaticConfig,
+        config: &LocalSubscriberConfig,
+    ) -> Result<Self, SubscriberCreateError> {
+        let (_, payload_offset) = static_config.message_layout::<Header>();
+
+        Ok(Self {
+            subscriber: fail!(from "TypeErasedSubscriber::new()", when Subscriber::new(service, static_config, config),
+                "Unable to create TypeErasedSubscriber port since the underlying subscriber could not be created."),
+            payload_offset,
+            payload_size: static_config.payload_size(),
+            max_slice_len: static_config.max_slice_len(),
+        })
+    }
+
+    fn type_erase<'subscriber>(
+        &'subscriber self,
+        sample: Option<Sample<'a, 'subscriber, 'config, Service, Header, [u8]>>,
+    ) -> Option<TypeErasedSample<'a, 'subscriber, 'config, Service>> {
+        sample.map(|sample| {
+            // a corrupted header must never lead to an access beyond the chunk
+            let number_of_elements = std::cmp::min(
+                sample.header().number_of_elements() as usize,
+                self.max_slice_len,
+            );
+            TypeErasedSample {
+                sample,
+                payload_offset: self.payload_offset,
+                payload_len: number_of_elements * self.payload_size,
+            }
+        })
+    }
+
+    /// Receives a [`TypeErasedSample`] from [`crate::port::publisher::Publisher`]. If no sample
+    /// could be received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    pub fn receive<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Option<TypeErasedSample<'a, 'subscriber, 'config, Service>>, ReceiveError> {
+        Ok(self.type_erase(self.subscriber.receive()?))
+    }
+
+    /// Receives a [`TypeErasedSample`] from [`crate::port::publisher::Publisher`]. If no
+    /// sample is available it blocks until a new sample was delivered or the timeout has passed.
+    /// See [`Subscriber::timed_receive()`] for details.
+    pub fn timed_receive<'subscriber>(
+        &'subscriber self,
+        timeout: Duration,
+    ) -> Result<Option<TypeErasedSample<'a, 'subscriber, 'config, Service>>, SubscriberWaitError>
+    {
+        Ok(self.type_erase(self.subscriber.timed_receive(timeout)?))
+    }
+
+    /// Receives a [`TypeErasedSample`] from [`crate::port::publisher::Publisher`]. If no
+    /// sample is available it blocks until a new sample was delivered.
+    /// See [`Subscriber::blocking_receive()`] for details.
+    pub fn blocking_receive<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Option<TypeErasedSample<'a, 'subscriber, 'config, Service>>, SubscriberWaitError>
+    {
+        Ok(self.type_erase(self.subscriber.blocking_receive()?))
+    }
+
+    /// Returns the number of samples that were lost right before the last received sample.
+    /// See [`Subscriber::lost_samples_since_last_receive()`] for details.
+    pub fn lost_samples_since_last_receive(&self) -> u64 {
+        self.subscriber.lost_samples_since_last_receive()
+    }
+
+    /// Returns the total number of lost samples of the given publisher.
+    /// See [`Subscriber::number_of_lost_samples()`] for details.
+    pub fn number_of_lost_samples(&self, publisher_id: UniquePublisherId) -> u64 {
+        self.subscriber.number_of_lost_samples(publisher_id)
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::publisher::Publisher`]s. It is
+    /// done implicitly whenever a sample is received.
+    pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
+
+#[self_referencing]
+struct SubscriberPorts<Service: service::Details<'static> + 'static> {
+    factory: PortFactory<'static, Service>,
+    #[borrows(factory)]
+    #[not_covariant]
+    subscriber: TypeErasedSubscriber<'this, 'static, Service>,
+}
+
+/// A [`TypeErasedSubscriber`] that owns the [`PortFactory`] of its [`crate::service::Service`].
+/// Since it does not borrow the factory, it can be stored next to the subscribers of other
+/// services, like in tools that handle a dynamic set of services.
+pub struct OwningTypeErasedSubscriber<Service: service::Details<'static> + 'static> {
+    ports: SubscriberPorts<Service>,
+}
+
+impl<Service: service::Details<'static> + 'static> Debug for OwningTypeErasedSubscriber<Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.ports.with_subscriber(|subscriber| {
+            write!(
+                f,
+                "OwningTypeErasedSubscriber {{ subscriber: {:?} }}",
+                subscriber
+            )
+        })
+    }
+}
+
+impl<Service: service::Details<'static> + 'static> OwningTypeErasedSubscriber<Service> {
+    /// Creates a [`TypeErasedSubscriber`] with the default settings and takes the ownership of
+    /// the [`PortFactory`]. On failure it returns a [`SubscriberCreateError`].
+    pub fn create(factory: PortFactory<'static, Service>) -> Result<Self, SubscriberCreateError> {
+        let ports = fail!(from "OwningTypeErasedSubscriber::create()", when (SubscriberPortsTryBuilder {
+                factory,
+                subscriber_builder: |factory: &PortFactory<'static, Service>| factory.subscriber().create(),
+            })
+            .try_build(),
+            "Unable to create the owning type erased subscriber since the subscriber could not be created.");
+
+        Ok(Self { ports })
+    }
+
+    /// Returns the [`PortFactory`] of the [`crate::service::Service`]
+    pub fn factory(&self) -> &PortFactory<'static, Service> {
+        self.ports.borrow_factory()
+    }
+
+    /// Calls `f` with the owned [`TypeErasedSubscriber`] and returns its result.
+    pub fn with_subscriber<R, F: FnOnce(&TypeErasedSubscriber<'_, 'static, Service>) -> R>(
+        &self,
+        f: F,
+    ) -> R {
+        self.ports.with_subscriber(|subscriber| f(subscriber))
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer().create()?;
+//!
+//! writer.write(0, 1234)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::Producer;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::{tag_port, PortType};
+use crate::port::port_identifiers::UniqueWriterId;
+use crate::service;
+
+/// Failures that can occur when a new [`Writer`] is created with the
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterCreateError {
+    ExceedsMaxSupportedWriters,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Defines the failures that can occur while a [`Writer::write()`] call.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterWriteError {
+    EntryDoesNotExist,
+}
+
+impl std::fmt::Display for WriterWriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WriterWriteError {}
+
+/// The writing endpoint of a blackboard based communication. There can be only one [`Writer`]
+/// per [`crate::service::Service`]. It updates the entries in place.
+pub struct Writer<'a, 'config: 'a, Service: service::Details<'config>, ValueType: Copy + Debug> {
+    service: &'a Service,
+    port_id: UniqueWriterId,
+    producers: Vec<Producer<'a, ValueType>>,
+    _dynamic_config_guard: UniqueIndex<'a>,
+    _node_port_tag: Option<Service::StaticStorage>,
+    _phantom_b: PhantomData<&'config ()>,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, ValueType: Copy + Debug> Debug
+    for Writer<'a, 'config, Service, ValueType>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Writer<{}, {}> {{ service: {:?}, port_id: {:?} }}",
+            std::any::type_name::<Service>(),
+            std::any::type_name::<ValueType>(),
+            self.service,
+            self.port_id
+        )
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, ValueType: Copy + Debug>
+    Writer<'a, 'config, Service, ValueType>
+{
+    pub(crate) fn new(service: &'a Service) -> Result<Self, WriterCreateError> {
+        let msg = "Unable to create Writer port";
+        let origin = "Writer::new()";
+        let port_id = UniqueWriterId::new();
+        let dynamic_config = service.state().dynamic_storage.get().blackboard();
+
+        let _dynamic_config_guard = match dynamic_config.add_writer_id(port_id) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                            "{} since it would exceed the maximum supported amount of writers of {}.",
+                            msg, service.state().static_config.blackboard().max_writers);
+            }
+        };
+
+        let number_of_entries = service.state().static_config.blackboard().keys.len();
+        let mut producers = Vec::with_capacity(number_of_entries);
+        for index in 0..number_of_entries {
+            match unsafe { dynamic_config.entry::<ValueType>(index) }.acquire_producer() {
+                Some(producer) => producers.push(producer),
+                None => {
+                    fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                        "{} since the entry {} is still owned by another writer.", msg, index);
+                }
+            }
+        }
+
+        Ok(Self {
+            service,
+            port_id,
+            producers,
+            _dynamic_config_guard,
+            _node_port_tag: tag_port(service, PortType::Writer, port_id.value()),
+            _phantom_b: PhantomData,
+        })
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.port_id
+    }
+
+    /// Updates the entry identified by `key` with `value`. All
+    /// [`crate::port::reader::Reader`]s will read the new value from now on. If the
+    /// [`crate::service::Service`] does not provide an entry with that `key` it returns
+    /// [`WriterWriteError::EntryDoesNotExist`].
+    pub fn write(&self, key: u64, value: ValueType) -> Result<(), WriterWriteError> {
+        match self
+            .service
+            .state()
+            .static_config
+            .blackboard()
+            .entry_index(key)
+        {
+            Some(index) => {
+                self.producers[index].store(value);
+                Ok(())
+            }
+            None => {
+                fail!(from self, with WriterWriteError::EntryDoesNotExist,
+                    "Unable to write entry since the blackboard does not contain the key {}.", key);
+            }
+        }
+    }
+}
@@ -16,11 +16,11 @@ use core::fmt;
 
 /// A `*const Message<Header, Data>` non-zero sample pointer to the message.
 #[repr(transparent)]
-pub(crate) struct RawSample<Header, Data> {
+pub(crate) struct RawSample<Header, Data: ?Sized> {
     message: *const Message<Header, Data>,
 }
 
-impl<Header, Data> RawSample<Header, Data> {
+impl<Header, Data: ?Sized> RawSample<Header, Data> {
     /// Creates a new `RawSample`.
     ///
     /// # Safety
@@ -77,22 +77,22 @@ impl<Header, Data> RawSample<Header, Data> {
     }
 }
 
-impl<Header, Data> Clone for RawSample<Header, Data> {
+impl<Header, Data: ?Sized> Clone for RawSample<Header, Data> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, Data> Copy for RawSample<Header, Data> {}
+impl<Header, Data: ?Sized> Copy for RawSample<Header, Data> {}
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for RawSample<Header, Data> {
+impl<Header: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug for RawSample<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.message, f)
     }
 }
 
-impl<Header, Data> fmt::Pointer for RawSample<Header, Data> {
+impl<Header, Data: ?Sized> fmt::Pointer for RawSample<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.message, f)
     }
@@ -100,11 +100,11 @@ impl<Header, Data> fmt::Pointer for RawSample<Header, Data> {
 
 /// A `*mut Message<Header, Data>` non-zero sample pointer to the message.
 #[repr(transparent)]
-pub(crate) struct RawSampleMut<Header, Data> {
+pub(crate) struct RawSampleMut<Header, Data: ?Sized> {
     message: *mut Message<Header, Data>,
 }
 
-impl<Header, Data> RawSampleMut<Header, Data> {
+impl<Header, Data: ?Sized> RawSampleMut<Header, Data> {
     /// Creates a new `RawSampleMut`.
     ///
     /// # Safety
@@ -183,22 +183,22 @@ impl<Header, Data> RawSampleMut<Header, Data> {
     }
 }
 
-impl<Header, Data> Clone for RawSampleMut<Header, Data> {
+impl<Header, Data: ?Sized> Clone for RawSampleMut<Header, Data> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, Data> Copy for RawSampleMut<Header, Data> {}
+impl<Header, Data: ?Sized> Copy for RawSampleMut<Header, Data> {}
 
-impl<Header: fmt::Debug, Data: fmt::Debug> fmt::Debug for RawSampleMut<Header, Data> {
+impl<Header: fmt::Debug, Data: fmt::Debug + ?Sized> fmt::Debug for RawSampleMut<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.as_ptr(), f)
     }
 }
 
-impl<Header, Data> fmt::Pointer for RawSampleMut<Header, Data> {
+impl<Header, Data: ?Sized> fmt::Pointer for RawSampleMut<Header, Data> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.as_ptr(), f)
     }
@@ -0,0 +1,76 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Connects the ports of iceoryx2 with the reactor of an arbitrary async executor. The
+//! ports provide a file descriptor which becomes readable whenever new data or events
+//! arrived. The [`Reactor`] registers this file descriptor at the executor and the returned
+//! [`Readiness`] wakes the waiting future as soon as the file descriptor is readable, no
+//! additional thread is required.
+//!
+//! # Example
+//!
+//! An adapter for the `AsyncFd` of the [tokio crate](https://crates.io/crates/tokio).
+//!
+//! ```ignore
+//! use iceoryx2::reactor::{Reactor, Readiness};
+//! use std::task::{Context, Poll};
+//! use tokio::io::unix::AsyncFd;
+//!
+//! struct TokioReactor;
+//! struct TokioReadiness(AsyncFd<i32>);
+//!
+//! impl Reactor for TokioReactor {
+//!     type Readiness = TokioReadiness;
+//!
+//!     fn register(&self, file_descriptor: i32) -> std::io::Result<TokioReadiness> {
+//!         Ok(TokioReadiness(AsyncFd::new(file_descriptor)?))
+//!     }
+//! }
+//!
+//! impl Readiness for TokioReadiness {
+//!     fn poll_readable(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+//!         match self.0.poll_read_ready(cx) {
+//!             Poll::Ready(Ok(mut guard)) => {
+//!                 // the port drains the file descriptor before it waits again
+//!                 guard.clear_ready();
+//!                 Poll::Ready(Ok(()))
+//!             }
+//!             Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+//!             Poll::Pending => Poll::Pending,
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::task::{Context, Poll};
+
+/// Registers the file descriptor of a port at the reactor of an async executor.
+pub trait Reactor {
+    /// The registration of a single file descriptor.
+    type Readiness: Readiness;
+
+    /// Registers the file descriptor. The file descriptor is owned by the port and must not
+    /// be closed by the reactor.
+    fn register(&self, file_descriptor: i32) -> std::io::Result<Self::Readiness>;
+}
+
+/// The registration of a file descriptor at the reactor of an async executor. The registration
+/// is removed when it goes out of scope.
+pub trait Readiness {
+    /// Returns [`Poll::Ready`] when the file descriptor is readable, otherwise
+    /// [`Poll::Pending`] and the waker of the [`Context`] is woken as soon as the file
+    /// descriptor becomes readable.
+    ///
+    /// The port consumes all pending data before it polls again, therefore a readiness that
+    /// was returned once must be cleared.
+    fn poll_readable(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>>;
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .request_response()
+//! #   .open_or_create::<u64, u64>()?;
+//! # let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("received: {:?}", *request);
+//!     println!("client id {:?}, request id {:?}",
+//!         request.header().client_id(), request.header().request_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{
+    port::server::Server, raw_sample::RawSample, service,
+    service::header::request_response::RequestHeader,
+};
+
+/// It stores the payload of a request and is acquired by the [`Server`] whenever it receives a
+/// new request from a [`crate::port::client::Client`] via [`Server::receive()`].
+/// It is used to loan the corresponding response with [`Server::loan()`] or
+/// [`Server::loan_uninit()`].
+#[derive(Debug)]
+pub struct Request<
+    'a,
+    'server,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    pub(crate) server: &'server Server<'a, 'config, Service, RequestType, ResponseType>,
+    pub(crate) ptr: RawSample<RequestHeader, RequestType>,
+    pub(crate) channel_id: usize,
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Deref
+    for Request<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    type Target = RequestType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Drop
+    for Request<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        self.server.release_request(self.channel_id, self.ptr);
+    }
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug>
+    Request<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    /// Returns a reference to the payload of the request
+    pub fn payload(&self) -> &RequestType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+}
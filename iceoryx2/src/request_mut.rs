@@ -0,0 +1,207 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! #
+//! # let service = zero_copy::Service::new(&service_name)
+//! #     .request_response()
+//! #     .open_or_create::<u64, u64>()?;
+//! #
+//! # let client = service.client().create()?;
+//!
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1234);
+//!
+//! println!("client port id: {:?}, request id: {:?}",
+//!     request.header().client_id(), request.header().request_id());
+//! client.send(request)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    port::client::Client, raw_sample::RawSampleMut, service,
+    service::header::request_response::RequestHeader,
+};
+use iceoryx2_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
+
+/// Acquired by a [`Client`] via [`Client::loan()`] or [`Client::loan_uninit()`]. It stores the
+/// payload of the request that will be sent to all connected [`crate::port::server::Server`]s.
+/// If the [`RequestMut`] is not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent requests in the [`Client`] and the
+/// [`Client`] is not thread-safe!
+#[derive(Debug)]
+pub struct RequestMut<
+    'a,
+    'client,
+    'config,
+    Service: service::Details<'config>,
+    M: Debug,
+    ResponseType: Debug,
+> {
+    client: &'client Client<'a, 'config, Service, M, ResponseType>,
+    ptr: RawSampleMut<RequestHeader, M>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<'config, Service: service::Details<'config>, M: Debug, ResponseType: Debug> Drop
+    for RequestMut<'_, '_, 'config, Service, M, ResponseType>
+{
+    fn drop(&mut self) {
+        self.client.release_request(self.offset_to_chunk);
+        self.client.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<
+        'a,
+        'client,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > RequestMut<'a, 'client, 'config, Service, MaybeUninit<RequestType>, ResponseType>
+{
+    pub(crate) fn new(
+        client: &'client Client<'a, 'config, Service, RequestType, ResponseType>,
+        ptr: RawSampleMut<RequestHeader, MaybeUninit<RequestType>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        client.loan_counter.fetch_add(1, Ordering::Relaxed);
+
+        // SAFETY: the transmute is not nice but safe since MaybeUninit is #[repr(transparent)} to the inner type
+        let client = unsafe { std::mem::transmute(client) };
+
+        Self {
+            client,
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Writes the payload to the request and labels the request as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(1234);
+    ///
+    /// client.send(request)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(
+        mut self,
+        value: RequestType,
+    ) -> RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<RequestType>`] container and labels the request as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<RequestType>`] really is initialized. Calling this when
+    /// the content is not fully initialized causes immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let client = service.client().create()?;
+    ///
+    /// let mut request = client.loan_uninit()?;
+    /// request.payload_mut().write(1234);
+    /// let request = unsafe { request.assume_init() };
+    ///
+    /// client.send(request)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(
+        self,
+    ) -> RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        'a,
+        'client,
+        'config,
+        Service: service::Details<'config>,
+        M: Debug, // `M` is either a `RequestType` or a `MaybeUninit<RequestType>`
+        ResponseType: Debug,
+    > RequestMut<'a, 'client, 'config, Service, M, ResponseType>
+{
+    pub(crate) fn offset_to_chunk(&self) -> PointerOffset {
+        self.offset_to_chunk
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the request.
+    ///
+    /// # Notes
+    ///
+    /// The generic parameter `M` is either a `RequestType` or a [`core::mem::MaybeUninit<RequestType>`], depending
+    /// which API is used to obtain the request. Obtaining a reference is safe for either type.
+    pub fn payload(&self) -> &M {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the request.
+    ///
+    /// # Notes
+    ///
+    /// The generic parameter `M` is either a `RequestType` or a [`core::mem::MaybeUninit<RequestType>`], depending
+    /// which API is used to obtain the request. Obtaining a mut reference is safe for either type.
+    pub fn payload_mut(&mut self) -> &mut M {
+        self.ptr.as_data_mut()
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .request_response()
+//! #   .open_or_create::<u64, u64>()?;
+//! # let client = service.client().create()?;
+//!
+//! while let Some(response) = client.receive()? {
+//!     println!("received: {:?}", *response);
+//!     println!("server id {:?}, request id {:?}",
+//!         response.header().server_id(), response.header().request_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{
+    port::client::Client, raw_sample::RawSample, service,
+    service::header::request_response::ResponseHeader,
+};
+
+/// It stores the payload of a response and is acquired by the [`Client`] whenever it receives
+/// a response from a [`crate::port::server::Server`] via [`Client::receive()`].
+#[derive(Debug)]
+pub struct Response<
+    'a,
+    'client,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    pub(crate) client: &'client Client<'a, 'config, Service, RequestType, ResponseType>,
+    pub(crate) ptr: RawSample<ResponseHeader, ResponseType>,
+    pub(crate) channel_id: usize,
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Deref
+    for Response<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    type Target = ResponseType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Drop
+    for Response<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        self.client.release_response(self.channel_id, self.ptr);
+    }
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug>
+    Response<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    /// Returns a reference to the payload of the response
+    pub fn payload(&self) -> &ResponseType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+}
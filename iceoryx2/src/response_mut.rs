@@ -0,0 +1,215 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! #
+//! # let service = zero_copy::Service::new(&service_name)
+//! #     .request_response()
+//! #     .open_or_create::<u64, u64>()?;
+//! #
+//! # let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     let response = server.loan_uninit(&request)?;
+//!     let response = response.write_payload(*request + 1);
+//!
+//!     println!("server port id: {:?}, client port id: {:?}, request id: {:?}",
+//!         response.header().server_id(), response.header().client_id(),
+//!         response.header().request_id());
+//!     server.send(response)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    port::server::Server, raw_sample::RawSampleMut, service,
+    service::header::request_response::ResponseHeader,
+};
+use iceoryx2_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
+
+/// Acquired by a [`Server`] via [`Server::loan()`] or [`Server::loan_uninit()`]. It stores the
+/// payload of the response that will be sent to the [`crate::port::client::Client`] of the
+/// corresponding [`crate::request::Request`]. If the [`ResponseMut`] is not sent it will release
+/// the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent responses in the [`Server`] and the
+/// [`Server`] is not thread-safe!
+#[derive(Debug)]
+pub struct ResponseMut<
+    'a,
+    'server,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    M: Debug,
+> {
+    server: &'server Server<'a, 'config, Service, RequestType, M>,
+    ptr: RawSampleMut<ResponseHeader, M>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, M: Debug> Drop
+    for ResponseMut<'_, '_, 'config, Service, RequestType, M>
+{
+    fn drop(&mut self) {
+        self.server.release_response(self.offset_to_chunk);
+        self.server.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<
+        'a,
+        'server,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > ResponseMut<'a, 'server, 'config, Service, RequestType, MaybeUninit<ResponseType>>
+{
+    pub(crate) fn new(
+        server: &'server Server<'a, 'config, Service, RequestType, ResponseType>,
+        ptr: RawSampleMut<ResponseHeader, MaybeUninit<ResponseType>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        server.loan_counter.fetch_add(1, Ordering::Relaxed);
+
+        // SAFETY: the transmute is not nice but safe since MaybeUninit is #[repr(transparent)} to the inner type
+        let server = unsafe { std::mem::transmute(server) };
+
+        Self {
+            server,
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Writes the payload to the response and labels the response as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let server = service.server().create()?;
+    ///
+    /// while let Some(request) = server.receive()? {
+    ///     let response = server.loan_uninit(&request)?;
+    ///     let response = response.write_payload(1234);
+    ///
+    ///     server.send(response)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(
+        mut self,
+        value: ResponseType,
+    ) -> ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<ResponseType>`] container and labels the response as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<ResponseType>`] really is initialized. Calling this when
+    /// the content is not fully initialized causes immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .request_response()
+    /// #     .open_or_create::<u64, u64>()?;
+    /// #
+    /// # let server = service.server().create()?;
+    ///
+    /// while let Some(request) = server.receive()? {
+    ///     let mut response = server.loan_uninit(&request)?;
+    ///     response.payload_mut().write(1234);
+    ///     let response = unsafe { response.assume_init() };
+    ///
+    ///     server.send(response)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(
+        self,
+    ) -> ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        'a,
+        'server,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        M: Debug, // `M` is either a `ResponseType` or a `MaybeUninit<ResponseType>`
+    > ResponseMut<'a, 'server, 'config, Service, RequestType, M>
+{
+    pub(crate) fn offset_to_chunk(&self) -> PointerOffset {
+        self.offset_to_chunk
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the response.
+    ///
+    /// # Notes
+    ///
+    /// The generic parameter `M` is either a `ResponseType` or a [`core::mem::MaybeUninit<ResponseType>`], depending
+    /// which API is used to obtain the response. Obtaining a reference is safe for either type.
+    pub fn payload(&self) -> &M {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the response.
+    ///
+    /// # Notes
+    ///
+    /// The generic parameter `M` is either a `ResponseType` or a [`core::mem::MaybeUninit<ResponseType>`], depending
+    /// which API is used to obtain the response. Obtaining a mut reference is safe for either type.
+    pub fn payload_mut(&mut self) -> &mut M {
+        self.ptr.as_data_mut()
+    }
+}
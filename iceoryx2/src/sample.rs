@@ -33,7 +33,9 @@
 
 use std::{fmt::Debug, ops::Deref};
 
-use crate::{port::subscriber::Subscriber, raw_sample::RawSample, service};
+use crate::{
+    payload_type::PayloadType, port::subscriber::Subscriber, raw_sample::RawSample, service,
+};
 
 /// It stores the payload and is acquired by the [`Subscriber`] whenever it receives new data from a
 /// [`crate::port::publisher::Publisher`] via [`Subscriber::receive()`].
@@ -44,15 +46,19 @@ pub struct Sample<
     'config,
     Service: service::Details<'config>,
     Header: Debug,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
     pub(crate) subscriber: &'subscriber Subscriber<'a, 'config, Service, MessageType>,
     pub(crate) ptr: RawSample<Header, MessageType>,
     pub(crate) channel_id: usize,
 }
 
-impl<'config, Service: service::Details<'config>, Header: Debug, MessageType: Debug> Deref
-    for Sample<'_, '_, 'config, Service, Header, MessageType>
+impl<
+        'config,
+        Service: service::Details<'config>,
+        Header: Debug,
+        MessageType: PayloadType + ?Sized,
+    > Deref for Sample<'_, '_, 'config, Service, Header, MessageType>
 {
     type Target = MessageType;
     fn deref(&self) -> &Self::Target {
@@ -66,7 +72,7 @@ impl<
         'config,
         Service: service::Details<'config>,
         Header: Debug,
-        MessageType: Debug,
+        MessageType: PayloadType + ?Sized,
     > Drop for Sample<'a, 'subscriber, 'config, Service, Header, MessageType>
 {
     fn drop(&mut self) {
@@ -80,7 +86,7 @@ impl<
         'config,
         Service: service::Details<'config>,
         Header: Debug,
-        MessageType: Debug,
+        MessageType: PayloadType + ?Sized,
     > Sample<'a, 'subscriber, 'config, Service, Header, MessageType>
 {
     /// Returns a reference to the payload of the sample
@@ -34,7 +34,9 @@
 //! # }
 //! ```
 
-use crate::{port::publisher::Publisher, raw_sample::RawSampleMut, service};
+use crate::{
+    payload_type::PayloadType, port::publisher::Publisher, raw_sample::RawSampleMut, service,
+};
 use iceoryx2_cal::shared_memory::*;
 use std::{fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
 
@@ -48,7 +50,8 @@ use std::{fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
 /// [`Publisher`] is not thread-safe!
 ///
 /// The generic parameter `M` is either a `MessageType` or a [`core::mem::MaybeUninit<MessageType>`], depending
-/// which API is used to obtain the sample.
+/// which API is used to obtain the sample. For slice payloads it is either a `[T]` or a
+/// `[`[`core::mem::MaybeUninit<T>`]`]`.
 #[derive(Debug)]
 pub struct SampleMut<
     'a,
@@ -56,14 +59,14 @@ pub struct SampleMut<
     'config,
     Service: service::Details<'config>,
     Header: Debug,
-    M: Debug,
+    M: PayloadType + ?Sized,
 > {
     publisher: &'publisher Publisher<'a, 'config, Service, M>,
     ptr: RawSampleMut<Header, M>,
     offset_to_chunk: PointerOffset,
 }
 
-impl<'config, Service: service::Details<'config>, Header: Debug, M: Debug> Drop
+impl<'config, Service: service::Details<'config>, Header: Debug, M: PayloadType + ?Sized> Drop
     for SampleMut<'_, '_, 'config, Service, Header, M>
 {
     fn drop(&mut self) {
@@ -167,13 +170,109 @@ impl<
     }
 }
 
+impl<'a, 'publisher, 'config, Service: service::Details<'config>, Header: Debug, T: Debug>
+    SampleMut<'a, 'publisher, 'config, Service, Header, [MaybeUninit<T>]>
+{
+    pub(crate) fn new_slice(
+        publisher: &'publisher Publisher<'a, 'config, Service, [T]>,
+        ptr: RawSampleMut<Header, [MaybeUninit<T>]>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        publisher.loan_counter.fetch_add(1, Ordering::Relaxed);
+
+        // SAFETY: the transmute is not nice but safe since MaybeUninit is #[repr(transparent)} to the inner type
+        let publisher = unsafe { std::mem::transmute(publisher) };
+
+        Self {
+            publisher,
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Initializes every element of the slice payload with the value returned by `initializer`
+    /// for the corresponding index and labels the sample as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let sample = publisher.loan_uninit_slice(8)?;
+    /// let sample = sample.write_from_fn(|index| index as u64 * 1234);
+    ///
+    /// publisher.send(sample)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_from_fn<F: FnMut(usize) -> T>(
+        mut self,
+        mut initializer: F,
+    ) -> SampleMut<'a, 'publisher, 'config, Service, Header, [T]> {
+        for (index, element) in self.payload_mut().iter_mut().enumerate() {
+            element.write(initializer(index));
+        }
+
+        // SAFETY: this is safe since every element of the payload was initialized in the loop above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the values of the [`core::mem::MaybeUninit<T>`] slice and labels the sample as
+    /// initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every element of the [`core::mem::MaybeUninit<T>`] slice
+    /// really is initialized. Calling this when the content is not fully initialized causes
+    /// immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .max_slice_len(16)
+    /// #     .open_or_create::<[u64]>()?;
+    /// #
+    /// # let publisher = service.publisher().create()?;
+    ///
+    /// let mut sample = publisher.loan_uninit_slice(2)?;
+    /// sample.payload_mut()[0].write(12);
+    /// sample.payload_mut()[1].write(34);
+    /// let sample = unsafe { sample.assume_init() };
+    ///
+    /// publisher.send(sample)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> SampleMut<'a, 'publisher, 'config, Service, Header, [T]> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
 impl<
         'a,
         'publisher,
         'config,
         Service: service::Details<'config>,
         Header: Debug,
-        M: Debug, // `M` is either a `MessageType` or a `MaybeUninit<MessageType>`
+        M: PayloadType + ?Sized, // `M` is either a `MessageType` or a `MaybeUninit<MessageType>` or the slice equivalent
     > SampleMut<'a, 'publisher, 'config, Service, Header, M>
 {
     pub(crate) fn offset_to_chunk(&self) -> PointerOffset {
@@ -0,0 +1,244 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::access_control::Operation;
+//! use iceoryx2_bb_posix::user::User;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let myself = User::from_self().expect("the user of the process must be known");
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     // only the current user is allowed to publish
+//!     .permit_user(myself.uid(), Operation::Publish)
+//!     // every member of the users primary group is allowed to subscribe
+//!     .permit_group(myself.gid(), Operation::Subscribe)
+//!     .create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::access_control_list::AclPermission;
+use iceoryx2_bb_posix::group::Group;
+use iceoryx2_bb_posix::user::User;
+use iceoryx2_cal::named_concept::AccessRights;
+use serde::{Deserialize, Serialize};
+
+/// The operations on a [`crate::service::Service`] that can be restricted to certain users and
+/// groups. Every operation corresponds to the creation of a port.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Operation {
+    /// Create a [`crate::port::publisher::Publisher`]
+    Publish,
+    /// Create a [`crate::port::subscriber::Subscriber`]
+    Subscribe,
+    /// Create a [`crate::port::notifier::Notifier`]
+    Notify,
+    /// Create a [`crate::port::listener::Listener`]
+    Listen,
+}
+
+/// The underlying resources of a [`crate::service::Service`] that are protected by the
+/// [`AccessControl`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(crate) enum Resource {
+    /// Read by every port when the service is opened.
+    StaticConfig,
+    /// Every port registers itself in the dynamic config.
+    DynamicConfig,
+    /// Written by the publisher and only read by the subscribers.
+    DataSegment,
+    /// Both the publisher and the subscriber update the queues of the connection.
+    Connection,
+    /// Owned by the listener, the notifiers write their events into it.
+    EventConnection,
+}
+
+impl Resource {
+    /// Returns the [`AclPermission`] an [`Operation`] requires on the resource or [`None`]
+    /// when the resource is not accessed.
+    fn required_permission(&self, operation: Operation) -> Option<AclPermission> {
+        match (self, operation) {
+            (Resource::StaticConfig, _) => Some(AclPermission::Read),
+            (Resource::DynamicConfig, _) => Some(AclPermission::ReadWrite),
+            (Resource::DataSegment, Operation::Subscribe) => Some(AclPermission::Read),
+            (Resource::DataSegment, _) => None,
+            (Resource::Connection, Operation::Publish | Operation::Subscribe) => {
+                Some(AclPermission::ReadWrite)
+            }
+            (Resource::Connection, _) => None,
+            (Resource::EventConnection, Operation::Notify) => Some(AclPermission::Write),
+            (Resource::EventConnection, _) => None,
+        }
+    }
+}
+
+/// Defines if an [`AccessRule`] refers to a user or a group.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum Subject {
+    User,
+    Group,
+}
+
+/// Permits a user or a group to perform an [`Operation`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AccessRule {
+    subject: Subject,
+    id: u32,
+    operation: Operation,
+}
+
+impl AccessRule {
+    /// Returns if the rule refers to a user or a group
+    pub fn subject(&self) -> Subject {
+        self.subject
+    }
+
+    /// Returns the uid of the user or the gid of the group
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Returns the permitted [`Operation`]
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+}
+
+/// The access control of a [`crate::service::Service`]. It is defined when the service is
+/// created and stored in its [`crate::service::static_config::StaticConfig`].
+///
+/// A service without [`AccessRule`]s is unrestricted. As soon as one rule is defined, the
+/// underlying resources of the service are only accessible by the owner, the user that created
+/// the service, and the users and groups of the rules. The users and groups are only granted
+/// the access their [`Operation`] requires, e.g. a subscriber can read but not write the data
+/// segments of the publishers. Furthermore, a port can only be created when the process' user,
+/// its primary group or one of its supplementary groups is permitted to perform the
+/// corresponding [`Operation`]. The owner is no exception.
+///
+/// Service variants that share their underlying resources between services, like
+/// [`crate::service::zero_copy_directory`], cannot restrict them to single users and groups.
+/// A restricted service of such a variant cannot be created, the creation fails with
+/// [`PublishSubscribeCreateError::AccessControlNotSupported`](crate::service::builder::publish_subscribe::PublishSubscribeCreateError::AccessControlNotSupported)
+/// or [`EventCreateError::AccessControlNotSupported`](crate::service::builder::event::EventCreateError::AccessControlNotSupported).
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AccessControl {
+    rules: Vec<AccessRule>,
+}
+
+impl AccessControl {
+    /// Returns all [`AccessRule`]s
+    pub fn rules(&self) -> &[AccessRule] {
+        &self.rules
+    }
+
+    /// Returns true when the access to the service is restricted, otherwise false.
+    pub fn is_restricted(&self) -> bool {
+        !self.rules.is_empty()
+    }
+
+    /// Returns true when the current process is permitted to perform the [`Operation`],
+    /// otherwise false.
+    pub fn is_permitted(&self, operation: Operation) -> bool {
+        if !self.is_restricted() {
+            return true;
+        }
+
+        let user = match User::from_self() {
+            Ok(user) => user,
+            Err(e) => {
+                warn!(from self, "Unable to acquire the user of the process ({:?}). The operation {:?} is not permitted.",
+                    e, operation);
+                return false;
+            }
+        };
+
+        let mut gids = match Group::supplementary_gids_of_self() {
+            Ok(gids) => gids,
+            Err(e) => {
+                warn!(from self, "Unable to acquire the supplementary groups of the process ({:?}). Only the primary group is considered for the operation {:?}.",
+                    e, operation);
+                vec![]
+            }
+        };
+        gids.push(user.gid());
+
+        self.rules
+            .iter()
+            .filter(|rule| rule.operation == operation)
+            .any(|rule| match rule.subject {
+                Subject::User => rule.id == user.uid(),
+                Subject::Group => gids.contains(&rule.id),
+            })
+    }
+
+    /// Adds a new [`AccessRule`]. Returns false when the rule would exceed the maximum supported
+    /// number of users or groups,
+    /// [`iceoryx2_cal::named_concept::MAX_NUMBER_OF_ACCESS_RIGHTS`].
+    pub(crate) fn add(&mut self, subject: Subject, id: u32, operation: Operation) -> bool {
+        let rule = AccessRule {
+            subject,
+            id,
+            operation,
+        };
+
+        if self.rules.contains(&rule) {
+            return true;
+        }
+
+        // the static config is accessed by every operation and contains therefore every user
+        // and group
+        if !Self::grant(
+            &mut self.access_rights(Resource::StaticConfig),
+            &rule,
+            AclPermission::Read,
+        ) {
+            return false;
+        }
+
+        self.rules.push(rule);
+        true
+    }
+
+    /// Returns the [`AccessRights`] that must be applied to the [`Resource`] so that every user
+    /// and group of the rules can perform their [`Operation`].
+    pub(crate) fn access_rights(&self, resource: Resource) -> AccessRights {
+        let mut access_rights = AccessRights::default();
+        for rule in &self.rules {
+            if let Some(permission) = resource.required_permission(rule.operation) {
+                Self::grant(&mut access_rights, rule, permission);
+            }
+        }
+        access_rights
+    }
+
+    fn grant(
+        access_rights: &mut AccessRights,
+        rule: &AccessRule,
+        permission: AclPermission,
+    ) -> bool {
+        match rule.subject {
+            Subject::User => access_rights.add_user(rule.id, permission),
+            Subject::Group => access_rights.add_group(rule.id, permission),
+        }
+    }
+}
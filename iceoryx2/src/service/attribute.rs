@@ -0,0 +1,143 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::attribute::AttributeSet;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/Odometry")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .attribute("unit", "m/s")
+//!     .attribute("schema_version", "2")
+//!     .create::<f64>()?;
+//!
+//! println!("unit: {:?}", service.attributes().get("unit"));
+//!
+//! // opening the service requires that all attributes of the builder are defined with the
+//! // same value
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .attribute("schema_version", "2")
+//!     .open::<f64>()?;
+//!
+//! // list only the services with a specific attribute
+//! let required = AttributeSet::new().define("unit", "m/s");
+//! for service in zero_copy::Service::list_with_attributes(&required)? {
+//!     println!("{}", service.service_name());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// A key-value pair that describes a [`crate::service::Service`], like the unit of the
+/// payload, the coordinate frame or the schema version.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    key: String,
+    value: String,
+}
+
+impl Attribute {
+    /// Returns the key of the attribute
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of the attribute
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// A set of [`Attribute`]s with unique keys. It is defined when a [`crate::service::Service`]
+/// is created and stored in its [`crate::service::static_config::StaticConfig`].
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSet {
+    attributes: Vec<Attribute>,
+}
+
+impl AttributeSet {
+    /// Creates an empty [`AttributeSet`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the value of the attribute with the given key. If the key is already defined,
+    /// its value is replaced.
+    pub fn define(mut self, key: &str, value: &str) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Returns the value of the attribute with the given key. If the key is not defined it
+    /// returns [`None`].
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(|attribute| attribute.value())
+    }
+
+    /// Returns an iterator over all [`Attribute`]s
+    pub fn iter(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter()
+    }
+
+    /// Returns the number of [`Attribute`]s
+    pub fn len(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns true when no [`Attribute`] is defined, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+    }
+
+    /// Returns true when every [`Attribute`] of `required` is defined with the same value in
+    /// this set, otherwise false.
+    pub fn satisfies(&self, required: &AttributeSet) -> bool {
+        self.first_unsatisfied(required).is_none()
+    }
+
+    /// Returns the first [`Attribute`] of `required` that is not defined with the same value in
+    /// this set.
+    pub(crate) fn first_unsatisfied<'a>(
+        &self,
+        required: &'a AttributeSet,
+    ) -> Option<&'a Attribute> {
+        required
+            .iter()
+            .find(|attribute| self.get(attribute.key()) != Some(attribute.value()))
+    }
+
+    pub(crate) fn insert(&mut self, key: &str, value: &str) {
+        match self
+            .attributes
+            .iter_mut()
+            .find(|attribute| attribute.key == key)
+        {
+            Some(attribute) => attribute.value = value.to_string(),
+            None => self.attributes.push(Attribute {
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+}
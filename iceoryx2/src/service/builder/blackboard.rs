@@ -0,0 +1,457 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use std::fmt::Debug;
+
+use crate::service;
+use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::blackboard;
+use crate::service::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::StaticStorageLocked;
+
+use super::ServiceState;
+
+/// Errors that can occur when an existing [`MessagingPattern::Blackboard`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum BlackboardOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleTypes,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    DoesNotSupportRequestedAmountOfReaders,
+    DoesNotProvideRequestedKeys,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for BlackboardOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::Blackboard`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum BlackboardCreateError {
+    Corrupted,
+    AlreadyExists,
+    NoEntriesProvided,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+}
+
+impl std::fmt::Display for BlackboardCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::Blackboard`] [`Service`] shall be
+    /// created or opened.
+    BlackboardOpenOrCreateError
+  mapping:
+    BlackboardOpenError,
+    BlackboardCreateError
+}
+
+impl std::fmt::Display for BlackboardOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::Blackboard`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<'config, ServiceType: service::Details<'config>, ValueType: Copy + Debug> {
+    base: builder::BuilderWithServiceType<'config, ServiceType>,
+    initial_values: Vec<ValueType>,
+    verify_number_of_readers: bool,
+}
+
+impl<'config, ServiceType: service::Details<'config>, ValueType: Copy + Debug>
+    Builder<'config, ServiceType, ValueType>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            initial_values: vec![],
+            verify_number_of_readers: false,
+        };
+
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::Blackboard(static_config::blackboard::StaticConfig::new(
+                new_self
+                    .base
+                    .global_config
+                    .defaults_for(new_self.base.service_config.service_name()),
+            ));
+        new_self.config_details_mut().type_name = std::any::type_name::<ValueType>().to_string();
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::blackboard::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::blackboard::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::reader::Reader`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::reader::Reader`] must be at least supported.
+    pub fn max_readers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_readers = value;
+        self.verify_number_of_readers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it adds a new entry identified by `key` with the
+    /// provided initial `value`. Adding the same `key` twice replaces the initial value.
+    /// If an existing [`Service`] is opened it defines which keys the [`Service`] must at
+    /// least provide, the `value` is ignored.
+    pub fn add(mut self, key: u64, value: ValueType) -> Self {
+        match self.config_details().entry_index(key) {
+            Some(index) => self.initial_values[index] = value,
+            None => {
+                self.config_details_mut().keys.push(key);
+                self.initial_values.push(value);
+            }
+        }
+        self
+    }
+
+    /// If the [`Service`] is created it defines the attribute with the given key and value,
+    /// see [`crate::service::attribute::AttributeSet`]. If an existing [`Service`] is opened
+    /// it requires the service to define the attribute with the same value, otherwise
+    /// [`BlackboardOpenError::IncompatibleAttributes`] is returned.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attribute(key, value);
+        self
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                if config.blackboard().type_name != self.config_details().type_name {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
+                        error_msg, &config.blackboard().type_name, self.config_details().type_name);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<'config, ServiceType, ValueType>, BlackboardOpenOrCreateError>
+    {
+        let msg = "Unable to open or create blackboard service";
+
+        match self.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open()?),
+            Ok(None) => Ok(self.create()?),
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open()?),
+            Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::IncompatibleTypes),
+                    "{} since the service is not type compatible.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        mut self,
+    ) -> Result<blackboard::PortFactory<'config, ServiceType, ValueType>, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with BlackboardOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
+                    let static_config = self.verify_service_properties(&static_config)?;
+
+                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
+                            with BlackboardOpenError::UnableToOpenDynamicServiceInformation,
+                            "{} since the dynamic service information could not be opened.", msg);
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Blackboard(static_config);
+
+                    return Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new::<ServiceType>(
+                            self.base.service_config,
+                            self.base.global_config,
+                            dynamic_config,
+                            static_storage,
+                            self.base.node_id,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout > self.base.global_config.global.service.creation_timeout {
+                        fail!(from self, with BlackboardOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with BlackboardOpenError::IncompatibleTypes,
+                    "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with BlackboardOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<'config, ServiceType, ValueType>, BlackboardCreateError>
+    {
+        self.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create blackboard service";
+
+        if self.initial_values.is_empty() {
+            fail!(from self, with BlackboardCreateError::NoEntriesProvided,
+                "{} since no entries were added to the blackboard.", msg);
+        }
+
+        match self.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = fail!(from self, when self.base.create_static_config_storage(),
+                    with BlackboardCreateError::UnableToCreateStaticServiceInformation,
+                    "{} since the static service information could not be created.", msg);
+
+                let blackboard_config = self.base.service_config.blackboard();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings::new::<ValueType>(
+                    blackboard_config.max_readers,
+                    blackboard_config.max_writers,
+                    blackboard_config.keys.len(),
+                );
+
+                let dynamic_config = self.base.create_dynamic_config_storage_with_initializer(
+                    dynamic_config::MessagingPattern::Blackboard(
+                        dynamic_config::blackboard::DynamicConfig::new(&dynamic_config_setting),
+                    ),
+                    dynamic_config::blackboard::DynamicConfig::memory_size(&dynamic_config_setting),
+                    |config| {
+                        for (index, value) in self.initial_values.iter().enumerate() {
+                            unsafe { config.blackboard().init_entry(index, *value) };
+                        }
+                        true
+                    },
+                );
+                let dynamic_config = fail!(from self, when dynamic_config,
+                    with BlackboardCreateError::InternalFailure,
+                    "{} since the dynamic service segment could not be created.", msg);
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with BlackboardCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with BlackboardCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                discovery::notify_service_change::<ServiceType>(self.base.global_config);
+
+                return Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new::<ServiceType>(
+                        self.base.service_config.clone(),
+                        self.base.global_config,
+                        dynamic_config,
+                        unlocked_static_details,
+                        self.base.node_id,
+                    ),
+                )));
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with BlackboardCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with BlackboardCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with BlackboardCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.blackboard_mut();
+
+        if settings.max_readers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_readers = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        let required_settings = self.base.service_config.blackboard();
+        if let Some(attribute) = existing_settings
+            .attributes()
+            .first_unsatisfied(&self.base.required_attributes)
+        {
+            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+                "{} since the service does not define the required attribute \"{}\" with the value \"{}\".",
+                msg, attribute.key(), attribute.value());
+        }
+
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            p => {
+                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_readers
+            && existing_settings.max_readers < required_settings.max_readers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+                                "{} since the service supports only {} readers but a support of {} readers was requested.",
+                                msg, existing_settings.max_readers, required_settings.max_readers);
+        }
+
+        if let Some(key) = required_settings
+            .keys
+            .iter()
+            .find(|key| existing_settings.entry_index(**key).is_none())
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotProvideRequestedKeys,
+                                "{} since the service does not provide an entry with the requested key {}.",
+                                msg, key);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
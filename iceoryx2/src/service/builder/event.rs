@@ -14,6 +14,7 @@
 //!
 //! See [`crate::service`]
 //!
+use crate::service::access_control::{Operation, Subject};
 use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::port_factory::event;
 use crate::service::*;
@@ -24,6 +25,8 @@ use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 
 use super::ServiceState;
 
+const SUPPORTED_OPERATIONS: [Operation; 2] = [Operation::Notify, Operation::Listen];
+
 /// Failures that can occur when an existing [`MessagingPattern::Event`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventOpenError {
@@ -31,6 +34,7 @@ pub enum EventOpenError {
     PermissionDenied,
     EventInCorruptedState,
     IncompatibleMessagingPattern,
+    IncompatibleAttributes,
     InternalFailure,
     HangsInCreation,
     DoesNotSupportRequestedAmountOfNotifiers,
@@ -55,6 +59,8 @@ pub enum EventCreateError {
     AlreadyExists,
     PermissionDenied,
     UnableToCreateStaticServiceInformation,
+    UnsupportedAccessRule,
+    AccessControlNotSupported,
 }
 
 impl std::fmt::Display for EventCreateError {
@@ -102,9 +108,13 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             verify_max_listeners: false,
         };
 
-        new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
-            static_config::event::StaticConfig::new(new_self.base.global_config),
-        );
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::Event(static_config::event::StaticConfig::new(
+                new_self
+                    .base
+                    .global_config
+                    .defaults_for(new_self.base.service_config.service_name()),
+            ));
 
         new_self
     }
@@ -136,6 +146,39 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         self
     }
 
+    /// If the [`Service`] is created it permits the user with the given uid to perform the
+    /// [`Operation`], either [`Operation::Notify`] or [`Operation::Listen`]. As soon as one
+    /// user or group is permitted, the [`Service`] is restricted to the permitted users and
+    /// groups, see [`crate::service::access_control::AccessControl`]. Any other [`Operation`]
+    /// lets the creation fail with [`EventCreateError::UnsupportedAccessRule`]. It has no
+    /// effect when an existing [`Service`] is opened.
+    pub fn permit_user(mut self, uid: u32, operation: Operation) -> Self {
+        self.base
+            .permit(Subject::User, uid, operation, &SUPPORTED_OPERATIONS);
+        self
+    }
+
+    /// If the [`Service`] is created it permits all members of the group with the given gid to
+    /// perform the [`Operation`], either [`Operation::Notify`] or [`Operation::Listen`]. As
+    /// soon as one user or group is permitted, the [`Service`] is restricted to the permitted
+    /// users and groups, see [`crate::service::access_control::AccessControl`]. Any other
+    /// [`Operation`] lets the creation fail with [`EventCreateError::UnsupportedAccessRule`].
+    /// It has no effect when an existing [`Service`] is opened.
+    pub fn permit_group(mut self, gid: u32, operation: Operation) -> Self {
+        self.base
+            .permit(Subject::Group, gid, operation, &SUPPORTED_OPERATIONS);
+        self
+    }
+
+    /// If the [`Service`] is created it defines the attribute with the given key and value,
+    /// see [`crate::service::attribute::AttributeSet`]. If an existing [`Service`] is opened
+    /// it requires the service to define the attribute with the same value, otherwise
+    /// [`EventOpenError::IncompatibleAttributes`] is returned.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attribute(key, value);
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(
@@ -177,6 +220,9 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         "{} since the event does not exist.", msg);
                 }
                 Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.access_control =
+                        static_config.access_control().clone();
+                    self.base.service_config.attributes = static_config.attributes().clone();
                     let static_config = self.verify_service_properties(&static_config)?;
 
                     let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
@@ -187,11 +233,12 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         MessagingPattern::Event(static_config);
 
                     return Ok(event::PortFactory::new(ServiceType::from_state(
-                        service::ServiceState::new(
+                        service::ServiceState::new::<ServiceType>(
                             self.base.service_config,
                             self.base.global_config,
                             dynamic_config,
                             static_storage,
+                            self.base.node_id,
                         ),
                     )));
                 }
@@ -228,6 +275,18 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
 
         let msg = "Unable to create event service";
 
+        if self.base.has_unsupported_access_rule {
+            fail!(from self, with EventCreateError::UnsupportedAccessRule,
+                "{} since an access rule permits an operation that is not supported by event services.", msg);
+        }
+
+        if self.base.service_config.access_control.is_restricted()
+            && !self.base.does_support_access_control()
+        {
+            fail!(from self, with EventCreateError::AccessControlNotSupported,
+                "{} since the service variant cannot restrict the access to its underlying resources to the permitted users and groups.", msg);
+        }
+
         match self.base.is_service_available() {
             Ok(None) => {
                 let static_config = fail!(from self, when self.base.create_static_config_storage(),
@@ -259,12 +318,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             with EventCreateError::Corrupted,
                             "{} since the configuration could not be written to the static storage.", msg);
 
+                discovery::notify_service_change::<ServiceType>(self.base.global_config);
+
                 return Ok(event::PortFactory::new(ServiceType::from_state(
-                    service::ServiceState::new(
+                    service::ServiceState::new::<ServiceType>(
                         self.base.service_config.clone(),
                         self.base.global_config,
                         dynamic_config,
                         unlocked_static_details,
+                        self.base.node_id,
                     ),
                 )));
             }
@@ -309,6 +371,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         let msg = "Unable to open event";
 
         let required_settings = self.base.service_config.event();
+        if let Some(attribute) = existing_settings
+            .attributes()
+            .first_unsatisfied(&self.base.required_attributes)
+        {
+            fail!(from self, with EventOpenError::IncompatibleAttributes,
+                "{} since the service does not define the required attribute \"{}\" with the value \"{}\".",
+                msg, attribute.key(), attribute.value());
+        }
+
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::Event(ref v) => v,
             p => {
@@ -14,29 +14,45 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
+/// Builder for [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
+
 use crate::config;
+use crate::node::NodeId;
 use crate::service;
+use crate::service::access_control::{Operation, Resource, Subject};
+use crate::service::attribute::AttributeSet;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::warn;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
 use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::named_concept::NamedConceptConfiguration;
 use iceoryx2_cal::named_concept::NamedConceptDoesExistError;
 use iceoryx2_cal::named_concept::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::MAX_NUMBER_OF_ACCESS_RIGHTS;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::*;
+use std::fmt::Debug;
 use std::marker::PhantomData;
 
 use super::config_scheme::dynamic_config_storage_config;
@@ -54,6 +70,37 @@ enum ServiceState {
     Corrupted,
 }
 
+/// Defines how a property of an existing [`Service`] is verified when it is opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum Requirement {
+    /// The property is not verified.
+    DontCare,
+    /// The [`Service`] must support at least the given value.
+    AtLeast(usize),
+    /// The [`Service`] must have exactly the given value.
+    Exactly(usize),
+}
+
+impl Requirement {
+    /// Returns the required value. If the property is not verified it returns [`None`].
+    pub fn value(&self) -> Option<usize> {
+        match self {
+            Requirement::DontCare => None,
+            Requirement::AtLeast(v) | Requirement::Exactly(v) => Some(*v),
+        }
+    }
+
+    /// Returns true when the actual value of the [`Service`] satisfies the requirement,
+    /// otherwise false.
+    pub fn is_satisfied_by(&self, actual: usize) -> bool {
+        match self {
+            Requirement::DontCare => true,
+            Requirement::AtLeast(v) => *v <= actual,
+            Requirement::Exactly(v) => *v == actual,
+        }
+    }
+}
+
 enum_gen! {
 #[doc(hidden)]
     OpenDynamicStorageFailure
@@ -93,25 +140,41 @@ impl std::error::Error for ReadStaticStorageFailure {}
 ///
 /// See [`crate::service`]
 #[derive(Debug)]
-pub struct Builder<S: Service> {
+pub struct Builder<'config, S: Service> {
     name: ServiceName,
+    node_id: Option<NodeId>,
+    config: &'config config::Config,
     _phantom_s: PhantomData<S>,
 }
 
-impl<S: Service> Builder<S> {
+impl<'config, S: Service> Builder<'config, S> {
     pub(crate) fn new(name: &ServiceName) -> Self {
         Self {
             name: *name,
+            node_id: None,
+            config: config::Config::get_global_config(),
+            _phantom_s: PhantomData,
+        }
+    }
+
+    pub(crate) fn new_with_node(
+        name: &ServiceName,
+        node_id: NodeId,
+        config: &'config config::Config,
+    ) -> Self {
+        Self {
+            name: *name,
+            node_id: Some(node_id),
+            config,
             _phantom_s: PhantomData,
         }
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
-    pub fn publish_subscribe<'config>(
-        self,
-    ) -> publish_subscribe::Builder<'config, S::Type<'config>> {
-        self.publish_subscribe_with_custom_config(config::Config::get_global_config())
+    pub fn publish_subscribe(self) -> publish_subscribe::Builder<'config, S::Type<'config>> {
+        let config = self.config;
+        self.publish_subscribe_with_custom_config(config)
     }
 
     /// Create a new builder to create a
@@ -126,14 +189,16 @@ impl<S: Service> Builder<S> {
                 <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
             >(&self.name, config),
             config,
+            self.node_id,
         )
         .publish_subscribe()
     }
 
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
-    pub fn event<'config>(self) -> event::Builder<'config, S::Type<'config>> {
-        self.event_with_custom_config(config::Config::get_global_config())
+    pub fn event(self) -> event::Builder<'config, S::Type<'config>> {
+        let config = self.config;
+        self.event_with_custom_config(config)
     }
 
     /// Create a new builder to create a
@@ -148,9 +213,84 @@ impl<S: Service> Builder<S> {
                 <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
             >(&self.name, config),
             config,
+            self.node_id,
         )
         .event()
     }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    pub fn request_response(self) -> request_response::Builder<'config, S::Type<'config>> {
+        let config = self.config;
+        self.request_response_with_custom_config(config)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn request_response_with_custom_config(
+        self,
+        config: &config::Config,
+    ) -> request_response::Builder<'_, S::Type<'_>> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_request_response::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+            self.node_id,
+        )
+        .request_response()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard<ValueType: Copy + Debug>(
+        self,
+    ) -> blackboard::Builder<'config, S::Type<'config>, ValueType> {
+        let config = self.config;
+        self.blackboard_with_custom_config(config)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn blackboard_with_custom_config<ValueType: Copy + Debug>(
+        self,
+        config: &config::Config,
+    ) -> blackboard::Builder<'_, S::Type<'_>, ValueType> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+            self.node_id,
+        )
+        .blackboard()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    pub fn pipeline(self) -> pipeline::Builder<'config, S::Type<'config>> {
+        let config = self.config;
+        self.pipeline_with_custom_config(config)
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn pipeline_with_custom_config(
+        self,
+        config: &config::Config,
+    ) -> pipeline::Builder<'_, S::Type<'_>> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_pipeline::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+            self.node_id,
+        )
+        .pipeline()
+    }
 }
 
 #[doc(hidden)]
@@ -158,15 +298,25 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<'config, ServiceType: service::Details<'config>> {
     service_config: StaticConfig,
     global_config: &'config config::Config,
+    node_id: Option<NodeId>,
+    required_attributes: AttributeSet,
+    has_unsupported_access_rule: bool,
     _phantom_data: PhantomData<ServiceType>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
 
 impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'config, ServiceType> {
-    fn new(service_config: StaticConfig, global_config: &'config config::Config) -> Self {
+    fn new(
+        service_config: StaticConfig,
+        global_config: &'config config::Config,
+        node_id: Option<NodeId>,
+    ) -> Self {
         Self {
             service_config,
             global_config,
+            node_id,
+            required_attributes: AttributeSet::default(),
+            has_unsupported_access_rule: false,
             _phantom_data: PhantomData,
             _phantom_lifetime_b: PhantomData,
         }
@@ -180,6 +330,62 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         event::Builder::new(self)
     }
 
+    fn request_response(self) -> request_response::Builder<'config, ServiceType> {
+        request_response::Builder::new(self)
+    }
+
+    fn blackboard<ValueType: Copy + Debug>(
+        self,
+    ) -> blackboard::Builder<'config, ServiceType, ValueType> {
+        blackboard::Builder::new(self)
+    }
+
+    fn pipeline(self) -> pipeline::Builder<'config, ServiceType> {
+        pipeline::Builder::new(self)
+    }
+
+    fn permit(
+        &mut self,
+        subject: Subject,
+        id: u32,
+        operation: Operation,
+        supported_operations: &[Operation],
+    ) {
+        if !supported_operations.contains(&operation) {
+            warn!(from self,
+                "Unable to permit the {:?} {} to {:?} since the messaging pattern supports only the operations {:?}. The service cannot be created.",
+                subject, id, operation, supported_operations);
+            self.has_unsupported_access_rule = true;
+            return;
+        }
+
+        if !self
+            .service_config
+            .access_control
+            .add(subject, id, operation)
+        {
+            warn!(from self,
+                "Unable to permit the {:?} {} to {:?} since it would exceed the maximum supported number of users or groups of {}. The rule is ignored.",
+                subject, id, operation, MAX_NUMBER_OF_ACCESS_RIGHTS);
+        }
+    }
+
+    /// Returns true when the access to every underlying resource of the service variant can be
+    /// restricted to the users and groups of the
+    /// [`AccessControl`](crate::service::access_control::AccessControl).
+    fn does_support_access_control(&self) -> bool {
+        <<ServiceType::StaticStorage as NamedConceptMgmt>::Configuration as NamedConceptConfiguration>::does_support_access_rights()
+            && <<ServiceType::DynamicStorage as NamedConceptMgmt>::Configuration as NamedConceptConfiguration>::does_support_access_rights()
+            && <<ServiceType::SharedMemory as NamedConceptMgmt>::Configuration as NamedConceptConfiguration>::does_support_access_rights()
+            && <<ServiceType::Connection as NamedConceptMgmt>::Configuration as NamedConceptConfiguration>::does_support_access_rights()
+            && <<ServiceType::Event as NamedConceptMgmt>::Configuration as NamedConceptConfiguration>::does_support_access_rights()
+    }
+
+    fn attribute(&mut self, key: &str, value: &str) {
+        self.service_config.attributes.insert(key, value);
+        self.required_attributes.insert(key, value);
+    }
+
     fn is_service_available(
         &self,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
@@ -249,19 +455,37 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         &self,
         messaging_pattern: super::dynamic_config::MessagingPattern,
         additional_size: usize,
+    ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
+        self.create_dynamic_config_storage_with_initializer(
+            messaging_pattern,
+            additional_size,
+            |_| true,
+        )
+    }
+
+    /// Creates the dynamic config storage like
+    /// [`BuilderWithServiceType::create_dynamic_config_storage()`] but calls the
+    /// `initializer` with the freshly initialized [`DynamicConfig`] before the storage becomes
+    /// accessible to others.
+    fn create_dynamic_config_storage_with_initializer<F: FnOnce(&DynamicConfig) -> bool>(
+        &self,
+        messaging_pattern: super::dynamic_config::MessagingPattern,
+        additional_size: usize,
+        initializer: F,
     ) -> Result<ServiceType::DynamicStorage, DynamicStorageCreateError> {
         match <<ServiceType::DynamicStorage as DynamicStorage<
             DynamicConfig,
         >>::Builder as NamedConceptBuilder<
             ServiceType::DynamicStorage,
         >>::new(&dynamic_config_storage_name(&self.service_config))
-            .config(&dynamic_config_storage_config::<ServiceType>(self.global_config))
+            .config(&dynamic_config_storage_config::<ServiceType>(self.global_config)
+                .access_rights(self.service_config.access_control.access_rights(Resource::DynamicConfig)))
             .supplementary_size(additional_size)
             .has_ownership(false)
             .create_and_initialize(DynamicConfig::new_uninit(messaging_pattern),
                 |config, allocator| {
                     unsafe { config.init(allocator) };
-                    true
+                    initializer(config)
                 }
                 ) {
                 Ok(dynamic_storage) => Ok(dynamic_storage),
@@ -305,7 +529,7 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
                     >>::new(&static_config_storage_name(self.service_config.uuid()))
                     .config(&static_config_storage_config::<ServiceType>(
                         self.global_config,
-                    ))
+                    ).access_rights(self.service_config.access_control.access_rights(Resource::StaticConfig)))
                     .has_ownership(false)
                     .create_locked(),
                     "Failed to create static service information since the underlying static storage could not be created."),
@@ -14,31 +14,38 @@
 //!
 //! See [`crate::service`]
 //!
+use std::alloc::Layout;
+
+use crate::payload_type::PayloadType;
 use crate::service;
+use crate::service::access_control::{Operation, Subject};
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::messaging_pattern::MessagingPattern;
-use crate::service::port_factory::publish_subscribe;
+use crate::service::port_factory::{publish_subscribe, type_erased};
 use crate::service::*;
-use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::static_storage::StaticStorageLocked;
 
-use super::ServiceState;
+use super::{Requirement, ServiceState};
 
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum PublishSubscribeOpenError {
     DoesNotExist,
     InternalFailure,
     IncompatibleTypes,
+    IncompatiblePayloadSize,
+    IncompatiblePayloadAlignment,
+    IncompatibleTypeHash,
+    IncompatibleAttributes,
     IncompatibleMessagingPattern,
-    DoesNotSupportRequestedMinBufferSize,
-    DoesNotSupportRequestedMinHistorySize,
-    DoesNotSupportRequestedMinSubscriberBorrowedSamples,
-    DoesNotSupportRequestedAmountOfPublishers,
-    DoesNotSupportRequestedAmountOfSubscribers,
+    /// Every [`Property`] of the existing [`Service`] that does not satisfy its [`Requirement`]
+    IncompatibleProperties(Vec<PropertyMismatch>),
+    DoesNotSupportNotifications,
+    IncompatibleCopyTransfer,
+    SafeOverflowNotSupportedWithCopyTransfer,
     IncompatibleOverflowBehavior,
     Inaccessible,
     PermissionDenied,
@@ -65,6 +72,9 @@ pub enum PublishSubscribeCreateError {
     InternalFailure,
     IsBeingCreatedByAnotherInstance,
     UnableToCreateStaticServiceInformation,
+    UnsupportedAccessRule,
+    AccessControlNotSupported,
+    SafeOverflowNotSupportedWithCopyTransfer,
 }
 
 impl std::fmt::Display for PublishSubscribeCreateError {
@@ -81,13 +91,24 @@ enum ServiceAvailabilityState {
     IncompatibleTypes,
 }
 
-enum_gen! {
-    /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
-    /// created or opened.
-    PublishSubscribeOpenOrCreateError
-  mapping:
-    PublishSubscribeOpenError,
-    PublishSubscribeCreateError
+/// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
+/// created or opened.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum PublishSubscribeOpenOrCreateError {
+    PublishSubscribeOpenError(PublishSubscribeOpenError),
+    PublishSubscribeCreateError(PublishSubscribeCreateError),
+}
+
+impl From<PublishSubscribeOpenError> for PublishSubscribeOpenOrCreateError {
+    fn from(v: PublishSubscribeOpenError) -> Self {
+        PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(v)
+    }
+}
+
+impl From<PublishSubscribeCreateError> for PublishSubscribeOpenOrCreateError {
+    fn from(v: PublishSubscribeCreateError) -> Self {
+        PublishSubscribeOpenOrCreateError::PublishSubscribeCreateError(v)
+    }
 }
 
 impl std::fmt::Display for PublishSubscribeOpenOrCreateError {
@@ -98,6 +119,100 @@ impl std::fmt::Display for PublishSubscribeOpenOrCreateError {
 
 impl std::error::Error for PublishSubscribeOpenOrCreateError {}
 
+const NUMBER_OF_PROPERTIES: usize = 6;
+const SUPPORTED_OPERATIONS: [Operation; 2] = [Operation::Publish, Operation::Subscribe];
+
+/// The properties of a [`MessagingPattern::PublishSubscribe`] [`Service`] that are verified with
+/// a [`Requirement`] when an existing [`Service`] is opened, see [`Builder::require()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum Property {
+    /// See [`Builder::max_publishers()`]
+    MaxPublishers,
+    /// See [`Builder::max_subscribers()`]
+    MaxSubscribers,
+    /// See [`Builder::subscriber_max_buffer_size()`]
+    SubscriberMaxBufferSize,
+    /// See [`Builder::history_size()`]
+    HistorySize,
+    /// See [`Builder::subscriber_max_borrowed_samples()`]
+    SubscriberMaxBorrowedSamples,
+    /// See [`Builder::max_slice_len()`]
+    MaxSliceLen,
+}
+
+impl Property {
+    const ALL: [Property; NUMBER_OF_PROPERTIES] = [
+        Property::MaxPublishers,
+        Property::MaxSubscribers,
+        Property::SubscriberMaxBufferSize,
+        Property::HistorySize,
+        Property::SubscriberMaxBorrowedSamples,
+        Property::MaxSliceLen,
+    ];
+
+    fn value(&self, config: &static_config::publish_subscribe::StaticConfig) -> usize {
+        match self {
+            Property::MaxPublishers => config.max_publishers,
+            Property::MaxSubscribers => config.max_subscribers,
+            Property::SubscriberMaxBufferSize => config.subscriber_max_buffer_size,
+            Property::HistorySize => config.history_size,
+            Property::SubscriberMaxBorrowedSamples => config.subscriber_max_borrowed_samples,
+            Property::MaxSliceLen => config.max_slice_len,
+        }
+    }
+
+    fn set_value(&self, config: &mut static_config::publish_subscribe::StaticConfig, value: usize) {
+        match self {
+            Property::MaxPublishers => config.max_publishers = value,
+            Property::MaxSubscribers => config.max_subscribers = value,
+            Property::SubscriberMaxBufferSize => config.subscriber_max_buffer_size = value,
+            Property::HistorySize => config.history_size = value,
+            Property::SubscriberMaxBorrowedSamples => {
+                config.subscriber_max_borrowed_samples = value
+            }
+            Property::MaxSliceLen => config.max_slice_len = value,
+        }
+    }
+}
+
+/// A [`Property`] of an existing [`Service`] that does not satisfy the [`Requirement`] of the
+/// [`Builder`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct PropertyMismatch {
+    property: Property,
+    requirement: Requirement,
+    actual: usize,
+}
+
+impl PropertyMismatch {
+    /// Returns the [`Property`] that does not satisfy the [`Requirement`]
+    pub fn property(&self) -> Property {
+        self.property
+    }
+
+    /// Returns the [`Requirement`] of the [`Builder`]
+    pub fn requirement(&self) -> Requirement {
+        self.requirement
+    }
+
+    /// Returns the actual value of the existing [`Service`]
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl std::fmt::Display for PropertyMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "{:?} is {} but {:?} was requested",
+            self.property,
+            self.actual,
+            self.requirement
+        )
+    }
+}
+
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
 ///
 /// # Example
@@ -106,28 +221,35 @@ impl std::error::Error for PublishSubscribeOpenOrCreateError {}
 #[derive(Debug)]
 pub struct Builder<'config, ServiceType: service::Details<'config>> {
     base: builder::BuilderWithServiceType<'config, ServiceType>,
-    verify_number_of_subscribers: bool,
-    verify_number_of_publishers: bool,
-    verify_subscriber_max_buffer_size: bool,
-    verify_subscriber_max_borrowed_samples: bool,
-    verify_publisher_history_size: bool,
+    requirements: [Requirement; NUMBER_OF_PROPERTIES],
     verify_enable_safe_overflow: bool,
+    verify_enable_notifications: bool,
+    verify_enable_copy_transfer: bool,
+    verify_type_name: bool,
+    verify_payload_layout: bool,
+    verify_type_hash: bool,
 }
 
 impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
     pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
         let mut new_self = Self {
             base,
-            verify_number_of_publishers: false,
-            verify_number_of_subscribers: false,
-            verify_subscriber_max_buffer_size: false,
-            verify_publisher_history_size: false,
-            verify_subscriber_max_borrowed_samples: false,
+            requirements: [Requirement::DontCare; NUMBER_OF_PROPERTIES],
             verify_enable_safe_overflow: false,
+            verify_enable_notifications: false,
+            verify_enable_copy_transfer: false,
+            verify_type_name: true,
+            verify_payload_layout: false,
+            verify_type_hash: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
-            static_config::publish_subscribe::StaticConfig::new(new_self.base.global_config),
+            static_config::publish_subscribe::StaticConfig::new(
+                new_self
+                    .base
+                    .global_config
+                    .defaults_for(new_self.base.service_config.service_name()),
+            ),
         );
 
         new_self
@@ -151,8 +273,40 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         }
     }
 
+    fn set_payload_type<MessageType: PayloadType + ?Sized>(&mut self) {
+        self.set_payload_type_details(
+            std::any::type_name::<MessageType>(),
+            MessageType::element_layout(),
+        );
+
+        if !MessageType::is_slice() {
+            self.config_details_mut().max_slice_len = 1;
+            self.requirements[Property::MaxSliceLen as usize] = Requirement::DontCare;
+        }
+    }
+
+    fn set_payload_type_details(&mut self, type_name: &str, element_layout: Layout) {
+        let settings = self.config_details_mut();
+        settings.type_name = type_name.to_string();
+        settings.payload_size = element_layout.size();
+        settings.payload_alignment = element_layout.align();
+        self.verify_payload_layout = true;
+    }
+
+    /// If the [`Service`] is created, it stores the user defined hash or version of the payload
+    /// type. If an existing [`Service`] is opened it requires the service to have the same
+    /// hash, otherwise [`PublishSubscribeOpenError::IncompatibleTypeHash`] is returned. In
+    /// contrast to the type name, the hash is under the control of the user and can be kept
+    /// stable across compilers and crates, or changed whenever the layout of the type changes.
+    pub fn type_hash(mut self, value: u64) -> Self {
+        self.config_details_mut().type_hash = Some(value);
+        self.verify_type_hash = true;
+        self
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
+    /// The safe overflow cannot be combined with [`Builder::enable_copy_transfer()`].
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
         self.config_details_mut().enable_safe_overflow = value;
         self.verify_enable_safe_overflow = true;
@@ -162,55 +316,287 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
-    pub fn subscriber_max_borrowed_samples(mut self, value: usize) -> Self {
-        self.config_details_mut().subscriber_max_borrowed_samples = std::cmp::max(value, 1);
-        self.verify_subscriber_max_borrowed_samples = true;
-        self
+    pub fn subscriber_max_borrowed_samples(self, value: usize) -> Self {
+        self.require(
+            Property::SubscriberMaxBorrowedSamples,
+            Requirement::AtLeast(std::cmp::max(value, 1)),
+        )
     }
 
     /// If the [`Service`] is created it defines the maximum history size a
     /// [`crate::port::subscriber::Subscriber`] can request on connection. If an existing
     /// [`Service`] is opened it defines the minimum required.
-    pub fn history_size(mut self, value: usize) -> Self {
-        self.config_details_mut().history_size = value;
-        self.verify_publisher_history_size = true;
-        self
+    pub fn history_size(self, value: usize) -> Self {
+        self.require(Property::HistorySize, Requirement::AtLeast(value))
     }
 
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can store in its internal buffer. If an existing
     /// [`Service`] is opened it defines the minimum required.
-    pub fn subscriber_max_buffer_size(mut self, value: usize) -> Self {
-        self.config_details_mut().subscriber_max_buffer_size = value;
-        self.verify_subscriber_max_buffer_size = true;
-        self
+    pub fn subscriber_max_buffer_size(self, value: usize) -> Self {
+        self.require(
+            Property::SubscriberMaxBufferSize,
+            Requirement::AtLeast(value),
+        )
     }
 
     /// If the [`Service`] is created it defines how many [`crate::port::subscriber::Subscriber`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::subscriber::Subscriber`] must be at least supported.
-    pub fn max_subscribers(mut self, value: usize) -> Self {
-        self.config_details_mut().max_subscribers = value;
-        self.verify_number_of_subscribers = true;
-        self
+    pub fn max_subscribers(self, value: usize) -> Self {
+        self.require(Property::MaxSubscribers, Requirement::AtLeast(value))
     }
 
     /// If the [`Service`] is created it defines how many [`crate::port::publisher::Publisher`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::publisher::Publisher`] must be at least supported.
-    pub fn max_publishers(mut self, value: usize) -> Self {
-        self.config_details_mut().max_publishers = value;
-        self.verify_number_of_publishers = true;
+    pub fn max_publishers(self, value: usize) -> Self {
+        self.require(Property::MaxPublishers, Requirement::AtLeast(value))
+    }
+
+    /// If the [`Service`] is created it defines how many elements a slice payload like `[u64]`
+    /// can contain at most. If an existing [`Service`] is opened it defines the minimum required.
+    /// It has no effect on fixed size payloads.
+    pub fn max_slice_len(self, value: usize) -> Self {
+        self.require(Property::MaxSliceLen, Requirement::AtLeast(value))
+    }
+
+    /// If the [`Service`] is created it defines the value of the [`Property`], unless the
+    /// [`Requirement`] is [`Requirement::DontCare`]. If an existing [`Service`] is opened it
+    /// requires the [`Property`] to satisfy the [`Requirement`]. The setters of the properties,
+    /// like [`Builder::max_subscribers()`], require [`Requirement::AtLeast`] the given value.
+    ///
+    /// When the [`Service`] is opened and a [`Property`] does not satisfy its [`Requirement`],
+    /// [`PublishSubscribeOpenError::IncompatibleProperties`] with all mismatches is returned.
+    /// [`Builder::incompatible_properties()`] provides them without opening the [`Service`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::builder::Requirement;
+    /// use iceoryx2::service::builder::publish_subscribe::Property;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/Telemetry")?;
+    ///
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     // a service that is opened must have exactly a history of 4 samples
+    ///     .require(Property::HistorySize, Requirement::Exactly(4))
+    ///     // and any amount of subscribers
+    ///     .require(Property::MaxSubscribers, Requirement::DontCare)
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn require(mut self, property: Property, requirement: Requirement) -> Self {
+        if let Some(value) = requirement.value() {
+            property.set_value(self.config_details_mut(), value);
+        }
+        self.requirements[property as usize] = requirement;
+        self
+    }
+
+    /// Returns every [`Property`] of the existing [`Service`] that does not satisfy the
+    /// [`Requirement`] of the [`Builder`] when it is opened with the `MessageType`. The
+    /// [`Service`] is not opened.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/Diagnostics")?;
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    ///
+    /// let builder = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .max_subscribers(1000)
+    ///     .history_size(1000);
+    ///
+    /// for mismatch in builder.incompatible_properties::<u64>()? {
+    ///     println!("{}", mismatch);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn incompatible_properties<MessageType: PayloadType + ?Sized>(
+        &self,
+    ) -> Result<Vec<PropertyMismatch>, PublishSubscribeOpenError> {
+        let msg = "Unable to acquire the incompatible properties of the publish subscribe service";
+
+        let mut requirements = self.requirements;
+        if !MessageType::is_slice() {
+            requirements[Property::MaxSliceLen as usize] = Requirement::DontCare;
+        }
+
+        match self.base.is_service_available() {
+            Ok(Some((static_config, _))) => match &static_config.messaging_pattern {
+                MessagingPattern::PublishSubscribe(ref existing_settings) => {
+                    Ok(Self::property_mismatches(&requirements, existing_settings))
+                }
+                p => {
+                    fail!(from self, with PublishSubscribeOpenError::IncompatibleMessagingPattern,
+                        "{} since a service with the messaging pattern {:?} exists but MessagingPattern::PublishSubscribe is required.", msg, p);
+                }
+            },
+            Ok(None) | Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
+                fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                    "{} since the service does not exist.", msg);
+            }
+            Err(ServiceState::IncompatibleMessagingPattern) => {
+                fail!(from self, with PublishSubscribeOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceState::Corrupted) => {
+                fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceState::PermissionDenied) => {
+                fail!(from self, with PublishSubscribeOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    fn property_mismatches(
+        requirements: &[Requirement; NUMBER_OF_PROPERTIES],
+        existing_settings: &static_config::publish_subscribe::StaticConfig,
+    ) -> Vec<PropertyMismatch> {
+        Property::ALL
+            .iter()
+            .map(|property| PropertyMismatch {
+                property: *property,
+                requirement: requirements[*property as usize],
+                actual: property.value(existing_settings),
+            })
+            .filter(|mismatch| !mismatch.requirement.is_satisfied_by(mismatch.actual))
+            .collect()
+    }
+
+    /// If the [`Service`] is created it defines if the [`crate::port::publisher::Publisher`]
+    /// notifies the [`crate::port::subscriber::Subscriber`]s whenever a new
+    /// [`crate::sample::Sample`] was delivered. It is required to wait for new samples with
+    /// [`crate::port::subscriber::Subscriber::timed_receive()`] or
+    /// [`crate::port::subscriber::Subscriber::blocking_receive()`]. If an existing [`Service`]
+    /// is opened and notifications are enabled it requires the service to support them.
+    pub fn enable_notifications(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notifications = value;
+        self.verify_enable_notifications = value;
+        self
+    }
+
+    /// If the [`Service`] is created it defines if the [`crate::port::publisher::Publisher`]
+    /// delivers copies of every [`crate::sample::Sample`] to the
+    /// [`crate::port::subscriber::Subscriber`]s via the [`crate::service::Details::CopyChannel`]
+    /// instead of sharing its data segment with them. A subscriber never maps memory a
+    /// publisher can write to, therefore a misbehaving publisher cannot corrupt the samples
+    /// that were already received. The price is an additional copy of every sample.
+    ///
+    /// When the subscriber buffer is full, a sample is either discarded or the publisher
+    /// blocks, depending on the
+    /// [`crate::service::port_factory::publisher::UnableToDeliverStrategy`]. The safe
+    /// overflow is not supported in this mode, requesting it explicitly with
+    /// [`Builder::enable_safe_overflow()`] fails with
+    /// [`PublishSubscribeCreateError::SafeOverflowNotSupportedWithCopyTransfer`] or
+    /// [`PublishSubscribeOpenError::SafeOverflowNotSupportedWithCopyTransfer`], otherwise the
+    /// safe overflow default of the config is ignored. The samples are transferred in frames of
+    /// [`crate::port::copy_frame::COPY_FRAME_DATA_SIZE`] bytes and the underlying channel may
+    /// limit how many frames can be buffered, e.g. by the maximum queue length of unix
+    /// datagram sockets.
+    ///
+    /// If an existing [`Service`] is opened it requires the same setting.
+    pub fn enable_copy_transfer(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_copy_transfer = value;
+        self.verify_enable_copy_transfer = true;
+        self
+    }
+
+    /// If the [`Service`] is created it permits the user with the given uid to perform the
+    /// [`Operation`], either [`Operation::Publish`] or [`Operation::Subscribe`]. As soon as one
+    /// user or group is permitted, the [`Service`] is restricted to the permitted users and
+    /// groups, see [`crate::service::access_control::AccessControl`]. Any other [`Operation`]
+    /// lets the creation fail with [`PublishSubscribeCreateError::UnsupportedAccessRule`]. It
+    /// has no effect when an existing [`Service`] is opened.
+    pub fn permit_user(mut self, uid: u32, operation: Operation) -> Self {
+        self.base
+            .permit(Subject::User, uid, operation, &SUPPORTED_OPERATIONS);
+        self
+    }
+
+    /// If the [`Service`] is created it permits all members of the group with the given gid to
+    /// perform the [`Operation`], either [`Operation::Publish`] or [`Operation::Subscribe`]. As
+    /// soon as one user or group is permitted, the [`Service`] is restricted to the permitted
+    /// users and groups, see [`crate::service::access_control::AccessControl`]. Any other
+    /// [`Operation`] lets the creation fail with
+    /// [`PublishSubscribeCreateError::UnsupportedAccessRule`]. It has no effect when an
+    /// existing [`Service`] is opened.
+    pub fn permit_group(mut self, gid: u32, operation: Operation) -> Self {
+        self.base
+            .permit(Subject::Group, gid, operation, &SUPPORTED_OPERATIONS);
+        self
+    }
+
+    /// If the [`Service`] is created it defines the attribute with the given key and value,
+    /// see [`crate::service::attribute::AttributeSet`]. If an existing [`Service`] is opened
+    /// it requires the service to define the attribute with the same value, otherwise
+    /// [`PublishSubscribeOpenError::IncompatibleAttributes`] is returned.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attribute(key, value);
         self
     }
 
+    /// Applies all settings of an existing [`Service`], given by its
+    /// [`static_config::publish_subscribe::StaticConfig`] and its
+    /// [`attribute::AttributeSet`], e.g. to provide the same [`Service`] on another host or to
+    /// replay a recorded [`Service`]. The payload type is not applied, it is defined when the
+    /// [`Service`] is created or opened, like with [`Builder::open_or_create_type_erased()`].
+    pub fn apply_static_config(
+        self,
+        static_config: &static_config::publish_subscribe::StaticConfig,
+        attributes: &attribute::AttributeSet,
+    ) -> Self {
+        let mut builder = self
+            .max_publishers(static_config.max_publishers)
+            .max_subscribers(static_config.max_subscribers)
+            .history_size(static_config.history_size)
+            .subscriber_max_buffer_size(static_config.subscriber_max_buffer_size)
+            .subscriber_max_borrowed_samples(static_config.subscriber_max_borrowed_samples)
+            .max_slice_len(static_config.max_slice_len)
+            .enable_notifications(static_config.enable_notifications)
+            .enable_copy_transfer(static_config.enable_copy_transfer);
+
+        // copy transfer never uses the safe overflow, see Builder::enable_copy_transfer()
+        if !static_config.enable_copy_transfer {
+            builder = builder.enable_safe_overflow(static_config.enable_safe_overflow);
+        }
+
+        if let Some(type_hash) = static_config.type_hash {
+            builder = builder.type_hash(type_hash);
+        }
+
+        for attribute in attributes.iter() {
+            builder = builder.attribute(attribute.key(), attribute.value());
+        }
+
+        builder
+    }
+
     fn is_service_available(
         &mut self,
         error_msg: &str,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available() {
             Ok(Some((config, storage))) => {
-                if config.publish_subscribe().type_name != self.config_details().type_name {
+                if self.verify_type_name
+                    && config.publish_subscribe().type_name != self.config_details().type_name
+                {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
                         "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
                         error_msg, &config.publish_subscribe().type_name , self.config_details().type_name);
@@ -225,14 +611,14 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
 
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
-    pub fn open_or_create<MessageType: Debug>(
+    pub fn open_or_create<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        self.set_payload_type::<MessageType>();
 
         match self.is_service_available(msg) {
             Ok(Some(_)) => Ok(self.open::<MessageType>()?),
@@ -262,14 +648,111 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// Opens an existing [`Service`].
-    pub fn open<MessageType: Debug>(
+    pub fn open<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
         PublishSubscribeOpenError,
     > {
+        self.set_payload_type::<MessageType>();
+        Ok(publish_subscribe::PortFactory::new(self.open_service()?))
+    }
+
+    /// Opens an existing [`Service`] without knowing its payload type. In contrast to
+    /// [`Builder::open()`] the type name of the [`Service`] is not verified, all other
+    /// requirements are still verified. The payload is accessed as raw bytes whose size and
+    /// alignment are defined by the [`Service`], see
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::payload_size()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// # let typed_service = zero_copy::Service::new(&service_name)
+    /// #     .publish_subscribe()
+    /// #     .open_or_create::<u64>()?;
+    ///
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_type_erased()?;
+    ///
+    /// println!("type name: {}", service.static_config().type_name());
+    /// let subscriber = service.subscriber().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_type_erased(
+        mut self,
+    ) -> Result<type_erased::PortFactory<'config, ServiceType>, PublishSubscribeOpenError> {
+        self.verify_type_name = false;
+        Ok(type_erased::PortFactory::new(self.open_service()?))
+    }
+
+    /// Opens an existing [`Service`] or creates a new one without knowing the payload type at
+    /// compile time. The payload type is described by its `type_name` and the `element_layout`
+    /// of a single element. An existing [`Service`] must have the same type name and layout,
+    /// like it is required by [`Builder::open_or_create()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use std::alloc::Layout;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    ///
+    /// let service = zero_copy::Service::new(&service_name)
+    ///     .publish_subscribe()
+    ///     .open_or_create_type_erased("u64", Layout::new::<u64>())?;
+    ///
+    /// let publisher = service.publisher().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_or_create_type_erased(
+        mut self,
+        type_name: &str,
+        element_layout: Layout,
+    ) -> Result<type_erased::PortFactory<'config, ServiceType>, PublishSubscribeOpenOrCreateError>
+    {
+        let msg = "Unable to open or create type erased publish subscribe service";
+        self.set_payload_type_details(type_name, element_layout);
+
+        match self.is_service_available(msg) {
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(type_erased::PortFactory::new(self.open_service()?)),
+            Ok(None) => Ok(type_erased::PortFactory::new(self.create_service()?)),
+            Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleTypes),
+                    "{} since the service is not type compatible.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    fn open_service(mut self) -> Result<ServiceType, PublishSubscribeOpenError> {
         let msg = "Unable to open publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
 
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with PublishSubscribeOpenError::InternalFailure,
@@ -282,6 +765,9 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         "{} since the service does not exist.", msg);
                 }
                 Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.access_control =
+                        static_config.access_control().clone();
+                    self.base.service_config.attributes = static_config.attributes().clone();
                     let static_config = self.verify_service_properties(&static_config)?;
 
                     let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
@@ -291,14 +777,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(static_config.clone());
 
-                    return Ok(publish_subscribe::PortFactory::new(
-                        ServiceType::from_state(service::ServiceState::new(
-                            self.base.service_config,
-                            self.base.global_config,
-                            dynamic_config,
-                            static_storage,
-                        )),
-                    ));
+                    return Ok(ServiceType::from_state(service::ServiceState::new::<
+                        ServiceType,
+                    >(
+                        self.base.service_config,
+                        self.base.global_config,
+                        dynamic_config,
+                        static_storage,
+                        self.base.node_id,
+                    )));
                 }
                 Err(ServiceAvailabilityState::ServiceState(
                     ServiceState::IsBeingCreatedByAnotherInstance,
@@ -336,16 +823,38 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// Creates a new [`Service`].
-    pub fn create<MessageType: Debug>(
+    pub fn create<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
         PublishSubscribeCreateError,
     > {
+        self.set_payload_type::<MessageType>();
+        Ok(publish_subscribe::PortFactory::new(self.create_service()?))
+    }
+
+    fn create_service(mut self) -> Result<ServiceType, PublishSubscribeCreateError> {
         self.adjust_properties_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+
+        if self.base.has_unsupported_access_rule {
+            fail!(from self, with PublishSubscribeCreateError::UnsupportedAccessRule,
+                "{} since an access rule permits an operation that is not supported by publish subscribe services.", msg);
+        }
+
+        if self.base.service_config.access_control.is_restricted()
+            && !self.base.does_support_access_control()
+        {
+            fail!(from self, with PublishSubscribeCreateError::AccessControlNotSupported,
+                "{} since the service variant cannot restrict the access to its underlying resources to the permitted users and groups.", msg);
+        }
+
+        if self.config_details().enable_copy_transfer && self.config_details().enable_safe_overflow
+        {
+            fail!(from self, with PublishSubscribeCreateError::SafeOverflowNotSupportedWithCopyTransfer,
+                "{} since the safe overflow was requested but it is not supported in combination with copy transfer.", msg);
+        }
 
         if !self.config_details().enable_safe_overflow
             && (self.config_details().subscriber_max_buffer_size
@@ -393,14 +902,17 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             with PublishSubscribeCreateError::Corrupted,
                             "{} since the configuration could not be written to the static storage.", msg);
 
-                return Ok(publish_subscribe::PortFactory::new(
-                    ServiceType::from_state(service::ServiceState::new(
-                        self.base.service_config.clone(),
-                        self.base.global_config,
-                        dynamic_config,
-                        unlocked_static_details,
-                    )),
-                ));
+                discovery::notify_service_change::<ServiceType>(self.base.global_config);
+
+                return Ok(ServiceType::from_state(service::ServiceState::new::<
+                    ServiceType,
+                >(
+                    self.base.service_config.clone(),
+                    self.base.global_config,
+                    dynamic_config,
+                    unlocked_static_details,
+                    self.base.node_id,
+                )));
             }
             Ok(Some(_))
             | Err(ServiceAvailabilityState::IncompatibleTypes)
@@ -429,8 +941,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
 
     fn adjust_properties_to_meaningful_values(&mut self) {
         let origin = format!("{:?}", self);
+        let is_safe_overflow_requested = self.verify_enable_safe_overflow;
         let settings = self.base.service_config.publish_subscribe_mut();
 
+        // the safe overflow default of the config does not apply to copy transfer, only an
+        // explicit request is rejected
+        if settings.enable_copy_transfer && !is_safe_overflow_requested {
+            settings.enable_safe_overflow = false;
+        }
+
         if settings.subscriber_max_borrowed_samples == 0 {
             warn!(from origin,
                 "Setting the subscribers max borrowed samples to 0 is not supported. Adjust it to 1, the smallest supported value.");
@@ -454,6 +973,12 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
+
+        if settings.max_slice_len == 0 {
+            warn!(from origin,
+                "Setting the maximum slice length to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_slice_len = 1;
+        }
     }
 
     fn verify_service_properties(
@@ -463,6 +988,24 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         let msg = "Unable to open publish subscribe service";
 
         let required_settings = self.base.service_config.publish_subscribe();
+        if self.verify_enable_safe_overflow
+            && required_settings.enable_safe_overflow
+            && self.verify_enable_copy_transfer
+            && required_settings.enable_copy_transfer
+        {
+            fail!(from self, with PublishSubscribeOpenError::SafeOverflowNotSupportedWithCopyTransfer,
+                "{} since the safe overflow was requested but it is not supported in combination with copy transfer.", msg);
+        }
+
+        if let Some(attribute) = existing_settings
+            .attributes()
+            .first_unsatisfied(&self.base.required_attributes)
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
+                "{} since the service does not define the required attribute \"{}\" with the value \"{}\".",
+                msg, attribute.key(), attribute.value());
+        }
+
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
             p => {
@@ -471,46 +1014,37 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             }
         };
 
-        if self.verify_number_of_publishers
-            && existing_settings.max_publishers < required_settings.max_publishers
+        if self.verify_payload_layout
+            && existing_settings.payload_size != required_settings.payload_size
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers,
-                                "{} since the service supports only {} publishers but a support of {} publishers was requested.",
-                                msg, existing_settings.max_publishers, required_settings.max_publishers);
+            fail!(from self, with PublishSubscribeOpenError::IncompatiblePayloadSize,
+                                "{} since the service has a payload size of {} bytes but a payload size of {} bytes was requested.",
+                                msg, existing_settings.payload_size, required_settings.payload_size);
         }
 
-        if self.verify_number_of_subscribers
-            && existing_settings.max_subscribers < required_settings.max_subscribers
+        if self.verify_payload_layout
+            && existing_settings.payload_alignment != required_settings.payload_alignment
         {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers,
-                                "{} since the service supports only {} subscribers but a support of {} subscribers was requested.",
-                                msg, existing_settings.max_subscribers, required_settings.max_subscribers);
+            fail!(from self, with PublishSubscribeOpenError::IncompatiblePayloadAlignment,
+                                "{} since the service has a payload alignment of {} but a payload alignment of {} was requested.",
+                                msg, existing_settings.payload_alignment, required_settings.payload_alignment);
         }
 
-        if self.verify_subscriber_max_buffer_size
-            && existing_settings.subscriber_max_buffer_size
-                < required_settings.subscriber_max_buffer_size
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize,
-                                "{} since the service supports only a subscriber buffer size of {} but a buffer size of {} was requested.",
-                                msg, existing_settings.subscriber_max_buffer_size, required_settings.subscriber_max_buffer_size);
+        if self.verify_type_hash && existing_settings.type_hash != required_settings.type_hash {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleTypeHash,
+                                "{} since the service has the type hash {:?} but the type hash {:?} was requested.",
+                                msg, existing_settings.type_hash, required_settings.type_hash);
         }
 
-        if self.verify_publisher_history_size
-            && existing_settings.history_size < required_settings.history_size
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize,
-                                "{} since the service supports only a history size of {} but a history size of {} was requested.",
-                                msg, existing_settings.history_size, required_settings.history_size);
-        }
+        let mismatches = Self::property_mismatches(&self.requirements, existing_settings);
+        if !mismatches.is_empty() {
+            for mismatch in &mismatches {
+                debug!(from self, "{} since the property does not satisfy the requirement: {}.", msg, mismatch);
+            }
 
-        if self.verify_subscriber_max_borrowed_samples
-            && existing_settings.subscriber_max_borrowed_samples
-                < required_settings.subscriber_max_borrowed_samples
-        {
-            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples,
-                                "{} since the service supports only {} borrowed subscriber samples but a {} borrowed subscriber samples were requested.",
-                                msg, existing_settings.subscriber_max_borrowed_samples, required_settings.subscriber_max_borrowed_samples);
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleProperties(mismatches),
+                "{} since {} of the service properties do not satisfy the requirements.",
+                msg, mismatches.len());
         }
 
         if self.verify_enable_safe_overflow
@@ -521,6 +1055,20 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                                 msg);
         }
 
+        if self.verify_enable_notifications && !existing_settings.enable_notifications {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportNotifications,
+                                "{} since the service does not support notifications but they were requested.",
+                                msg);
+        }
+
+        if self.verify_enable_copy_transfer
+            && existing_settings.enable_copy_transfer != required_settings.enable_copy_transfer
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleCopyTransfer,
+                                "{} since the service has an incompatible copy transfer setting.",
+                                msg);
+        }
+
         Ok(existing_settings.clone())
     }
 }
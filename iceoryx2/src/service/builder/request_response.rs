@@ -0,0 +1,573 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use crate::service;
+use crate::service::dynamic_config::request_response::DynamicConfigSettings;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::request_response;
+use crate::service::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::serialize::Serialize;
+use iceoryx2_cal::static_storage::StaticStorageLocked;
+
+use super::ServiceState;
+
+/// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleTypes,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    DoesNotSupportRequestedAmountOfClients,
+    DoesNotSupportRequestedAmountOfServers,
+    DoesNotSupportRequestedMinServerBufferSize,
+    DoesNotSupportRequestedMinServerBorrowedRequests,
+    DoesNotSupportRequestedMinClientBufferSize,
+    DoesNotSupportRequestedMinClientBorrowedResponses,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::RequestResponse`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::RequestResponse`] [`Service`] shall be
+    /// created or opened.
+    RequestResponseOpenOrCreateError
+  mapping:
+    RequestResponseOpenError,
+    RequestResponseCreateError
+}
+
+impl std::fmt::Display for RequestResponseOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<'config, ServiceType: service::Details<'config>> {
+    base: builder::BuilderWithServiceType<'config, ServiceType>,
+    verify_number_of_clients: bool,
+    verify_number_of_servers: bool,
+    verify_server_max_buffer_size: bool,
+    verify_server_max_borrowed_requests: bool,
+    verify_client_max_buffer_size: bool,
+    verify_client_max_borrowed_responses: bool,
+}
+
+impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_clients: false,
+            verify_number_of_servers: false,
+            verify_server_max_buffer_size: false,
+            verify_server_max_borrowed_requests: false,
+            verify_client_max_buffer_size: false,
+            verify_client_max_borrowed_responses: false,
+        };
+
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::RequestResponse(static_config::request_response::StaticConfig::new(
+                new_self
+                    .base
+                    .global_config
+                    .defaults_for(new_self.base.service_config.service_name()),
+            ));
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::client::Client`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::client::Client`] must be at least supported.
+    pub fn max_clients(mut self, value: usize) -> Self {
+        self.config_details_mut().max_clients = value;
+        self.verify_number_of_clients = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::server::Server`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::server::Server`] must be at least supported.
+    pub fn max_servers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_servers = value;
+        self.verify_number_of_servers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many requests of a single
+    /// [`crate::port::client::Client`] a [`crate::port::server::Server`] can store in its
+    /// internal buffer. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn server_max_buffer_size(mut self, value: usize) -> Self {
+        self.config_details_mut().server_max_buffer_size = value;
+        self.verify_server_max_buffer_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::request::Request`]s of a
+    /// single [`crate::port::client::Client`] a [`crate::port::server::Server`] can borrow at
+    /// most in parallel. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn server_max_borrowed_requests(mut self, value: usize) -> Self {
+        self.config_details_mut().server_max_borrowed_requests = value;
+        self.verify_server_max_borrowed_requests = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many responses of a single
+    /// [`crate::port::server::Server`] a [`crate::port::client::Client`] can store in its
+    /// internal buffer. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn client_max_buffer_size(mut self, value: usize) -> Self {
+        self.config_details_mut().client_max_buffer_size = value;
+        self.verify_client_max_buffer_size = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::response::Response`]s of a
+    /// single [`crate::port::server::Server`] a [`crate::port::client::Client`] can borrow at
+    /// most in parallel. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn client_max_borrowed_responses(mut self, value: usize) -> Self {
+        self.config_details_mut().client_max_borrowed_responses = value;
+        self.verify_client_max_borrowed_responses = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the attribute with the given key and value,
+    /// see [`crate::service::attribute::AttributeSet`]. If an existing [`Service`] is opened
+    /// it requires the service to define the attribute with the same value, otherwise
+    /// [`RequestResponseOpenError::IncompatibleAttributes`] is returned.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attribute(key, value);
+        self
+    }
+
+    fn set_type_names<RequestType: Debug, ResponseType: Debug>(&mut self) {
+        let config = self.config_details_mut();
+        config.request_type_name = std::any::type_name::<RequestType>().to_string();
+        config.response_type_name = std::any::type_name::<ResponseType>().to_string();
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                if config.request_response().request_type_name
+                    != self.config_details().request_type_name
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the request type \"{}\" but the requested type is \"{}\".",
+                        error_msg, &config.request_response().request_type_name, self.config_details().request_type_name);
+                }
+
+                if config.request_response().response_type_name
+                    != self.config_details().response_type_name
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the response type \"{}\" but the requested type is \"{}\".",
+                        error_msg, &config.request_response().response_type_name, self.config_details().response_type_name);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create<RequestType: Debug, ResponseType: Debug>(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestType, ResponseType>,
+        RequestResponseOpenOrCreateError,
+    > {
+        let msg = "Unable to open or create request response service";
+        self.set_type_names::<RequestType, ResponseType>();
+
+        match self.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open::<RequestType, ResponseType>()?),
+            Ok(None) => Ok(self.create::<RequestType, ResponseType>()?),
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open::<RequestType, ResponseType>()?),
+            Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleTypes),
+                    "{} since the service is not type compatible.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open<RequestType: Debug, ResponseType: Debug>(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestType, ResponseType>,
+        RequestResponseOpenError,
+    > {
+        let msg = "Unable to open request response service";
+        self.set_type_names::<RequestType, ResponseType>();
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
+                    let static_config = self.verify_service_properties(&static_config)?;
+
+                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
+                            with RequestResponseOpenError::UnableToOpenDynamicServiceInformation,
+                            "{} since the dynamic service information could not be opened.", msg);
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::RequestResponse(static_config);
+
+                    return Ok(request_response::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new::<ServiceType>(
+                            self.base.service_config,
+                            self.base.global_config,
+                            dynamic_config,
+                            static_storage,
+                            self.base.node_id,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout > self.base.global_config.global.service.creation_timeout {
+                        fail!(from self, with RequestResponseOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleTypes,
+                    "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with RequestResponseOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create<RequestType: Debug, ResponseType: Debug>(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestType, ResponseType>,
+        RequestResponseCreateError,
+    > {
+        self.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create request response service";
+        self.set_type_names::<RequestType, ResponseType>();
+
+        match self.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = fail!(from self, when self.base.create_static_config_storage(),
+                    with RequestResponseCreateError::UnableToCreateStaticServiceInformation,
+                    "{} since the static service information could not be created.", msg);
+
+                let reqres_config = self.base.service_config.request_response();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_clients: reqres_config.max_clients,
+                    number_of_servers: reqres_config.max_servers,
+                };
+
+                let dynamic_config = self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::RequestResponse(
+                        dynamic_config::request_response::DynamicConfig::new(
+                            &dynamic_config_setting,
+                        ),
+                    ),
+                    dynamic_config::request_response::DynamicConfig::memory_size(
+                        &dynamic_config_setting,
+                    ),
+                );
+                let dynamic_config = fail!(from self, when dynamic_config,
+                    with RequestResponseCreateError::InternalFailure,
+                    "{} since the dynamic service segment could not be created.", msg);
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                discovery::notify_service_change::<ServiceType>(self.base.global_config);
+
+                return Ok(request_response::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new::<ServiceType>(
+                        self.base.service_config.clone(),
+                        self.base.global_config,
+                        dynamic_config,
+                        unlocked_static_details,
+                        self.base.node_id,
+                    ),
+                )));
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.request_response_mut();
+
+        if settings.max_clients == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_clients = 1;
+        }
+
+        if settings.max_servers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_servers = 1;
+        }
+
+        if settings.server_max_buffer_size == 0 {
+            warn!(from origin,
+                "Setting the servers buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.server_max_buffer_size = 1;
+        }
+
+        if settings.server_max_borrowed_requests == 0 {
+            warn!(from origin,
+                "Setting the servers max borrowed requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.server_max_borrowed_requests = 1;
+        }
+
+        if settings.client_max_buffer_size == 0 {
+            warn!(from origin,
+                "Setting the clients buffer size to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.client_max_buffer_size = 1;
+        }
+
+        if settings.client_max_borrowed_responses == 0 {
+            warn!(from origin,
+                "Setting the clients max borrowed responses to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.client_max_borrowed_responses = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::request_response::StaticConfig, RequestResponseOpenError> {
+        let msg = "Unable to open request response service";
+
+        let required_settings = self.base.service_config.request_response();
+        if let Some(attribute) = existing_settings
+            .attributes()
+            .first_unsatisfied(&self.base.required_attributes)
+        {
+            fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
+                "{} since the service does not define the required attribute \"{}\" with the value \"{}\".",
+                msg, attribute.key(), attribute.value());
+        }
+
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            p => {
+                fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::RequestResponse is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_clients
+            && existing_settings.max_clients < required_settings.max_clients
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
+                                "{} since the service supports only {} clients but a support of {} clients was requested.",
+                                msg, existing_settings.max_clients, required_settings.max_clients);
+        }
+
+        if self.verify_number_of_servers
+            && existing_settings.max_servers < required_settings.max_servers
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
+                                "{} since the service supports only {} servers but a support of {} servers was requested.",
+                                msg, existing_settings.max_servers, required_settings.max_servers);
+        }
+
+        if self.verify_server_max_buffer_size
+            && existing_settings.server_max_buffer_size < required_settings.server_max_buffer_size
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedMinServerBufferSize,
+                                "{} since the service supports only a server buffer size of {} but a buffer size of {} was requested.",
+                                msg, existing_settings.server_max_buffer_size, required_settings.server_max_buffer_size);
+        }
+
+        if self.verify_server_max_borrowed_requests
+            && existing_settings.server_max_borrowed_requests
+                < required_settings.server_max_borrowed_requests
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedMinServerBorrowedRequests,
+                                "{} since the service supports only {} borrowed server requests but {} borrowed server requests were requested.",
+                                msg, existing_settings.server_max_borrowed_requests, required_settings.server_max_borrowed_requests);
+        }
+
+        if self.verify_client_max_buffer_size
+            && existing_settings.client_max_buffer_size < required_settings.client_max_buffer_size
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedMinClientBufferSize,
+                                "{} since the service supports only a client buffer size of {} but a buffer size of {} was requested.",
+                                msg, existing_settings.client_max_buffer_size, required_settings.client_max_buffer_size);
+        }
+
+        if self.verify_client_max_borrowed_responses
+            && existing_settings.client_max_borrowed_responses
+                < required_settings.client_max_borrowed_responses
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedMinClientBorrowedResponses,
+                                "{} since the service supports only {} borrowed client responses but {} borrowed client responses were requested.",
+                                msg, existing_settings.client_max_borrowed_responses, required_settings.client_max_borrowed_responses);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
@@ -89,6 +89,17 @@ pub(crate) fn connection_config<'config, Service: crate::service::Details<'confi
     )
 }
 
+pub(crate) fn copy_channel_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::CopyChannel as NamedConceptMgmt>::Configuration {
+    generate_default_config::<<Service::CopyChannel as NamedConceptMgmt>::Configuration>(
+        "copy_channel_config",
+        &global_config.global.prefix,
+        &global_config.global.service.copy_channel_suffix,
+        &global_config.global.root_path,
+    )
+}
+
 pub(crate) fn data_segment_config<'config, Service: crate::service::Details<'config>>(
     global_config: &config::Config,
 ) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
@@ -99,3 +110,45 @@ pub(crate) fn data_segment_config<'config, Service: crate::service::Details<'con
         &global_config.global.root_path,
     )
 }
+
+pub(crate) fn node_details_storage_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    let mut path_hint = global_config.global.root_path.clone();
+    path_hint.push_str(&global_config.global.node.directory);
+
+    generate_default_config::<<Service::StaticStorage as NamedConceptMgmt>::Configuration>(
+        "node_details_storage_config",
+        &global_config.global.prefix,
+        &global_config.global.node.details_suffix,
+        &path_hint,
+    )
+}
+
+pub(crate) fn node_port_tag_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
+    let mut path_hint = global_config.global.root_path.clone();
+    path_hint.push_str(&global_config.global.node.directory);
+
+    generate_default_config::<<Service::StaticStorage as NamedConceptMgmt>::Configuration>(
+        "node_port_tag_config",
+        &global_config.global.prefix,
+        &global_config.global.node.port_tag_suffix,
+        &path_hint,
+    )
+}
+
+pub(crate) fn discovery_listener_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    let mut path_hint = global_config.global.root_path.clone();
+    path_hint.push_str(&global_config.global.service.directory);
+
+    generate_default_config::<<Service::Event as NamedConceptMgmt>::Configuration>(
+        "discovery_listener_config",
+        &global_config.global.prefix,
+        &global_config.global.service.discovery_listener_suffix,
+        &path_hint,
+    )
+}
@@ -0,0 +1,259 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_log::{debug, warn};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::named_concept::NamedConceptMgmt;
+
+use crate::config;
+use crate::port::port_identifiers::*;
+use crate::service;
+use crate::service::config_scheme::{connection_config, copy_channel_config, data_segment_config};
+use crate::service::dynamic_config::{list_ids, remove_dead_ids, DynamicConfig};
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::naming_scheme::*;
+use crate::service::static_config::{self, StaticConfig};
+
+/// Removes all ports of the [`crate::service::Service`] whose owning process died from the
+/// dynamic config and removes the resources they left behind, like data segments, connections
+/// and event concepts. Returns the number of removed ports.
+pub(crate) fn cleanup_dead_ports<'config, Service: service::Details<'config>>(
+    service: &Service,
+) -> usize {
+    cleanup_dead_ports_of_service::<Service>(
+        &service.state().static_config,
+        service.state().dynamic_storage.get(),
+        service.state().global_config,
+    )
+}
+
+/// Same as [`cleanup_dead_ports()`] but works directly on the configs of the service, so that
+/// the service does not have to be opened.
+pub(crate) fn cleanup_dead_ports_of_service<'config, Service: service::Details<'config>>(
+    static_config: &StaticConfig,
+    dynamic_config: &DynamicConfig,
+    global_config: &config::Config,
+) -> usize {
+    let origin = "cleanup_dead_ports()";
+    if !dynamic_config.try_acquire_cleanup_lock() {
+        debug!(from origin, "Skip the cleanup of the service \"{}\" since another process is already cleaning it up.",
+            static_config.service_name());
+        return 0;
+    }
+
+    let number_of_dead_ports = unsafe {
+        match static_config.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref pubsub_config) => {
+                cleanup_publish_subscribe::<Service>(pubsub_config, dynamic_config, global_config)
+            }
+            MessagingPattern::Event(_) => cleanup_event::<Service>(dynamic_config),
+            MessagingPattern::RequestResponse(_) => {
+                cleanup_request_response::<Service>(dynamic_config, global_config)
+            }
+            MessagingPattern::Blackboard(_) => cleanup_blackboard(dynamic_config),
+            MessagingPattern::Pipeline(_) => {
+                cleanup_pipeline::<Service>(dynamic_config, global_config)
+            }
+        }
+    };
+
+    dynamic_config.release_cleanup_lock();
+    number_of_dead_ports
+}
+
+unsafe fn cleanup_publish_subscribe<'config, Service: service::Details<'config>>(
+    static_config: &static_config::publish_subscribe::StaticConfig,
+    dynamic_config: &DynamicConfig,
+    global_config: &config::Config,
+) -> usize {
+    let dynamic_config = dynamic_config.publish_subscribe();
+    let dead_publishers = remove_dead_ids(
+        &dynamic_config.publishers,
+        UniquePublisherId::is_owner_alive,
+    );
+    let dead_subscribers = remove_dead_ids(
+        &dynamic_config.subscribers,
+        UniqueSubscriberId::is_owner_alive,
+    );
+    let publishers = list_ids(&dynamic_config.publishers);
+    let subscribers = list_ids(&dynamic_config.subscribers);
+
+    for publisher_id in &dead_publishers {
+        remove_data_segment::<Service>(global_config, &data_segment_name(*publisher_id));
+        for subscriber_id in subscribers.iter().chain(dead_subscribers.iter()) {
+            remove_connection::<Service>(
+                global_config,
+                &connection_name(*publisher_id, *subscriber_id),
+            );
+        }
+    }
+
+    for subscriber_id in &dead_subscribers {
+        remove_event_concept::<Service>(&subscriber_event_concept_name(subscriber_id));
+        if static_config.has_copy_transfer() {
+            remove_copy_channel::<Service>(global_config, &copy_channel_name(*subscriber_id));
+        }
+        for publisher_id in &publishers {
+            remove_connection::<Service>(
+                global_config,
+                &connection_name(*publisher_id, *subscriber_id),
+            );
+        }
+    }
+
+    dead_publishers.len() + dead_subscribers.len()
+}
+
+unsafe fn cleanup_event<'config, Service: service::Details<'config>>(
+    dynamic_config: &DynamicConfig,
+) -> usize {
+    let dynamic_config = dynamic_config.event();
+    let dead_listeners =
+        remove_dead_ids(&dynamic_config.listeners, UniqueListenerId::is_owner_alive);
+    let dead_notifiers =
+        remove_dead_ids(&dynamic_config.notifiers, UniqueNotifierId::is_owner_alive);
+
+    for listener_id in &dead_listeners {
+        remove_event_concept::<Service>(&event_concept_name(listener_id));
+    }
+
+    dead_listeners.len() + dead_notifiers.len()
+}
+
+unsafe fn cleanup_request_response<'config, Service: service::Details<'config>>(
+    dynamic_config: &DynamicConfig,
+    global_config: &config::Config,
+) -> usize {
+    let dynamic_config = dynamic_config.request_response();
+    let dead_clients = remove_dead_ids(&dynamic_config.clients, UniqueClientId::is_owner_alive);
+    let dead_servers = remove_dead_ids(&dynamic_config.servers, UniqueServerId::is_owner_alive);
+    let clients = list_ids(&dynamic_config.clients);
+    let servers = list_ids(&dynamic_config.servers);
+
+    for client_id in &dead_clients {
+        remove_data_segment::<Service>(global_config, &client_data_segment_name(*client_id));
+        for server_id in servers.iter().chain(dead_servers.iter()) {
+            remove_connection::<Service>(
+                global_config,
+                &request_connection_name(*client_id, *server_id),
+            );
+            remove_connection::<Service>(
+                global_config,
+                &response_connection_name(*server_id, *client_id),
+            );
+        }
+    }
+
+    for server_id in &dead_servers {
+        remove_data_segment::<Service>(global_config, &server_data_segment_name(*server_id));
+        for client_id in &clients {
+            remove_connection::<Service>(
+                global_config,
+                &request_connection_name(*client_id, *server_id),
+            );
+            remove_connection::<Service>(
+                global_config,
+                &response_connection_name(*server_id, *client_id),
+            );
+        }
+    }
+
+    dead_clients.len() + dead_servers.len()
+}
+
+unsafe fn cleanup_blackboard(dynamic_config: &DynamicConfig) -> usize {
+    let dynamic_config = dynamic_config.blackboard();
+    let dead_readers = remove_dead_ids(&dynamic_config.readers, UniqueReaderId::is_owner_alive);
+    let dead_writers = remove_dead_ids(&dynamic_config.writers, UniqueWriterId::is_owner_alive);
+
+    // a blackboard supports only one writer which owns the producers of all entries, the
+    // producers of a dead writer were never released and would block every future writer
+    if !dead_writers.is_empty() {
+        dynamic_config.release_producers();
+    }
+
+    dead_readers.len() + dead_writers.len()
+}
+
+unsafe fn cleanup_pipeline<'config, Service: service::Details<'config>>(
+    dynamic_config: &DynamicConfig,
+    global_config: &config::Config,
+) -> usize {
+    let dynamic_config = dynamic_config.pipeline();
+    let dead_producers =
+        remove_dead_ids(&dynamic_config.producers, UniqueProducerId::is_owner_alive);
+    let dead_consumers =
+        remove_dead_ids(&dynamic_config.consumers, UniqueConsumerId::is_owner_alive);
+    let producers = list_ids(&dynamic_config.producers);
+    let consumers = list_ids(&dynamic_config.consumers);
+
+    for producer_id in &dead_producers {
+        remove_data_segment::<Service>(global_config, &producer_data_segment_name(*producer_id));
+        for consumer_id in consumers.iter().chain(dead_consumers.iter()) {
+            remove_connection::<Service>(
+                global_config,
+                &pipeline_connection_name(*producer_id, *consumer_id),
+            );
+        }
+    }
+
+    for consumer_id in &dead_consumers {
+        for producer_id in &producers {
+            remove_connection::<Service>(
+                global_config,
+                &pipeline_connection_name(*producer_id, *consumer_id),
+            );
+        }
+    }
+
+    dead_producers.len() + dead_consumers.len()
+}
+
+fn remove_data_segment<'config, Service: service::Details<'config>>(
+    global_config: &config::Config,
+    name: &FileName,
+) {
+    let config = data_segment_config::<Service>(global_config);
+    if let Err(e) =
+        unsafe { <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(name, &config) }
+    {
+        warn!(from "cleanup_dead_ports()", "Unable to remove the data segment \"{}\" of a dead port ({:?}).", name, e);
+    }
+}
+
+fn remove_connection<'config, Service: service::Details<'config>>(
+    global_config: &config::Config,
+    name: &FileName,
+) {
+    let config = connection_config::<Service>(global_config);
+    if let Err(e) = unsafe { <Service::Connection as NamedConceptMgmt>::remove_cfg(name, &config) }
+    {
+        warn!(from "cleanup_dead_ports()", "Unable to remove the connection \"{}\" of a dead port ({:?}).", name, e);
+    }
+}
+
+fn remove_copy_channel<'config, Service: service::Details<'config>>(
+    global_config: &config::Config,
+    name: &FileName,
+) {
+    let config = copy_channel_config::<Service>(global_config);
+    if let Err(e) = unsafe { <Service::CopyChannel as NamedConceptMgmt>::remove_cfg(name, &config) }
+    {
+        warn!(from "cleanup_dead_ports()", "Unable to remove the copy channel \"{}\" of a dead port ({:?}).", name, e);
+    }
+}
+
+fn remove_event_concept<'config, Service: service::Details<'config>>(name: &FileName) {
+    if let Err(e) = unsafe { <Service::Event as NamedConceptMgmt>::remove(name) } {
+        warn!(from "cleanup_dead_ports()", "Unable to remove the event concept \"{}\" of a dead port ({:?}).", name, e);
+    }
+}
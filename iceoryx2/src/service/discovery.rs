@@ -0,0 +1,344 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::discovery::{DiscoveryEvent, ServiceDiscovery};
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut discovery = ServiceDiscovery::<zero_copy::Service>::new()?;
+//!
+//! // all services that existed when the discovery was created
+//! for service in discovery.services() {
+//!     println!("{}", service.service_name());
+//! }
+//!
+//! let service = zero_copy::Service::new(&ServiceName::new("My/Funk/ServiceName")?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! for event in discovery.timed_update(Duration::from_millis(100))? {
+//!     match event {
+//!         DiscoveryEvent::ServiceAdded(service) => println!("added: {}", service.service_name()),
+//!         DiscoveryEvent::ServiceRemoved(service) => println!("removed: {}", service.service_name()),
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::event::{
+    Event, Listener, ListenerBuilder, ListenerCreateError, Notifier, NotifierBuilder,
+};
+use iceoryx2_cal::named_concept::*;
+
+use crate::config;
+use crate::port::event_id::EventId;
+use crate::service::config_scheme::{discovery_listener_config, static_config_storage_config};
+use crate::service::naming_scheme::{
+    discovery_listener_name, extract_id_from_discovery_listener_name,
+};
+use crate::service::static_config::StaticConfig;
+use crate::service::{self, read_static_config, Service, ServiceListError};
+
+/// Failures that can occur when a [`ServiceDiscovery`] is created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ServiceDiscoveryCreateError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceDiscoveryCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceDiscoveryCreateError {}
+
+/// A change of the set of services that was detected by the [`ServiceDiscovery`].
+#[derive(Debug, Clone)]
+pub enum DiscoveryEvent {
+    /// A service was created, contains its [`StaticConfig`]
+    ServiceAdded(StaticConfig),
+    /// A service was removed, contains the [`StaticConfig`] it had
+    ServiceRemoved(StaticConfig),
+}
+
+/// Keeps track of all services that exist under a [`config::Config`]. Whenever a service is
+/// created or removed the [`ServiceDiscovery`] is notified and reports the change as
+/// [`DiscoveryEvent`] on the next update. In contrast to [`service::Details::list()`] only the
+/// static configuration of newly added services is read, all other services are cached.
+///
+/// # Example
+///
+/// See [`crate::service::discovery`]
+#[derive(Debug)]
+pub struct ServiceDiscovery<'config, S: Service> {
+    listener: <<S::Type<'config> as service::Details<'config>>::Event as Event<EventId>>::Listener,
+    services: HashMap<String, StaticConfig>,
+    config: &'config config::Config,
+}
+
+impl<'config, S: Service> ServiceDiscovery<'config, S> {
+    /// Creates a new [`ServiceDiscovery`] for all services that are created with the global
+    /// [`config::Config`].
+    pub fn new() -> Result<Self, ServiceDiscoveryCreateError> {
+        Self::new_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Creates a new [`ServiceDiscovery`] for all services that are created with the provided
+    /// [`config::Config`].
+    pub fn new_with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<Self, ServiceDiscoveryCreateError> {
+        let msg = "Unable to create service discovery";
+        let origin = "ServiceDiscovery::new_with_custom_config()";
+
+        let listener_id = fail!(from origin, when UniqueSystemId::new(),
+                with ServiceDiscoveryCreateError::InternalError,
+                "{} since the listener id could not be generated.", msg);
+
+        // the listener must be created before the services are listed, otherwise a service that
+        // is created in between would never be reported
+        let listener = fail!(from origin,
+                when <<S::Type<'config> as service::Details<'config>>::Event as Event<EventId>>::ListenerBuilder::new(&discovery_listener_name(&listener_id))
+                    .config(&discovery_listener_config::<S::Type<'config>>(config))
+                    .create(),
+                map ListenerCreateError::InsufficientPermissions => ServiceDiscoveryCreateError::InsufficientPermissions,
+                unmatched ServiceDiscoveryCreateError::InternalError,
+                "{} since the underlying event concept could not be created.", msg);
+
+        let mut new_self = Self {
+            listener,
+            services: HashMap::new(),
+            config,
+        };
+
+        fail!(from origin, when new_self.refresh(),
+                map ServiceListError::InsufficientPermissions => ServiceDiscoveryCreateError::InsufficientPermissions,
+                unmatched ServiceDiscoveryCreateError::InternalError,
+                "{} since the existing services could not be listed.", msg);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`StaticConfig`]s of all services known to the [`ServiceDiscovery`]. The
+    /// list is only changed by an update or [`ServiceDiscovery::refresh()`].
+    pub fn services(&self) -> impl Iterator<Item = &StaticConfig> {
+        self.services.values()
+    }
+
+    /// Non-blocking update. If the [`ServiceDiscovery`] was notified about a created or removed
+    /// service since the last update, the cache is updated and the changes are returned.
+    pub fn try_update(&mut self) -> Result<Vec<DiscoveryEvent>, ServiceListError> {
+        if self.consume_notifications()? {
+            self.refresh()
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Blocks until the [`ServiceDiscovery`] was notified about a created or removed service or
+    /// the timeout has passed. Returns the changes since the last update.
+    pub fn timed_update(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Vec<DiscoveryEvent>, ServiceListError> {
+        let has_notification =
+            fail!(from "ServiceDiscovery::timed_update()", when self.listener.timed_wait(timeout),
+                with ServiceListError::InternalError,
+                "Failed to wait for service changes since the underlying event concept failed.")
+            .is_some();
+
+        if self.consume_notifications()? || has_notification {
+            self.refresh()
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Blocks until the [`ServiceDiscovery`] was notified about a created or removed service.
+    /// Returns the changes since the last update.
+    pub fn blocking_update(&mut self) -> Result<Vec<DiscoveryEvent>, ServiceListError> {
+        fail!(from "ServiceDiscovery::blocking_update()", when self.listener.blocking_wait(),
+                with ServiceListError::InternalError,
+                "Failed to wait for service changes since the underlying event concept failed.");
+        self.consume_notifications()?;
+        self.refresh()
+    }
+
+    /// Compares the cache with the services that currently exist without waiting for a
+    /// notification. Only the static configuration of services that are not yet cached is
+    /// read. Returns the changes since the last update.
+    pub fn refresh(&mut self) -> Result<Vec<DiscoveryEvent>, ServiceListError> {
+        let static_storage_config = static_config_storage_config::<S::Type<'config>>(self.config);
+
+        let existing_services = fail!(from "ServiceDiscovery::refresh()",
+                when <<S::Type<'config> as service::Details<'config>>::StaticStorage as NamedConceptMgmt>::list_cfg(&static_storage_config),
+                map NamedConceptListError::InsufficientPermissions => ServiceListError::InsufficientPermissions,
+                unmatched ServiceListError::InternalError,
+                "Unable to refresh the service discovery due to a failure while collecting all active services.");
+
+        let mut events = vec![];
+        let removed_services: Vec<String> = self
+            .services
+            .keys()
+            .filter(|uuid| {
+                !existing_services
+                    .iter()
+                    .any(|s| s.as_bytes() == uuid.as_bytes())
+            })
+            .cloned()
+            .collect();
+
+        for uuid in removed_services {
+            if let Some(service_config) = self.services.remove(&uuid) {
+                events.push(DiscoveryEvent::ServiceRemoved(service_config));
+            }
+        }
+
+        for service_storage in existing_services {
+            let is_cached = std::str::from_utf8(service_storage.as_bytes())
+                .map(|uuid| self.services.contains_key(uuid))
+                .unwrap_or(false);
+
+            if is_cached {
+                continue;
+            }
+
+            if let Some(service_config) = read_static_config::<S::Type<'config>>(
+                &service_storage,
+                &static_storage_config,
+                self.config,
+            ) {
+                self.services
+                    .insert(service_config.uuid().to_string(), service_config.clone());
+                events.push(DiscoveryEvent::ServiceAdded(service_config));
+            }
+        }
+
+        Ok(events)
+    }
+
+    fn consume_notifications(&self) -> Result<bool, ServiceListError> {
+        let mut has_notification = false;
+        while fail!(from "ServiceDiscovery::consume_notifications()", when self.listener.try_wait(),
+                with ServiceListError::InternalError,
+                "Failed to acquire service changes since the underlying event concept failed.")
+        .is_some()
+        {
+            has_notification = true;
+        }
+
+        Ok(has_notification)
+    }
+}
+
+/// Notifies every [`ServiceDiscovery`] of the [`config::Config`] that a service was created or
+/// removed. The listeners of processes that died without removing them are removed.
+pub(crate) fn notify_service_change<'config, Service: service::Details<'config>>(
+    config: &config::Config,
+) {
+    let origin = "notify_service_change()";
+    let listener_config = discovery_listener_config::<Service>(config);
+
+    let listeners = match <Service::Event as NamedConceptMgmt>::list_cfg(&listener_config) {
+        Ok(listeners) => listeners,
+        Err(e) => {
+            warn!(from origin, "Unable to notify the service discovery about a service change since its listeners could not be listed ({:?}).", e);
+            return;
+        }
+    };
+
+    for listener in listeners {
+        let is_notified = match <Service::Event as Event<EventId>>::NotifierBuilder::new(&listener)
+            .config(&listener_config)
+            .open()
+        {
+            Ok(notifier) => notifier.notify(EventId::default()).is_ok(),
+            Err(_) => false,
+        };
+
+        if !is_notified {
+            remove_dead_listener::<Service>(&listener, &listener_config);
+        }
+    }
+}
+
+fn remove_dead_listener<'config, Service: service::Details<'config>>(
+    listener: &FileName,
+    listener_config: &<Service::Event as NamedConceptMgmt>::Configuration,
+) {
+    let origin = "remove_dead_listener()";
+    let listener_id = match extract_id_from_discovery_listener_name(listener) {
+        Some(id) => UniqueSystemId::from(id),
+        None => return,
+    };
+
+    if Process::from_pid(listener_id.pid()).is_alive() {
+        debug!(from origin, "Unable to notify the service discovery listener \"{}\" about a service change.", listener);
+        return;
+    }
+
+    match unsafe { <Service::Event as NamedConceptMgmt>::remove_cfg(listener, listener_config) } {
+        Ok(_) => {
+            debug!(from origin, "Removed the service discovery listener \"{}\" of a dead process.", listener);
+        }
+        Err(e) => {
+            warn!(from origin, "Unable to remove the service discovery listener \"{}\" of a dead process ({:?}).", listener, e);
+        }
+    }
+}
+
+/// Notifies every [`ServiceDiscovery`] when the service it belongs to was removed. Since the
+/// service is removed when its static and dynamic storage are dropped, it must be dropped after
+/// them.
+#[derive(Debug)]
+pub(crate) struct RemovalNotifier<'config> {
+    config: &'config config::Config,
+    notify: fn(&config::Config),
+    is_service_removed: bool,
+}
+
+impl<'config> RemovalNotifier<'config> {
+    pub(crate) fn new<Service: service::Details<'config>>(config: &'config config::Config) -> Self {
+        Self {
+            config,
+            notify: notify_service_change::<Service>,
+            is_service_removed: false,
+        }
+    }
+
+    pub(crate) fn notify_on_drop(&mut self) {
+        self.is_service_removed = true;
+    }
+}
+
+impl<'config> Drop for RemovalNotifier<'config> {
+    fn drop(&mut self) {
+        if self.is_service_removed {
+            (self.notify)(self.config);
+        }
+    }
+}
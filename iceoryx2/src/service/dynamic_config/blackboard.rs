@@ -0,0 +1,182 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! println!("number of active readers:         {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:         {:?}", blackboard.dynamic_config().number_of_writers());
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{
+    release_producer_of_type_erased_atomic, UnrestrictedAtomic,
+};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::port::port_identifiers::{UniqueReaderId, UniqueWriterId};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_writers: usize,
+    pub number_of_entries: usize,
+    pub entry_layout: Layout,
+}
+
+impl DynamicConfigSettings {
+    pub fn new<T: Copy>(
+        number_of_readers: usize,
+        number_of_writers: usize,
+        number_of_entries: usize,
+    ) -> Self {
+        Self {
+            number_of_readers,
+            number_of_writers,
+            number_of_entries,
+            entry_layout: Layout::new::<UnrestrictedAtomic<T>>(),
+        }
+    }
+
+    fn entries_layout(&self) -> Layout {
+        fatal_panic!(from self,
+            when Layout::from_size_align(
+                self.entry_layout.size() * self.number_of_entries,
+                self.entry_layout.align()),
+            "This should never happen! The entries of the blackboard exceed the maximum supported memory size.")
+    }
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints and the
+/// entries of the blackboard.
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<UniqueReaderId>,
+    pub(crate) writers: Container<UniqueWriterId>,
+    entries: RelocatablePointer<u8>,
+    settings: DynamicConfigSettings,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+            entries: unsafe { RelocatablePointer::new_uninit() },
+            settings: *config,
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+        let entries = fatal_panic!(from "blackboard::DynamicConfig::init",
+            when allocator.allocate(self.settings.entries_layout()),
+            "This should never happen! Unable to allocate the memory for the blackboard entries.");
+        self.entries.init(entries);
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<UniqueReaderId>::memory_size(config.number_of_readers)
+            + Container::<UniqueWriterId>::memory_size(config.number_of_writers)
+            + config.entries_layout().size()
+            + config.entry_layout.align()
+            - 1
+    }
+
+    /// Returns how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    pub(crate) fn add_reader_id(&self, id: UniqueReaderId) -> Option<UniqueIndex> {
+        unsafe { self.readers.add(id) }
+    }
+
+    pub(crate) fn add_writer_id(&self, id: UniqueWriterId) -> Option<UniqueIndex> {
+        unsafe { self.writers.add(id) }
+    }
+
+    fn entry_ptr<T: Copy>(&self, index: usize) -> *mut UnrestrictedAtomic<T> {
+        if Layout::new::<UnrestrictedAtomic<T>>() != self.settings.entry_layout {
+            fatal_panic!(from self,
+                "This should never happen! Trying to access the blackboard entries with the incompatible type \"{}\".",
+                std::any::type_name::<T>());
+        }
+
+        if index >= self.settings.number_of_entries {
+            fatal_panic!(from self,
+                "This should never happen! Trying to access the blackboard entry {} but only {} entries exist.",
+                index, self.settings.number_of_entries);
+        }
+
+        unsafe { (self.entries.as_ptr() as *mut UnrestrictedAtomic<T>).add(index) }
+    }
+
+    /// Writes the initial value of an entry. Must be called for every entry exactly once
+    /// directly after [`DynamicConfig::init()`] and before the entry is accessed via
+    /// [`DynamicConfig::entry()`].
+    pub(crate) unsafe fn init_entry<T: Copy>(&self, index: usize, value: T) {
+        self.entry_ptr::<T>(index)
+            .write(UnrestrictedAtomic::new(value));
+    }
+
+    /// Returns the entry stored at the provided index. The type `T` must be the same type
+    /// that was used in [`DynamicConfig::init_entry()`].
+    pub(crate) unsafe fn entry<T: Copy>(&self, index: usize) -> &UnrestrictedAtomic<T> {
+        &*self.entry_ptr::<T>(index)
+    }
+
+    /// Releases the producers of all entries so that a new [`crate::port::writer::Writer`]
+    /// can acquire them again. Used when the [`crate::port::writer::Writer`] that owned them
+    /// died.
+    ///
+    /// # Safety
+    ///
+    ///  * no living [`crate::port::writer::Writer`] must own the producers of the entries
+    pub(crate) unsafe fn release_producers(&self) {
+        for index in 0..self.settings.number_of_entries {
+            release_producer_of_type_erased_atomic(
+                self.entries
+                    .as_ptr()
+                    .add(index * self.settings.entry_layout.size()),
+            );
+        }
+    }
+}
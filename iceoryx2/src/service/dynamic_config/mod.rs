@@ -10,25 +10,44 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service.
 pub mod event;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service.
+pub mod pipeline;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 /// based service.
 pub mod publish_subscribe;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service.
+pub mod request_response;
+
 use std::{
-    fmt::Display,
+    fmt::{Debug, Display},
     sync::atomic::{AtomicU64, Ordering},
 };
 
+use iceoryx2_bb_lock_free::mpmc::container::Container;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+use serde::Serialize;
 
 const MARKED_FOR_DESTRUCTION: u64 = u64::MAX - 1;
+const NO_CLEANUP_OWNER: u64 = 0;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub(crate) enum DecrementReferenceCounterResult {
@@ -36,10 +55,42 @@ pub(crate) enum DecrementReferenceCounterResult {
     NoMoreOwners,
 }
 
+/// A snapshot of the number of ports that are currently connected to a
+/// [`Service`](crate::service::Service), grouped by the ports of its
+/// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize)]
+#[serde(tag = "messaging_pattern")]
+pub enum NumberOfPorts {
+    PublishSubscribe {
+        publishers: usize,
+        subscribers: usize,
+    },
+    Event {
+        notifiers: usize,
+        listeners: usize,
+    },
+    RequestResponse {
+        clients: usize,
+        servers: usize,
+    },
+    Blackboard {
+        readers: usize,
+        writers: usize,
+    },
+    Pipeline {
+        producers: usize,
+        consumers: usize,
+    },
+}
+
 #[derive(Debug)]
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    RequestResponse(request_response::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
+    Pipeline(pipeline::DynamicConfig),
 }
 
 #[doc(hidden)]
@@ -47,6 +98,7 @@ pub(crate) enum MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     reference_counter: AtomicU64,
+    cleanup_owner: AtomicU64,
 }
 
 impl Display for DynamicConfig {
@@ -64,6 +116,7 @@ impl DynamicConfig {
         Self {
             messaging_pattern,
             reference_counter: AtomicU64::new(1),
+            cleanup_owner: AtomicU64::new(NO_CLEANUP_OWNER),
         }
     }
 
@@ -71,6 +124,9 @@ impl DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::RequestResponse(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
+            MessagingPattern::Pipeline(ref v) => v.init(allocator),
         }
     }
 
@@ -121,6 +177,62 @@ impl DynamicConfig {
         result
     }
 
+    /// Ensures that only one process at a time removes dead ports from the dynamic config.
+    /// If the process that held the lock died, the lock is taken over. Returns true when the lock
+    /// was acquired, otherwise false.
+    pub(crate) fn try_acquire_cleanup_lock(&self) -> bool {
+        let own_pid = Process::from_self().id().value() as u64;
+        let mut current_owner = self.cleanup_owner.load(Ordering::Relaxed);
+
+        loop {
+            if current_owner != NO_CLEANUP_OWNER
+                && Process::from_pid(ProcessId::new(current_owner as _)).is_alive()
+            {
+                return false;
+            }
+
+            match self.cleanup_owner.compare_exchange(
+                current_owner,
+                own_pid,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(v) => current_owner = v,
+            }
+        }
+    }
+
+    pub(crate) fn release_cleanup_lock(&self) {
+        self.cleanup_owner
+            .store(NO_CLEANUP_OWNER, Ordering::Release);
+    }
+
+    pub(crate) fn number_of_ports(&self) -> NumberOfPorts {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => NumberOfPorts::PublishSubscribe {
+                publishers: v.number_of_publishers(),
+                subscribers: v.number_of_subscribers(),
+            },
+            MessagingPattern::Event(ref v) => NumberOfPorts::Event {
+                notifiers: v.number_of_notifiers(),
+                listeners: v.number_of_listeners(),
+            },
+            MessagingPattern::RequestResponse(ref v) => NumberOfPorts::RequestResponse {
+                clients: v.number_of_clients(),
+                servers: v.number_of_servers(),
+            },
+            MessagingPattern::Blackboard(ref v) => NumberOfPorts::Blackboard {
+                readers: v.number_of_readers(),
+                writers: v.number_of_writers(),
+            },
+            MessagingPattern::Pipeline(ref v) => NumberOfPorts::Pipeline {
+                producers: v.number_of_producers(),
+                consumers: v.number_of_consumers(),
+            },
+        }
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -138,4 +250,65 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access request_response::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access pipeline::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+}
+
+/// Removes every id from the [`Container`] whose owning process is no longer alive and returns
+/// them.
+///
+/// # Safety
+///
+///  * the caller must hold the lock acquired with [`DynamicConfig::try_acquire_cleanup_lock()`]
+///
+pub(crate) unsafe fn remove_dead_ids<T: Copy + Debug>(
+    container: &Container<T>,
+    is_alive: fn(&T) -> bool,
+) -> Vec<T> {
+    let mut dead_ids = vec![];
+    container.get_state().for_each(|index, id| {
+        if !is_alive(id) {
+            dead_ids.push((index, *id));
+        }
+    });
+
+    dead_ids
+        .into_iter()
+        .map(|(index, id)| {
+            container.remove_raw_index(index);
+            id
+        })
+        .collect()
+}
+
+/// Returns all ids that are currently stored in the [`Container`].
+pub(crate) fn list_ids<T: Copy + Debug>(container: &Container<T>) -> Vec<T> {
+    let mut ids = vec![];
+    unsafe { container.get_state() }.for_each(|_, id| ids.push(*id));
+    ids
 }
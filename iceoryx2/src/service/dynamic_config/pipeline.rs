@@ -0,0 +1,90 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! println!("number of active producers:       {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active consumers:       {:?}", pipeline.dynamic_config().number_of_consumers());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::port::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_producers: usize,
+    pub number_of_consumers: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Pipeline`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) producers: Container<UniqueProducerId>,
+    pub(crate) consumers: Container<UniqueConsumerId>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            producers: unsafe { Container::new_uninit(config.number_of_producers) },
+            consumers: unsafe { Container::new_uninit(config.number_of_consumers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.producers.init(allocator),
+            "This should never happen! Unable to initialize producer port id container.");
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.consumers.init(allocator),
+            "This should never happen! Unable to initialize consumer port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<UniqueProducerId>::memory_size(config.number_of_producers)
+            + Container::<UniqueConsumerId>::memory_size(config.number_of_consumers)
+    }
+
+    /// Returns how many [`crate::port::producer::Producer`] ports are currently connected.
+    pub fn number_of_producers(&self) -> usize {
+        self.producers.len()
+    }
+
+    /// Returns how many [`crate::port::consumer::Consumer`] ports are currently connected.
+    pub fn number_of_consumers(&self) -> usize {
+        self.consumers.len()
+    }
+
+    pub(crate) fn add_producer_id(&self, id: UniqueProducerId) -> Option<UniqueIndex> {
+        unsafe { self.producers.add(id) }
+    }
+
+    pub(crate) fn add_consumer_id(&self, id: UniqueConsumerId) -> Option<UniqueIndex> {
+        unsafe { self.consumers.add(id) }
+    }
+}
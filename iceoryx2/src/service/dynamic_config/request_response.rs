@@ -0,0 +1,90 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! println!("number of active clients:         {:?}", reqres.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", reqres.dynamic_config().number_of_servers());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_clients: usize,
+    pub number_of_servers: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::RequestResponse`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) clients: Container<UniqueClientId>,
+    pub(crate) servers: Container<UniqueServerId>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            clients: unsafe { Container::new_uninit(config.number_of_clients) },
+            servers: unsafe { Container::new_uninit(config.number_of_servers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "request_response::DynamicConfig::init",
+            when self.clients.init(allocator),
+            "This should never happen! Unable to initialize client port id container.");
+        fatal_panic!(from "request_response::DynamicConfig::init",
+            when self.servers.init(allocator),
+            "This should never happen! Unable to initialize server port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<UniqueClientId>::memory_size(config.number_of_clients)
+            + Container::<UniqueServerId>::memory_size(config.number_of_servers)
+    }
+
+    /// Returns how many [`crate::port::client::Client`] ports are currently connected.
+    pub fn number_of_clients(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns how many [`crate::port::server::Server`] ports are currently connected.
+    pub fn number_of_servers(&self) -> usize {
+        self.servers.len()
+    }
+
+    pub(crate) fn add_client_id(&self, id: UniqueClientId) -> Option<UniqueIndex> {
+        unsafe { self.clients.add(id) }
+    }
+
+    pub(crate) fn add_server_id(&self, id: UniqueServerId) -> Option<UniqueIndex> {
+        unsafe { self.servers.add(id) }
+    }
+}
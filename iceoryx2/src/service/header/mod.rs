@@ -10,6 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// Message header used by
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
 /// Message header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
+
+/// Message headers used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
@@ -26,6 +26,7 @@
 //!
 //! while let Some(sample) = subscriber.receive()? {
 //!     println!("header: {:?}", sample.header());
+//!     println!("sequence number: {}", sample.header().sequence_number());
 //! }
 //! # Ok(())
 //! # }
@@ -48,10 +49,17 @@ struct TimeStamp {
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     time_stamp: TimeStamp,
+    number_of_elements: u64,
+    sequence_number: u64,
+    priority: u8,
 }
 
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId) -> Self {
+    pub(crate) fn new(
+        publisher_port_id: UniquePublisherId,
+        number_of_elements: u64,
+        priority: u8,
+    ) -> Self {
         let now = Time::now().unwrap();
         Self {
             publisher_port_id,
@@ -59,9 +67,23 @@ impl Header {
                 seconds: now.seconds(),
                 nanoseconds: now.nanoseconds(),
             },
+            number_of_elements,
+            sequence_number: 0,
+            priority,
         }
     }
 
+    /// Assigns the sequence number and updates the time stamp right before the sample is
+    /// delivered.
+    pub(crate) fn stamp(&mut self, sequence_number: u64) {
+        let now = Time::now().unwrap();
+        self.time_stamp = TimeStamp {
+            seconds: now.seconds(),
+            nanoseconds: now.nanoseconds(),
+        };
+        self.sequence_number = sequence_number;
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
@@ -74,4 +96,25 @@ impl Header {
             .seconds(self.time_stamp.seconds)
             .create()
     }
+
+    /// Returns the number of elements of the payload. For slice payloads it is the length of
+    /// the slice, for all other payloads it is always 1.
+    pub fn number_of_elements(&self) -> u64 {
+        self.number_of_elements
+    }
+
+    /// Returns the sequence number of the [`crate::sample::Sample`]. Every
+    /// [`crate::port::publisher::Publisher`] numbers the samples it sends consecutively,
+    /// starting with 0. A gap between two samples of the same publisher means that the
+    /// samples in between were lost, see
+    /// [`crate::port::subscriber::Subscriber::lost_samples_since_last_receive()`].
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the priority of the source [`crate::port::publisher::Publisher`], see
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::priority()`].
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
 }
@@ -0,0 +1,101 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("header: {:?}", request.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
+/// Message header of a request sent by a [`crate::port::client::Client`] in a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based communication.
+#[derive(Debug)]
+#[repr(C)]
+pub struct RequestHeader {
+    client_port_id: UniqueClientId,
+    request_id: u64,
+}
+
+impl RequestHeader {
+    pub(crate) fn new(client_port_id: UniqueClientId, request_id: u64) -> Self {
+        Self {
+            client_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueClientId`] of the source [`crate::port::client::Client`].
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request. It is unique for every request of a
+    /// [`crate::port::client::Client`] and is stored in the header of every response
+    /// to this request.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
+
+/// Message header of a response sent by a [`crate::port::server::Server`] in a
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based communication.
+#[derive(Debug)]
+#[repr(C)]
+pub struct ResponseHeader {
+    server_port_id: UniqueServerId,
+    client_port_id: UniqueClientId,
+    request_id: u64,
+}
+
+impl ResponseHeader {
+    pub(crate) fn new(server_port_id: UniqueServerId, request: &RequestHeader) -> Self {
+        Self {
+            server_port_id,
+            client_port_id: request.client_port_id,
+            request_id: request.request_id,
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the source [`crate::port::server::Server`].
+    pub fn server_id(&self) -> UniqueServerId {
+        self.server_port_id
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`crate::port::client::Client`] that sent the
+    /// corresponding request.
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request this response belongs to.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
@@ -31,8 +31,38 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Request-Response
+//!
+//! See the
+//! [Wikipedia Article: Request-response](https://en.wikipedia.org/wiki/Request%E2%80%93response).
+//! It uses bi-directional communication where `n`
+//! [`Client`](crate::port::client::Client)s send requests to `m`
+//! [`Server`](crate::port::server::Server)s. Every response is delivered only to the
+//! [`Client`](crate::port::client::Client) that sent the corresponding request.
+//!
+//! ### Blackboard
+//!
+//! Provides a fixed set of typed entries which are identified by a key. A single
+//! [`Writer`](crate::port::writer::Writer) updates the entries in place and `n`
+//! [`Reader`](crate::port::reader::Reader)s read the latest value of an entry at any time.
+//! There is no queue involved, a [`Reader`](crate::port::reader::Reader) always acquires a
+//! consistent copy of the most recent value.
+//!
+//! ### Pipeline
+//!
+//! Distributes work among a pool of workers. `n`
+//! [`Producer`](crate::port::producer::Producer)s send samples to `m`
+//! [`Consumer`](crate::port::consumer::Consumer)s, but in contrast to publish-subscribe every
+//! sample is delivered to exactly one [`Consumer`](crate::port::consumer::Consumer). The
+//! [`Consumer`](crate::port::consumer::Consumer)s can join and leave at any time, samples that
+//! a leaving [`Consumer`](crate::port::consumer::Consumer) has not received are handed over to
+//! the remaining ones.
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
+use crate::service::static_config::pipeline;
 use crate::service::static_config::publish_subscribe;
+use crate::service::static_config::request_response;
 use serde::{Deserialize, Serialize};
 
 /// Contains the static config of the corresponding messaging pattern.
@@ -42,6 +72,9 @@ use serde::{Deserialize, Serialize};
 pub enum MessagingPattern {
     PublishSubscribe(publish_subscribe::StaticConfig),
     Event(event::StaticConfig),
+    RequestResponse(request_response::StaticConfig),
+    Blackboard(blackboard::StaticConfig),
+    Pipeline(pipeline::StaticConfig),
 }
 
 impl MessagingPattern {
@@ -53,6 +86,15 @@ impl MessagingPattern {
             MessagingPattern::Event(_) => {
                 matches!(rhs, MessagingPattern::Event(_))
             }
+            MessagingPattern::RequestResponse(_) => {
+                matches!(rhs, MessagingPattern::RequestResponse(_))
+            }
+            MessagingPattern::Blackboard(_) => {
+                matches!(rhs, MessagingPattern::Blackboard(_))
+            }
+            MessagingPattern::Pipeline(_) => {
+                matches!(rhs, MessagingPattern::Pipeline(_))
+            }
         }
     }
 
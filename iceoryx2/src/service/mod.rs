@@ -37,6 +37,35 @@
 //! # }
 //! ```
 //!
+//! ## Publish-Subscribe With Slices
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/PointCloud")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     // a sample can contain up to 1024 elements
+//!     .max_slice_len(1024)
+//!     .open_or_create::<[f32]>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! let sample = publisher.loan_uninit_slice(3)?;
+//! let sample = sample.write_from_fn(|i| i as f32 * 0.5);
+//! publisher.send(sample)?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received {} elements: {:?}", sample.len(), sample.payload());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Event
 //!
 //! ```
@@ -58,6 +87,52 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // define the messaging pattern
+//!     .request_response()
+//!     // various QoS
+//!     .max_clients(4)
+//!     .max_servers(2)
+//!     .server_max_buffer_size(3)
+//!     .client_max_buffer_size(3)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // define the messaging pattern
+//!     .blackboard::<u64>()
+//!     // various QoS
+//!     .max_readers(4)
+//!     // the entries of the blackboard
+//!     .add(0, 0)
+//!     .add(1, 0)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Publish-Subscribe With Custom Configuration
 //!
 //! ```
@@ -135,18 +210,37 @@ pub mod process_local;
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod zero_copy;
 
+/// A configuration when communicating between different processes that stores all shared
+/// resources in a few shared memory directories, see [`zero_copy_directory::Service`].
+pub mod zero_copy_directory;
+
+/// Detects created and removed [`Service`]s without polling [`Details::list()`]
+pub mod discovery;
+
+/// Restricts which users and groups can access a [`Service`] and create its ports
+pub mod access_control;
+
+/// User defined key-value pairs that describe a [`Service`]
+pub mod attribute;
+
 pub(crate) mod config_scheme;
+pub(crate) mod dead_port_cleanup;
 pub(crate) mod naming_scheme;
 
 use std::fmt::Debug;
 
 use crate::config;
+use crate::node::NodeId;
+use crate::port::copy_frame::CopyFrame;
 use crate::port::event_id::EventId;
+use crate::service::attribute::AttributeSet;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, trace, warn};
-use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::communication_channel::CommunicationChannel;
+use iceoryx2_cal::dynamic_storage::{DynamicStorage, DynamicStorageOpenError};
 use iceoryx2_cal::event::Event;
 use iceoryx2_cal::hash::Hash;
 use iceoryx2_cal::named_concept::NamedConceptListError;
@@ -158,7 +252,8 @@ use iceoryx2_cal::static_storage::*;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 
 use self::builder::Builder;
-use self::dynamic_config::DecrementReferenceCounterResult;
+use self::discovery::RemovalNotifier;
+use self::dynamic_config::{DecrementReferenceCounterResult, NumberOfPorts};
 use self::service_name::ServiceName;
 
 /// Failure that can be reported by [`Details::does_exist()`] or
@@ -193,6 +288,23 @@ impl std::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
+/// Failure that can be reported by [`Details::number_of_ports()`] or
+/// [`Details::number_of_ports_with_custom_config()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceNumberOfPortsError {
+    DoesNotExist,
+    NotYetInitialized,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceNumberOfPortsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceNumberOfPortsError {}
+
 /// Represents the [`Service`]s state.
 #[derive(Debug)]
 pub struct ServiceState<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> {
@@ -200,26 +312,40 @@ pub struct ServiceState<'config, Static: StaticStorage, Dynamic: DynamicStorage<
     pub(crate) global_config: &'config config::Config,
     pub(crate) dynamic_storage: Dynamic,
     pub(crate) static_storage: Static,
+    pub(crate) node_id: Option<NodeId>,
+    // must be the last member since it notifies after the storages were removed
+    removal_notifier: RemovalNotifier<'config>,
 }
 
 impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>>
     ServiceState<'config, Static, Dynamic>
 {
-    pub(crate) fn new(
+    pub(crate) fn new<
+        ServiceType: Details<'config, StaticStorage = Static, DynamicStorage = Dynamic>,
+    >(
         static_config: StaticConfig,
         global_config: &'config config::Config,
         dynamic_storage: Dynamic,
         static_storage: Static,
+        node_id: Option<NodeId>,
     ) -> Self {
         let new_self = Self {
             static_config,
             global_config,
             dynamic_storage,
             static_storage,
+            node_id,
+            removal_notifier: RemovalNotifier::new::<ServiceType>(global_config),
         };
         trace!(from new_self, "open service");
         new_self
     }
+
+    /// Returns the [`config::Defaults`] of the service, see [`config::Config::defaults_for()`]
+    pub(crate) fn defaults(&self) -> &config::Defaults {
+        self.global_config
+            .defaults_for(self.static_config.service_name())
+    }
 }
 
 impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Drop
@@ -233,18 +359,69 @@ impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Dro
             DecrementReferenceCounterResult::NoMoreOwners => {
                 self.static_storage.acquire_ownership();
                 self.dynamic_storage.acquire_ownership();
+                self.removal_notifier.notify_on_drop();
                 trace!(from self, "close and remove service");
             }
         }
     }
 }
 
+/// Reads the [`StaticConfig`] of the service that is stored under the given name. Returns
+/// [`None`] when the static service information is not readable or inconsistent.
+pub(crate) fn read_static_config<'config, Service: Details<'config>>(
+    service_storage: &FileName,
+    static_storage_config: &<Service::StaticStorage as NamedConceptMgmt>::Configuration,
+    config: &config::Config,
+) -> Option<StaticConfig> {
+    let origin = "read_static_config()";
+    let reader = match <<Service::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
+        Service::StaticStorage,
+    >>::new(service_storage)
+    .config(static_storage_config)
+    .has_ownership(false)
+    .open()
+    {
+        Ok(reader) => reader,
+        Err(e) => {
+            warn!(from origin, "Unable to open the static service info \"{}\" for reading ({:?}).",
+                service_storage, e);
+            return None;
+        }
+    };
+
+    let mut content = String::from_utf8(vec![b' '; reader.len() as usize]).unwrap();
+    if let Err(e) = reader.read(unsafe { content.as_mut_vec().as_mut_slice() }) {
+        warn!(from origin, "Unable to read the static service info \"{}\" ({:?}).",
+            service_storage, e);
+        return None;
+    }
+
+    let service_config = match Service::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
+        content.as_mut_vec()
+    }) {
+        Ok(service_config) => service_config,
+        Err(e) => {
+            warn!(from origin, "Unable to deserialize the static service info \"{}\" ({:?}).",
+                    service_storage, e);
+            return None;
+        }
+    };
+
+    if service_storage.as_bytes() != service_config.uuid().as_bytes() {
+        warn!(from origin, "Detected service {:?} with an inconsistent hash of {} when acquiring services according to config {:?}",
+            service_config, service_storage, config);
+        return None;
+    }
+
+    Some(service_config)
+}
+
 /// Represents a service. Used to create or open new services with the [`Builder`].
 pub trait Service: Sized {
     type Type<'a>: Details<'a>;
 
     /// Creates a new [`Builder`] for a given service name
-    fn new(name: &ServiceName) -> Builder<Self> {
+    fn new(name: &ServiceName) -> Builder<'static, Self> {
         Builder::new(name)
     }
 }
@@ -275,6 +452,10 @@ pub trait Details<'config>: Debug + Sized {
     /// The mechanism used to signal events between endpoints.
     type Event: Event<EventId>;
 
+    /// The channel used to transfer copies of the payload to a subscriber when the service
+    /// uses copy transfer instead of sharing the publishers data segment.
+    type CopyChannel: CommunicationChannel<CopyFrame>;
+
     #[doc(hidden)]
     fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self;
 
@@ -433,49 +614,116 @@ pub trait Details<'config>: Debug + Sized {
 
         let mut service_vec = vec![];
         for service_storage in services {
-            let reader =
-                match <<Self::StaticStorage as StaticStorage>::Builder as NamedConceptBuilder<
-                    Self::StaticStorage,
-                >>::new(&service_storage)
-                .config(&static_storage_config.clone())
-                .has_ownership(false)
-                .open()
-                {
-                    Ok(reader) => reader,
-                    Err(e) => {
-                        warn!(from origin, "Unable to acquire a list of all service since the static service info \"{}\" could not be opened for reading ({:?}).",
-                           service_storage, e );
-                        continue;
-                    }
-                };
-
-            let mut content = String::from_utf8(vec![b' '; reader.len() as usize]).unwrap();
-            if let Err(e) = reader.read(unsafe { content.as_mut_vec().as_mut_slice() }) {
-                warn!(from origin, "Unable to acquire a list of all service since the static service info \"{}\" could not be read ({:?}).",
-                           service_storage, e );
-                continue;
+            if let Some(service_config) =
+                read_static_config::<Self>(&service_storage, &static_storage_config, config)
+            {
+                service_vec.push(service_config);
             }
+        }
 
-            let service_config = match Self::ConfigSerializer::deserialize::<StaticConfig>(unsafe {
-                content.as_mut_vec()
-            }) {
-                Ok(service_config) => service_config,
-                Err(e) => {
-                    warn!(from origin, "Unable to acquire a list of all service since the static service info \"{}\" could not be deserialized ({:?}).",
-                       service_storage, e );
-                    continue;
-                }
-            };
+        Ok(service_vec)
+    }
 
-            if service_storage.as_bytes() != service_config.uuid().as_bytes() {
-                warn!(from origin, "Detected service {:?} with an inconsistent hash of {} when acquiring services according to config {:?}",
-                    service_config, service_storage, config);
-                continue;
-            }
+    /// Returns a list of all created services in the system that define every
+    /// [`crate::service::attribute::Attribute`] of `required` with the same value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::attribute::AttributeSet;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let required = AttributeSet::new().define("coordinate_frame", "vehicle");
+    /// let services = zero_copy::Service::list_with_attributes(&required)?;
+    ///
+    /// for service in services {
+    ///     println!("\n{:#?}", &service);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_with_attributes(
+        required: &AttributeSet,
+    ) -> Result<Vec<StaticConfig>, ServiceListError> {
+        Self::list_with_attributes_and_custom_config(required, config::Config::get_global_config())
+    }
 
-            service_vec.push(service_config);
-        }
+    /// Returns a list of all services created under a given [`config::Config`] that define
+    /// every [`crate::service::attribute::Attribute`] of `required` with the same value.
+    fn list_with_attributes_and_custom_config(
+        required: &AttributeSet,
+        config: &'config config::Config,
+    ) -> Result<Vec<StaticConfig>, ServiceListError> {
+        let mut services = Self::list_with_custom_config(config)?;
+        services.retain(|service| service.attributes().satisfies(required));
+        Ok(services)
+    }
 
-        Ok(service_vec)
+    /// Returns the number of ports that are currently connected to a service. The
+    /// [`StaticConfig`] is usually acquired via [`Details::list()`]. In contrast to opening the
+    /// service, the service is not kept alive while its ports are counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// for service in zero_copy::Service::list()? {
+    ///     let number_of_ports = zero_copy::Service::number_of_ports(&service)?;
+    ///     println!("{}: {:?}", service.service_name(), number_of_ports);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn number_of_ports(
+        static_config: &StaticConfig,
+    ) -> Result<NumberOfPorts, ServiceNumberOfPortsError> {
+        Self::number_of_ports_with_custom_config(static_config, config::Config::get_global_config())
+    }
+
+    /// Returns the number of ports that are currently connected to a service that was created
+    /// under a given [`config::Config`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut custom_config = Config::default();
+    /// for service in zero_copy::Service::list_with_custom_config(&custom_config)? {
+    ///     let number_of_ports =
+    ///         zero_copy::Service::number_of_ports_with_custom_config(&service, &custom_config)?;
+    ///     println!("{}: {:?}", service.service_name(), number_of_ports);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn number_of_ports_with_custom_config(
+        static_config: &StaticConfig,
+        config: &'config config::Config,
+    ) -> Result<NumberOfPorts, ServiceNumberOfPortsError> {
+        let msg = format!(
+            "Unable to acquire the number of ports of the service \"{}\"",
+            static_config.service_name()
+        );
+        let origin = "Service::number_of_ports_with_custom_config()";
+
+        let dynamic_storage = fail!(from origin, when
+                <<Self::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder as NamedConceptBuilder<
+                    Self::DynamicStorage,
+                >>::new(&naming_scheme::dynamic_config_storage_name(static_config))
+                .config(&config_scheme::dynamic_config_storage_config::<Self>(config))
+                .has_ownership(false)
+                .open(),
+                map DynamicStorageOpenError::DoesNotExist => ServiceNumberOfPortsError::DoesNotExist;
+                    DynamicStorageOpenError::InitializationNotYetFinalized => ServiceNumberOfPortsError::NotYetInitialized,
+                unmatched ServiceNumberOfPortsError::InternalError,
+                "{} since the dynamic service information could not be opened.", msg);
+
+        Ok(dynamic_storage.get().number_of_ports())
     }
 }
@@ -10,20 +10,26 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::node::NodeId;
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueConsumerId, UniqueListenerId, UniqueProducerId, UniquePublisherId,
+    UniqueServerId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 
 use super::static_config::StaticConfig;
 
 pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
     let msg = "The system does not support the required file name length for the listeners event concept name.";
-    let origin = "event_concept_name()";
-    let mut file = fatal_panic!(from origin, when FileName::new(listener_id.0.pid().to_string().as_bytes()), "{}", msg);
-    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(listener_id.0.value().to_string().as_bytes()), "{}", msg);
-    file
+    event_concept_name_impl("event_concept_name()", msg, &listener_id.0)
+}
+
+pub(crate) fn subscriber_event_concept_name(subscriber_id: &UniqueSubscriberId) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers event concept name.";
+    event_concept_name_impl("subscriber_event_concept_name()", msg, &subscriber_id.0)
 }
 
 pub(crate) fn dynamic_config_storage_name(static_config: &StaticConfig) -> FileName {
@@ -38,19 +44,109 @@ pub(crate) fn connection_name(
     publisher_id: UniquePublisherId,
     subscriber_id: UniqueSubscriberId,
 ) -> FileName {
-    let mut file = FileName::new(publisher_id.0.value().to_string().as_bytes()).unwrap();
+    connection_name_impl(&publisher_id.0, &subscriber_id.0)
+}
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    connection_name_impl(&client_id.0, &server_id.0)
+}
+
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    connection_name_impl(&server_id.0, &client_id.0)
+}
+
+pub(crate) fn pipeline_connection_name(
+    producer_id: UniqueProducerId,
+    consumer_id: UniqueConsumerId,
+) -> FileName {
+    connection_name_impl(&producer_id.0, &consumer_id.0)
+}
+
+pub(crate) fn copy_channel_name(subscriber_id: UniqueSubscriberId) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers copy channel.";
+    data_segment_name_impl("copy_channel_name()", msg, &subscriber_id.0)
+}
+
+pub(crate) fn data_segment_name(publisher_id: UniquePublisherId) -> FileName {
+    let msg = "The system does not support the required file name length for the publishers data segment.";
+    data_segment_name_impl("data_segment_name()", msg, &publisher_id.0)
+}
+
+pub(crate) fn client_data_segment_name(client_id: UniqueClientId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the clients data segment.";
+    data_segment_name_impl("client_data_segment_name()", msg, &client_id.0)
+}
+
+pub(crate) fn server_data_segment_name(server_id: UniqueServerId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the servers data segment.";
+    data_segment_name_impl("server_data_segment_name()", msg, &server_id.0)
+}
+
+pub(crate) fn producer_data_segment_name(producer_id: UniqueProducerId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the producers data segment.";
+    data_segment_name_impl("producer_data_segment_name()", msg, &producer_id.0)
+}
+
+pub(crate) fn node_details_storage_name(node_id: &NodeId) -> FileName {
+    let msg = "The system does not support the required file name length for the node details.";
+    fatal_panic!(from "node_details_storage_name()",
+        when FileName::new(node_id.value().to_string().as_bytes()), "{}", msg)
+}
+
+pub(crate) fn node_port_tag_name(node_id: &NodeId, port_id: u128) -> FileName {
+    let origin = "node_port_tag_name()";
+    let msg = "The system does not support the required file name length for the node port tag.";
+    let mut file = node_details_storage_name(node_id);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.to_string().as_bytes()), "{}", msg);
+    file
+}
+
+/// Extracts the node id and the port id from a name created with [`node_port_tag_name()`].
+pub(crate) fn extract_ids_from_node_port_tag_name(name: &FileName) -> Option<(u128, u128)> {
+    let name = std::str::from_utf8(name.as_bytes()).ok()?;
+    let (node_id, port_id) = name.split_once('_')?;
+    Some((node_id.parse().ok()?, port_id.parse().ok()?))
+}
+
+pub(crate) fn discovery_listener_name(listener_id: &UniqueSystemId) -> FileName {
+    let msg = "The system does not support the required file name length for the service discovery listener.";
+    fatal_panic!(from "discovery_listener_name()",
+        when FileName::new(listener_id.value().to_string().as_bytes()), "{}", msg)
+}
+
+/// Extracts the listener id from a name created with [`discovery_listener_name()`].
+pub(crate) fn extract_id_from_discovery_listener_name(name: &FileName) -> Option<u128> {
+    std::str::from_utf8(name.as_bytes()).ok()?.parse().ok()
+}
+
+fn connection_name_impl(sender_id: &UniqueSystemId, receiver_id: &UniqueSystemId) -> FileName {
+    let mut file = FileName::new(sender_id.value().to_string().as_bytes()).unwrap();
     file.push(b'_').unwrap();
-    file.push_bytes(subscriber_id.0.value().to_string().as_bytes())
+    file.push_bytes(receiver_id.value().to_string().as_bytes())
         .unwrap();
     file
 }
 
-pub(crate) fn data_segment_name(publisher_id: UniquePublisherId) -> FileName {
-    let msg = "The system does not support the required file name length for the publishers data segment.";
-    let origin = "data_segment_name()";
+fn data_segment_name_impl(origin: &str, msg: &str, port_id: &UniqueSystemId) -> FileName {
+    let mut file = fatal_panic!(from origin, when FileName::new(port_id.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.value().to_string().as_bytes()), "{}", msg);
+    file
+}
 
-    let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
+fn event_concept_name_impl(origin: &str, msg: &str, port_id: &UniqueSystemId) -> FileName {
+    let mut file = fatal_panic!(from origin, when FileName::new(port_id.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.value().to_string().as_bytes()), "{}", msg);
     file
 }
@@ -0,0 +1,165 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .add(1, 0)
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", blackboard.name());
+//! println!("uuid:                             {:?}", blackboard.uuid());
+//! println!("type name:                        {:?}", blackboard.static_config().type_name());
+//! println!("keys:                             {:?}", blackboard.static_config().keys());
+//! println!("max readers:                      {:?}", blackboard.static_config().max_supported_readers());
+//! println!("number of active readers:         {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:         {:?}", blackboard.dynamic_config().number_of_writers());
+//!
+//! let writer = blackboard.writer().create()?;
+//! let reader = blackboard.reader().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dead_port_cleanup, dynamic_config, static_config};
+
+use super::{reader::PortFactoryReader, writer::PortFactoryWriter};
+
+/// The factory for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::reader::Reader`]
+/// or [`crate::port::writer::Writer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<'config, Service: service::Details<'config>, ValueType: Copy + Debug> {
+    pub(crate) service: Service,
+    _phantom_value_type: PhantomData<ValueType>,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<'config, Service: service::Details<'config>, ValueType: Copy + Debug> Send
+    for PortFactory<'config, Service, ValueType>
+{
+}
+unsafe impl<'config, Service: service::Details<'config>, ValueType: Copy + Debug> Sync
+    for PortFactory<'config, Service, ValueType>
+{
+}
+
+impl<'config, Service: service::Details<'config>, ValueType: Copy + Debug>
+    PortFactory<'config, Service, ValueType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_value_type: PhantomData,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined when
+    /// it was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::blackboard::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::blackboard::StaticConfig {
+        self.service.state().static_config.blackboard()
+    }
+
+    /// Returns the [`dynamic_config::blackboard::DynamicConfig`] of the [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::blackboard::DynamicConfig {
+        self.service.state().dynamic_storage.get().blackboard()
+    }
+
+    /// Removes all ports of the [`crate::service::Service`] whose owning process died, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::cleanup_dead_ports()`].
+    /// Returns the number of removed ports.
+    pub fn cleanup_dead_ports(&self) -> usize {
+        dead_port_cleanup::cleanup_dead_ports(&self.service)
+    }
+
+    /// Returns a [`PortFactoryReader`] to create a new
+    /// [`crate::port::reader::Reader`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let blackboard = zero_copy::Service::new(&service_name)
+    ///     .blackboard::<u64>()
+    ///     .add(0, 0)
+    ///     .open_or_create()?;
+    ///
+    /// let reader = blackboard.reader().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader<'a>(&'a self) -> PortFactoryReader<'a, 'config, Service, ValueType> {
+        PortFactoryReader::new(self)
+    }
+
+    /// Returns a [`PortFactoryWriter`] to create a new
+    /// [`crate::port::writer::Writer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let blackboard = zero_copy::Service::new(&service_name)
+    ///     .blackboard::<u64>()
+    ///     .add(0, 0)
+    ///     .open_or_create()?;
+    ///
+    /// let writer = blackboard.writer().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer<'a>(&'a self) -> PortFactoryWriter<'a, 'config, Service, ValueType> {
+        PortFactoryWriter::new(self)
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let consumer = pipeline.consumer().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::consumer::{Consumer, ConsumerCreateError},
+    service,
+};
+
+use super::pipeline::PortFactory;
+
+/// Factory to create a new [`Consumer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryConsumer<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    MessageType: Debug,
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+    PortFactoryConsumer<'factory, 'config, Service, MessageType>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
+        Self { factory }
+    }
+
+    /// Creates a new [`Consumer`] or returns a [`ConsumerCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Consumer<'factory, 'config, Service, MessageType>, ConsumerCreateError> {
+        Ok(
+            fail!(from self, when Consumer::new(&self.factory.service, self.factory.service.state().static_config.pipeline()),
+                "Failed to create new Consumer port."),
+        )
+    }
+}
@@ -35,7 +35,9 @@
 //! ```
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
-use crate::service::{self, static_config};
+use crate::service::access_control::AccessControl;
+use crate::service::attribute::AttributeSet;
+use crate::service::{self, dead_port_cleanup, static_config};
 use crate::service::{dynamic_config, ServiceName};
 use std::marker::PhantomData;
 
@@ -73,6 +75,18 @@ impl<'config, Service: service::Details<'config>> PortFactory<'config, Service>
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined when
+    /// it was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`AccessControl`] of the [`crate::service::Service`]. It defines which users
+    /// and groups are permitted to create which ports.
+    pub fn access_control(&self) -> &AccessControl {
+        self.service.state().static_config.access_control()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::event::StaticConfig {
@@ -85,6 +99,13 @@ impl<'config, Service: service::Details<'config>> PortFactory<'config, Service>
         self.service.state().dynamic_storage.get().event()
     }
 
+    /// Removes all ports of the [`crate::service::Service`] whose owning process died, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::cleanup_dead_ports()`].
+    /// Returns the number of removed ports.
+    pub fn cleanup_dead_ports(&self) -> usize {
+        dead_port_cleanup::cleanup_dead_ports(&self.service)
+    }
+
     /// Returns a [`PortFactoryNotifier`] to create a new [`crate::port::notifier::Notifier`] port
     ///
     /// # Example
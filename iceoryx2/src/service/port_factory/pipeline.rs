@@ -0,0 +1,165 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! println!("name:                             {:?}", pipeline.name());
+//! println!("uuid:                             {:?}", pipeline.uuid());
+//! println!("type name:                        {:?}", pipeline.static_config().type_name());
+//! println!("max producers:                    {:?}", pipeline.static_config().max_supported_producers());
+//! println!("max consumers:                    {:?}", pipeline.static_config().max_supported_consumers());
+//! println!("number of active producers:       {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active consumers:       {:?}", pipeline.dynamic_config().number_of_consumers());
+//!
+//! let producer = pipeline.producer().create()?;
+//! let consumer = pipeline.consumer().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dead_port_cleanup, dynamic_config, static_config};
+
+use super::{consumer::PortFactoryConsumer, producer::PortFactoryProducer};
+
+/// The factory for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::producer::Producer`]
+/// or [`crate::port::consumer::Consumer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<'config, Service: service::Details<'config>, MessageType: Debug> {
+    pub(crate) service: Service,
+    _phantom_message_type: PhantomData<MessageType>,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Send
+    for PortFactory<'config, Service, MessageType>
+{
+}
+unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Sync
+    for PortFactory<'config, Service, MessageType>
+{
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug>
+    PortFactory<'config, Service, MessageType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_message_type: PhantomData,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined when
+    /// it was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::pipeline::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::pipeline::StaticConfig {
+        self.service.state().static_config.pipeline()
+    }
+
+    /// Returns the [`dynamic_config::pipeline::DynamicConfig`] of the [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::pipeline::DynamicConfig {
+        self.service.state().dynamic_storage.get().pipeline()
+    }
+
+    /// Removes all ports of the [`crate::service::Service`] whose owning process died, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::cleanup_dead_ports()`].
+    /// Returns the number of removed ports.
+    pub fn cleanup_dead_ports(&self) -> usize {
+        dead_port_cleanup::cleanup_dead_ports(&self.service)
+    }
+
+    /// Returns a [`PortFactoryProducer`] to create a new
+    /// [`crate::port::producer::Producer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::producer::DistributionStrategy;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let pipeline = zero_copy::Service::new(&service_name)
+    ///     .pipeline()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let producer = pipeline.producer()
+    ///                        .max_loaned_samples(3)
+    ///                        .distribution_strategy(DistributionStrategy::FirstFree)
+    ///                        .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn producer<'a>(&'a self) -> PortFactoryProducer<'a, 'config, Service, MessageType> {
+        PortFactoryProducer::new(self)
+    }
+
+    /// Returns a [`PortFactoryConsumer`] to create a new
+    /// [`crate::port::consumer::Consumer`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    /// let pipeline = zero_copy::Service::new(&service_name)
+    ///     .pipeline()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let consumer = pipeline.consumer().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn consumer<'a>(&'a self) -> PortFactoryConsumer<'a, 'config, Service, MessageType> {
+        PortFactoryConsumer::new(self)
+    }
+}
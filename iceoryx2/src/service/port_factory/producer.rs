@@ -0,0 +1,125 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::port_factory::producer::DistributionStrategy;
+//! use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let producer = pipeline.producer()
+//!                        .max_loaned_samples(6)
+//!                        .distribution_strategy(DistributionStrategy::RoundRobin)
+//!                        .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                        .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::producer::{Producer, ProducerCreateError},
+    service,
+};
+
+use super::{pipeline::PortFactory, publisher::UnableToDeliverStrategy};
+
+/// Defines how the [`Producer`] selects the [`crate::port::consumer::Consumer`] that
+/// receives the next sample.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum DistributionStrategy {
+    /// The [`crate::port::consumer::Consumer`]s receive the samples in turns. A
+    /// [`crate::port::consumer::Consumer`] whose buffer is full is skipped.
+    #[default]
+    RoundRobin,
+    /// Every sample is delivered to the first [`crate::port::consumer::Consumer`] whose
+    /// buffer is not full.
+    FirstFree,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalProducerConfig {
+    pub(crate) max_loaned_samples: usize,
+    pub(crate) distribution_strategy: DistributionStrategy,
+    pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+}
+
+/// Factory to create a new [`Producer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryProducer<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    MessageType: Debug,
+> {
+    config: LocalProducerConfig,
+    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+    PortFactoryProducer<'factory, 'config, Service, MessageType>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
+        let defaults = &factory.service.state().defaults().pipeline;
+
+        Self {
+            config: LocalProducerConfig {
+                max_loaned_samples: defaults.producer_max_loaned_samples,
+                distribution_strategy: DistributionStrategy::default(),
+                unable_to_deliver_strategy: defaults.unable_to_deliver_strategy,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::work_item_mut::WorkItemMut`] the [`Producer`] can loan with
+    /// [`Producer::loan()`] or [`Producer::loan_uninit()`] in parallel.
+    pub fn max_loaned_samples(mut self, value: usize) -> Self {
+        self.config.max_loaned_samples = value;
+        self
+    }
+
+    /// Sets the [`DistributionStrategy`] of the [`Producer`].
+    pub fn distribution_strategy(mut self, value: DistributionStrategy) -> Self {
+        self.config.distribution_strategy = value;
+        self
+    }
+
+    /// Sets the [`UnableToDeliverStrategy`] that is pursued when the buffers of all
+    /// [`crate::port::consumer::Consumer`]s are full.
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.config.unable_to_deliver_strategy = value;
+        self
+    }
+
+    /// Creates a new [`Producer`] or returns a [`ProducerCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Producer<'factory, 'config, Service, MessageType>, ProducerCreateError> {
+        Ok(
+            fail!(from self, when Producer::new(&self.factory.service, self.factory.service.state().static_config.pipeline(), &self.config),
+                "Failed to create new Producer port."),
+        )
+    }
+}
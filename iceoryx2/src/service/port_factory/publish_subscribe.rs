@@ -44,8 +44,11 @@ use std::{fmt::Debug, marker::PhantomData};
 
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
+use crate::payload_type::PayloadType;
+use crate::service::access_control::AccessControl;
+use crate::service::attribute::AttributeSet;
 use crate::service::service_name::ServiceName;
-use crate::service::{self, dynamic_config, static_config};
+use crate::service::{self, dead_port_cleanup, dynamic_config, static_config};
 
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
@@ -55,22 +58,26 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 /// [`crate::port::publisher::Publisher`]
 /// or [`crate::port::subscriber::Subscriber`] ports.
 #[derive(Debug)]
-pub struct PortFactory<'config, Service: service::Details<'config>, MessageType: Debug> {
+pub struct PortFactory<
+    'config,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+> {
     pub(crate) service: Service,
     _phantom_message_type: PhantomData<MessageType>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
 
-unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Send
+unsafe impl<'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized> Send
     for PortFactory<'config, Service, MessageType>
 {
 }
-unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Sync
+unsafe impl<'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized> Sync
     for PortFactory<'config, Service, MessageType>
 {
 }
 
-impl<'config, Service: service::Details<'config>, MessageType: Debug>
+impl<'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     PortFactory<'config, Service, MessageType>
 {
     pub(crate) fn new(service: Service) -> Self {
@@ -91,6 +98,18 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined when
+    /// it was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`AccessControl`] of the [`crate::service::Service`]. It defines which users
+    /// and groups are permitted to create which ports.
+    pub fn access_control(&self) -> &AccessControl {
+        self.service.state().static_config.access_control()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
@@ -107,6 +126,14 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
             .publish_subscribe()
     }
 
+    /// Removes all ports of the [`crate::service::Service`] whose owning process died, for
+    /// instance because it was killed. The stale resources of these ports, like data segments
+    /// and connections, are removed as well and the freed slots can be used by new ports.
+    /// Returns the number of removed ports.
+    pub fn cleanup_dead_ports(&self) -> usize {
+        dead_port_cleanup::cleanup_dead_ports(&self.service)
+    }
+
     /// Returns a [`PortFactorySubscriber`] to create a new
     /// [`crate::port::subscriber::Subscriber`] port.
     ///
@@ -127,7 +154,7 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
     /// # }
     /// ```
     pub fn subscriber<'a>(&'a self) -> PortFactorySubscriber<'a, 'config, Service, MessageType> {
-        PortFactorySubscriber { factory: self }
+        PortFactorySubscriber::new(self)
     }
 
     /// Returns a [`PortFactoryPublisher`] to create a new
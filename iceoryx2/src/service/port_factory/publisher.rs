@@ -36,6 +36,7 @@ use iceoryx2_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Serialize};
 
 use crate::{
+    payload_type::PayloadType,
     port::publisher::{Publisher, PublisherCreateError},
     service,
 };
@@ -100,6 +101,7 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+    pub(crate) priority: u8,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -110,13 +112,13 @@ pub struct PortFactoryPublisher<
     'factory,
     'config,
     Service: service::Details<'config>,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
     config: LocalPublisherConfig,
     pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     PortFactoryPublisher<'factory, 'config, Service, MessageType>
 {
     pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
@@ -125,17 +127,16 @@ impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
                 max_loaned_samples: factory
                     .service
                     .state()
-                    .global_config
-                    .defaults
+                    .defaults()
                     .publish_subscribe
                     .publisher_max_loaned_samples,
                 unable_to_deliver_strategy: factory
                     .service
                     .state()
-                    .global_config
-                    .defaults
+                    .defaults()
                     .publish_subscribe
                     .unable_to_deliver_strategy,
+                priority: 0,
             },
             factory,
         }
@@ -154,6 +155,15 @@ impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
         self
     }
 
+    /// Sets the priority of the [`Publisher`]. A
+    /// [`crate::port::subscriber::Subscriber`] that was created with
+    /// [`ReceiveOrder::Priority`](crate::service::port_factory::subscriber::ReceiveOrder::Priority)
+    /// receives the samples of publishers with a higher priority first. The default is 0.
+    pub fn priority(mut self, value: u8) -> Self {
+        self.config.priority = value;
+        self
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(
         self,
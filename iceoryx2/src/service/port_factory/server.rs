@@ -0,0 +1,103 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = reqres.server()
+//!                    .max_loaned_responses(6)
+//!                    .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::server::{Server, ServerCreateError},
+    service,
+};
+
+use super::request_response::PortFactory;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalServerConfig {
+    pub(crate) max_loaned_responses: usize,
+}
+
+/// Factory to create a new [`Server`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryServer<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    config: LocalServerConfig,
+    pub(crate) factory: &'factory PortFactory<'config, Service, RequestType, ResponseType>,
+}
+
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > PortFactoryServer<'factory, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<'config, Service, RequestType, ResponseType>,
+    ) -> Self {
+        Self {
+            config: LocalServerConfig {
+                max_loaned_responses: factory
+                    .service
+                    .state()
+                    .defaults()
+                    .request_response
+                    .server_max_loaned_responses,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::response_mut::ResponseMut`] the [`Server`] can loan with
+    /// [`Server::loan()`] or [`Server::loan_uninit()`] in parallel.
+    pub fn max_loaned_responses(mut self, value: usize) -> Self {
+        self.config.max_loaned_responses = value;
+        self
+    }
+
+    /// Creates a new [`Server`] or returns a [`ServerCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Server<'factory, 'config, Service, RequestType, ResponseType>, ServerCreateError>
+    {
+        Ok(
+            fail!(from self, when Server::new(&self.factory.service, self.factory.service.state().static_config.request_response(), &self.config),
+                "Failed to create new Server port."),
+        )
+    }
+}
@@ -14,7 +14,7 @@
 //!
 //! ```
 //! use iceoryx2::prelude::*;
-//! use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+//! use iceoryx2::service::port_factory::subscriber::ReceiveOrder;
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new("My/Funk/ServiceName")?;
@@ -23,6 +23,7 @@
 //!     .open_or_create::<u64>()?;
 //!
 //! let subscriber = pubsub.subscriber()
+//!                     .receive_order(ReceiveOrder::TimeStamp)
 //!                     .create()?;
 //!
 //! # Ok(())
@@ -34,12 +35,43 @@ use std::fmt::Debug;
 use iceoryx2_bb_log::fail;
 
 use crate::{
+    payload_type::PayloadType,
     port::subscriber::{Subscriber, SubscriberCreateError},
     service,
 };
 
 use super::publish_subscribe::PortFactory;
 
+/// Defines the order in which [`Subscriber::receive()`] returns the
+/// [`crate::sample::Sample`]s when multiple [`crate::port::publisher::Publisher`]s are
+/// connected. The samples of a single publisher are always received in the order they were sent.
+///
+/// To merge the publishers, [`ReceiveOrder::TimeStamp`] and [`ReceiveOrder::Priority`] keep
+/// the oldest sample of every publisher borrowed until it is received. It counts towards
+/// [`crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_borrowed_samples()`]
+/// of its publisher. A publisher whose samples are all borrowed is skipped until one of its
+/// samples is released, the samples of the other publishers are still received.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum ReceiveOrder {
+    /// The publishers are processed one after another, all available samples of one
+    /// publisher are received before the next publisher is processed. It has the lowest
+    /// overhead.
+    #[default]
+    ByPublisher,
+    /// The samples of all publishers are merged by their
+    /// [`Header::time_stamp()`](crate::service::header::publish_subscribe::Header::time_stamp()).
+    TimeStamp,
+    /// The samples of the publishers with the highest
+    /// [`Header::priority()`](crate::service::header::publish_subscribe::Header::priority())
+    /// are received first, samples with the same priority are merged by their time stamp.
+    Priority,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalSubscriberConfig {
+    pub(crate) receive_order: ReceiveOrder,
+}
+
 /// Factory to create a new [`Subscriber`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication.
@@ -48,20 +80,34 @@ pub struct PortFactorySubscriber<
     'factory,
     'config,
     Service: service::Details<'config>,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
+    config: LocalSubscriberConfig,
     pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     PortFactorySubscriber<'factory, 'config, Service, MessageType>
 {
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
+        Self {
+            config: LocalSubscriberConfig::default(),
+            factory,
+        }
+    }
+
+    /// Sets the [`ReceiveOrder`].
+    pub fn receive_order(mut self, value: ReceiveOrder) -> Self {
+        self.config.receive_order = value;
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         &self,
     ) -> Result<Subscriber<'factory, 'config, Service, MessageType>, SubscriberCreateError> {
         Ok(
-            fail!(from self, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe()),
+            fail!(from self, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &self.config),
                 "Failed to create new Subscriber port."),
         )
     }
@@ -0,0 +1,133 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let typed_service = zero_copy::Service::new(&service_name)
+//! #     .publish_subscribe()
+//! #     .open_or_create::<u64>()?;
+//! let pubsub = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_type_erased()?;
+//!
+//! println!("name:                             {:?}", pubsub.name());
+//! println!("type name:                        {:?}", pubsub.static_config().type_name());
+//! println!("payload size:                     {:?}", pubsub.static_config().payload_size());
+//! println!("payload alignment:                {:?}", pubsub.static_config().payload_alignment());
+//!
+//! let publisher = pubsub.publisher().create()?;
+//! let subscriber = pubsub.subscriber().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::access_control::AccessControl;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dead_port_cleanup, dynamic_config, static_config};
+
+use super::{
+    type_erased_publisher::PortFactoryTypeErasedPublisher,
+    type_erased_subscriber::PortFactoryTypeErasedSubscriber,
+};
+
+/// The factory for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// services whose payload type is not known at compile time. It can acquire dynamic and static
+/// service informations and create
+/// [`crate::port::type_erased_publisher::TypeErasedPublisher`]
+/// or [`crate::port::type_erased_subscriber::TypeErasedSubscriber`] ports.
+#[derive(Debug)]
+pub struct PortFactory<'config, Service: service::Details<'config>> {
+    pub(crate) service: Service,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<'config, Service: service::Details<'config>> Send for PortFactory<'config, Service> {}
+unsafe impl<'config, Service: service::Details<'config>> Sync for PortFactory<'config, Service> {}
+
+impl<'config, Service: service::Details<'config>> PortFactory<'config, Service> {
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`] that was defined when
+    /// it was created.
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`AccessControl`] of the [`crate::service::Service`]. It defines which users
+    /// and groups are permitted to create which ports.
+    pub fn access_control(&self) -> &AccessControl {
+        self.service.state().static_config.access_control()
+    }
+
+    /// Returns the [`static_config::publish_subscribe::StaticConfig`] of the
+    /// [`crate::service::Service`]. Contains all settings that never change during the lifetime
+    /// of the service, like the type name and the size of the payload.
+    pub fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.service.state().static_config.publish_subscribe()
+    }
+
+    /// Returns the [`dynamic_config::publish_subscribe::DynamicConfig`] of the
+    /// [`crate::service::Service`]. Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+    }
+
+    /// Removes all ports of the [`crate::service::Service`] whose owning process died, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::cleanup_dead_ports()`].
+    /// Returns the number of removed ports.
+    pub fn cleanup_dead_ports(&self) -> usize {
+        dead_port_cleanup::cleanup_dead_ports(&self.service)
+    }
+
+    /// Returns a [`PortFactoryTypeErasedSubscriber`] to create a new
+    /// [`crate::port::type_erased_subscriber::TypeErasedSubscriber`] port.
+    pub fn subscriber<'a>(&'a self) -> PortFactoryTypeErasedSubscriber<'a, 'config, Service> {
+        PortFactoryTypeErasedSubscriber::new(self)
+    }
+
+    /// Returns a [`PortFactoryTypeErasedPublisher`] to create a new
+    /// [`crate::port::type_erased_publisher::TypeErasedPublisher`] port.
+    pub fn publisher<'a>(&'a self) -> PortFactoryTypeErasedPublisher<'a, 'config, Service> {
+        PortFactoryTypeErasedPublisher::new(self)
+    }
+}
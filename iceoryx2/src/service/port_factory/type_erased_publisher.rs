@@ -0,0 +1,101 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let typed_service = zero_copy::Service::new(&service_name)
+//! #     .publish_subscribe()
+//! #     .open_or_create::<u64>()?;
+//! let pubsub = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_type_erased()?;
+//!
+//! let publisher = pubsub.publisher()
+//!                     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::{publisher::PublisherCreateError, type_erased_publisher::TypeErasedPublisher},
+    service,
+};
+
+use super::{
+    publisher::{LocalPublisherConfig, UnableToDeliverStrategy},
+    type_erased::PortFactory,
+};
+
+/// Factory to create a new [`TypeErasedPublisher`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryTypeErasedPublisher<'factory, 'config, Service: service::Details<'config>> {
+    config: LocalPublisherConfig,
+    pub(crate) factory: &'factory PortFactory<'config, Service>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>>
+    PortFactoryTypeErasedPublisher<'factory, 'config, Service>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service>) -> Self {
+        Self {
+            config: LocalPublisherConfig {
+                // the payload is copied into a sample that is sent right away
+                max_loaned_samples: 1,
+                unable_to_deliver_strategy: factory
+                    .service
+                    .state()
+                    .defaults()
+                    .publish_subscribe
+                    .unable_to_deliver_strategy,
+                priority: 0,
+            },
+            factory,
+        }
+    }
+
+    /// Sets the [`UnableToDeliverStrategy`].
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.config.unable_to_deliver_strategy = value;
+        self
+    }
+
+    /// Sets the priority of the [`TypeErasedPublisher`].
+    /// See [`crate::service::port_factory::publisher::PortFactoryPublisher::priority()`].
+    pub fn priority(mut self, value: u8) -> Self {
+        self.config.priority = value;
+        self
+    }
+
+    /// Creates a new [`TypeErasedPublisher`] or returns a [`PublisherCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<TypeErasedPublisher<'factory, 'config, Service>, PublisherCreateError> {
+        Ok(
+            fail!(from self, when TypeErasedPublisher::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &self.config),
+                "Failed to create new TypeErasedPublisher port."),
+        )
+    }
+}
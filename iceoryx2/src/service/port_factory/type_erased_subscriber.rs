@@ -0,0 +1,82 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let typed_service = zero_copy::Service::new(&service_name)
+//! #     .publish_subscribe()
+//! #     .open_or_create::<u64>()?;
+//! let pubsub = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_type_erased()?;
+//!
+//! let subscriber = pubsub.subscriber()
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::{subscriber::SubscriberCreateError, type_erased_subscriber::TypeErasedSubscriber},
+    service,
+};
+
+use super::{
+    subscriber::{LocalSubscriberConfig, ReceiveOrder},
+    type_erased::PortFactory,
+};
+
+/// Factory to create a new [`TypeErasedSubscriber`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryTypeErasedSubscriber<'factory, 'config, Service: service::Details<'config>> {
+    config: LocalSubscriberConfig,
+    pub(crate) factory: &'factory PortFactory<'config, Service>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>>
+    PortFactoryTypeErasedSubscriber<'factory, 'config, Service>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service>) -> Self {
+        Self {
+            config: LocalSubscriberConfig::default(),
+            factory,
+        }
+    }
+
+    /// Sets the [`ReceiveOrder`].
+    pub fn receive_order(mut self, value: ReceiveOrder) -> Self {
+        self.config.receive_order = value;
+        self
+    }
+
+    /// Creates a new [`TypeErasedSubscriber`] or returns a [`SubscriberCreateError`] on failure.
+    pub fn create(
+        &self,
+    ) -> Result<TypeErasedSubscriber<'factory, 'config, Service>, SubscriberCreateError> {
+        Ok(
+            fail!(from self, when TypeErasedSubscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &self.config),
+                "Failed to create new TypeErasedSubscriber port."),
+        )
+    }
+}
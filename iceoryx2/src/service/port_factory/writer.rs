@@ -0,0 +1,65 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use crate::port::writer::{Writer, WriterCreateError};
+use iceoryx2_bb_log::fail;
+
+use crate::service;
+
+use super::blackboard::PortFactory;
+
+/// Factory to create a new [`Writer`] port/endpoint for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryWriter<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    ValueType: Copy + Debug,
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, ValueType>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>, ValueType: Copy + Debug>
+    PortFactoryWriter<'factory, 'config, Service, ValueType>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, ValueType>) -> Self {
+        Self { factory }
+    }
+
+    /// Creates a new [`Writer`] port or returns a [`WriterCreateError`] on failure.
+    pub fn create(
+        &self,
+    ) -> Result<Writer<'factory, 'config, Service, ValueType>, WriterCreateError> {
+        Ok(fail!(from self, when Writer::new(&self.factory.service),
+                "Failed to create new Writer port."))
+    }
+}
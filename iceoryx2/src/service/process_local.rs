@@ -30,6 +30,7 @@
 //! # }
 //! ```
 
+use crate::port::copy_frame::CopyFrame;
 use crate::port::event_id::EventId;
 use crate::service::dynamic_config::DynamicConfig;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
@@ -59,6 +60,9 @@ impl<'config> crate::service::Details<'config> for Service<'config> {
     type SharedMemory = shared_memory::process_local::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::process_local::Connection;
     type Event = event::process_local::Event<EventId>;
+    // the process local communication channel can only transfer indices, the unix datagram
+    // channel is used instead since it supports multiple senders and arbitrary frames
+    type CopyChannel = communication_channel::unix_datagram::Channel<CopyFrame>;
 
     fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
         Self { state }
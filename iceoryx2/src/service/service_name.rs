@@ -45,7 +45,7 @@ impl ServiceName {
         })
     }
 
-    fn as_str(&self) -> &str {
+    pub(crate) fn as_str(&self) -> &str {
         // SAFETY: `ServieName` was created from a `&str` and therefore this conversion is safe
         unsafe { std::str::from_utf8_unchecked(self.value.as_bytes()) }
     }
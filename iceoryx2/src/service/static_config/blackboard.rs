@@ -0,0 +1,82 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add(0, 0)
+//!     .open_or_create()?;
+//!
+//! println!("type name:                        {:?}", blackboard.static_config().type_name());
+//! println!("keys:                             {:?}", blackboard.static_config().keys());
+//! println!("max readers:                      {:?}", blackboard.static_config().max_supported_readers());
+//! println!("max writers:                      {:?}", blackboard.static_config().max_supported_writers());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_readers: usize,
+    pub(crate) max_writers: usize,
+    pub(crate) type_name: String,
+    pub(crate) keys: Vec<u64>,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
+        Self {
+            max_readers: defaults.blackboard.max_readers,
+            max_writers: 1,
+            type_name: String::new(),
+            keys: vec![],
+        }
+    }
+
+    pub(crate) fn entry_index(&self, key: u64) -> Option<usize> {
+        self.keys.iter().position(|k| *k == key)
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::reader::Reader`] ports
+    pub fn max_supported_readers(&self) -> usize {
+        self.max_readers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports
+    pub fn max_supported_writers(&self) -> usize {
+        self.max_writers
+    }
+
+    /// Returns the type name of the entries of the [`crate::service::Service`].
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the keys of all entries the [`crate::service::Service`] provides.
+    pub fn keys(&self) -> &[u64] {
+        &self.keys
+    }
+}
@@ -40,10 +40,10 @@ pub struct StaticConfig {
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_notifiers: config.defaults.event.max_notifiers,
-            max_listeners: config.defaults.event.max_listeners,
+            max_notifiers: defaults.event.max_notifiers,
+            max_listeners: defaults.event.max_listeners,
         }
     }
 
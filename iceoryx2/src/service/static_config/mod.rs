@@ -10,16 +10,33 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The static service configuration of an
+/// [`MessagingPattern::Blackboard`]
+/// based service.
+pub mod blackboard;
+
 /// The static service configuration of an
 /// [`MessagingPattern::Event`]
 /// based service.
 pub mod event;
 
+/// The static service configuration of an
+/// [`MessagingPattern::Pipeline`]
+/// based service.
+pub mod pipeline;
+
 /// The static service configuration of an
 /// [`MessagingPattern::PublishSubscribe`]
 /// based service.
 pub mod publish_subscribe;
 
+/// The static service configuration of an
+/// [`MessagingPattern::RequestResponse`]
+/// based service.
+pub mod request_response;
+
+use crate::service::access_control::AccessControl;
+use crate::service::attribute::AttributeSet;
 use crate::service::messaging_pattern::MessagingPattern;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_cal::hash::Hash;
@@ -35,6 +52,10 @@ pub struct StaticConfig {
     uuid: String,
     service_name: ServiceName,
     pub(crate) messaging_pattern: MessagingPattern,
+    #[serde(default)]
+    pub(crate) access_control: AccessControl,
+    #[serde(default)]
+    pub(crate) attributes: AttributeSet,
 }
 
 impl StaticConfig {
@@ -45,7 +66,11 @@ impl StaticConfig {
         Self {
             uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
             service_name: *service_name,
-            messaging_pattern: MessagingPattern::Event(event::StaticConfig::new(config)),
+            messaging_pattern: MessagingPattern::Event(event::StaticConfig::new(
+                config.defaults_for(service_name),
+            )),
+            access_control: AccessControl::default(),
+            attributes: AttributeSet::default(),
         }
     }
 
@@ -57,8 +82,55 @@ impl StaticConfig {
             uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
             service_name: *service_name,
             messaging_pattern: MessagingPattern::PublishSubscribe(
-                publish_subscribe::StaticConfig::new(config),
+                publish_subscribe::StaticConfig::new(config.defaults_for(service_name)),
+            ),
+            access_control: AccessControl::default(),
+            attributes: AttributeSet::default(),
+        }
+    }
+
+    pub(crate) fn new_request_response<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        Self {
+            uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
+            service_name: *service_name,
+            messaging_pattern: MessagingPattern::RequestResponse(
+                request_response::StaticConfig::new(config.defaults_for(service_name)),
             ),
+            access_control: AccessControl::default(),
+            attributes: AttributeSet::default(),
+        }
+    }
+
+    pub(crate) fn new_blackboard<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        Self {
+            uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
+            service_name: *service_name,
+            messaging_pattern: MessagingPattern::Blackboard(blackboard::StaticConfig::new(
+                config.defaults_for(service_name),
+            )),
+            access_control: AccessControl::default(),
+            attributes: AttributeSet::default(),
+        }
+    }
+
+    pub(crate) fn new_pipeline<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        Self {
+            uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
+            service_name: *service_name,
+            messaging_pattern: MessagingPattern::Pipeline(pipeline::StaticConfig::new(
+                config.defaults_for(service_name),
+            )),
+            access_control: AccessControl::default(),
+            attributes: AttributeSet::default(),
         }
     }
 
@@ -77,6 +149,16 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns the [`AccessControl`] of the [`crate::service::Service`]
+    pub fn access_control(&self) -> &AccessControl {
+        &self.access_control
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -119,4 +201,61 @@ impl StaticConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn request_response_mut(&mut self) -> &mut request_response::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn pipeline_mut(&mut self) -> &mut pipeline::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Pipeline(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
@@ -0,0 +1,96 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! println!("type name:                        {:?}", pipeline.static_config().type_name());
+//! println!("max producers:                    {:?}", pipeline.static_config().max_supported_producers());
+//! println!("max consumers:                    {:?}", pipeline.static_config().max_supported_consumers());
+//! println!("consumer buffer size:             {:?}", pipeline.static_config().consumer_max_buffer_size());
+//! println!("consumer max borrowed samples:    {:?}", pipeline.static_config().consumer_max_borrowed_samples());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Pipeline`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_producers: usize,
+    pub(crate) max_consumers: usize,
+    pub(crate) consumer_max_buffer_size: usize,
+    pub(crate) consumer_max_borrowed_samples: usize,
+    pub(crate) type_name: String,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
+        Self {
+            max_producers: defaults.pipeline.max_producers,
+            max_consumers: defaults.pipeline.max_consumers,
+            consumer_max_buffer_size: defaults.pipeline.consumer_max_buffer_size,
+            consumer_max_borrowed_samples: defaults.pipeline.consumer_max_borrowed_samples,
+            type_name: String::new(),
+        }
+    }
+
+    pub(crate) fn required_amount_of_samples_per_data_segment(
+        &self,
+        producer_max_loaned_samples: usize,
+    ) -> usize {
+        self.max_consumers * (self.consumer_max_buffer_size + self.consumer_max_borrowed_samples)
+            + producer_max_loaned_samples
+            + 1
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::producer::Producer`] ports
+    pub fn max_supported_producers(&self) -> usize {
+        self.max_producers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::consumer::Consumer`] ports
+    pub fn max_supported_consumers(&self) -> usize {
+        self.max_consumers
+    }
+
+    /// Returns how many samples of a single [`crate::port::producer::Producer`] can be stored
+    /// in the buffer of a [`crate::port::consumer::Consumer`] port.
+    pub fn consumer_max_buffer_size(&self) -> usize {
+        self.consumer_max_buffer_size
+    }
+
+    /// Returns how many [`crate::work_item::WorkItem`]s a [`crate::port::consumer::Consumer`]
+    /// port can borrow in parallel at most from a single [`crate::port::producer::Producer`].
+    pub fn consumer_max_borrowed_samples(&self) -> usize {
+        self.consumer_max_borrowed_samples
+    }
+
+    /// Returns the type name of the samples of the [`crate::service::Service`].
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+}
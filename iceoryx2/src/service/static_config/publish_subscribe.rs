@@ -28,12 +28,21 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("max slice length:                 {:?}", pubsub.static_config().max_slice_len());
+//! println!("notifications:                    {:?}", pubsub.static_config().has_notifications());
+//! println!("copy transfer:                    {:?}", pubsub.static_config().has_copy_transfer());
+//! println!("payload size:                     {:?}", pubsub.static_config().payload_size());
+//! println!("payload alignment:                {:?}", pubsub.static_config().payload_alignment());
+//! println!("type hash:                        {:?}", pubsub.static_config().type_hash());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use std::alloc::Layout;
+
 use crate::config;
+use iceoryx2_bb_log::fatal_panic;
 use serde::{Deserialize, Serialize};
 
 /// The static configuration of an
@@ -48,25 +57,61 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) max_slice_len: usize,
+    pub(crate) enable_notifications: bool,
+    #[serde(default)]
+    pub(crate) enable_copy_transfer: bool,
     pub(crate) type_name: String,
+    pub(crate) payload_size: usize,
+    pub(crate) payload_alignment: usize,
+    #[serde(default, with = "type_hash_serialization")]
+    pub(crate) type_hash: Option<u64>,
+}
+
+// toml supports only signed 64-bit integers, therefore the hash is stored as hex string
+mod type_hash_serialization {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_some(&format!("{:#018x}", v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(v) => u64::from_str_radix(v.trim_start_matches("0x"), 16)
+                .map(Some)
+                .map_err(serde::de::Error::custom),
+            None => Ok(None),
+        }
+    }
 }
 
 impl StaticConfig {
-    pub(crate) fn new(config: &config::Config) -> Self {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
         Self {
-            max_subscribers: config.defaults.publish_subscribe.max_subscribers,
-            max_publishers: config.defaults.publish_subscribe.max_publishers,
-            history_size: config.defaults.publish_subscribe.publisher_history_size,
-            subscriber_max_buffer_size: config
-                .defaults
-                .publish_subscribe
-                .subscriber_max_buffer_size,
-            subscriber_max_borrowed_samples: config
-                .defaults
+            max_subscribers: defaults.publish_subscribe.max_subscribers,
+            max_publishers: defaults.publish_subscribe.max_publishers,
+            history_size: defaults.publish_subscribe.publisher_history_size,
+            subscriber_max_buffer_size: defaults.publish_subscribe.subscriber_max_buffer_size,
+            subscriber_max_borrowed_samples: defaults
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
-            enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_safe_overflow: defaults.publish_subscribe.enable_safe_overflow,
+            max_slice_len: 1,
+            enable_notifications: false,
+            enable_copy_transfer: false,
             type_name: String::new(),
+            payload_size: 0,
+            payload_alignment: 1,
+            type_hash: None,
         }
     }
 
@@ -104,8 +149,62 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the maximum number of elements a slice payload can contain. For services with a
+    /// fixed size payload it is always 1.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Returns true if the [`crate::port::publisher::Publisher`] notifies the
+    /// [`crate::port::subscriber::Subscriber`]s whenever a new [`crate::sample::Sample`] was
+    /// delivered, otherwise false.
+    pub fn has_notifications(&self) -> bool {
+        self.enable_notifications
+    }
+
+    /// Returns true if the [`crate::port::publisher::Publisher`] delivers copies of every
+    /// [`crate::sample::Sample`] to the [`crate::port::subscriber::Subscriber`]s instead of
+    /// sharing its data segment with them, otherwise false.
+    pub fn has_copy_transfer(&self) -> bool {
+        self.enable_copy_transfer
+    }
+
     /// Returns the type name of the [`crate::service::Service`].
     pub fn type_name(&self) -> &str {
         &self.type_name
     }
+
+    /// Returns the size of the payload type. For slice payloads it is the size of a single
+    /// element.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns the alignment of the payload type. For slice payloads it is the alignment of a
+    /// single element.
+    pub fn payload_alignment(&self) -> usize {
+        self.payload_alignment
+    }
+
+    /// Returns the user defined hash of the payload type, see
+    /// [`crate::service::builder::publish_subscribe::Builder::type_hash()`]. If the creator of
+    /// the [`crate::service::Service`] did not define one, it returns [`None`].
+    pub fn type_hash(&self) -> Option<u64> {
+        self.type_hash
+    }
+
+    /// Returns the layout of a message that consists of the `Header` and a payload with
+    /// [`StaticConfig::max_slice_len()`] elements together with the offset of the payload
+    /// inside the message.
+    pub(crate) fn message_layout<Header>(&self) -> (Layout, usize) {
+        let layout = Layout::from_size_align(
+            self.payload_size * self.max_slice_len,
+            self.payload_alignment,
+        )
+        .and_then(|payload| Layout::new::<Header>().extend(payload));
+
+        let (layout, payload_offset) = fatal_panic!(from self, when layout,
+            "This should never happen! The payload layout of the service is invalid.");
+        (layout.pad_to_align(), payload_offset)
+    }
 }
@@ -0,0 +1,131 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! println!("request type name:                {:?}", reqres.static_config().request_type_name());
+//! println!("response type name:               {:?}", reqres.static_config().response_type_name());
+//! println!("max clients:                      {:?}", reqres.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", reqres.static_config().max_supported_servers());
+//! println!("server buffer size:               {:?}", reqres.static_config().server_max_buffer_size());
+//! println!("server max borrowed requests:     {:?}", reqres.static_config().server_max_borrowed_requests());
+//! println!("client buffer size:               {:?}", reqres.static_config().client_max_buffer_size());
+//! println!("client max borrowed responses:    {:?}", reqres.static_config().client_max_borrowed_responses());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::RequestResponse`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_clients: usize,
+    pub(crate) max_servers: usize,
+    pub(crate) server_max_buffer_size: usize,
+    pub(crate) server_max_borrowed_requests: usize,
+    pub(crate) client_max_buffer_size: usize,
+    pub(crate) client_max_borrowed_responses: usize,
+    pub(crate) request_type_name: String,
+    pub(crate) response_type_name: String,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(defaults: &config::Defaults) -> Self {
+        Self {
+            max_clients: defaults.request_response.max_clients,
+            max_servers: defaults.request_response.max_servers,
+            server_max_buffer_size: defaults.request_response.server_max_buffer_size,
+            server_max_borrowed_requests: defaults.request_response.server_max_borrowed_requests,
+            client_max_buffer_size: defaults.request_response.client_max_buffer_size,
+            client_max_borrowed_responses: defaults.request_response.client_max_borrowed_responses,
+            request_type_name: String::new(),
+            response_type_name: String::new(),
+        }
+    }
+
+    pub(crate) fn required_amount_of_requests_per_data_segment(
+        &self,
+        client_max_loaned_requests: usize,
+    ) -> usize {
+        self.max_servers * (self.server_max_buffer_size + self.server_max_borrowed_requests)
+            + client_max_loaned_requests
+            + 1
+    }
+
+    pub(crate) fn required_amount_of_responses_per_data_segment(
+        &self,
+        server_max_loaned_responses: usize,
+    ) -> usize {
+        self.max_clients * (self.client_max_buffer_size + self.client_max_borrowed_responses)
+            + server_max_loaned_responses
+            + 1
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::client::Client`] ports
+    pub fn max_supported_clients(&self) -> usize {
+        self.max_clients
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::server::Server`] ports
+    pub fn max_supported_servers(&self) -> usize {
+        self.max_servers
+    }
+
+    /// Returns how many requests of a single [`crate::port::client::Client`] can be stored in
+    /// the buffer of a [`crate::port::server::Server`] port.
+    pub fn server_max_buffer_size(&self) -> usize {
+        self.server_max_buffer_size
+    }
+
+    /// Returns how many [`crate::request::Request`]s a [`crate::port::server::Server`] port
+    /// can borrow in parallel at most from a single [`crate::port::client::Client`].
+    pub fn server_max_borrowed_requests(&self) -> usize {
+        self.server_max_borrowed_requests
+    }
+
+    /// Returns how many responses of a single [`crate::port::server::Server`] can be stored in
+    /// the buffer of a [`crate::port::client::Client`] port.
+    pub fn client_max_buffer_size(&self) -> usize {
+        self.client_max_buffer_size
+    }
+
+    /// Returns how many [`crate::response::Response`]s a [`crate::port::client::Client`] port
+    /// can borrow in parallel at most from a single [`crate::port::server::Server`].
+    pub fn client_max_borrowed_responses(&self) -> usize {
+        self.client_max_borrowed_responses
+    }
+
+    /// Returns the type name of the requests of the [`crate::service::Service`].
+    pub fn request_type_name(&self) -> &str {
+        &self.request_type_name
+    }
+
+    /// Returns the type name of the responses of the [`crate::service::Service`].
+    pub fn response_type_name(&self) -> &str {
+        &self.response_type_name
+    }
+}
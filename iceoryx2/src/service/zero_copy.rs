@@ -30,6 +30,7 @@
 //! # }
 //! ```
 
+use crate::port::copy_frame::CopyFrame;
 use crate::port::event_id::EventId;
 use crate::service::dynamic_config::DynamicConfig;
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
@@ -59,6 +60,7 @@ impl<'config> crate::service::Details<'config> for Service<'config> {
     type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
     type Event = event::unix_datagram_socket::Event<EventId>;
+    type CopyChannel = communication_channel::unix_datagram::Channel<CopyFrame>;
 
     fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
         Self { state }
@@ -0,0 +1,87 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::zero_copy_directory;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//!
+//! // use `zero_copy_directory` as communication variant
+//! let service = zero_copy_directory::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::port::copy_frame::CopyFrame;
+use crate::port::event_id::EventId;
+use crate::service::dynamic_config::DynamicConfig;
+use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Defines a zero copy inter-process communication setup like [`crate::service::zero_copy`]
+/// but the dynamic configs, the data segments and the connections of all services are stored
+/// as files in a few [`iceoryx2_cal::shared_memory_directory::SharedMemoryDirectory`]s instead
+/// of a POSIX shared memory object each. Use it when the number of services exceeds the
+/// limits of the operating system for file descriptors or memory mappings. The directories
+/// grow on demand and are removed by the last process that uses them when they are empty.
+///
+/// Since the directories are shared by all services, the access to a service cannot be
+/// restricted with the [`crate::service::access_control::AccessControl`].
+#[derive(Debug)]
+pub struct Service<'config> {
+    state: ServiceState<
+        'config,
+        static_storage::file::Storage,
+        dynamic_storage::shared_memory_directory::Storage<DynamicConfig>,
+    >,
+}
+
+impl<'config> crate::service::Service for Service<'config> {
+    type Type<'b> = Service<'b>;
+}
+
+impl<'config> crate::service::Details<'config> for Service<'config> {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::shared_memory_directory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::shared_memory_directory::Memory<PoolAllocator>;
+    type Connection = zero_copy_connection::shared_memory_directory::Connection;
+    type Event = event::unix_datagram_socket::Event<EventId>;
+    type CopyChannel = communication_channel::unix_datagram::Channel<CopyFrame>;
+
+    fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
+        Self { state }
+    }
+
+    fn state(&self) -> &ServiceState<'config, Self::StaticStorage, Self::DynamicStorage> {
+        &self.state
+    }
+
+    fn state_mut(
+        &mut self,
+    ) -> &mut ServiceState<'config, Self::StaticStorage, Self::DynamicStorage> {
+        &mut self.state
+    }
+}
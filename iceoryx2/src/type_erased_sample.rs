@@ -0,0 +1,67 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let typed_service = zero_copy::Service::new(&service_name)
+//! #   .publish_subscribe()
+//! #   .open_or_create::<u64>()?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .publish_subscribe()
+//! #   .open_type_erased()?;
+//! # let subscriber = service.subscriber().create()?;
+//!
+//! while let Some(sample) = subscriber.receive()? {
+//!     println!("received: {:?}", sample.payload());
+//!     println!("header timestamp {:?}, publisher id {:?}",
+//!         sample.header().time_stamp(), sample.header().publisher_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{sample::Sample, service, service::header::publish_subscribe::Header};
+
+/// It stores the payload as raw bytes and is acquired by the
+/// [`crate::port::type_erased_subscriber::TypeErasedSubscriber`] whenever it receives new data
+/// from a [`crate::port::publisher::Publisher`].
+#[derive(Debug)]
+pub struct TypeErasedSample<'a, 'subscriber, 'config, Service: service::Details<'config>> {
+    pub(crate) sample: Sample<'a, 'subscriber, 'config, Service, Header, [u8]>,
+    pub(crate) payload_offset: usize,
+    pub(crate) payload_len: usize,
+}
+
+impl<'a, 'subscriber, 'config, Service: service::Details<'config>>
+    TypeErasedSample<'a, 'subscriber, 'config, Service>
+{
+    /// Returns the payload as raw bytes. It contains
+    /// [`Header::number_of_elements()`] times
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::payload_size()`] bytes.
+    /// Padding bytes of the payload type have an unspecified value.
+    pub fn payload(&self) -> &[u8] {
+        let message = self.sample.header() as *const Header as *const u8;
+        // SAFETY: the chunk was allocated with the message layout of the service and contains
+        // at least `payload_len` bytes starting at `payload_offset`
+        unsafe { std::slice::from_raw_parts(message.add(self.payload_offset), self.payload_len) }
+    }
+
+    /// Returns a reference to the [`Header`] of the sample.
+    pub fn header(&self) -> &Header {
+        self.sample.header()
+    }
+}
@@ -0,0 +1,434 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ## Multiplexing Listeners And Intervals
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::waitset::{WaitSetAttachmentId, WaitSetRunResult};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let event_1 = zero_copy::Service::new(&ServiceName::new("MyEvent1")?)
+//!     .event()
+//!     .open_or_create()?;
+//! let event_2 = zero_copy::Service::new(&ServiceName::new("MyEvent2")?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener_1 = event_1.listener().create()?;
+//! let listener_2 = event_2.listener().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create()?;
+//! let guard_1 = waitset.attach_notification(&listener_1)?;
+//! let guard_2 = waitset.attach_notification(&listener_2)?;
+//! let interval = waitset.attach_interval(Duration::from_secs(1));
+//!
+//! let on_event = |attachment_id: WaitSetAttachmentId| {
+//!     if attachment_id.has_event_from(&guard_1) {
+//!         listener_1.try_wait_all(|id| println!("MyEvent1 triggered {:?}", id)).ok();
+//!     } else if attachment_id.has_event_from(&guard_2) {
+//!         listener_2.try_wait_all(|id| println!("MyEvent2 triggered {:?}", id)).ok();
+//!     } else if attachment_id.has_event_from(&interval) {
+//!         println!("one second has passed");
+//!     }
+//! };
+//!
+//! while let Ok(WaitSetRunResult::AllEventsHandled) = waitset.wait_and_process(on_event) {}
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::Debug,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    file_descriptor::FileDescriptor, file_descriptor_set::SynchronousMultiplexing,
+    signal::SignalHandler,
+};
+use iceoryx2_cal::reactor::{
+    posix_select, Reactor, ReactorAttachError, ReactorBuilder, ReactorWaitError,
+};
+
+/// Defines the failures that can occur when a [`WaitSet`] is created with the
+/// [`WaitSetBuilder`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetCreateError {
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetCreateError {}
+
+/// Defines the failures that can occur when something is attached to the [`WaitSet`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetAttachmentError {
+    InsufficientCapacity,
+    AlreadyAttached,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetAttachmentError {}
+
+/// Defines the failures that can occur in [`WaitSet::wait_and_process()`] and
+/// [`WaitSet::timed_wait_and_process()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetRunError {
+    InsufficientPermissions,
+    NoAttachments,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetRunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetRunError {}
+
+/// The result of a successful [`WaitSet::wait_and_process()`] or
+/// [`WaitSet::timed_wait_and_process()`] call.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetRunResult {
+    /// The user pressed CTRL+c or the process received a termination signal.
+    TerminationRequest,
+    /// The waiting was interrupted by a signal.
+    Interrupt,
+    /// Every attachment that fired was handed to the callback, or the timeout has passed.
+    AllEventsHandled,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+enum AttachmentIdType {
+    Notification(i32),
+    Timer(u64),
+}
+
+/// Identifies the attachment that fired. It is handed to the callback of
+/// [`WaitSet::wait_and_process()`] and can be compared with the [`WaitSetGuard`] that was
+/// returned when the attachment was attached.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct WaitSetAttachmentId {
+    attachment: AttachmentIdType,
+}
+
+impl WaitSetAttachmentId {
+    /// Returns the [`WaitSetAttachmentId`] of the attachment that is represented by the
+    /// [`WaitSetGuard`].
+    pub fn from_guard(guard: &WaitSetGuard<'_, '_>) -> Self {
+        Self {
+            attachment: guard.attachment,
+        }
+    }
+
+    /// Returns true when the event originated from the attachment that is represented by the
+    /// [`WaitSetGuard`], otherwise false.
+    pub fn has_event_from(&self, guard: &WaitSetGuard<'_, '_>) -> bool {
+        self.attachment == guard.attachment
+    }
+}
+
+#[derive(Debug)]
+struct Timer {
+    period: Duration,
+    next_trigger: Instant,
+    is_periodic: bool,
+    has_expired: bool,
+}
+
+/// Represents an attachment of the [`WaitSet`]. As soon as the guard goes out of scope the
+/// attachment is detached.
+pub struct WaitSetGuard<'waitset, 'attachment> {
+    waitset: &'waitset WaitSet,
+    attachment: AttachmentIdType,
+    _reactor_guard: Option<<posix_select::Reactor as Reactor>::Guard<'waitset, 'attachment>>,
+}
+
+impl Debug for WaitSetGuard<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WaitSetGuard {{ attachment: {:?} }}", self.attachment)
+    }
+}
+
+impl Drop for WaitSetGuard<'_, '_> {
+    fn drop(&mut self) {
+        match self.attachment {
+            AttachmentIdType::Notification(fd) => {
+                self.waitset.notifications.borrow_mut().retain(|v| *v != fd)
+            }
+            AttachmentIdType::Timer(id) => {
+                self.waitset.timers.borrow_mut().remove(&id);
+            }
+        }
+    }
+}
+
+/// Creates a new [`WaitSet`].
+#[derive(Debug, Default)]
+pub struct WaitSetBuilder {}
+
+impl WaitSetBuilder {
+    /// Creates a new [`WaitSetBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the [`WaitSet`].
+    pub fn create(self) -> Result<WaitSet, WaitSetCreateError> {
+        let reactor = fail!(from self,
+            when <posix_select::Reactor as Reactor>::Builder::new().create(),
+            with WaitSetCreateError::InternalError,
+            "Unable to create WaitSet since the underlying reactor could not be created.");
+
+        Ok(WaitSet {
+            reactor,
+            notifications: RefCell::new(vec![]),
+            timers: RefCell::new(BTreeMap::new()),
+            timer_id_counter: AtomicU64::new(0),
+        })
+    }
+}
+
+/// The event multiplexer of iceoryx2. Multiple [`crate::port::listener::Listener`]s, timers
+/// and intervals can be attached and [`WaitSet::wait_and_process()`] blocks until at least one
+/// of them fires. Every attachment that fired is handed to the provided callback, see the
+/// reactor pattern.
+///
+/// Only [`crate::port::listener::Listener`]s of services whose event concept is based on file
+/// descriptors, like [`crate::service::zero_copy::Service`], can be attached.
+#[derive(Debug)]
+pub struct WaitSet {
+    reactor: posix_select::Reactor,
+    notifications: RefCell<Vec<i32>>,
+    timers: RefCell<BTreeMap<u64, Timer>>,
+    timer_id_counter: AtomicU64,
+}
+
+impl WaitSet {
+    /// Attaches an object that signals via a file descriptor, like a
+    /// [`crate::port::listener::Listener`], to the [`WaitSet`]. Whenever the object is
+    /// notified the [`WaitSet`] wakes up.
+    pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment>, WaitSetAttachmentError> {
+        let msg = "Unable to attach notification to WaitSet";
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+
+        if self.notifications.borrow().contains(&fd) {
+            fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
+                "{} since {:?} is already attached.", msg, attachment);
+        }
+
+        let reactor_guard = match self.reactor.attach(attachment) {
+            Ok(guard) => guard,
+            Err(ReactorAttachError::CapacityExceeded) => {
+                fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                    "{} since it would exceed the capacity of {} attachments.",
+                    msg, posix_select::Reactor::capacity());
+            }
+            Err(ReactorAttachError::UnknownError(e)) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{} due to an internal error in the underlying reactor ({}).", msg, e);
+            }
+        };
+
+        self.notifications.borrow_mut().push(fd);
+
+        Ok(WaitSetGuard {
+            waitset: self,
+            attachment: AttachmentIdType::Notification(fd),
+            _reactor_guard: Some(reactor_guard),
+        })
+    }
+
+    /// Attaches a timer to the [`WaitSet`] that fires once when the `timeout` has passed. The
+    /// timer stays attached until the [`WaitSetGuard`] goes out of scope.
+    pub fn attach_timer(&self, timeout: Duration) -> WaitSetGuard<'_, '_> {
+        self.attach_timer_impl(timeout, false)
+    }
+
+    /// Attaches an interval to the [`WaitSet`] that fires periodically whenever the `period`
+    /// has passed.
+    pub fn attach_interval(&self, period: Duration) -> WaitSetGuard<'_, '_> {
+        self.attach_timer_impl(period, true)
+    }
+
+    fn attach_timer_impl(&self, period: Duration, is_periodic: bool) -> WaitSetGuard<'_, '_> {
+        let id = self.timer_id_counter.fetch_add(1, Ordering::Relaxed);
+        self.timers.borrow_mut().insert(
+            id,
+            Timer {
+                period,
+                next_trigger: Instant::now() + period,
+                is_periodic,
+                has_expired: false,
+            },
+        );
+
+        WaitSetGuard {
+            waitset: self,
+            attachment: AttachmentIdType::Timer(id),
+            _reactor_guard: None,
+        }
+    }
+
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.notifications.borrow().len() + self.timers.borrow().len()
+    }
+
+    /// Returns true if the [`WaitSet`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of [`crate::port::listener::Listener`]s that can be attached.
+    pub fn capacity(&self) -> usize {
+        posix_select::Reactor::capacity()
+    }
+
+    /// Blocks until at least one attachment fires and calls `fn_call` with the
+    /// [`WaitSetAttachmentId`] of every attachment that fired.
+    pub fn wait_and_process<F: FnMut(WaitSetAttachmentId)>(
+        &self,
+        fn_call: F,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process_impl(fn_call, None)
+    }
+
+    /// Blocks until at least one attachment fires or the `timeout` has passed and calls
+    /// `fn_call` with the [`WaitSetAttachmentId`] of every attachment that fired.
+    pub fn timed_wait_and_process<F: FnMut(WaitSetAttachmentId)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        self.wait_and_process_impl(fn_call, Some(timeout))
+    }
+
+    fn time_until_next_timer(&self) -> Option<Duration> {
+        let now = Instant::now();
+        self.timers
+            .borrow()
+            .values()
+            .filter(|timer| !timer.has_expired)
+            .map(|timer| timer.next_trigger.saturating_duration_since(now))
+            .min()
+    }
+
+    fn fired_timers(&self) -> Vec<u64> {
+        let now = Instant::now();
+        let mut fired_timers = vec![];
+
+        for (id, timer) in self.timers.borrow_mut().iter_mut() {
+            if timer.has_expired || timer.next_trigger > now {
+                continue;
+            }
+
+            fired_timers.push(*id);
+            if timer.is_periodic {
+                while timer.next_trigger <= now {
+                    timer.next_trigger += timer.period;
+                }
+            } else {
+                timer.has_expired = true;
+            }
+        }
+
+        fired_timers
+    }
+
+    fn wait_and_process_impl<F: FnMut(WaitSetAttachmentId)>(
+        &self,
+        mut fn_call: F,
+        timeout: Option<Duration>,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        let msg = "Unable to wait on WaitSet";
+        if SignalHandler::termination_requested() {
+            return Ok(WaitSetRunResult::TerminationRequest);
+        }
+
+        let next_timer = self.time_until_next_timer();
+        if self.notifications.borrow().is_empty() && next_timer.is_none() {
+            fail!(from self, with WaitSetRunError::NoAttachments,
+                "{} since it has no attachments that can fire, therefore the call would block forever.", msg);
+        }
+
+        let timeout = match (timeout, next_timer) {
+            (Some(timeout), Some(next_timer)) => Some(timeout.min(next_timer)),
+            (timeout, next_timer) => timeout.or(next_timer),
+        };
+
+        let mut fired_notifications = vec![];
+        let collect_fds =
+            |fd: &FileDescriptor| fired_notifications.push(unsafe { fd.native_handle() });
+
+        let result = match timeout {
+            Some(timeout) => self.reactor.timed_wait(collect_fds, timeout),
+            None => self.reactor.blocking_wait(collect_fds),
+        };
+
+        match result {
+            Ok(()) => (),
+            Err(ReactorWaitError::Interrupt) => {
+                if SignalHandler::termination_requested() {
+                    return Ok(WaitSetRunResult::TerminationRequest);
+                }
+                return Ok(WaitSetRunResult::Interrupt);
+            }
+            Err(ReactorWaitError::InsufficientPermissions) => {
+                fail!(from self, with WaitSetRunError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(ReactorWaitError::UnknownError) => {
+                fail!(from self, with WaitSetRunError::InternalError,
+                    "{} due to an internal error in the underlying reactor.", msg);
+            }
+        }
+
+        for fd in fired_notifications {
+            fn_call(WaitSetAttachmentId {
+                attachment: AttachmentIdType::Notification(fd),
+            });
+        }
+
+        for id in self.fired_timers() {
+            fn_call(WaitSetAttachmentId {
+                attachment: AttachmentIdType::Timer(id),
+            });
+        }
+
+        Ok(WaitSetRunResult::AllEventsHandled)
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName")?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .pipeline()
+//! #   .open_or_create::<u64>()?;
+//! # let consumer = service.consumer().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//!     println!("producer id {:?}, work item id {:?}",
+//!         work_item.header().producer_id(), work_item.header().work_item_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{
+    port::consumer::Consumer, raw_sample::RawSample, service, service::header::pipeline::Header,
+};
+
+/// It stores the payload of a work item and is acquired by the [`Consumer`] whenever it
+/// receives a new work item from a [`crate::port::producer::Producer`] via
+/// [`Consumer::receive()`]. The work item is returned to the producer when it goes out of
+/// scope.
+#[derive(Debug)]
+pub struct WorkItem<'a, 'consumer, 'config, Service: service::Details<'config>, MessageType: Debug>
+{
+    pub(crate) consumer: &'consumer Consumer<'a, 'config, Service, MessageType>,
+    pub(crate) ptr: RawSample<Header, MessageType>,
+    pub(crate) channel_id: usize,
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug> Deref
+    for WorkItem<'_, '_, 'config, Service, MessageType>
+{
+    type Target = MessageType;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_data_ref()
+    }
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug> Drop
+    for WorkItem<'_, '_, 'config, Service, MessageType>
+{
+    fn drop(&mut self) {
+        self.consumer.release_work_item(self.channel_id, self.ptr);
+    }
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug>
+    WorkItem<'_, '_, 'config, Service, MessageType>
+{
+    /// Returns a reference to the payload of the work item
+    pub fn payload(&self) -> &MessageType {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+//! #
+//! # let service = zero_copy::Service::new(&service_name)
+//! #     .pipeline()
+//! #     .open_or_create::<u64>()?;
+//! #
+//! # let producer = service.producer().create()?;
+//! # let consumer = service.consumer().create()?;
+//!
+//! let work_item = producer.loan_uninit()?;
+//! let work_item = work_item.write_payload(1234);
+//!
+//! println!("producer port id: {:?}, work item id: {:?}",
+//!     work_item.header().producer_id(), work_item.header().work_item_id());
+//! producer.send(work_item)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    port::producer::Producer, raw_sample::RawSampleMut, service, service::header::pipeline::Header,
+};
+use iceoryx2_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
+
+/// Acquired by a [`Producer`] via [`Producer::loan()`] or [`Producer::loan_uninit()`]. It stores
+/// the payload of the work item that will be sent to exactly one connected
+/// [`crate::port::consumer::Consumer`]. If the [`WorkItemMut`] is not sent it will release the
+/// loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent work items in the [`Producer`] and the
+/// [`Producer`] is not thread-safe!
+#[derive(Debug)]
+pub struct WorkItemMut<'a, 'producer, 'config, Service: service::Details<'config>, M: Debug> {
+    producer: &'producer Producer<'a, 'config, Service, M>,
+    ptr: RawSampleMut<Header, M>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<'config, Service: service::Details<'config>, M: Debug> Drop
+    for WorkItemMut<'_, '_, 'config, Service, M>
+{
+    fn drop(&mut self) {
+        self.producer.release_sample(self.offset_to_chunk);
+        self.producer.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<'a, 'producer, 'config, Service: service::Details<'config>, MessageType: Debug>
+    WorkItemMut<'a, 'producer, 'config, Service, MaybeUninit<MessageType>>
+{
+    pub(crate) fn new(
+        producer: &'producer Producer<'a, 'config, Service, MessageType>,
+        ptr: RawSampleMut<Header, MaybeUninit<MessageType>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        producer.loan_counter.fetch_add(1, Ordering::Relaxed);
+
+        // SAFETY: the transmute is not nice but safe since MaybeUninit is #[repr(transparent)} to the inner type
+        let producer = unsafe { std::mem::transmute(producer) };
+
+        Self {
+            producer,
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    /// Writes the payload to the work item and labels the work item as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .pipeline()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    /// # let consumer = service.consumer().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(1234);
+    ///
+    /// producer.send(work_item)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(
+        mut self,
+        value: MessageType,
+    ) -> WorkItemMut<'a, 'producer, 'config, Service, MessageType> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`core::mem::MaybeUninit<MessageType>`] container and labels the work item as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`core::mem::MaybeUninit<MessageType>`] really is initialized. Calling this when
+    /// the content is not fully initialized causes immediate undefined behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let service_name = ServiceName::new("My/Funk/ServiceName").unwrap();
+    /// #
+    /// # let service = zero_copy::Service::new(&service_name)
+    /// #     .pipeline()
+    /// #     .open_or_create::<u64>()?;
+    /// #
+    /// # let producer = service.producer().create()?;
+    /// # let consumer = service.consumer().create()?;
+    ///
+    /// let mut work_item = producer.loan_uninit()?;
+    /// work_item.payload_mut().write(1234);
+    /// let work_item = unsafe { work_item.assume_init() };
+    ///
+    /// producer.send(work_item)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> WorkItemMut<'a, 'producer, 'config, Service, MessageType> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self)
+    }
+}
+
+impl<
+        'a,
+        'producer,
+        'config,
+        Service: service::Details<'config>,
+        M: Debug, // `M` is either a `MessageType` or a `MaybeUninit<MessageType>`
+    > WorkItemMut<'a, 'producer, 'config, Service, M>
+{
+    pub(crate) fn offset_to_chunk(&self) -> PointerOffset {
+        self.offset_to_chunk
+    }
+
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns a reference to the payload of the work item.
+    ///
+    /// # Notes
+    ///
+    /// The generic parameter `M` is either a `MessageType` or a [`core::mem::MaybeUninit<MessageType>`], depending
+    /// which API is used to obtain the work item. Obtaining a reference is safe for either type.
+    pub fn payload(&self) -> &M {
+        self.ptr.as_data_ref()
+    }
+
+    /// Returns a mutable reference to the payload of the work item.
+    ///
+    /// # Notes
+    ///
+    /// The generic parameter `M` is either a `MessageType` or a [`core::mem::MaybeUninit<MessageType>`], depending
+    /// which API is used to obtain the work item. Obtaining a mut reference is safe for either type.
+    pub fn payload_mut(&mut self) -> &mut M {
+        self.ptr.as_data_mut()
+    }
+}
@@ -0,0 +1,428 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(feature = "async")]
+mod async_port {
+    use std::cell::{Cell, RefCell};
+    use std::future::Future;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::time::Duration;
+
+    use iceoryx2::port::async_listener::AsyncListener;
+    use iceoryx2::port::async_subscriber::{AsyncSubscriber, AsyncSubscriberCreateError};
+    use iceoryx2::port::event_id::EventId;
+    use iceoryx2::reactor::{Reactor, Readiness};
+    use iceoryx2::service::{service_name::ServiceName, zero_copy, Service};
+    use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+    use iceoryx2_bb_posix::file_descriptor_set::{
+        FileDescriptorSet, FileEvent, SynchronousMultiplexing,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "async_port_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[derive(Default)]
+    struct TestWaker {
+        is_woken: AtomicBool,
+    }
+
+    impl Wake for TestWaker {
+        fn wake(self: Arc<Self>) {
+            self.is_woken.store(true, Ordering::Relaxed);
+        }
+    }
+
+    // a reactor whose file descriptors become readable only when the test signals them
+    #[derive(Default)]
+    struct TestReactor {
+        registered_file_descriptors: RefCell<Vec<i32>>,
+        is_readable: Rc<Cell<bool>>,
+        waker: Rc<RefCell<Option<Waker>>>,
+    }
+
+    impl TestReactor {
+        fn signal_readable(&self) {
+            self.is_readable.set(true);
+            if let Some(waker) = self.waker.borrow_mut().take() {
+                waker.wake();
+            }
+        }
+    }
+
+    struct TestReadiness {
+        is_readable: Rc<Cell<bool>>,
+        waker: Rc<RefCell<Option<Waker>>>,
+    }
+
+    impl Reactor for TestReactor {
+        type Readiness = TestReadiness;
+
+        fn register(&self, file_descriptor: i32) -> std::io::Result<TestReadiness> {
+            self.registered_file_descriptors
+                .borrow_mut()
+                .push(file_descriptor);
+            Ok(TestReadiness {
+                is_readable: self.is_readable.clone(),
+                waker: self.waker.clone(),
+            })
+        }
+    }
+
+    impl Readiness for TestReadiness {
+        fn poll_readable(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            if self.is_readable.replace(false) {
+                Poll::Ready(Ok(()))
+            } else {
+                *self.waker.borrow_mut() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    const TIMEOUT: Duration = Duration::from_millis(100);
+    const MAX_NUMBER_OF_REACTOR_TURNS: usize = 50;
+
+    fn is_open(file_descriptor: i32) -> bool {
+        // FileDescriptor::new() verifies the file descriptor but takes the ownership
+        FileDescriptor::new(file_descriptor)
+            .map(std::mem::forget)
+            .is_some()
+    }
+
+    struct Registration {
+        file_descriptor: FileDescriptor,
+        is_readable: Cell<bool>,
+        waker: RefCell<Option<Waker>>,
+    }
+
+    impl FileDescriptorBased for Registration {
+        fn file_descriptor(&self) -> &FileDescriptor {
+            &self.file_descriptor
+        }
+    }
+
+    impl SynchronousMultiplexing for Registration {}
+
+    // a reactor that waits with select on the file descriptors of all registered ports
+    #[derive(Default)]
+    struct SelectReactor {
+        registrations: Rc<RefCell<Vec<Rc<Registration>>>>,
+        was_open_on_deregistration: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl SelectReactor {
+        fn turn(&self, timeout: Duration) {
+            let registrations = self.registrations.borrow().clone();
+            let fd_set = FileDescriptorSet::new();
+            let _guards: Vec<_> = registrations
+                .iter()
+                .map(|r| fd_set.add(r.as_ref()).unwrap())
+                .collect();
+
+            let mut readable_file_descriptors = vec![];
+            fd_set
+                .timed_wait(timeout, FileEvent::Read, |fd| {
+                    readable_file_descriptors.push(unsafe { fd.native_handle() })
+                })
+                .unwrap();
+
+            for registration in &registrations {
+                if readable_file_descriptors
+                    .contains(&unsafe { registration.file_descriptor.native_handle() })
+                {
+                    registration.is_readable.set(true);
+                    if let Some(waker) = registration.waker.borrow_mut().take() {
+                        waker.wake();
+                    }
+                }
+            }
+        }
+
+        fn block_on<F: Future>(&self, mut future: std::pin::Pin<&mut F>) -> Option<F::Output> {
+            let waker = Waker::from(Arc::new(TestWaker::default()));
+            let mut cx = Context::from_waker(&waker);
+
+            for _ in 0..MAX_NUMBER_OF_REACTOR_TURNS {
+                if let Poll::Ready(v) = future.as_mut().poll(&mut cx) {
+                    return Some(v);
+                }
+                self.turn(TIMEOUT);
+            }
+
+            None
+        }
+    }
+
+    struct SelectReadiness {
+        registration: Rc<Registration>,
+        registrations: Rc<RefCell<Vec<Rc<Registration>>>>,
+        was_open_on_deregistration: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl Drop for SelectReadiness {
+        fn drop(&mut self) {
+            let file_descriptor = unsafe { self.registration.file_descriptor.native_handle() };
+            self.was_open_on_deregistration
+                .borrow_mut()
+                .push(is_open(file_descriptor));
+            self.registrations
+                .borrow_mut()
+                .retain(|r| !Rc::ptr_eq(r, &self.registration));
+        }
+    }
+
+    impl Reactor for SelectReactor {
+        type Readiness = SelectReadiness;
+
+        fn register(&self, file_descriptor: i32) -> std::io::Result<SelectReadiness> {
+            let registration = Rc::new(Registration {
+                file_descriptor: FileDescriptor::non_owning_new(file_descriptor)
+                    .ok_or(std::io::ErrorKind::InvalidInput)?,
+                is_readable: Cell::new(false),
+                waker: RefCell::new(None),
+            });
+            self.registrations.borrow_mut().push(registration.clone());
+
+            Ok(SelectReadiness {
+                registration,
+                registrations: self.registrations.clone(),
+                was_open_on_deregistration: self.was_open_on_deregistration.clone(),
+            })
+        }
+    }
+
+    impl Readiness for SelectReadiness {
+        fn poll_readable(&self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+            if self.registration.is_readable.replace(false) {
+                Poll::Ready(Ok(()))
+            } else {
+                *self.registration.waker.borrow_mut() = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn async_listener_registers_file_descriptor_at_reactor() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let reactor = TestReactor::default();
+
+        let sut = AsyncListener::new(event.listener().create().unwrap(), &reactor);
+        assert_that!(sut, is_ok);
+        assert_that!(*reactor.registered_file_descriptors.borrow(), len 1);
+    }
+
+    #[test]
+    fn async_listener_wait_is_pending_until_event_arrives() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let reactor = TestReactor::default();
+        let notifier = event.notifier().create().unwrap();
+        let mut sut = AsyncListener::new(event.listener().create().unwrap(), &reactor).unwrap();
+
+        let test_waker = Arc::new(TestWaker::default());
+        let waker = Waker::from(test_waker.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut wait = std::pin::pin!(sut.wait());
+
+        assert_that!(wait.as_mut().poll(&mut cx).is_pending(), eq true);
+
+        assert_that!(
+            notifier.notify_with_custom_event_id(EventId::new(12)),
+            is_ok
+        );
+        reactor.signal_readable();
+        assert_that!(test_waker.is_woken.load(Ordering::Relaxed), eq true);
+
+        match wait.as_mut().poll(&mut cx) {
+            Poll::Ready(Ok(event_ids)) => assert_that!(event_ids, eq[EventId::new(12)]),
+            v => unreachable!(
+                "The future must be ready with a result but returned {:?}.",
+                v
+            ),
+        }
+    }
+
+    #[test]
+    fn async_listener_wait_returns_immediately_when_events_are_present() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let reactor = TestReactor::default();
+        let notifier = event.notifier().create().unwrap();
+        let mut sut = AsyncListener::new(event.listener().create().unwrap(), &reactor).unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(3)), is_ok);
+
+        let waker = Waker::from(Arc::new(TestWaker::default()));
+        let mut cx = Context::from_waker(&waker);
+        let mut wait = std::pin::pin!(sut.wait());
+
+        match wait.as_mut().poll(&mut cx) {
+            Poll::Ready(Ok(event_ids)) => assert_that!(event_ids, eq[EventId::new(3)]),
+            v => unreachable!(
+                "The future must be ready with a result but returned {:?}.",
+                v
+            ),
+        }
+    }
+
+    #[test]
+    fn async_subscriber_requires_notifications() {
+        let service = zero_copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let reactor = TestReactor::default();
+
+        let sut = AsyncSubscriber::new(service.subscriber().create().unwrap(), &reactor);
+        assert_that!(sut.err().unwrap(), eq AsyncSubscriberCreateError::NotificationsNotEnabled);
+    }
+
+    #[test]
+    fn async_subscriber_receive_is_pending_until_sample_arrives() {
+        let service = zero_copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let reactor = TestReactor::default();
+        let sut = AsyncSubscriber::new(service.subscriber().create().unwrap(), &reactor).unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let waker = Waker::from(Arc::new(TestWaker::default()));
+        let mut cx = Context::from_waker(&waker);
+        let mut receive = std::pin::pin!(sut.receive());
+
+        assert_that!(receive.as_mut().poll(&mut cx).is_pending(), eq true);
+
+        assert_that!(publisher.send_copy(8912), is_ok);
+        reactor.signal_readable();
+
+        match receive.as_mut().poll(&mut cx) {
+            Poll::Ready(Ok(sample)) => assert_that!(*sample, eq 8912),
+            v => unreachable!(
+                "The future must be ready with a result but returned {:?}.",
+                v
+            ),
+        }
+    }
+
+    #[test]
+    fn async_listener_file_descriptor_becomes_readable_when_notified() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let reactor = SelectReactor::default();
+        let notifier = event.notifier().create().unwrap();
+        let _sut = AsyncListener::new(event.listener().create().unwrap(), &reactor).unwrap();
+        let registration = reactor.registrations.borrow()[0].clone();
+
+        reactor.turn(Duration::ZERO);
+        assert_that!(registration.is_readable.get(), eq false);
+
+        assert_that!(notifier.notify(), is_ok);
+        reactor.turn(TIMEOUT);
+        assert_that!(registration.is_readable.get(), eq true);
+    }
+
+    #[test]
+    fn async_listener_wait_does_not_lose_event_notified_before_reactor_waits() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let reactor = SelectReactor::default();
+        let notifier = event.notifier().create().unwrap();
+        let mut sut = AsyncListener::new(event.listener().create().unwrap(), &reactor).unwrap();
+
+        let waker = Waker::from(Arc::new(TestWaker::default()));
+        let mut cx = Context::from_waker(&waker);
+        let mut wait = std::pin::pin!(sut.wait());
+        assert_that!(wait.as_mut().poll(&mut cx).is_pending(), eq true);
+
+        // the event arrives after the listener found no events but before the reactor waits
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), is_ok);
+
+        match reactor.block_on(wait) {
+            Some(Ok(event_ids)) => assert_that!(event_ids, eq[EventId::new(5)]),
+            v => unreachable!("The event must be received but the result is {:?}.", v),
+        }
+    }
+
+    #[test]
+    fn async_subscriber_receive_does_not_lose_sample_sent_before_reactor_waits() {
+        let service = zero_copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let reactor = SelectReactor::default();
+        let sut = AsyncSubscriber::new(service.subscriber().create().unwrap(), &reactor).unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let waker = Waker::from(Arc::new(TestWaker::default()));
+        let mut cx = Context::from_waker(&waker);
+        let mut receive = std::pin::pin!(sut.receive());
+        assert_that!(receive.as_mut().poll(&mut cx).is_pending(), eq true);
+
+        // the sample arrives after the subscriber found no samples but before the reactor waits
+        assert_that!(publisher.send_copy(7281), is_ok);
+
+        match reactor.block_on(receive) {
+            Some(Ok(sample)) => assert_that!(*sample, eq 7281),
+            v => unreachable!("The sample must be received but the result is {:?}.", v),
+        }
+    }
+
+    #[test]
+    fn async_ports_deregister_file_descriptor_before_it_is_closed() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let service = zero_copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let reactor = SelectReactor::default();
+
+        let listener = AsyncListener::new(event.listener().create().unwrap(), &reactor).unwrap();
+        let subscriber =
+            AsyncSubscriber::new(service.subscriber().create().unwrap(), &reactor).unwrap();
+        assert_that!(*reactor.registrations.borrow(), len 2);
+
+        drop(listener);
+        drop(subscriber);
+
+        assert_that!(*reactor.registrations.borrow(), len 0);
+        assert_that!(*reactor.was_open_on_deregistration.borrow(), eq vec![true, true]);
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Test harness for tests that require a second process which is killed while it owns
+//! resources.
+//!
+//! The child process is the test binary itself that executes exactly one test, the child
+//! process test. The service name is handed over via an environment variable so that the
+//! child process test returns immediately when it is executed as an ordinary test.
+//!
+//! ```ignore
+//! #[test]
+//! fn child_process() {
+//!     let service_name = match child_process_service_name() {
+//!         Some(v) => v,
+//!         None => return,
+//!     };
+//!
+//!     // acquire the resources
+//!
+//!     wait_until_killed();
+//! }
+//! ```
+
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+
+const SERVICE_NAME_ENV: &str = "IOX2_TEST_CHILD_PROCESS_SERVICE_NAME";
+const TIMEOUT: Duration = Duration::from_secs(10);
+const CYCLE_TIME: Duration = Duration::from_millis(1);
+
+/// Returns a unique name that starts with the provided prefix.
+pub fn generate_name(prefix: &str) -> String {
+    format!("{}_{}", prefix, UniqueSystemId::new().unwrap().value())
+}
+
+/// Waits until the condition is satisfied and fails the test when it is not satisfied within
+/// the timeout.
+pub fn wait_for<F: Fn() -> bool>(condition: F) {
+    let start = Instant::now();
+    while !condition() {
+        assert_that!(start.elapsed(), lt TIMEOUT);
+        std::thread::sleep(CYCLE_TIME);
+    }
+}
+
+/// Spawns the test binary as child process that executes only the test with the provided
+/// path, e.g. `"my_module::child_process"`, and hands over the service name.
+pub fn spawn_child_process(test_path: &str, service_name: &str) -> Child {
+    Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test_path, "--nocapture"])
+        .env(SERVICE_NAME_ENV, service_name)
+        .spawn()
+        .unwrap()
+}
+
+/// Returns the service name when called from within a child process spawned with
+/// [`spawn_child_process()`], otherwise [`None`].
+pub fn child_process_service_name() -> Option<String> {
+    std::env::var(SERVICE_NAME_ENV).ok()
+}
+
+/// Blocks the child process so that it holds its resources until it is killed.
+pub fn wait_until_killed() -> ! {
+    loop {
+        std::thread::sleep(TIMEOUT);
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod config {
+    use iceoryx2::config::{Config, ConfigCreationError};
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::config::TEMP_DIRECTORY;
+    use iceoryx2_bb_posix::file::{CreationMode, File, FileBuilder};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+
+    struct ConfigFile {
+        path: FilePath,
+    }
+
+    impl ConfigFile {
+        fn new(contents: &str) -> Self {
+            let mut file = FileName::new(b"config_tests_").unwrap();
+            file.push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+            let path = FilePath::from_path_and_file(&TEMP_DIRECTORY, &file).unwrap();
+
+            let mut file = FileBuilder::new(&path)
+                .creation_mode(CreationMode::PurgeAndCreate)
+                .create()
+                .unwrap();
+            file.write(contents.as_bytes()).unwrap();
+
+            Self { path }
+        }
+    }
+
+    impl Drop for ConfigFile {
+        fn drop(&mut self) {
+            File::remove(&self.path).ok();
+        }
+    }
+
+    fn no_environment() -> Vec<(String, String)> {
+        vec![]
+    }
+
+    fn environment(name: &str, value: &str) -> Vec<(String, String)> {
+        vec![(name.to_string(), value.to_string())]
+    }
+
+    #[test]
+    fn config_without_layers_contains_default_values() {
+        let sut = Config::from_layers(&[], no_environment()).unwrap();
+        let defaults = Config::default();
+
+        assert_that!(sut.defaults.publish_subscribe.max_publishers, eq defaults.defaults.publish_subscribe.max_publishers);
+        assert_that!(sut.global.service.directory, eq defaults.global.service.directory);
+        assert_that!(sut.services, len 0);
+    }
+
+    #[test]
+    fn config_file_only_needs_to_contain_overridden_entries() {
+        let file = ConfigFile::new(
+            r#"
+            [defaults.event]
+            max_listeners = 7
+            "#,
+        );
+
+        let sut = Config::from_file(&file.path).unwrap();
+        let defaults = Config::default();
+
+        assert_that!(sut.defaults.event.max_listeners, eq 7);
+        assert_that!(sut.defaults.event.max_notifiers, eq defaults.defaults.event.max_notifiers);
+        assert_that!(sut.global.prefix, eq defaults.global.prefix);
+    }
+
+    #[test]
+    fn later_layers_override_earlier_layers() {
+        let system = ConfigFile::new(
+            r#"
+            [defaults.publish_subscribe]
+            max_publishers = 3
+            max_subscribers = 4
+            "#,
+        );
+        let user = ConfigFile::new(
+            r#"
+            [defaults.publish_subscribe]
+            max_subscribers = 5
+            "#,
+        );
+
+        let sut = Config::from_layers(&[system.path, user.path], no_environment()).unwrap();
+
+        assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 3);
+        assert_that!(sut.defaults.publish_subscribe.max_subscribers, eq 5);
+    }
+
+    #[test]
+    fn not_existing_config_files_are_skipped() {
+        let file = ConfigFile::new(
+            r#"
+            [defaults.blackboard]
+            max_readers = 3
+            "#,
+        );
+        let not_existing = FilePath::new(b"/some/not/existing/iceoryx2.toml").unwrap();
+
+        let sut = Config::from_layers(&[not_existing, file.path], no_environment()).unwrap();
+
+        assert_that!(sut.defaults.blackboard.max_readers, eq 3);
+    }
+
+    #[test]
+    fn environment_variables_override_config_files() {
+        let file = ConfigFile::new(
+            r#"
+            [defaults.publish_subscribe]
+            max_publishers = 3
+            "#,
+        );
+
+        let mut env = environment("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS", "9");
+        env.push((
+            "IOX2_GLOBAL_SERVICE_CREATION_TIMEOUT_SECS".to_string(),
+            "11".to_string(),
+        ));
+        env.push((
+            "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_ENABLE_SAFE_OVERFLOW".to_string(),
+            "false".to_string(),
+        ));
+        env.push(("IOX2_GLOBAL_PREFIX".to_string(), "env_".to_string()));
+
+        let sut = Config::from_layers(&[file.path], env).unwrap();
+
+        assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 9);
+        assert_that!(sut.defaults.publish_subscribe.enable_safe_overflow, eq false);
+        assert_that!(sut.global.service.creation_timeout.as_secs(), eq 11);
+        assert_that!(sut.global.prefix, eq "env_");
+    }
+
+    #[test]
+    fn environment_variables_without_prefix_or_entry_are_ignored() {
+        let mut env = environment("IOX2_SOME_UNKNOWN_ENTRY", "1");
+        env.push(("DEFAULTS_EVENT_MAX_LISTENERS".to_string(), "0".to_string()));
+
+        let sut = Config::from_layers(&[], env).unwrap();
+
+        assert_that!(sut.defaults.event.max_listeners, eq Config::default().defaults.event.max_listeners);
+    }
+
+    #[test]
+    fn environment_variable_with_invalid_value_fails() {
+        let sut = Config::from_layers(
+            &[],
+            environment("IOX2_DEFAULTS_EVENT_MAX_LISTENERS", "many"),
+        );
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq ConfigCreationError::InvalidEnvironmentVariable);
+    }
+
+    #[test]
+    fn config_file_with_unknown_key_fails() {
+        let file = ConfigFile::new(
+            r#"
+            [defaults.publish_subscribe]
+            max_publisher = 3
+            "#,
+        );
+
+        let sut = Config::from_layers(&[file.path], no_environment());
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq ConfigCreationError::UnknownKey);
+    }
+
+    #[test]
+    fn service_override_with_unknown_key_fails() {
+        let file = ConfigFile::new(
+            r#"
+            [services."camera/*".event]
+            max_listener = 3
+            "#,
+        );
+
+        let sut = Config::from_layers(&[file.path], no_environment());
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq ConfigCreationError::UnknownKey);
+    }
+
+    #[test]
+    fn config_file_with_out_of_range_value_fails() {
+        let file = ConfigFile::new(
+            r#"
+            [defaults.request_response]
+            max_servers = 0
+            "#,
+        );
+
+        let sut = Config::from_layers(&[file.path], no_environment());
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq ConfigCreationError::ValueOutOfRange);
+    }
+
+    #[test]
+    fn history_larger_than_buffer_without_safe_overflow_fails() {
+        let env = vec![
+            (
+                "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_ENABLE_SAFE_OVERFLOW".to_string(),
+                "false".to_string(),
+            ),
+            (
+                "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_SUBSCRIBER_MAX_BUFFER_SIZE".to_string(),
+                "2".to_string(),
+            ),
+            (
+                "IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_PUBLISHER_HISTORY_SIZE".to_string(),
+                "3".to_string(),
+            ),
+        ];
+
+        let sut = Config::from_layers(&[], env);
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq ConfigCreationError::ValueOutOfRange);
+    }
+
+    #[test]
+    fn service_overrides_are_applied_on_final_defaults() {
+        let file = ConfigFile::new(
+            r#"
+            [services."camera/*".publish_subscribe]
+            max_subscribers = 2
+
+            [defaults.publish_subscribe]
+            max_publishers = 3
+            "#,
+        );
+
+        let sut = Config::from_layers(
+            &[file.path],
+            environment("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS", "5"),
+        )
+        .unwrap();
+
+        let camera = sut.defaults_for(&ServiceName::new("camera/front").unwrap());
+        assert_that!(camera.publish_subscribe.max_subscribers, eq 2);
+        assert_that!(camera.publish_subscribe.max_publishers, eq 5);
+
+        let lidar = sut.defaults_for(&ServiceName::new("lidar/front").unwrap());
+        assert_that!(lidar.publish_subscribe.max_subscribers, eq sut.defaults.publish_subscribe.max_subscribers);
+    }
+
+    #[test]
+    fn most_specific_service_pattern_is_used() {
+        let file = ConfigFile::new(
+            r#"
+            [services."*".event]
+            max_listeners = 3
+
+            [services."camera/*".event]
+            max_listeners = 4
+
+            [services."camera/front".event]
+            max_listeners = 5
+            "#,
+        );
+
+        let sut = Config::from_layers(&[file.path], no_environment()).unwrap();
+
+        assert_that!(sut.defaults_for(&ServiceName::new("radar").unwrap()).event.max_listeners, eq 3);
+        assert_that!(sut.defaults_for(&ServiceName::new("camera/back").unwrap()).event.max_listeners, eq 4);
+        assert_that!(sut.defaults_for(&ServiceName::new("camera/front").unwrap()).event.max_listeners, eq 5);
+    }
+
+    #[test]
+    fn service_builder_uses_service_overrides() {
+        let mut config = Config::default();
+        let mut camera_defaults = config.defaults.clone();
+        camera_defaults.publish_subscribe.max_subscribers = 3;
+        camera_defaults.event.max_notifiers = 2;
+        config
+            .services
+            .insert("config_tests_camera_*".to_string(), camera_defaults);
+
+        let id = UniqueSystemId::new().unwrap().value();
+        let camera_name = ServiceName::new(&format!("config_tests_camera_{}", id)).unwrap();
+        let camera_event_name =
+            ServiceName::new(&format!("config_tests_camera_event_{}", id)).unwrap();
+        let other_name = ServiceName::new(&format!("config_tests_other_{}", id)).unwrap();
+
+        let camera = zero_copy::Service::new(&camera_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(camera.static_config().max_supported_subscribers(), eq 3);
+
+        let camera_event = zero_copy::Service::new(&camera_event_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+        assert_that!(camera_event.static_config().max_supported_notifiers(), eq 2);
+
+        let other = zero_copy::Service::new(&other_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(other.static_config().max_supported_subscribers(), eq config.defaults.publish_subscribe.max_subscribers);
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+mod dead_port_cleanup {
+    use crate::common::*;
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
+
+    type CopyChannel = <zero_copy::Service<'static> as Details<'static>>::CopyChannel;
+
+    fn event_service_name(service_name: &str) -> ServiceName {
+        ServiceName::new(&format!("{}_event", service_name)).unwrap()
+    }
+
+    fn blackboard_service_name(service_name: &str) -> ServiceName {
+        ServiceName::new(&format!("{}_blackboard", service_name)).unwrap()
+    }
+
+    fn list_copy_channels() -> Vec<FileName> {
+        let config = Config::get_global_config();
+        let copy_channel_config = <CopyChannel as NamedConceptMgmt>::Configuration::default()
+            .prefix(FileName::new(config.global.prefix.as_bytes()).unwrap())
+            .suffix(FileName::new(config.global.service.copy_channel_suffix.as_bytes()).unwrap())
+            .path_hint(Path::new(config.global.root_path.as_bytes()).unwrap());
+
+        CopyChannel::list_cfg(&copy_channel_config).unwrap()
+    }
+
+    /// Is executed by the child process spawned in the tests below and does nothing when it is
+    /// executed as an ordinary test.
+    #[test]
+    fn port_owner_process() {
+        let service_name = match child_process_service_name() {
+            Some(v) => v,
+            None => return,
+        };
+
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let event = zero_copy::Service::new(&event_service_name(&service_name))
+            .event()
+            .open()
+            .unwrap();
+        let blackboard = zero_copy::Service::new(&blackboard_service_name(&service_name))
+            .blackboard::<u64>()
+            .open()
+            .unwrap();
+
+        let subscriber = service.subscriber().create().unwrap();
+        let publisher = service.publisher().create().unwrap();
+        publisher.send_copy(1234).unwrap();
+        let _sample = subscriber.receive().unwrap();
+        let _listener = event.listener().create().unwrap();
+        let _reader = blackboard.reader().create().unwrap();
+        let writer = blackboard.writer().create().unwrap();
+        writer.write(0, 1234).unwrap();
+
+        // hold the ports until the process is killed
+        wait_until_killed();
+    }
+
+    #[test]
+    fn cleanup_removes_ports_of_killed_process() {
+        let service_name = generate_name("dead_port_cleanup_tests");
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .max_publishers(1)
+            .max_subscribers(1)
+            .create::<u64>()
+            .unwrap();
+        let event = zero_copy::Service::new(&event_service_name(&service_name))
+            .event()
+            .max_listeners(1)
+            .create()
+            .unwrap();
+        let _blackboard = zero_copy::Service::new(&blackboard_service_name(&service_name))
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let mut child = spawn_child_process("dead_port_cleanup::port_owner_process", &service_name);
+        wait_for(|| event.dynamic_config().number_of_listeners() == 1);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+
+        assert_that!(service.cleanup_dead_ports(), eq 0);
+        assert_that!(event.cleanup_dead_ports(), eq 0);
+        assert_that!(service.publisher().create(), is_err);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_that!(service.cleanup_dead_ports(), eq 2);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 0);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+        assert_that!(event.cleanup_dead_ports(), eq 1);
+        assert_that!(event.dynamic_config().number_of_listeners(), eq 0);
+
+        assert_that!(service.cleanup_dead_ports(), eq 0);
+        assert_that!(event.cleanup_dead_ports(), eq 0);
+
+        let publisher = service.publisher().create();
+        assert_that!(publisher, is_ok);
+        let subscriber = service.subscriber().create();
+        assert_that!(subscriber, is_ok);
+        assert_that!(event.listener().create(), is_ok);
+
+        assert_that!(publisher.unwrap().send_copy(5678), is_ok);
+        assert_that!(*subscriber.unwrap().receive().unwrap().unwrap(), eq 5678);
+    }
+
+    #[test]
+    fn cleanup_removes_copy_channel_of_killed_subscriber() {
+        let service_name = generate_name("dead_port_cleanup_tests");
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+        let event = zero_copy::Service::new(&event_service_name(&service_name))
+            .event()
+            .create()
+            .unwrap();
+        let _blackboard = zero_copy::Service::new(&blackboard_service_name(&service_name))
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        // no other test of this binary uses copy transfer
+        let copy_channels = list_copy_channels();
+        let mut child = spawn_child_process("dead_port_cleanup::port_owner_process", &service_name);
+        wait_for(|| event.dynamic_config().number_of_listeners() == 1);
+
+        let copy_channels_of_child: Vec<FileName> = list_copy_channels()
+            .into_iter()
+            .filter(|c| !copy_channels.contains(c))
+            .collect();
+        assert_that!(copy_channels_of_child, len 1);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_that!(service.cleanup_dead_ports(), eq 2);
+        assert_that!(list_copy_channels().contains(&copy_channels_of_child[0]), eq false);
+    }
+
+    #[test]
+    fn cleanup_releases_blackboard_entries_of_killed_writer() {
+        let service_name = generate_name("dead_port_cleanup_tests");
+        let _service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let _event = zero_copy::Service::new(&event_service_name(&service_name))
+            .event()
+            .create()
+            .unwrap();
+        let blackboard = zero_copy::Service::new(&blackboard_service_name(&service_name))
+            .blackboard::<u64>()
+            .add(0, 0)
+            .add(1, 0)
+            .create()
+            .unwrap();
+
+        let reader = blackboard.reader().create().unwrap();
+
+        let mut child = spawn_child_process("dead_port_cleanup::port_owner_process", &service_name);
+        wait_for(|| reader.read(0) == Ok(1234));
+        assert_that!(blackboard.dynamic_config().number_of_writers(), eq 1);
+        assert_that!(blackboard.dynamic_config().number_of_readers(), eq 2);
+        assert_that!(blackboard.writer().create(), is_err);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_that!(blackboard.cleanup_dead_ports(), eq 2);
+        assert_that!(blackboard.dynamic_config().number_of_writers(), eq 0);
+        assert_that!(blackboard.dynamic_config().number_of_readers(), eq 1);
+
+        let writer = blackboard.writer().create();
+        assert_that!(writer, is_ok);
+        assert_that!(writer.unwrap().write(1, 5678), is_ok);
+        assert_that!(reader.read(0), eq Ok(1234));
+        assert_that!(reader.read(1), eq Ok(5678));
+    }
+
+    #[test]
+    fn cleanup_keeps_ports_of_alive_processes() {
+        let service_name = generate_name("dead_port_cleanup_tests");
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let _publisher = service.publisher().create().unwrap();
+        let _subscriber = service.subscriber().create().unwrap();
+
+        assert_that!(service.cleanup_dead_ports(), eq 0);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+#[generic_tests::define]
+mod node {
+    use iceoryx2::config::Config;
+    use iceoryx2::node::{node_name::NodeName, Node, NodeBuilder, PortType};
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "node_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    /// Every test uses its own node registry so that the tests do not see each others nodes.
+    fn generate_config() -> Config {
+        let mut config = Config::default();
+        config.global.node.directory =
+            format!("node_tests_{}", UniqueSystemId::new().unwrap().value());
+        config
+    }
+
+    #[test]
+    fn node_is_listed_while_it_exists<Sut: Service>() {
+        let config = generate_config();
+        let node_name = NodeName::new("my_funky_node").unwrap();
+
+        let sut = NodeBuilder::new(&node_name)
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+        assert_that!(*sut.name(), eq node_name);
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].id(), eq sut.id());
+        assert_that!(*nodes[0].name(), eq node_name);
+        assert_that!(nodes[0].pid(), eq Process::from_self().id());
+        assert_that!(nodes[0].is_alive(), eq true);
+        assert_that!(nodes[0].ports(), len 0);
+
+        drop(sut);
+
+        assert_that!(Node::<Sut>::list_with_custom_config(&config).unwrap(), len 0);
+    }
+
+    #[test]
+    fn multiple_nodes_are_listed<Sut: Service>() {
+        const NUMBER_OF_NODES: usize = 4;
+        let config = generate_config();
+
+        let mut nodes = vec![];
+        for i in 0..NUMBER_OF_NODES {
+            nodes.push(
+                NodeBuilder::new(&NodeName::new(&format!("node_{}", i)).unwrap())
+                    .config(&config)
+                    .create::<Sut>()
+                    .unwrap(),
+            );
+        }
+
+        let sut = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(sut, len NUMBER_OF_NODES);
+        for node in &nodes {
+            assert_that!(sut.iter().filter(|n| n.id() == node.id()).count(), eq 1);
+        }
+
+        nodes.pop();
+        assert_that!(Node::<Sut>::list_with_custom_config(&config).unwrap(), len NUMBER_OF_NODES - 1);
+    }
+
+    #[test]
+    fn nodes_with_the_same_name_have_different_ids<Sut: Service>() {
+        let config = generate_config();
+        let node_name = NodeName::new("my_funky_node").unwrap();
+
+        let sut1 = NodeBuilder::new(&node_name)
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+        let sut2 = NodeBuilder::new(&node_name)
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+
+        assert_that!(sut1.id(), ne sut2.id());
+        assert_that!(Node::<Sut>::list_with_custom_config(&config).unwrap(), len 2);
+    }
+
+    #[test]
+    fn ports_created_via_node_are_listed<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let sut = NodeBuilder::new(&NodeName::new("my_funky_node").unwrap())
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let event = sut.service_builder(&service_name).event().create().unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+        let listener = event.listener().create().unwrap();
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        let ports = nodes[0].ports();
+        assert_that!(ports, len 3);
+        for port in ports {
+            assert_that!(*port.service_name(), eq service_name);
+        }
+
+        let publisher_port = ports
+            .iter()
+            .find(|p| p.port_type() == PortType::Publisher)
+            .unwrap();
+        assert_that!(publisher_port.port_id(), eq publisher.id().value());
+        assert_that!(publisher_port.service_uuid(), eq service.uuid());
+        assert_that!(ports.iter().filter(|p| p.port_type() == PortType::Subscriber).count(), eq 1);
+        assert_that!(ports.iter().filter(|p| p.port_type() == PortType::Listener).count(), eq 1);
+
+        drop(publisher);
+        drop(listener);
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes[0].ports(), len 1);
+        assert_that!(nodes[0].ports()[0].port_type(), eq PortType::Subscriber);
+        assert_that!(nodes[0].ports()[0].port_id(), eq subscriber.id().value());
+    }
+
+    #[test]
+    fn ports_of_services_not_created_via_node_are_not_listed<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let _sut = NodeBuilder::new(&NodeName::new("my_funky_node").unwrap())
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+
+        let service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let _publisher = service.publisher().create().unwrap();
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].ports(), len 0);
+    }
+
+    #[test]
+    fn ports_are_listed_at_the_node_that_created_the_service<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let node_a = NodeBuilder::new(&NodeName::new("node_a").unwrap())
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+        let node_b = NodeBuilder::new(&NodeName::new("node_b").unwrap())
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+
+        let service_a = node_a
+            .service_builder(&service_name)
+            .pipeline()
+            .create::<u64>()
+            .unwrap();
+        let service_b = node_b
+            .service_builder(&service_name)
+            .pipeline()
+            .open::<u64>()
+            .unwrap();
+
+        let _producer = service_a.producer().create().unwrap();
+        let _consumer_1 = service_b.consumer().create().unwrap();
+        let _consumer_2 = service_b.consumer().create().unwrap();
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        let view_a = nodes.iter().find(|n| n.id() == node_a.id()).unwrap();
+        let view_b = nodes.iter().find(|n| n.id() == node_b.id()).unwrap();
+
+        assert_that!(view_a.ports(), len 1);
+        assert_that!(view_a.ports()[0].port_type(), eq PortType::Producer);
+        assert_that!(view_b.ports(), len 2);
+        for port in view_b.ports() {
+            assert_that!(port.port_type(), eq PortType::Consumer);
+        }
+    }
+
+    #[test]
+    fn cleanup_dead_nodes_keeps_alive_nodes<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let sut = NodeBuilder::new(&NodeName::new("my_funky_node").unwrap())
+            .config(&config)
+            .create::<Sut>()
+            .unwrap();
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let _publisher = service.publisher().create().unwrap();
+
+        assert_that!(Node::<Sut>::cleanup_dead_nodes_with_custom_config(&config), eq Ok(0));
+
+        let nodes = Node::<Sut>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].ports(), len 1);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 1);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+mod node_cleanup {
+    use crate::common::*;
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    /// The node registry of the child process is identified by the service name, so that both
+    /// processes use the same registry without interfering with other tests.
+    fn config_for(service_name: &str) -> Config {
+        let mut config = Config::default();
+        config.global.node.directory = service_name.to_string();
+        config
+    }
+
+    /// Is executed by the child process spawned in the tests below and does nothing when it is
+    /// executed as an ordinary test.
+    #[test]
+    fn node_owner_process() {
+        let service_name = match child_process_service_name() {
+            Some(v) => v,
+            None => return,
+        };
+
+        let config = config_for(&service_name);
+        let node = NodeBuilder::new(&NodeName::new("node_owner").unwrap())
+            .config(&config)
+            .create::<zero_copy::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+
+        let _publisher = service.publisher().create().unwrap();
+        let _subscriber = service.subscriber().create().unwrap();
+
+        // hold the node until the process is killed
+        wait_until_killed();
+    }
+
+    #[test]
+    fn cleanup_removes_node_and_ports_of_killed_process() {
+        let service_name = generate_name("node_cleanup_tests");
+        let config = config_for(&service_name);
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe_with_custom_config(&config)
+            .max_publishers(1)
+            .max_subscribers(1)
+            .create::<u64>()
+            .unwrap();
+
+        let mut child = spawn_child_process("node_cleanup::node_owner_process", &service_name);
+        wait_for(|| {
+            Node::<zero_copy::Service>::list_with_custom_config(&config)
+                .unwrap()
+                .iter()
+                .any(|n| n.ports().len() == 2)
+        });
+
+        let nodes = Node::<zero_copy::Service>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].pid().value() as u32, eq child.id());
+        assert_that!(nodes[0].is_alive(), eq true);
+        assert_that!(
+            Node::<zero_copy::Service>::cleanup_dead_nodes_with_custom_config(&config),
+            eq Ok(0)
+        );
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        let nodes = Node::<zero_copy::Service>::list_with_custom_config(&config).unwrap();
+        assert_that!(nodes, len 1);
+        assert_that!(nodes[0].is_alive(), eq false);
+
+        assert_that!(
+            Node::<zero_copy::Service>::cleanup_dead_nodes_with_custom_config(&config),
+            eq Ok(1)
+        );
+        assert_that!(
+            Node::<zero_copy::Service>::list_with_custom_config(&config).unwrap(),
+            len 0
+        );
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 0);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+
+        let publisher = service.publisher().create();
+        assert_that!(publisher, is_ok);
+        let subscriber = service.subscriber().create();
+        assert_that!(subscriber, is_ok);
+    }
+}
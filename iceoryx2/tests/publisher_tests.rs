@@ -208,6 +208,9 @@ mod publisher {
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
+    #[instantiate_tests(<iceoryx2::service::zero_copy_directory::Service>)]
+    mod zero_copy_directory {}
+
     #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
     mod process_local {}
 }
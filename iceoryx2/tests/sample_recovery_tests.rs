@@ -0,0 +1,102 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod common;
+
+mod sample_recovery {
+    use std::time::Duration;
+
+    use crate::common::*;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    const CYCLE_TIME: Duration = Duration::from_millis(1);
+    const BUFFER_SIZE: usize = 2;
+    const MAX_BORROWED_SAMPLES: usize = 2;
+
+    fn ack_service_name(service_name: &str) -> ServiceName {
+        ServiceName::new(&format!("{}_ack", service_name)).unwrap()
+    }
+
+    /// Is executed by the child process spawned in
+    /// `publisher_recovers_samples_of_killed_subscriber_process()` and does nothing when it is
+    /// executed as an ordinary test.
+    #[test]
+    fn subscriber_process() {
+        let service_name = match child_process_service_name() {
+            Some(v) => v,
+            None => return,
+        };
+
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let mut borrowed_samples = vec![];
+        while borrowed_samples.len() < MAX_BORROWED_SAMPLES {
+            match subscriber.receive().unwrap() {
+                Some(sample) => borrowed_samples.push(sample),
+                None => std::thread::sleep(CYCLE_TIME),
+            }
+        }
+
+        let ack_service = zero_copy::Service::new(&ack_service_name(&service_name))
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        let _ack = ack_service.subscriber().create().unwrap();
+
+        // hold the samples until the process is killed
+        wait_until_killed();
+    }
+
+    #[test]
+    fn publisher_recovers_samples_of_killed_subscriber_process() {
+        let service_name = generate_name("sample_recovery_tests");
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .create::<u64>()
+            .unwrap();
+        let ack_service = zero_copy::Service::new(&ack_service_name(&service_name))
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let mut child = spawn_child_process("sample_recovery::subscriber_process", &service_name);
+        wait_for(|| service.dynamic_config().number_of_subscribers() == 1);
+
+        // the subscriber borrows the first samples and acknowledges it
+        for i in 0..MAX_BORROWED_SAMPLES {
+            assert_that!(publisher.send_copy(i as u64).unwrap(), eq 1);
+        }
+        wait_for(|| ack_service.dynamic_config().number_of_subscribers() == 1);
+
+        // the remaining samples stay in the subscribers buffer
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i as u64).unwrap(), eq 1);
+        }
+
+        assert_that!(publisher.recover_samples_from_dead_subscribers(), eq 0);
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert_that!(publisher.recover_samples_from_dead_subscribers(), eq BUFFER_SIZE + MAX_BORROWED_SAMPLES);
+        assert_that!(publisher.recover_samples_from_dead_subscribers(), eq 0);
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_access_control {
+    use iceoryx2::port::listener::ListenerCreateError;
+    use iceoryx2::port::notifier::NotifierCreateError;
+    use iceoryx2::port::publisher::PublisherCreateError;
+    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::service::access_control::{Operation, Subject};
+    use iceoryx2::service::builder::event::EventCreateError;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::group::Group;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::named_concept::MAX_NUMBER_OF_ACCESS_RIGHTS;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_access_control_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn service_without_rules_is_unrestricted<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().is_restricted(), eq false);
+        assert_that!(sut.access_control().is_permitted(Operation::Publish), eq true);
+        assert_that!(sut.access_control().is_permitted(Operation::Subscribe), eq true);
+        assert_that!(sut.publisher().create(), is_ok);
+        assert_that!(sut.subscriber().create(), is_ok);
+    }
+
+    #[test]
+    fn permitted_user_can_publish_and_subscribe<Sut: Service>() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid, Operation::Publish)
+            .permit_user(uid, Operation::Subscribe)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().is_restricted(), eq true);
+        assert_that!(sut.access_control().rules(), len 2);
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn operation_without_permission_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid, Operation::Publish)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.publisher().create(), is_ok);
+
+        let subscriber = sut.subscriber().create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::PermissionDenied);
+    }
+
+    #[test]
+    fn rules_of_other_users_do_not_permit_operation<Sut: Service>() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid + 1, Operation::Publish)
+            .permit_user(uid, Operation::Subscribe)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create();
+        assert_that!(publisher, is_err);
+        assert_that!(publisher.err().unwrap(), eq PublisherCreateError::PermissionDenied);
+        assert_that!(sut.subscriber().create(), is_ok);
+    }
+
+    #[test]
+    fn permitted_group_can_notify_and_listen<Sut: Service>() {
+        let service_name = generate_name();
+        let gid = User::from_self().unwrap().gid();
+        let sut = Sut::new(&service_name)
+            .event()
+            .permit_group(gid, Operation::Notify)
+            .permit_group(gid, Operation::Listen)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.access_control().rules(), len 2);
+        for rule in sut.access_control().rules() {
+            assert_that!(rule.subject(), eq Subject::Group);
+            assert_that!(rule.id(), eq gid);
+        }
+
+        let mut listener = sut.listener().create().unwrap();
+        let notifier = sut.notifier().create().unwrap();
+
+        // the notifier is granted access to the event connection of the listener
+        assert_that!(notifier.notify(), eq Ok(1));
+        assert_that!(listener.try_wait().unwrap(), len 1);
+    }
+
+    #[test]
+    fn event_operations_without_permission_fail<Sut: Service>() {
+        let service_name = generate_name();
+        let gid = User::from_self().unwrap().gid();
+        let sut = Sut::new(&service_name)
+            .event()
+            .permit_group(gid, Operation::Notify)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.notifier().create(), is_ok);
+
+        let listener = sut.listener().create();
+        assert_that!(listener, is_err);
+        assert_that!(listener.err().unwrap(), eq ListenerCreateError::PermissionDenied);
+
+        let sut = Sut::new(&generate_name())
+            .event()
+            .permit_group(gid, Operation::Listen)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.listener().create(), is_ok);
+
+        let notifier = sut.notifier().create();
+        assert_that!(notifier, is_err);
+        assert_that!(notifier.err().unwrap(), eq NotifierCreateError::PermissionDenied);
+    }
+
+    #[test]
+    fn permitted_supplementary_group_can_publish<Sut: Service>() {
+        let user = User::from_self().unwrap();
+        let gid = match Group::supplementary_gids_of_self()
+            .unwrap()
+            .into_iter()
+            .find(|gid| *gid != user.gid())
+        {
+            Some(gid) => gid,
+            // the process is not member of a supplementary group
+            None => return,
+        };
+
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe()
+            .permit_group(gid, Operation::Publish)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().is_permitted(Operation::Publish), eq true);
+        assert_that!(sut.publisher().create(), is_ok);
+    }
+
+    #[test]
+    fn publish_subscribe_service_with_event_operation_cannot_be_created<Sut: Service>() {
+        let uid = User::from_self().unwrap().uid();
+
+        for operation in [Operation::Notify, Operation::Listen] {
+            let sut = Sut::new(&generate_name())
+                .publish_subscribe()
+                .permit_user(uid, Operation::Publish)
+                .permit_user(uid, operation)
+                .create::<u64>();
+
+            assert_that!(sut, is_err);
+            assert_that!(sut.err().unwrap(), eq PublishSubscribeCreateError::UnsupportedAccessRule);
+        }
+    }
+
+    #[test]
+    fn event_service_with_publish_subscribe_operation_cannot_be_created<Sut: Service>() {
+        let uid = User::from_self().unwrap().uid();
+
+        for operation in [Operation::Publish, Operation::Subscribe] {
+            let sut = Sut::new(&generate_name())
+                .event()
+                .permit_user(uid, Operation::Notify)
+                .permit_user(uid, operation)
+                .create();
+
+            assert_that!(sut, is_err);
+            assert_that!(sut.err().unwrap(), eq EventCreateError::UnsupportedAccessRule);
+        }
+    }
+
+    #[test]
+    fn opened_service_uses_access_control_of_creator<Sut: Service>() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+        let _creator = Sut::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid, Operation::Publish)
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid, Operation::Subscribe)
+            .open::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().rules(), len 1);
+        assert_that!(sut.access_control().rules()[0].operation(), eq Operation::Publish);
+        assert_that!(sut.publisher().create(), is_ok);
+
+        let subscriber = sut.subscriber().create();
+        assert_that!(subscriber, is_err);
+        assert_that!(subscriber.err().unwrap(), eq SubscriberCreateError::PermissionDenied);
+    }
+
+    #[test]
+    fn duplicated_rules_are_stored_once<Sut: Service>() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid, Operation::Publish)
+            .permit_user(uid, Operation::Publish)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().rules(), len 1);
+    }
+
+    #[test]
+    fn rules_exceeding_the_maximum_number_of_users_are_ignored<Sut: Service>() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+        let mut builder = Sut::new(&service_name).publish_subscribe();
+        for i in 0..MAX_NUMBER_OF_ACCESS_RIGHTS {
+            builder = builder.permit_user(uid + i as u32 + 1, Operation::Subscribe);
+        }
+        let sut = builder
+            .permit_user(uid, Operation::Publish)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().rules(), len MAX_NUMBER_OF_ACCESS_RIGHTS);
+
+        let publisher = sut.publisher().create();
+        assert_that!(publisher, is_err);
+        assert_that!(publisher.err().unwrap(), eq PublisherCreateError::PermissionDenied);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+mod service_access_control_zero_copy_directory {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::access_control::Operation;
+    use iceoryx2::service::builder::event::EventCreateError;
+    use iceoryx2::service::builder::publish_subscribe::{
+        PublishSubscribeCreateError, PublishSubscribeOpenError,
+    };
+    use iceoryx2::service::zero_copy_directory;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_access_control_zero_copy_directory_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn restricted_service_cannot_be_created() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+
+        let sut = zero_copy_directory::Service::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid, Operation::Publish)
+            .create::<u64>();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::AccessControlNotSupported));
+
+        let sut = zero_copy_directory::Service::new(&service_name)
+            .event()
+            .permit_user(uid, Operation::Notify)
+            .create();
+        assert_that!(sut.err(), eq Some(EventCreateError::AccessControlNotSupported));
+
+        assert_that!(zero_copy_directory::Service::does_exist(&service_name), eq Ok(false));
+    }
+
+    #[test]
+    fn non_permitted_user_cannot_access_service_that_shall_be_restricted() {
+        let service_name = generate_name();
+        let uid = User::from_self().unwrap().uid();
+
+        let sut = zero_copy_directory::Service::new(&service_name)
+            .publish_subscribe()
+            .permit_user(uid + 1, Operation::Publish)
+            .permit_user(uid + 1, Operation::Subscribe)
+            .create::<u64>();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::AccessControlNotSupported));
+
+        // the service would have been accessible for everyone, therefore it must not exist
+        let sut = zero_copy_directory::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn unrestricted_service_can_be_created() {
+        let service_name = generate_name();
+
+        let sut = zero_copy_directory::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.access_control().is_restricted(), eq false);
+        assert_that!(sut.publisher().create(), is_ok);
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_attribute {
+    use iceoryx2::service::attribute::AttributeSet;
+    use iceoryx2::service::builder::blackboard::BlackboardOpenError;
+    use iceoryx2::service::builder::event::EventOpenError;
+    use iceoryx2::service::builder::pipeline::PipelineOpenError;
+    use iceoryx2::service::builder::publish_subscribe::{
+        PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+    };
+    use iceoryx2::service::builder::request_response::RequestResponseOpenError;
+    use iceoryx2::service::{service_name::ServiceName, Details, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_attribute_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn service_without_attributes_has_empty_attribute_set<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.attributes().is_empty(), eq true);
+    }
+
+    #[test]
+    fn created_service_stores_attributes<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("unit", "m/s")
+            .attribute("owner", "perception")
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.attributes(), len 2);
+        assert_that!(sut.attributes().get("unit"), eq Some("m/s"));
+        assert_that!(sut.attributes().get("owner"), eq Some("perception"));
+        assert_that!(sut.attributes().get("frame"), eq None);
+    }
+
+    #[test]
+    fn defining_an_attribute_twice_replaces_its_value<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .event()
+            .attribute("schema_version", "1")
+            .attribute("schema_version", "2")
+            .create()
+            .unwrap();
+
+        assert_that!(sut.attributes(), len 1);
+        assert_that!(sut.attributes().get("schema_version"), eq Some("2"));
+    }
+
+    #[test]
+    fn open_without_requirements_provides_attributes_of_creator<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("frame", "vehicle")
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+
+        assert_that!(sut.attributes().get("frame"), eq Some("vehicle"));
+    }
+
+    #[test]
+    fn open_with_matching_attributes_works<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("frame", "vehicle")
+            .attribute("unit", "m")
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("unit", "m")
+            .open::<u64>();
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().attributes(), len 2);
+    }
+
+    #[test]
+    fn open_with_different_attribute_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("unit", "m")
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("unit", "mm")
+            .open::<u64>();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn open_with_missing_attribute_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .event()
+            .attribute("unit", "m")
+            .create()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .attribute("owner", "perception")
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq EventOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn open_or_create_with_different_attribute_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("schema_version", "1")
+            .open_or_create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("schema_version", "2")
+            .open_or_create::<u64>();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleAttributes));
+    }
+
+    #[test]
+    fn request_response_open_with_different_attribute_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .request_response()
+            .attribute("owner", "planning")
+            .create::<u64, u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .attribute("owner", "perception")
+            .open::<u64, u64>();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RequestResponseOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn event_open_with_different_attribute_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .event()
+            .attribute("unit", "m")
+            .create()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .event()
+            .attribute("unit", "mm")
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq EventOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn blackboard_open_with_different_attribute_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .attribute("owner", "planning")
+            .create()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .attribute("owner", "perception")
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn blackboard_open_with_missing_attribute_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .attribute("owner", "planning")
+            .create()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .attribute("unit", "m")
+            .open();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn pipeline_open_with_different_attribute_value_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .pipeline()
+            .attribute("schema_version", "1")
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .attribute("schema_version", "2")
+            .open::<u64>();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PipelineOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn pipeline_open_with_missing_attribute_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let _creator = Sut::new(&service_name)
+            .pipeline()
+            .attribute("schema_version", "1")
+            .create::<u64>()
+            .unwrap();
+
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .attribute("owner", "perception")
+            .open::<u64>();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PipelineOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn list_with_attributes_returns_only_matching_services<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let other_service_name = generate_name();
+        let id = UniqueSystemId::new().unwrap().value().to_string();
+
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .attribute("list_test_id", &id)
+            .attribute("unit", "m")
+            .create::<u64>()
+            .unwrap();
+
+        let _other = Sut::new(&other_service_name)
+            .event()
+            .attribute("list_test_id", &id)
+            .attribute("unit", "s")
+            .create()
+            .unwrap();
+
+        let services =
+            Sut::list_with_attributes(&AttributeSet::new().define("list_test_id", &id)).unwrap();
+        assert_that!(services, len 2);
+
+        let services = Sut::list_with_attributes(
+            &AttributeSet::new()
+                .define("list_test_id", &id)
+                .define("unit", "m"),
+        )
+        .unwrap();
+        assert_that!(services, len 1);
+        assert_that!(*services[0].service_name(), eq service_name);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
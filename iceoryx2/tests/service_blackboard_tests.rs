@@ -0,0 +1,312 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_blackboard {
+    use iceoryx2::port::reader::{ReaderCreateError, ReaderReadError};
+    use iceoryx2::port::writer::{WriterCreateError, WriterWriteError};
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Pose {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn creating_service_without_entries_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).blackboard::<u64>().create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardCreateError::NoEntriesProvided);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).blackboard::<u64>().open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_type<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).blackboard::<u32>().open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name).blackboard::<u64>().open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_different_messaging_pattern<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).event().create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).blackboard::<u64>().open();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            BlackboardOpenError::IncompatibleMessagingPattern
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(2)
+            .add(0, 0)
+            .add(1, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(1, 0)
+            .add(2, 0)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            BlackboardOpenError::DoesNotProvideRequestedKeys
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(1)
+            .add(1, 0)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.static_config().keys().to_vec(), eq vec![0]);
+    }
+
+    #[test]
+    fn number_of_readers_and_writers_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_READERS: usize = 4;
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(MAX_READERS)
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for i in 0..MAX_READERS {
+            readers.push(sut.reader().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_readers(), eq i + 1);
+        }
+
+        let reader = sut.reader().create();
+        assert_that!(reader, is_err);
+        assert_that!(reader.err().unwrap(), eq ReaderCreateError::ExceedsMaxSupportedReaders);
+
+        let writer = sut.writer().create();
+        assert_that!(writer, is_ok);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+        let writer2 = sut.writer().create();
+        assert_that!(writer2, is_err);
+        assert_that!(writer2.err().unwrap(), eq WriterCreateError::ExceedsMaxSupportedWriters);
+
+        drop(writer);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+        assert_that!(sut.writer().create(), is_ok);
+
+        readers.clear();
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 0);
+    }
+
+    #[test]
+    fn reader_reads_initial_values<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(3, 33)
+            .add(1, 11)
+            .add(2, 22)
+            .add(1, 111)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader().create().unwrap();
+        assert_that!(reader.read(1), eq Ok(111));
+        assert_that!(reader.read(2), eq Ok(22));
+        assert_that!(reader.read(3), eq Ok(33));
+    }
+
+    #[test]
+    fn reader_reads_written_values<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<Pose>()
+            .add(
+                0,
+                Pose {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            )
+            .add(
+                1,
+                Pose {
+                    x: 1.0,
+                    y: 1.0,
+                    z: 1.0,
+                },
+            )
+            .create()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name).blackboard::<Pose>().open().unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader = sut2.reader().create().unwrap();
+
+        for i in 0..10 {
+            let pose = Pose {
+                x: i as f64,
+                y: 2.0 * i as f64,
+                z: 3.0 * i as f64,
+            };
+            assert_that!(writer.write(0, pose), is_ok);
+            assert_that!(reader.read(0), eq Ok(pose));
+            assert_that!(reader.read(1), eq Ok(Pose { x: 1.0, y: 1.0, z: 1.0 }));
+        }
+    }
+
+    #[test]
+    fn accessing_non_existing_entry_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader = sut.reader().create().unwrap();
+
+        assert_that!(writer.write(1, 123), eq Err(WriterWriteError::EntryDoesNotExist));
+        assert_that!(reader.read(1), eq Err(ReaderReadError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn written_values_are_visible_to_all_readers<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(3)
+            .add(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let readers = [
+            sut.reader().create().unwrap(),
+            sut.reader().create().unwrap(),
+            sut.reader().create().unwrap(),
+        ];
+
+        assert_that!(writer.write(0, 8127), is_ok);
+        for reader in &readers {
+            assert_that!(reader.read(0), eq Ok(8127));
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -0,0 +1,236 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_discovery {
+    use std::time::Duration;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::service::discovery::{DiscoveryEvent, ServiceDiscovery};
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_discovery_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    /// Every test uses its own service directory so that the tests do not see each others
+    /// services.
+    fn generate_config() -> Config {
+        let mut config = Config::default();
+        config.global.service.directory = format!(
+            "service_discovery_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        );
+        config
+    }
+
+    fn added_services(events: &[DiscoveryEvent]) -> Vec<ServiceName> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                DiscoveryEvent::ServiceAdded(s) => Some(*s.service_name()),
+                DiscoveryEvent::ServiceRemoved(_) => None,
+            })
+            .collect()
+    }
+
+    fn removed_services(events: &[DiscoveryEvent]) -> Vec<ServiceName> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                DiscoveryEvent::ServiceRemoved(s) => Some(*s.service_name()),
+                DiscoveryEvent::ServiceAdded(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn existing_services_are_known_after_creation<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let _service = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+
+        let sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let services: Vec<_> = sut.services().collect();
+        assert_that!(services, len 1);
+        assert_that!(*services[0].service_name(), eq service_name);
+    }
+
+    #[test]
+    fn update_without_changes_reports_nothing<Sut: Service>() {
+        let config = generate_config();
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        assert_that!(sut.try_update().unwrap(), is_empty);
+        assert_that!(sut.timed_update(TIMEOUT).unwrap(), is_empty);
+        assert_that!(sut.services().count(), eq 0);
+    }
+
+    #[test]
+    fn created_service_is_reported_as_added<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let _service = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let events = sut.try_update().unwrap();
+        assert_that!(added_services(&events), eq vec![service_name]);
+        assert_that!(removed_services(&events), is_empty);
+        assert_that!(sut.services().count(), eq 1);
+
+        assert_that!(sut.try_update().unwrap(), is_empty);
+    }
+
+    #[test]
+    fn removed_service_is_reported_as_removed<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+        drop(service);
+
+        let events = sut.timed_update(TIMEOUT).unwrap();
+        assert_that!(added_services(&events), is_empty);
+        assert_that!(removed_services(&events), eq vec![service_name]);
+        assert_that!(sut.services().count(), eq 0);
+    }
+
+    #[test]
+    fn opened_service_is_not_reported<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let _service = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let opened_service = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .open()
+            .unwrap();
+        drop(opened_service);
+
+        assert_that!(sut.try_update().unwrap(), is_empty);
+        assert_that!(sut.services().count(), eq 1);
+    }
+
+    #[test]
+    fn multiple_changes_are_reported_with_one_update<Sut: Service>() {
+        const NUMBER_OF_SERVICES: usize = 5;
+        let config = generate_config();
+        let removed_service_name = generate_name();
+        let removed_service = Sut::new(&removed_service_name)
+            .pipeline_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let mut services = vec![];
+        let mut service_names = vec![];
+        for _ in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+            services.push(
+                Sut::new(&service_name)
+                    .event_with_custom_config(&config)
+                    .create()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+        }
+        drop(removed_service);
+
+        let events = sut.try_update().unwrap();
+        let added = added_services(&events);
+        assert_that!(added, len NUMBER_OF_SERVICES);
+        for service_name in &service_names {
+            assert_that!(added, contains * service_name);
+        }
+        assert_that!(removed_services(&events), eq vec![removed_service_name]);
+        assert_that!(sut.services().count(), eq NUMBER_OF_SERVICES);
+    }
+
+    #[test]
+    fn multiple_discoveries_are_notified<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let mut sut1 = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+        let mut sut2 = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let _service = Sut::new(&service_name)
+            .request_response_with_custom_config(&config)
+            .create::<u64, u64>()
+            .unwrap();
+
+        assert_that!(added_services(&sut1.try_update().unwrap()), eq vec![service_name]);
+        assert_that!(added_services(&sut2.try_update().unwrap()), eq vec![service_name]);
+    }
+
+    #[test]
+    fn refresh_detects_changes_without_notification<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let _service = Sut::new(&service_name)
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+
+        assert_that!(added_services(&sut.refresh().unwrap()), eq vec![service_name]);
+        // the pending notification does not report the service a second time
+        assert_that!(sut.try_update().unwrap(), is_empty);
+    }
+
+    #[test]
+    fn services_of_other_configs_are_not_reported<Sut: Service>() {
+        let config = generate_config();
+        let other_config = generate_config();
+        let mut sut = ServiceDiscovery::<Sut>::new_with_custom_config(&config).unwrap();
+
+        let _service = Sut::new(&generate_name())
+            .event_with_custom_config(&other_config)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.timed_update(TIMEOUT).unwrap(), is_empty);
+        assert_that!(sut.services().count(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -0,0 +1,429 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_pipeline {
+    use iceoryx2::port::consumer::ConsumerCreateError;
+    use iceoryx2::port::producer::{ProducerCreateError, ProducerSendCopyError, ProducerSendError};
+    use iceoryx2::port::publisher::LoanError;
+    use iceoryx2::service::builder::pipeline::{PipelineCreateError, PipelineOpenError};
+    use iceoryx2::service::port_factory::producer::DistributionStrategy;
+    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().create::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().open::<u64>();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PipelineOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_type<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().open::<i64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name).pipeline().open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_different_messaging_pattern<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PipelineOpenError::IncompatibleMessagingPattern
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(2)
+            .max_consumers(2)
+            .consumer_max_buffer_size(2)
+            .consumer_max_borrowed_samples(2)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(3)
+            .open::<u64>();
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PipelineOpenError::DoesNotSupportRequestedAmountOfProducers
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .max_consumers(3)
+            .open::<u64>();
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PipelineOpenError::DoesNotSupportRequestedAmountOfConsumers
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .consumer_max_buffer_size(3)
+            .open::<u64>();
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PipelineOpenError::DoesNotSupportRequestedMinConsumerBufferSize
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .consumer_max_borrowed_samples(3)
+            .open::<u64>();
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PipelineOpenError::DoesNotSupportRequestedMinConsumerBorrowedSamples
+        );
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(1)
+            .max_consumers(1)
+            .consumer_max_buffer_size(1)
+            .consumer_max_borrowed_samples(1)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn number_of_producers_and_consumers_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_PRODUCERS: usize = 4;
+        const MAX_CONSUMERS: usize = 3;
+
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(MAX_PRODUCERS)
+            .max_consumers(MAX_CONSUMERS)
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name).pipeline().open::<u64>().unwrap();
+
+        let mut producers = vec![];
+        let mut consumers = vec![];
+
+        for i in 0..MAX_PRODUCERS {
+            producers.push(sut.producer().create().unwrap());
+            assert_that!(sut2.dynamic_config().number_of_producers(), eq i + 1);
+        }
+
+        for i in 0..MAX_CONSUMERS {
+            consumers.push(sut2.consumer().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_consumers(), eq i + 1);
+        }
+
+        let producer = sut.producer().create();
+        assert_that!(producer.err().unwrap(), eq ProducerCreateError::ExceedsMaxSupportedProducers);
+
+        let consumer = sut.consumer().create();
+        assert_that!(consumer.err().unwrap(), eq ConsumerCreateError::ExceedsMaxSupportedConsumers);
+
+        producers.pop();
+        consumers.pop();
+        assert_that!(sut.dynamic_config().number_of_producers(), eq MAX_PRODUCERS - 1);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq MAX_CONSUMERS - 1);
+
+        assert_that!(sut.producer().create(), is_ok);
+        assert_that!(sut.consumer().create(), is_ok);
+    }
+
+    #[test]
+    fn simple_pipeline_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+        let sut2 = Sut::new(&service_name).pipeline().open::<u64>().unwrap();
+
+        let consumer = sut.consumer().create().unwrap();
+        let producer = sut2.producer().create().unwrap();
+
+        let work_item = producer.loan_uninit().unwrap();
+        let work_item = work_item.write_payload(1234);
+        assert_that!(producer.send(work_item), eq Ok(consumer.id()));
+
+        let work_item = consumer.receive().unwrap();
+        assert_that!(work_item, is_some);
+        let work_item = work_item.unwrap();
+        assert_that!(*work_item, eq 1234);
+        assert_that!(work_item.header().producer_id(), eq producer.id());
+        assert_that!(consumer.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn every_work_item_is_delivered_to_exactly_one_consumer<Sut: Service>() {
+        const NUMBER_OF_CONSUMERS: usize = 3;
+        const NUMBER_OF_WORK_ITEMS: u64 = 6;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_consumers(NUMBER_OF_CONSUMERS)
+            .consumer_max_buffer_size(NUMBER_OF_WORK_ITEMS as usize)
+            .create::<u64>()
+            .unwrap();
+
+        let mut consumers = vec![];
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            consumers.push(sut.consumer().create().unwrap());
+        }
+        let producer = sut.producer().create().unwrap();
+
+        for i in 0..NUMBER_OF_WORK_ITEMS {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+
+        let mut received = vec![];
+        for consumer in &consumers {
+            while let Some(work_item) = consumer.receive().unwrap() {
+                received.push(*work_item);
+            }
+        }
+
+        received.sort();
+        assert_that!(received, eq(0..NUMBER_OF_WORK_ITEMS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn round_robin_distributes_work_items_evenly<Sut: Service>() {
+        const NUMBER_OF_CONSUMERS: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_consumers(NUMBER_OF_CONSUMERS)
+            .create::<u64>()
+            .unwrap();
+
+        let mut consumers = vec![];
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            consumers.push(sut.consumer().create().unwrap());
+        }
+        let producer = sut
+            .producer()
+            .distribution_strategy(DistributionStrategy::RoundRobin)
+            .create()
+            .unwrap();
+
+        for i in 0..NUMBER_OF_CONSUMERS as u64 {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+
+        for consumer in &consumers {
+            assert_that!(consumer.receive().unwrap(), is_some);
+            assert_that!(consumer.receive().unwrap(), is_none);
+        }
+    }
+
+    #[test]
+    fn first_free_prefers_the_first_consumer_with_free_buffer<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_consumers(2)
+            .consumer_max_buffer_size(BUFFER_SIZE)
+            .create::<u64>()
+            .unwrap();
+
+        let consumer_1 = sut.consumer().create().unwrap();
+        let consumer_2 = sut.consumer().create().unwrap();
+        let producer = sut
+            .producer()
+            .distribution_strategy(DistributionStrategy::FirstFree)
+            .create()
+            .unwrap();
+
+        let first_consumer = producer.send_copy(0).unwrap();
+        for i in 1..BUFFER_SIZE as u64 {
+            assert_that!(producer.send_copy(i), eq Ok(first_consumer));
+        }
+        let second_consumer = producer.send_copy(BUFFER_SIZE as u64).unwrap();
+        assert_that!(second_consumer, ne first_consumer);
+
+        let mut number_of_received_work_items = 0;
+        for consumer in [&consumer_1, &consumer_2] {
+            while consumer.receive().unwrap().is_some() {
+                number_of_received_work_items += 1;
+            }
+        }
+        assert_that!(number_of_received_work_items, eq BUFFER_SIZE + 1);
+    }
+
+    #[test]
+    fn send_without_consumer_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        assert_that!(producer.send_copy(5).err().unwrap(), eq ProducerSendCopyError::SendError(ProducerSendError::NoConsumerConnected));
+    }
+
+    #[test]
+    fn send_fails_when_all_consumers_are_busy_with_discard_strategy<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_consumers(1)
+            .consumer_max_buffer_size(BUFFER_SIZE)
+            .create::<u64>()
+            .unwrap();
+
+        let consumer = sut.consumer().create().unwrap();
+        let producer = sut
+            .producer()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE as u64 {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+
+        let work_item = producer.loan().unwrap();
+        assert_that!(producer.send(work_item).err().unwrap(), eq ProducerSendError::AllConsumersAreBusy);
+
+        assert_that!(consumer.receive().unwrap(), is_some);
+        assert_that!(producer.send_copy(0), is_ok);
+    }
+
+    #[test]
+    fn work_items_of_disconnected_consumer_are_handed_over<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_consumers(2)
+            .consumer_max_buffer_size(BUFFER_SIZE)
+            .create::<u64>()
+            .unwrap();
+
+        let consumer_1 = sut.consumer().create().unwrap();
+        let producer = sut
+            .producer()
+            .distribution_strategy(DistributionStrategy::FirstFree)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE as u64 {
+            assert_that!(producer.send_copy(i), eq Ok(consumer_1.id()));
+        }
+
+        let consumer_2 = sut.consumer().create().unwrap();
+        drop(consumer_1);
+        assert_that!(producer.update_connections(), is_ok);
+
+        let mut received = vec![];
+        while let Some(work_item) = consumer_2.receive().unwrap() {
+            received.push(*work_item);
+        }
+        assert_that!(received, eq(0..BUFFER_SIZE as u64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn producer_can_loan_at_most_max_loaned_samples<Sut: Service>() {
+        const MAX_LOANED_SAMPLES: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+
+        let producer = sut
+            .producer()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()
+            .unwrap();
+
+        let mut work_items = vec![];
+        for _ in 0..MAX_LOANED_SAMPLES {
+            let work_item = producer.loan();
+            assert_that!(work_item, is_ok);
+            work_items.push(work_item.unwrap());
+        }
+
+        let work_item = producer.loan();
+        assert_that!(work_item.err().unwrap(), eq LoanError::ExceedsMaxLoanedChunks);
+
+        work_items.pop();
+        assert_that!(producer.loan(), is_ok);
+    }
+
+    #[test]
+    fn work_items_are_recycled<Sut: Service>() {
+        const NUMBER_OF_ITERATIONS: u64 = 128;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+
+        let consumer = sut.consumer().create().unwrap();
+        let producer = sut.producer().create().unwrap();
+
+        for i in 0..NUMBER_OF_ITERATIONS {
+            assert_that!(producer.send_copy(i), eq Ok(consumer.id()));
+
+            let work_item = consumer.receive().unwrap().unwrap();
+            assert_that!(*work_item, eq i);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
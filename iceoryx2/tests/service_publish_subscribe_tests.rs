@@ -14,14 +14,25 @@
 mod service_publish_subscribe {
     use iceoryx2::config::Config;
     use iceoryx2::port::publisher::{LoanError, PublisherCreateError};
-    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::port::subscriber::{ReceiveError, SubscriberCreateError, SubscriberWaitError};
+    use iceoryx2::service::builder::publish_subscribe::Property;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::Requirement;
+    use iceoryx2::service::dynamic_config::NumberOfPorts;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::subscriber::ReceiveOrder;
     use iceoryx2::service::static_config::StaticConfig;
-    use iceoryx2::service::{service_name::ServiceName, Details, Service};
+    use iceoryx2::service::{
+        service_name::ServiceName, Details, Service, ServiceNumberOfPortsError,
+    };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use std::sync::Barrier;
+    use std::time::{Duration, Instant};
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -31,6 +42,15 @@ mod service_publish_subscribe {
         .unwrap()
     }
 
+    fn mismatched_properties<T>(result: Result<T, PublishSubscribeOpenError>) -> Vec<Property> {
+        match result {
+            Err(PublishSubscribeOpenError::IncompatibleProperties(mismatches)) => {
+                mismatches.iter().map(|m| m.property()).collect()
+            }
+            _ => vec![],
+        }
+    }
+
     #[test]
     fn creating_non_existing_service_works<Sut: Service>() {
         let service_name = generate_name();
@@ -96,6 +116,100 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_fails_when_service_has_type_with_same_name_but_different_size<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = {
+            #[derive(Debug)]
+            #[allow(dead_code)]
+            struct Payload(u64);
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .create::<Payload>()
+        };
+        assert_that!(sut, is_ok);
+
+        let sut2 = {
+            #[derive(Debug)]
+            #[allow(dead_code)]
+            struct Payload(u32);
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .open::<Payload>()
+        };
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatiblePayloadSize);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_type_with_same_name_but_different_alignment<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = {
+            #[derive(Debug)]
+            #[repr(C, align(16))]
+            #[allow(dead_code)]
+            struct Payload([u8; 16]);
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .create::<Payload>()
+        };
+        assert_that!(sut, is_ok);
+
+        let sut2 = {
+            #[derive(Debug)]
+            #[repr(C)]
+            #[allow(dead_code)]
+            struct Payload([u8; 16]);
+            Sut::new(&service_name)
+                .publish_subscribe()
+                .open::<Payload>()
+        };
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatiblePayloadAlignment);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_different_type_hash<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .type_hash(0xfedcba9876543210)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().type_hash(), eq Some(0xfedcba9876543210));
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .type_hash(0x4321)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeHash);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .type_hash(0xfedcba9876543210)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_with_type_hash_fails_when_service_has_no_type_hash<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.as_ref().unwrap().static_config().type_hash(), is_none);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .type_hash(0x1234)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeHash);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
         let service_name = generate_name();
@@ -117,10 +231,7 @@ mod service_publish_subscribe {
             .open::<u64>();
 
         assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfPublishers
-        );
+        assert_that!(mismatched_properties(sut2), eq vec![Property::MaxPublishers]);
 
         let sut2 = Sut::new(&service_name)
             .publish_subscribe()
@@ -136,10 +247,7 @@ mod service_publish_subscribe {
             .open::<u64>();
 
         assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfSubscribers
-        );
+        assert_that!(mismatched_properties(sut2), eq vec![Property::MaxSubscribers]);
 
         let sut2 = Sut::new(&service_name)
             .publish_subscribe()
@@ -167,10 +275,7 @@ mod service_publish_subscribe {
             .open::<u64>();
 
         assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinHistorySize
-        );
+        assert_that!(mismatched_properties(sut2), eq vec![Property::HistorySize]);
 
         let sut2 = Sut::new(&service_name)
             .publish_subscribe()
@@ -186,10 +291,7 @@ mod service_publish_subscribe {
             .open::<u64>();
 
         assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinSubscriberBorrowedSamples
-        );
+        assert_that!(mismatched_properties(sut2), eq vec![Property::SubscriberMaxBorrowedSamples]);
 
         let sut2 = Sut::new(&service_name)
             .publish_subscribe()
@@ -205,10 +307,7 @@ mod service_publish_subscribe {
             .open::<u64>();
 
         assert_that!(sut2, is_err);
-        assert_that!(
-            sut2.err().unwrap(), eq
-            PublishSubscribeOpenError::DoesNotSupportRequestedMinBufferSize
-        );
+        assert_that!(mismatched_properties(sut2), eq vec![Property::SubscriberMaxBufferSize]);
 
         let sut2 = Sut::new(&service_name)
             .publish_subscribe()
@@ -218,6 +317,141 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_verifies_exact_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_subscribers(4)
+            .history_size(2)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .require(Property::MaxSubscribers, Requirement::Exactly(4))
+            .require(Property::HistorySize, Requirement::Exactly(2))
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .require(Property::MaxSubscribers, Requirement::Exactly(3))
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(mismatched_properties(sut2), eq vec![Property::MaxSubscribers]);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .require(Property::HistorySize, Requirement::Exactly(3))
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(mismatched_properties(sut2), eq vec![Property::HistorySize]);
+    }
+
+    #[test]
+    fn open_ignores_properties_without_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .require(Property::MaxPublishers, Requirement::DontCare)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().max_supported_publishers(), eq 2);
+    }
+
+    #[test]
+    fn create_uses_value_of_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .require(Property::MaxSubscribers, Requirement::Exactly(5))
+            .require(Property::SubscriberMaxBufferSize, Requirement::AtLeast(6))
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_subscribers(), eq 5);
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 6);
+    }
+
+    #[test]
+    fn incompatible_properties_lists_every_mismatch<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .max_subscribers(2)
+            .history_size(2)
+            .subscriber_max_buffer_size(2)
+            .create::<u64>()
+            .unwrap();
+
+        let builder = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .max_subscribers(1)
+            .require(Property::HistorySize, Requirement::Exactly(1))
+            .subscriber_max_buffer_size(4);
+
+        let mismatches = builder.incompatible_properties::<u64>().unwrap();
+        assert_that!(mismatches, len 3);
+
+        assert_that!(mismatches[0].property(), eq Property::MaxPublishers);
+        assert_that!(mismatches[0].requirement(), eq Requirement::AtLeast(3));
+        assert_that!(mismatches[0].actual(), eq 2);
+
+        assert_that!(mismatches[1].property(), eq Property::SubscriberMaxBufferSize);
+        assert_that!(mismatches[1].requirement(), eq Requirement::AtLeast(4));
+        assert_that!(mismatches[1].actual(), eq 2);
+
+        assert_that!(mismatches[2].property(), eq Property::HistorySize);
+        assert_that!(mismatches[2].requirement(), eq Requirement::Exactly(1));
+        assert_that!(mismatches[2].actual(), eq 2);
+
+        let sut2 = builder.open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleProperties(mismatches)
+        );
+    }
+
+    #[test]
+    fn incompatible_properties_ignores_max_slice_len_of_non_slice_types<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let builder = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(10);
+
+        let mismatches = builder.incompatible_properties::<u64>().unwrap();
+        assert_that!(mismatches, len 0);
+        assert_that!(builder.open::<u64>(), is_ok);
+    }
+
+    #[test]
+    fn incompatible_properties_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .incompatible_properties::<u64>();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublishSubscribeOpenError::DoesNotExist);
+    }
+
     #[test]
     fn open_does_not_fail_when_service_owner_is_dropped<Sut: Service>() {
         let service_name = generate_name();
@@ -1049,90 +1283,843 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn does_exist_works_single<Sut: Service + Details<'static>>() {
+    fn set_max_slice_len_to_zero_adjusts_it_to_one<Sut: Service>() {
         let service_name = generate_name();
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
-
-        let _sut = Sut::new(&service_name)
+        let sut = Sut::new(&service_name)
             .publish_subscribe()
-            .create::<u64>()
+            .max_slice_len(0)
+            .create::<[u64]>()
             .unwrap();
 
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
+        assert_that!(sut.static_config().max_slice_len(), eq 1);
+    }
 
-        drop(_sut);
+    #[test]
+    fn open_fails_when_slice_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(10)
+            .create::<[u64]>();
+        assert_that!(sut, is_ok);
 
-        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(11)
+            .open::<[u64]>();
+        assert_that!(sut2, is_err);
+        assert_that!(mismatched_properties(sut2), eq vec![Property::MaxSliceLen]);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(9)
+            .open::<[u64]>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().max_slice_len(), eq 10);
     }
 
     #[test]
-    fn does_exist_works_many<Sut: Service + Details<'static>>() {
-        const NUMBER_OF_SERVICES: usize = 8;
-
-        let mut services = vec![];
-        let mut service_names = vec![];
+    fn slice_communication_works<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 32;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u64]>()
+            .unwrap();
 
-        for i in 0..NUMBER_OF_SERVICES {
-            let service_name = generate_name();
-            assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
 
-            services.push(
-                Sut::new(&service_name)
-                    .publish_subscribe()
-                    .create::<u64>()
-                    .unwrap(),
-            );
-            service_names.push(service_name);
+        for len in 0..=MAX_SLICE_LEN {
+            let sample = publisher.loan_uninit_slice(len).unwrap();
+            let sample = sample.write_from_fn(|i| (len * 1000 + i) as u64);
+            assert_that!(publisher.send(sample), eq Ok(1));
 
-            for s in service_names.iter().take(i + 1) {
-                assert_that!(Sut::does_exist(s).unwrap(), eq true);
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().number_of_elements(), eq len as u64);
+            assert_that!(sample.payload(), len len);
+            for (i, element) in sample.payload().iter().enumerate() {
+                assert_that!(*element, eq(len * 1000 + i) as u64);
             }
         }
 
-        for i in 0..NUMBER_OF_SERVICES {
-            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
-                assert_that!(Sut::does_exist(s).unwrap(), eq true);
-            }
-
-            for s in service_names
-                .iter()
-                .take(NUMBER_OF_SERVICES)
-                .skip(NUMBER_OF_SERVICES - i)
-            {
-                assert_that!(Sut::does_exist(s).unwrap(), eq false);
-            }
+        let mut sample = publisher.loan_slice(3).unwrap();
+        assert_that!(sample.payload_mut(), eq [0, 0, 0]);
+        sample.payload_mut()[1] = 42;
+        assert_that!(publisher.send(sample), eq Ok(1));
 
-            services.pop();
-        }
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq [0, 42, 0]);
     }
 
     #[test]
-    fn list_works<Sut: Service + Details<'static>>() {
-        const NUMBER_OF_SERVICES: usize = 8;
+    fn loan_slice_fails_when_max_slice_len_is_exceeded<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u8]>()
+            .unwrap();
 
-        let mut services = vec![];
-        let mut service_names = vec![];
+        let publisher = sut.publisher().create().unwrap();
 
-        let contains_service_names = |names, state: Vec<StaticConfig>| {
-            for n in names {
-                let mut name_found = false;
-                for s in &state {
-                    if *s.service_name() == n {
-                        name_found = true;
-                        break;
-                    }
-                }
+        let sample = publisher.loan_uninit_slice(MAX_SLICE_LEN + 1);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq LoanError::ExceedsMaxLoanSize);
 
-                if !name_found {
-                    return false;
-                }
-            }
+        let sample = publisher.loan_slice(MAX_SLICE_LEN + 1);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq LoanError::ExceedsMaxLoanSize);
 
-            true
-        };
+        assert_that!(publisher.loan_slice(MAX_SLICE_LEN), is_ok);
+    }
 
-        for i in 0..NUMBER_OF_SERVICES {
+    #[test]
+    fn slice_publisher_reuses_chunks_with_history<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 1021;
+        const HISTORY_SIZE: usize = 3;
+        const ITERATIONS: usize = 100;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .create::<[u16]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        for n in 0..ITERATIONS {
+            let len = n * 13 % MAX_SLICE_LEN;
+            let sample = publisher
+                .loan_uninit_slice(len)
+                .unwrap()
+                .write_from_fn(|i| (i + n) as u16);
+            assert_that!(publisher.send(sample), is_ok);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len len);
+            assert_that!(sample.payload().last().copied(), eq len.checked_sub(1).map(|i| (i + n) as u16));
+        }
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+        for i in 0..HISTORY_SIZE {
+            let n = ITERATIONS - HISTORY_SIZE + i;
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len n * 13 % MAX_SLICE_LEN);
+        }
+    }
+
+    #[test]
+    fn open_fails_when_copy_transfer_setting_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_copy_transfer(), eq true);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_copy_transfer(false)
+            .open::<u64>();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleCopyTransfer));
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().has_copy_transfer(), eq true);
+    }
+
+    #[test]
+    fn apply_static_config_creates_service_with_the_same_settings<Sut: Service>() {
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe()
+            .max_publishers(3)
+            .max_subscribers(4)
+            .history_size(2)
+            .subscriber_max_buffer_size(5)
+            .subscriber_max_borrowed_samples(6)
+            .enable_notifications(true)
+            .type_hash(0x9876)
+            .attribute("unit", "m")
+            .create::<u64>()
+            .unwrap();
+
+        let service_name = generate_name();
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .apply_static_config(sut.static_config(), sut.attributes())
+            .create::<u64>();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.static_config(), eq sut.static_config());
+        assert_that!(sut2.attributes(), eq sut.attributes());
+
+        let sut3 = Sut::new(&service_name)
+            .publish_subscribe()
+            .apply_static_config(sut.static_config(), sut.attributes())
+            .open::<u64>();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn copy_transfer_with_safe_overflow_cannot_be_created<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_safe_overflow(true)
+            .enable_copy_transfer(true)
+            .create::<u64>();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::SafeOverflowNotSupportedWithCopyTransfer));
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_copy_transfer(true)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().static_config().has_safe_overflow(), eq false);
+    }
+
+    #[test]
+    fn copy_transfer_with_safe_overflow_cannot_be_opened<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_safe_overflow(true)
+            .enable_copy_transfer(true)
+            .open::<u64>();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::SafeOverflowNotSupportedWithCopyTransfer));
+    }
+
+    #[test]
+    fn copy_transfer_communication_works<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(value * 3), eq Ok(1));
+        }
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq value * 3);
+            assert_that!(sample.header().publisher_id(), eq publisher.id());
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn copy_transfer_delivers_large_slices_and_history<Sut: Service>() {
+        // requires multiple frames per sample
+        const MAX_SLICE_LEN: usize = 1500;
+        const HISTORY_SIZE: usize = 2;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .history_size(HISTORY_SIZE)
+            .subscriber_max_buffer_size(HISTORY_SIZE)
+            .enable_copy_transfer(true)
+            .create::<[u64]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+
+        for len in [0, 1, MAX_SLICE_LEN / 2, MAX_SLICE_LEN] {
+            let sample = publisher
+                .loan_uninit_slice(len)
+                .unwrap()
+                .write_from_fn(|i| (len * 10000 + i) as u64);
+            assert_that!(publisher.send(sample), eq Ok(0));
+        }
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        for len in [MAX_SLICE_LEN / 2, MAX_SLICE_LEN] {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.payload(), len len);
+            for (i, element) in sample.payload().iter().enumerate() {
+                assert_that!(*element, eq(len * 10000 + i) as u64);
+            }
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        let sample = publisher.loan_slice(MAX_SLICE_LEN).unwrap();
+        assert_that!(publisher.send(sample), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), len MAX_SLICE_LEN);
+        assert_that!(sample.payload().iter().all(|e| *e == 0), eq true);
+    }
+
+    #[test]
+    fn copy_transfer_enforces_max_borrowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(1)
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), eq Ok(1));
+        assert_that!(publisher.send_copy(2), eq Ok(1));
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrowedSamples));
+
+        drop(sample);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+    }
+
+    #[test]
+    fn copy_transfer_discards_samples_when_buffer_is_full<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 1000;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(1)
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut number_of_discarded_samples = 0;
+        for value in 0..NUMBER_OF_SAMPLES {
+            if publisher.send_copy(value).unwrap() == 0 {
+                number_of_discarded_samples += 1;
+            }
+        }
+        assert_that!(number_of_discarded_samples, gt 0);
+
+        let mut number_of_received_samples = 0;
+        while let Some(sample) = subscriber.receive().unwrap() {
+            assert_that!(*sample, eq number_of_received_samples);
+            number_of_received_samples += 1;
+        }
+        assert_that!(number_of_received_samples, eq NUMBER_OF_SAMPLES - number_of_discarded_samples);
+    }
+
+    #[test]
+    fn sequence_numbers_increase_only_with_sent_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(5)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        drop(publisher.loan().unwrap());
+        publisher.send_copy(2).unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 0);
+        drop(sample);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 1);
+        assert_that!(subscriber.lost_samples_since_last_receive(), eq 0);
+        assert_that!(subscriber.number_of_lost_samples(publisher.id()), eq 0);
+    }
+
+    #[test]
+    fn lost_samples_are_reported_when_buffer_overflows<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        publisher.send_copy(0).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 0);
+        assert_that!(subscriber.lost_samples_since_last_receive(), eq 0);
+
+        for value in 1..4 {
+            publisher.send_copy(value).unwrap();
+        }
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 3);
+        assert_that!(subscriber.lost_samples_since_last_receive(), eq 2);
+
+        publisher.send_copy(4).unwrap();
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 4);
+        assert_that!(subscriber.lost_samples_since_last_receive(), eq 0);
+        assert_that!(subscriber.number_of_lost_samples(publisher.id()), eq 2);
+    }
+
+    #[test]
+    fn lost_samples_are_tracked_per_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher_1 = sut.publisher().create().unwrap();
+        let publisher_2 = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        publisher_1.send_copy(0).unwrap();
+        publisher_2.send_copy(0).unwrap();
+        while subscriber.receive().unwrap().is_some() {}
+
+        for value in 1..5 {
+            publisher_1.send_copy(value).unwrap();
+        }
+        publisher_2.send_copy(1).unwrap();
+        while subscriber.receive().unwrap().is_some() {}
+
+        assert_that!(subscriber.number_of_lost_samples(publisher_1.id()), eq 2);
+        assert_that!(subscriber.number_of_lost_samples(publisher_2.id()), eq 0);
+    }
+
+    #[test]
+    fn copy_transfer_reports_lost_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 1000;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(1)
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut last_delivered_sample = 0;
+        for value in 0..NUMBER_OF_SAMPLES {
+            if publisher.send_copy(value).unwrap() != 0 {
+                last_delivered_sample = value;
+            }
+        }
+
+        let mut number_of_received_samples = 0;
+        while subscriber.receive().unwrap().is_some() {
+            number_of_received_samples += 1;
+        }
+
+        assert_that!(subscriber.number_of_lost_samples(publisher.id()), eq last_delivered_sample + 1 - number_of_received_samples);
+    }
+
+    #[test]
+    fn receive_order_by_time_stamp_merges_publishers<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(5)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher_1 = sut.publisher().create().unwrap();
+        let publisher_2 = sut.publisher().create().unwrap();
+        let subscriber = sut
+            .subscriber()
+            .receive_order(ReceiveOrder::TimeStamp)
+            .create()
+            .unwrap();
+
+        for value in 0..4 {
+            match value % 2 {
+                0 => publisher_2.send_copy(value).unwrap(),
+                _ => publisher_1.send_copy(value).unwrap(),
+            };
+        }
+
+        for value in 0..4 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq value);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receive_order_by_time_stamp_skips_publishers_with_all_samples_borrowed<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(5)
+            .subscriber_max_borrowed_samples(1)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher_1 = sut.publisher().create().unwrap();
+        let publisher_2 = sut.publisher().create().unwrap();
+        let subscriber = sut
+            .subscriber()
+            .receive_order(ReceiveOrder::TimeStamp)
+            .create()
+            .unwrap();
+
+        for value in 0..4 {
+            match value % 2 {
+                0 => publisher_1.send_copy(value).unwrap(),
+                _ => publisher_2.send_copy(value).unwrap(),
+            };
+        }
+
+        let sample_0 = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample_0, eq 0);
+        // the first publisher is skipped since its only sample is borrowed
+        let sample_1 = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample_1, eq 1);
+        assert_that!(subscriber.receive().err(), eq Some(ReceiveError::ExceedsMaxBorrowedSamples));
+
+        drop(sample_0);
+        let sample_2 = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample_2, eq 2);
+
+        drop(sample_1);
+        let sample_3 = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample_3, eq 3);
+    }
+
+    #[test]
+    fn receive_order_by_priority_prefers_high_priority_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(5)
+            .create::<u64>()
+            .unwrap();
+
+        let low_priority_publisher = sut.publisher().priority(1).create().unwrap();
+        let high_priority_publisher = sut.publisher().priority(2).create().unwrap();
+        let subscriber = sut
+            .subscriber()
+            .receive_order(ReceiveOrder::Priority)
+            .create()
+            .unwrap();
+
+        low_priority_publisher.send_copy(3).unwrap();
+        low_priority_publisher.send_copy(4).unwrap();
+        high_priority_publisher.send_copy(1).unwrap();
+        high_priority_publisher.send_copy(2).unwrap();
+
+        for value in 1..5 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq value);
+            let expected_priority = if value < 3 { 2 } else { 1 };
+            assert_that!(sample.header().priority(), eq expected_priority);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn copy_transfer_receive_order_by_priority_prefers_high_priority_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(2)
+            .enable_copy_transfer(true)
+            .create::<u64>()
+            .unwrap();
+
+        let low_priority_publisher = sut.publisher().create().unwrap();
+        let high_priority_publisher = sut.publisher().priority(1).create().unwrap();
+        let subscriber = sut
+            .subscriber()
+            .receive_order(ReceiveOrder::Priority)
+            .create()
+            .unwrap();
+
+        low_priority_publisher.send_copy(3).unwrap();
+        low_priority_publisher.send_copy(4).unwrap();
+        high_priority_publisher.send_copy(1).unwrap();
+        high_priority_publisher.send_copy(2).unwrap();
+
+        for value in 1..5 {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn open_fails_when_notifications_are_requested_but_not_supported<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_notifications(), eq false);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .open::<u64>();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::DoesNotSupportNotifications));
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn waiting_for_samples_fails_when_notifications_are_not_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.timed_receive(TIMEOUT).err(), eq Some(SubscriberWaitError::NotificationsNotEnabled));
+        assert_that!(subscriber.blocking_receive().err(), eq Some(SubscriberWaitError::NotificationsNotEnabled));
+    }
+
+    #[test]
+    fn timed_receive_returns_none_when_timeout_has_passed<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_notifications(), eq true);
+
+        let _publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let start = Instant::now();
+        let sample = subscriber.timed_receive(TIMEOUT);
+        assert_that!(sample, is_ok);
+        assert_that!(sample.unwrap(), is_none);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn timed_and_blocking_receive_return_available_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .subscriber_max_buffer_size(4)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(12), is_ok);
+        assert_that!(publisher.send_copy(34), is_ok);
+        assert_that!(publisher.send_copy(56), is_ok);
+
+        assert_that!(*subscriber.timed_receive(TIMEOUT).unwrap().unwrap(), eq 12);
+        assert_that!(*subscriber.blocking_receive().unwrap().unwrap(), eq 34);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 56);
+        assert_that!(subscriber.timed_receive(TIMEOUT).unwrap(), is_none);
+    }
+
+    #[test]
+    fn blocking_receive_wakes_up_when_sample_is_sent<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let sut = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .enable_notifications(true)
+                    .open::<u64>()
+                    .unwrap();
+                let publisher = sut.publisher().create().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                assert_that!(publisher.send_copy(8912), is_ok);
+            });
+
+            barrier.wait();
+            let start = Instant::now();
+            let sample = subscriber.blocking_receive().unwrap();
+            assert_that!(start.elapsed(), ge TIMEOUT);
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 8912);
+        });
+    }
+
+    #[test]
+    fn blocking_receive_does_not_miss_samples_sent_while_receiving<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 1024;
+        let _watchdog = Watchdog::new(Duration::from_secs(10));
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let publisher = sut.publisher().create().unwrap();
+                barrier.wait();
+                for i in 0..NUMBER_OF_SAMPLES {
+                    assert_that!(publisher.send_copy(i), is_ok);
+                    std::thread::yield_now();
+                }
+            });
+
+            barrier.wait();
+            let mut expected = 0;
+            while expected < NUMBER_OF_SAMPLES {
+                // a lost notification blocks forever and the watchdog terminates the test
+                if let Some(sample) = subscriber.blocking_receive().unwrap() {
+                    assert_that!(*sample, eq expected);
+                    expected += 1;
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn does_exist_works_single<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq true);
+
+        drop(_sut);
+
+        assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+    }
+
+    #[test]
+    fn does_exist_works_many<Sut: Service + Details<'static>>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        for i in 0..NUMBER_OF_SERVICES {
+            let service_name = generate_name();
+            assert_that!(Sut::does_exist(&service_name).unwrap(), eq false);
+
+            services.push(
+                Sut::new(&service_name)
+                    .publish_subscribe()
+                    .create::<u64>()
+                    .unwrap(),
+            );
+            service_names.push(service_name);
+
+            for s in service_names.iter().take(i + 1) {
+                assert_that!(Sut::does_exist(s).unwrap(), eq true);
+            }
+        }
+
+        for i in 0..NUMBER_OF_SERVICES {
+            for s in service_names.iter().take(NUMBER_OF_SERVICES - i) {
+                assert_that!(Sut::does_exist(s).unwrap(), eq true);
+            }
+
+            for s in service_names
+                .iter()
+                .take(NUMBER_OF_SERVICES)
+                .skip(NUMBER_OF_SERVICES - i)
+            {
+                assert_that!(Sut::does_exist(s).unwrap(), eq false);
+            }
+
+            services.pop();
+        }
+    }
+
+    #[test]
+    fn list_works<Sut: Service + Details<'static>>() {
+        const NUMBER_OF_SERVICES: usize = 8;
+
+        let mut services = vec![];
+        let mut service_names = vec![];
+
+        let contains_service_names = |names, state: Vec<StaticConfig>| {
+            for n in names {
+                let mut name_found = false;
+                for s in &state {
+                    if *s.service_name() == n {
+                        name_found = true;
+                        break;
+                    }
+                }
+
+                if !name_found {
+                    return false;
+                }
+            }
+
+            true
+        };
+
+        for i in 0..NUMBER_OF_SERVICES {
             let service_name = generate_name();
 
             services.push(
@@ -1159,9 +2146,48 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn number_of_ports_of_listed_service_works<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let static_config = Sut::list()
+            .unwrap()
+            .into_iter()
+            .find(|s| *s.service_name() == service_name)
+            .unwrap();
+
+        assert_that!(Sut::number_of_ports(&static_config).unwrap(), eq NumberOfPorts::PublishSubscribe {
+            publishers: 0,
+            subscribers: 0
+        });
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber_1 = sut.subscriber().create().unwrap();
+        let subscriber_2 = sut.subscriber().create().unwrap();
+
+        assert_that!(Sut::number_of_ports(&static_config).unwrap(), eq NumberOfPorts::PublishSubscribe {
+            publishers: 1,
+            subscribers: 2
+        });
+
+        drop(publisher);
+        drop(subscriber_1);
+        drop(subscriber_2);
+        drop(sut);
+
+        assert_that!(Sut::number_of_ports(&static_config).err(), eq Some(ServiceNumberOfPortsError::DoesNotExist));
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
+    #[instantiate_tests(<iceoryx2::service::zero_copy_directory::Service>)]
+    mod zero_copy_directory {}
+
     #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
     mod process_local {}
 }
@@ -0,0 +1,449 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use iceoryx2::port::client::ClientCreateError;
+    use iceoryx2::port::publisher::LoanError;
+    use iceoryx2::port::server::{ResponseSendError, ServerCreateError};
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            RequestResponseCreateError::AlreadyExists
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RequestResponseOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_types<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<i64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, i64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_different_messaging_pattern<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            RequestResponseOpenError::IncompatibleMessagingPattern
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(2)
+            .max_servers(2)
+            .server_max_buffer_size(2)
+            .server_max_borrowed_requests(2)
+            .client_max_buffer_size(2)
+            .client_max_borrowed_responses(2)
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_clients(3)
+            .open::<u64, u64>();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_servers(3)
+            .open::<u64, u64>();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .server_max_buffer_size(3)
+            .open::<u64, u64>();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinServerBufferSize);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .server_max_borrowed_requests(3)
+            .open::<u64, u64>();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinServerBorrowedRequests);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .client_max_buffer_size(3)
+            .open::<u64, u64>();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinClientBufferSize);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .client_max_borrowed_responses(3)
+            .open::<u64, u64>();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinClientBorrowedResponses);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_clients(1)
+            .max_servers(1)
+            .server_max_buffer_size(1)
+            .server_max_borrowed_requests(1)
+            .client_max_buffer_size(1)
+            .client_max_borrowed_responses(1)
+            .open::<u64, u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(5)
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open_or_create::<u64, u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().max_supported_clients(), eq 5);
+    }
+
+    #[test]
+    fn number_of_clients_and_servers_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_CLIENTS: usize = 4;
+        const MAX_SERVERS: usize = 3;
+
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(MAX_CLIENTS)
+            .max_servers(MAX_SERVERS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>()
+            .unwrap();
+
+        let mut clients = vec![];
+        let mut servers = vec![];
+
+        for i in 0..MAX_CLIENTS {
+            clients.push(sut.client().create().unwrap());
+            assert_that!(sut2.dynamic_config().number_of_clients(), eq i + 1);
+        }
+
+        for i in 0..MAX_SERVERS {
+            servers.push(sut2.server().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_servers(), eq i + 1);
+        }
+
+        let client = sut.client().create();
+        assert_that!(client.err().unwrap(), eq ClientCreateError::ExceedsMaxSupportedClients);
+
+        let server = sut.server().create();
+        assert_that!(server.err().unwrap(), eq ServerCreateError::ExceedsMaxSupportedServers);
+
+        clients.pop();
+        servers.pop();
+        assert_that!(sut.dynamic_config().number_of_clients(), eq MAX_CLIENTS - 1);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq MAX_SERVERS - 1);
+
+        assert_that!(sut.client().create(), is_ok);
+        assert_that!(sut.server().create(), is_ok);
+    }
+
+    #[test]
+    fn simple_request_response_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut2.client().create().unwrap();
+
+        assert_that!(client.send_copy(1234), eq Ok(1));
+
+        let request = server.receive().unwrap();
+        assert_that!(request, is_some);
+        let request = request.unwrap();
+        assert_that!(*request, eq 1234);
+        assert_that!(request.header().client_id(), eq client.id());
+
+        let response = server.loan_uninit(&request).unwrap();
+        let response = response.write_payload(*request + 1);
+        assert_that!(server.send(response), is_ok);
+
+        let response = client.receive().unwrap();
+        assert_that!(response, is_some);
+        let response = response.unwrap();
+        assert_that!(*response, eq 1235);
+        assert_that!(response.header().server_id(), eq server.id());
+        assert_that!(client.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_is_delivered_to_all_servers<Sut: Service>() {
+        const NUMBER_OF_SERVERS: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_servers(NUMBER_OF_SERVERS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let mut servers = vec![];
+        for _ in 0..NUMBER_OF_SERVERS {
+            servers.push(sut.server().create().unwrap());
+        }
+        let client = sut.client().create().unwrap();
+
+        assert_that!(client.send_copy(42), eq Ok(NUMBER_OF_SERVERS));
+
+        for server in &servers {
+            let request = server.receive().unwrap().unwrap();
+            assert_that!(*request, eq 42);
+            assert_that!(server.send_copy(&request, 84), is_ok);
+        }
+
+        for _ in 0..NUMBER_OF_SERVERS {
+            let response = client.receive().unwrap();
+            assert_that!(response, is_some);
+            assert_that!(*response.unwrap(), eq 84);
+        }
+        assert_that!(client.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn response_is_delivered_only_to_requesting_client<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client_1 = sut.client().create().unwrap();
+        let client_2 = sut.client().create().unwrap();
+
+        assert_that!(client_1.send_copy(1), eq Ok(1));
+        assert_that!(client_2.send_copy(2), eq Ok(1));
+
+        while let Some(request) = server.receive().unwrap() {
+            assert_that!(server.send_copy(&request, *request * 10), is_ok);
+        }
+
+        let response = client_1.receive().unwrap();
+        assert_that!(*response.unwrap(), eq 10);
+        assert_that!(client_1.receive().unwrap(), is_none);
+
+        let response = client_2.receive().unwrap();
+        assert_that!(*response.unwrap(), eq 20);
+        assert_that!(client_2.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_id_is_contained_in_response<Sut: Service>() {
+        const NUMBER_OF_REQUESTS: u64 = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .server_max_buffer_size(NUMBER_OF_REQUESTS as usize)
+            .client_max_buffer_size(NUMBER_OF_REQUESTS as usize)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+
+        let mut request_ids = vec![];
+        for i in 0..NUMBER_OF_REQUESTS {
+            let request = client.loan_uninit().unwrap().write_payload(i);
+            request_ids.push(request.header().request_id());
+            assert_that!(client.send(request), eq Ok(1));
+        }
+
+        while let Some(request) = server.receive().unwrap() {
+            assert_that!(request_ids[*request as usize], eq request.header().request_id());
+            let response = server.loan(&request).unwrap();
+            assert_that!(response.header().request_id(), eq request.header().request_id());
+            assert_that!(response.header().client_id(), eq client.id());
+            assert_that!(server.send(response), is_ok);
+        }
+
+        let mut counter = 0;
+        while let Some(response) = client.receive().unwrap() {
+            assert_that!(response.header().request_id(), eq request_ids[counter]);
+            counter += 1;
+        }
+        assert_that!(counter, eq NUMBER_OF_REQUESTS as usize);
+    }
+
+    #[test]
+    fn send_response_to_disconnected_client_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+
+        assert_that!(client.send_copy(5), eq Ok(1));
+        let request = server.receive().unwrap().unwrap();
+        drop(client);
+
+        let response = server.loan(&request).unwrap();
+        assert_that!(server.send(response).err().unwrap(), eq ResponseSendError::ClientNotConnected);
+    }
+
+    #[test]
+    fn client_can_loan_at_most_max_loaned_requests<Sut: Service>() {
+        const MAX_LOANED_REQUESTS: usize = 3;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let client = sut
+            .client()
+            .max_loaned_requests(MAX_LOANED_REQUESTS)
+            .create()
+            .unwrap();
+
+        let mut requests = vec![];
+        for _ in 0..MAX_LOANED_REQUESTS {
+            let request = client.loan();
+            assert_that!(request, is_ok);
+            requests.push(request.unwrap());
+        }
+
+        let request = client.loan();
+        assert_that!(request.err().unwrap(), eq LoanError::ExceedsMaxLoanedChunks);
+
+        requests.pop();
+        assert_that!(client.loan(), is_ok);
+    }
+
+    #[test]
+    fn requests_and_responses_are_recycled<Sut: Service>() {
+        const NUMBER_OF_ITERATIONS: u64 = 128;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+
+        for i in 0..NUMBER_OF_ITERATIONS {
+            assert_that!(client.send_copy(i), eq Ok(1));
+
+            let request = server.receive().unwrap().unwrap();
+            assert_that!(server.send_copy(&request, *request + 1), is_ok);
+            drop(request);
+
+            let response = client.receive().unwrap().unwrap();
+            assert_that!(*response, eq i + 1);
+        }
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
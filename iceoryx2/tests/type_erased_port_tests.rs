@@ -0,0 +1,194 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod type_erased_port {
+    use iceoryx2::port::type_erased_publisher::TypeErasedSendError;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    fn generate_name() -> TestResult<ServiceName> {
+        Ok(ServiceName::new(&format!(
+            "type_erased_port_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))?)
+    }
+
+    #[test]
+    fn open_type_erased_ignores_the_type_name<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()?;
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased()?;
+
+        assert_that!(sut.static_config().type_name(), eq service.static_config().type_name());
+        assert_that!(sut.static_config().payload_size(), eq std::mem::size_of::<u64>());
+        assert_that!(sut.static_config().payload_alignment(), eq std::mem::align_of::<u64>());
+        assert_that!(sut.static_config().max_slice_len(), eq 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_type_erased_fails_when_service_does_not_exist<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased();
+
+        assert_that!(sut, is_err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_erased_subscriber_receives_bytes_of_typed_publisher<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()?;
+        let publisher = service.publisher().create()?;
+
+        let type_erased_service = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased()?;
+        let sut = type_erased_service.subscriber().create()?;
+
+        assert_that!(sut.receive()?, is_none);
+        publisher.send_copy(0x0123456789abcdef)?;
+
+        let sample = sut.receive()?.unwrap();
+        assert_that!(sample.payload(), eq 0x0123456789abcdefu64.to_ne_bytes());
+        assert_that!(sample.header().number_of_elements(), eq 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_erased_subscriber_receives_bytes_of_slice_publisher<Sut: Service>() -> TestResult<()> {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u16]>()?;
+        let publisher = service.publisher().create()?;
+
+        let type_erased_service = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased()?;
+        let sut = type_erased_service.subscriber().create()?;
+
+        let sample = publisher
+            .loan_uninit_slice(3)?
+            .write_from_fn(|i| (i as u16 + 1) * 1000);
+        publisher.send(sample)?;
+
+        let sample = sut.receive()?.unwrap();
+        let expected: Vec<u8> = [1000u16, 2000, 3000]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        assert_that!(sample.payload(), eq expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_erased_publisher_sends_bytes_to_typed_subscriber<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()?;
+        let subscriber = service.subscriber().create()?;
+
+        let type_erased_service = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased()?;
+        let sut = type_erased_service.publisher().create()?;
+
+        for value in 0..4u64 {
+            assert_that!(unsafe { sut.send_bytes(&(value * 7).to_ne_bytes()) }, eq Ok(1));
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(*sample, eq value * 7);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_erased_publisher_sends_bytes_to_slice_subscriber<Sut: Service>() -> TestResult<()> {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name()?;
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u32]>()?;
+        let subscriber = service.subscriber().create()?;
+
+        let type_erased_service = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased()?;
+        let sut = type_erased_service.publisher().create()?;
+
+        let payload: Vec<u8> = [7u32, 8, 9, 10]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        assert_that!(unsafe { sut.send_bytes(&payload) }, eq Ok(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq [7, 8, 9, 10]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_erased_publisher_fails_when_payload_size_does_not_match<Sut: Service>() -> TestResult<()>
+    {
+        const MAX_SLICE_LEN: usize = 4;
+        let service_name = generate_name()?;
+        let _service = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u32]>()?;
+
+        let type_erased_service = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_type_erased()?;
+        let sut = type_erased_service.publisher().create()?;
+
+        for len in [0, 3, 5, 4 * MAX_SLICE_LEN + 4] {
+            let result = unsafe { sut.send_bytes(&vec![0u8; len]) };
+            assert_that!(result, eq Err(TypeErasedSendError::PayloadSizeMismatch));
+        }
+
+        assert_that!(unsafe { sut.send_bytes(&[0u8; 4 * MAX_SLICE_LEN]) }, eq Ok(0));
+
+        Ok(())
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
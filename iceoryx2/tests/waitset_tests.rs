@@ -0,0 +1,219 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod waitset {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::event_id::EventId;
+    use iceoryx2::service::{service_name::ServiceName, zero_copy, Service};
+    use iceoryx2::waitset::{
+        WaitSetAttachmentError, WaitSetAttachmentId, WaitSetBuilder, WaitSetRunError,
+        WaitSetRunResult,
+    };
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "waitset_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn create_works() {
+        let sut = WaitSetBuilder::new().create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(sut, is_empty);
+        assert_that!(sut, len 0);
+        assert_that!(sut.capacity(), gt 0);
+    }
+
+    #[test]
+    fn run_without_attachments_fails() {
+        let sut = WaitSetBuilder::new().create().unwrap();
+
+        let result = sut.wait_and_process(|_| {});
+        assert_that!(result, eq Err(WaitSetRunError::NoAttachments));
+
+        let result = sut.timed_wait_and_process(|_| {}, TIMEOUT);
+        assert_that!(result, eq Err(WaitSetRunError::NoAttachments));
+    }
+
+    #[test]
+    fn attaching_the_same_listener_twice_fails() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = event.listener().create().unwrap();
+        let sut = WaitSetBuilder::new().create().unwrap();
+
+        let _guard = sut.attach_notification(&listener).unwrap();
+        let result = sut.attach_notification(&listener);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+    }
+
+    #[test]
+    fn attachments_are_detached_when_guard_goes_out_of_scope() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = event.listener().create().unwrap();
+        let sut = WaitSetBuilder::new().create().unwrap();
+
+        let guard_1 = sut.attach_notification(&listener).unwrap();
+        let guard_2 = sut.attach_timer(TIMEOUT);
+        let guard_3 = sut.attach_interval(TIMEOUT);
+        assert_that!(sut, len 3);
+
+        drop(guard_1);
+        assert_that!(sut, len 2);
+        assert_that!(sut.attach_notification(&listener), is_ok);
+
+        drop(guard_2);
+        drop(guard_3);
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn notified_listener_wakes_up_waitset() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = event.listener().create().unwrap();
+        let notifier = event.notifier().create().unwrap();
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard = sut.attach_notification(&listener).unwrap();
+
+        assert_that!(
+            notifier.notify_with_custom_event_id(EventId::new(89)),
+            is_ok
+        );
+
+        let mut triggered_ids = vec![];
+        let result = sut.wait_and_process(|id| triggered_ids.push(id));
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(triggered_ids, eq vec![WaitSetAttachmentId::from_guard(&guard)]);
+
+        let mut received_event_ids = vec![];
+        assert_that!(
+            listener.try_wait_all(|id| received_event_ids.push(id)),
+            is_ok
+        );
+        assert_that!(received_event_ids, eq vec![EventId::new(89)]);
+    }
+
+    #[test]
+    fn only_notified_listeners_are_reported() {
+        let event_1 = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let event_2 = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener_1 = event_1.listener().create().unwrap();
+        let listener_2 = event_2.listener().create().unwrap();
+        let notifier_2 = event_2.notifier().create().unwrap();
+
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let guard_2 = sut.attach_notification(&listener_2).unwrap();
+
+        assert_that!(notifier_2.notify(), is_ok);
+
+        let mut triggered_ids = vec![];
+        let result = sut.timed_wait_and_process(|id| triggered_ids.push(id), TIMEOUT);
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(triggered_ids, len 1);
+        assert_that!(triggered_ids[0].has_event_from(&guard_2), eq true);
+        assert_that!(triggered_ids[0].has_event_from(&guard_1), eq false);
+    }
+
+    #[test]
+    fn timed_wait_returns_after_timeout_without_events() {
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = event.listener().create().unwrap();
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let _guard = sut.attach_notification(&listener).unwrap();
+
+        let start = Instant::now();
+        let mut triggered_ids = vec![];
+        let result = sut.timed_wait_and_process(|id| triggered_ids.push(id), TIMEOUT);
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(triggered_ids, is_empty);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+    }
+
+    #[test]
+    fn timer_fires_only_once() {
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard = sut.attach_timer(TIMEOUT);
+
+        let start = Instant::now();
+        let mut triggered_ids = vec![];
+        while triggered_ids.is_empty() {
+            assert_that!(sut.wait_and_process(|id| triggered_ids.push(id)), is_ok);
+        }
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(triggered_ids, eq vec![WaitSetAttachmentId::from_guard(&guard)]);
+
+        triggered_ids.clear();
+        let result = sut.timed_wait_and_process(|id| triggered_ids.push(id), TIMEOUT * 2);
+        assert_that!(result, eq Err(WaitSetRunError::NoAttachments));
+        assert_that!(triggered_ids, is_empty);
+        assert_that!(sut, len 1);
+
+        let event = zero_copy::Service::new(&generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let listener = event.listener().create().unwrap();
+        let _listener_guard = sut.attach_notification(&listener).unwrap();
+
+        let result = sut.timed_wait_and_process(|id| triggered_ids.push(id), TIMEOUT * 2);
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(triggered_ids, is_empty);
+    }
+
+    #[test]
+    fn interval_fires_periodically() {
+        let sut = WaitSetBuilder::new().create().unwrap();
+        let guard = sut.attach_interval(TIMEOUT);
+
+        let start = Instant::now();
+        let mut number_of_triggers = 0;
+        while number_of_triggers < 3 {
+            assert_that!(
+                sut.wait_and_process(|id| {
+                    assert_that!(id.has_event_from(&guard), eq true);
+                    number_of_triggers += 1;
+                }),
+                is_ok
+            );
+        }
+        assert_that!(start.elapsed(), ge TIMEOUT * 3);
+        assert_that!(sut, len 1);
+    }
+}
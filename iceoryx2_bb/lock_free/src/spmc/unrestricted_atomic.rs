@@ -67,9 +67,10 @@ unsafe impl<'a, T: Copy> Sync for Producer<'a, T> {}
 /// unrestricted.
 #[repr(C)]
 pub struct UnrestrictedAtomic<T: Copy> {
+    // must be the first member, see release_producer_of_type_erased_atomic()
+    has_producer: AtomicBool,
     write_cell: AtomicU64,
     data: [UnsafeCell<MaybeUninit<T>>; NUMBER_OF_CELLS],
-    has_producer: AtomicBool,
 }
 
 impl<T: Copy + Debug> Debug for UnrestrictedAtomic<T> {
@@ -85,6 +86,18 @@ impl<T: Copy + Debug> Debug for UnrestrictedAtomic<T> {
     }
 }
 
+/// Releases the [`Producer`] of an [`UnrestrictedAtomic`] without knowing its type so that a
+/// new [`Producer`] can be acquired again. Required when the process that owned the
+/// [`Producer`] died and its destructor was never called.
+///
+/// # Safety
+///
+///  * `atomic` must point to an initialized [`UnrestrictedAtomic`]
+///  * the currently acquired [`Producer`] must not be used anymore
+pub unsafe fn release_producer_of_type_erased_atomic(atomic: *const u8) {
+    (*(atomic as *const AtomicBool)).store(true, Ordering::Relaxed);
+}
+
 unsafe impl<T: Copy> Send for UnrestrictedAtomic<T> {}
 unsafe impl<T: Copy> Sync for UnrestrictedAtomic<T> {}
 
@@ -74,8 +74,10 @@
 //! ```
 
 use iceoryx2_bb_container::byte_string::*;
+use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::{enum_gen, scope_guard::ScopeGuardBuilder};
 use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::*;
 use std::fmt::Debug;
@@ -771,6 +773,31 @@ impl AccessControlList {
         );
     }
 
+    /// Applies the AccessControlList to a file. In contrast to
+    /// [`AccessControlList::apply_to_file_descriptor()`] it can also be applied to files that
+    /// cannot be opened, like the file of a unix domain socket.
+    pub fn apply_to_file(&self, path: &FilePath) -> Result<(), AccessControlListApplyError> {
+        if unsafe { posix::acl_valid(self.handle) } == -1 {
+            fail!(from self, with AccessControlListApplyError::ContainsInvalidValues, "Unable to apply the AccessControlList to file \"{}\" since it contains invalid values.", path);
+        }
+
+        if unsafe { posix::acl_set_file(path.as_c_str(), posix::ACL_TYPE_ACCESS, self.handle) } == 0
+        {
+            return Ok(());
+        }
+
+        let msg = "Unable to apply the AccessControlList to file";
+        handle_errno!(AccessControlListApplyError, from self,
+            Errno::EACCES => (InsufficientPermissions, "{} \"{}\" due to insufficient permissions.", msg, path),
+            Errno::EINVAL => (ListTooBig, "{} \"{}\" since it contains more entries than the file can obtain.", msg, path),
+            Errno::ENOSPC => (NoSpaceLeft, "{} \"{}\" since there is no space left on the target device.", msg, path),
+            Errno::ENOTSUP => (NotSupportedByFileSystem, "{} \"{}\" since it is not supported by the file-system.", msg, path),
+            Errno::EPERM => (InsufficientPermissions, "{} \"{}\" due to insufficient permissions.", msg, path),
+            Errno::EROFS => (ReadOnlyFileSystem, "{} \"{}\" since the file-system is read-only.", msg, path),
+            v => (UnknownError(v as i32), "{} \"{}\" since an unknown error occurred ({}).", msg, path, v)
+        );
+    }
+
     fn add_entry(
         &mut self,
         tag: posix::acl_tag_t,
@@ -133,6 +133,37 @@ impl Group {
         Self::from_gid(unsafe { posix::getgid() })
     }
 
+    /// Returns the gids of the supplementary groups of the process. The owners group of the
+    /// process, see [`Group::from_self()`], may or may not be contained.
+    pub fn supplementary_gids_of_self() -> Result<Vec<u32>, GroupError> {
+        let msg = "Unable to acquire the supplementary groups of the process";
+        let number_of_groups = unsafe { posix::getgroups(0, core::ptr::null_mut()) };
+        if number_of_groups < 0 {
+            let v = Errno::get();
+            fail!(from "Group::supplementary_gids_of_self()", with GroupError::UnknownError(v as i32),
+                "{} due to an unknown error ({}).", msg, v);
+        }
+
+        let mut gids: Vec<posix::gid_t> = vec![0; number_of_groups as usize];
+        let number_of_groups =
+            unsafe { posix::getgroups(gids.len() as posix::int, gids.as_mut_ptr()) };
+        if number_of_groups < 0 {
+            match Errno::get() {
+                Errno::EINVAL => {
+                    fail!(from "Group::supplementary_gids_of_self()", with GroupError::InsufficientBufferSize,
+                        "{} since the supplementary groups changed while they were acquired.", msg);
+                }
+                v => {
+                    fail!(from "Group::supplementary_gids_of_self()", with GroupError::UnknownError(v as i32),
+                        "{} due to an unknown error ({}).", msg, v);
+                }
+            }
+        }
+
+        gids.truncate(number_of_groups as usize);
+        Ok(gids)
+    }
+
     /// Create an group object from a given gid. If the gid does not exist an error will be
     /// returned.
     pub fn from_gid(gid: u32) -> Result<Group, GroupError> {
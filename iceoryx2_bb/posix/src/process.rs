@@ -158,6 +158,12 @@ impl Process {
         unsafe { posix::kill(self.pid.0, 0_i32) == 0 }
     }
 
+    /// Checks if the process exists. In contrast to [`Process::is_alive()`] a process that
+    /// cannot be signalled due to insufficient permissions is considered to exist.
+    pub fn does_exist(&self) -> bool {
+        unsafe { posix::kill(self.pid.0, 0_i32) == 0 || Errno::get() != Errno::ESRCH }
+    }
+
     /// Returns the id of the process.
     pub fn id(&self) -> ProcessId {
         self.pid
@@ -72,6 +72,14 @@ impl Display for UniqueSystemId {
     }
 }
 
+impl From<u128> for UniqueSystemId {
+    /// Restores a [`UniqueSystemId`] from a value that was acquired with
+    /// [`UniqueSystemId::value()`]
+    fn from(value: u128) -> Self {
+        Self { value }
+    }
+}
+
 impl UniqueSystemId {
     /// Creates a new system wide unique id
     pub fn new() -> Result<Self, UniqueSystemIdCreationError> {
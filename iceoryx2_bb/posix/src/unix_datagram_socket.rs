@@ -121,6 +121,7 @@ use std::mem::MaybeUninit;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::{mem::size_of, time::Duration};
 
+use crate::access_control_list::{AccessControlList, AccessControlListApplyError};
 use crate::clock::AsTimeval;
 use crate::file_descriptor::{FileDescriptor, FileDescriptorBased, FileDescriptorManagement};
 use crate::file_descriptor_set::SynchronousMultiplexing;
@@ -1058,6 +1059,16 @@ impl FileDescriptorBased for UnixDatagramReceiver {
     }
 }
 
-impl FileDescriptorManagement for UnixDatagramReceiver {}
+impl FileDescriptorManagement for UnixDatagramReceiver {
+    /// Sets a new access control list on the socket file so that it restricts who can connect
+    /// to the receiver. The access control list of the socket file descriptor itself has no
+    /// effect on the socket file.
+    fn set_access_control_list(
+        &self,
+        acl: &AccessControlList,
+    ) -> Result<(), AccessControlListApplyError> {
+        acl.apply_to_file(&self.socket.name)
+    }
+}
 
 impl SynchronousMultiplexing for UnixDatagramReceiver {}
@@ -39,3 +39,10 @@ fn group_works() {
     assert_that!(group_from_name.name(), eq group_from_gid.name());
     assert_that!(*group_from_name.name(), eq group_name);
 }
+
+#[test]
+fn group_supplementary_gids_of_self_works() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    assert_that!(Group::supplementary_gids_of_self(), is_ok);
+}
@@ -45,3 +45,12 @@ pub fn process_is_alive_works() {
     let process2 = Process::from_pid(ProcessId::new(posix::pid_t::MAX - 1));
     assert_that!(process2.is_alive(), eq false);
 }
+
+#[test]
+pub fn process_does_exist_works() {
+    let process = Process::from_self();
+    assert_that!(process.does_exist(), eq true);
+
+    let process2 = Process::from_pid(ProcessId::new(posix::pid_t::MAX - 1));
+    assert_that!(process2.does_exist(), eq false);
+}
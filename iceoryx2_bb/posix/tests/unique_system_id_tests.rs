@@ -35,3 +35,12 @@ fn unique_system_id_is_unique() {
     assert_that!(sut1.creation_time().seconds() + 2, ge sut2.creation_time().seconds());
     assert_that!(sut1.creation_time().seconds() + 3, ge sut3.creation_time().seconds());
 }
+
+#[test]
+fn unique_system_id_can_be_restored_from_value() {
+    let sut = UniqueSystemId::new().unwrap();
+    let restored = UniqueSystemId::from(sut.value());
+
+    assert_that!(restored, eq sut);
+    assert_that!(restored.pid(), eq sut.pid());
+}
@@ -65,6 +65,7 @@ use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConcep
 
 pub mod posix_shared_memory;
 pub mod process_local;
+pub mod shared_memory_directory;
 
 /// The default suffix of every dynamic storage
 pub const DEFAULT_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".dyn") };
@@ -41,7 +41,9 @@
 //! ```
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::access_control_list::AclPermission;
 use iceoryx2_bb_posix::directory::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
 use iceoryx2_bb_posix::shared_memory::*;
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -49,6 +51,7 @@ use std::ptr::NonNull;
 use std::sync::atomic::AtomicU64;
 
 pub use crate::dynamic_storage::*;
+use crate::static_storage::file::AccessRights;
 use crate::static_storage::file::NamedConceptConfiguration;
 use crate::static_storage::file::NamedConceptRemoveError;
 use iceoryx2_bb_system_types::path::Path;
@@ -72,6 +75,7 @@ pub struct Configuration {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    access_rights: AccessRights,
 }
 
 #[repr(C)]
@@ -86,6 +90,7 @@ impl Default for Configuration {
             path: DEFAULT_PATH_HINT,
             suffix: DEFAULT_SUFFIX,
             prefix: DEFAULT_PREFIX,
+            access_rights: AccessRights::default(),
         }
     }
 }
@@ -117,6 +122,11 @@ impl NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
 }
 
 impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<T> {
@@ -176,6 +186,15 @@ impl<T: Send + Sync + Debug> DynamicStorageBuilder<T, Storage<T>> for Builder<T>
             }
         };
 
+        if !self.config.access_rights.is_empty() {
+            let acl = fail!(from self, when self.config.access_rights.access_control_list(AclPermission::ReadWriteExecute),
+                with DynamicStorageCreateError::Creation,
+                "{} since the access control list could not be created.", msg);
+            fail!(from self, when shm.set_access_control_list(&acl),
+                with DynamicStorageCreateError::Creation,
+                "{} since the access control list could not be applied to the underlying shared memory.", msg);
+        }
+
         let value = shm.base_address().as_ptr() as *mut Data<T>;
         unsafe { core::ptr::addr_of_mut!((*value).data).write(initial_value) };
 
@@ -0,0 +1,427 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`DynamicStorage`] that is stored as file inside a
+//! [`crate::shared_memory_directory::SharedMemoryDirectory`]. The storages are distributed by
+//! the hash of their name over [`Configuration::number_of_directories`] directories whose names
+//! are derived from the prefix and the [`Configuration::directory_name`]. Every directory
+//! consists of two OS shared memory objects, which reduces the number of file descriptors and
+//! memory mappings drastically when many storages are used. A directory can hold at most 512
+//! storages. The size of its first data segment is defined by the first process that creates it
+//! with [`Configuration::directory_size`], further data segments are added when the memory is
+//! exhausted. A directory reserves its data segments as long as it contains a storage or is
+//! used by a process, with the defaults at least 1 MiB per directory.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::dynamic_storage::shared_memory_directory::*;
+//! use iceoryx2_cal::named_concept::*;
+//! use std::sync::atomic::{AtomicI64, Ordering};
+//!
+//! let storage_name = FileName::new(b"myDirStorageName").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 .create(AtomicI64::new(0)).unwrap();
+//! owner.get().store(123, Ordering::Relaxed);
+//!
+//! // usually a different process
+//! let storage = Builder::<AtomicI64>::new(&storage_name)
+//!                 .open().unwrap();
+//!
+//! println!("Initial value: {}", storage.get().load(Ordering::Relaxed));
+//! ```
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+pub use crate::dynamic_storage::*;
+use crate::named_concept::{
+    AccessRights, NamedConceptConfiguration, NamedConceptDoesExistError, NamedConceptListError,
+    NamedConceptRemoveError,
+};
+use crate::shared_memory_directory::file::File;
+use crate::shared_memory_directory::registry::{DirectoryHandle, DirectorySet};
+use crate::shared_memory_directory::{
+    SharedMemoryDirectoryCreateFileError, DEFAULT_NUMBER_OF_DIRECTORIES,
+};
+
+/// The name of the directory that is used when no other name is configured.
+pub const DEFAULT_DIRECTORY_NAME: FileName =
+    unsafe { FileName::new_unchecked(b"dynamic_storage_directory") };
+
+/// The size of the first data segment of a directory that is used when no other size is
+/// configured.
+pub const DEFAULT_DIRECTORY_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    /// The name of the directory in which the storage is stored.
+    pub directory_name: FileName,
+    /// The size of the first data segment of the directory when it does not exist and has to
+    /// be created.
+    pub directory_size: usize,
+    /// The number of directories over which the dynamic storage objects are distributed. A
+    /// single directory holds at most 512 of them.
+    pub number_of_directories: usize,
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+    access_rights: AccessRights,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            directory_name: DEFAULT_DIRECTORY_NAME,
+            directory_size: DEFAULT_DIRECTORY_SIZE,
+            number_of_directories: DEFAULT_NUMBER_OF_DIRECTORIES,
+            path: DEFAULT_PATH_HINT,
+            suffix: DEFAULT_SUFFIX,
+            prefix: DEFAULT_PREFIX,
+            access_rights: AccessRights::default(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
+
+    fn does_support_access_rights() -> bool {
+        false
+    }
+}
+
+impl Configuration {
+    fn file_name(&self, name: &FileName) -> FileName {
+        unsafe { FileName::new_unchecked(self.path_for(name).file_name()) }
+    }
+
+    fn directories(&self) -> DirectorySet {
+        DirectorySet::new(
+            &self.prefix,
+            &self.directory_name,
+            self.directory_size,
+            self.number_of_directories,
+        )
+    }
+}
+
+/// The builder of [`Storage`].
+#[derive(Debug)]
+pub struct Builder<T: Debug> {
+    storage_name: FileName,
+    supplementary_size: usize,
+    has_ownership: bool,
+    config: Configuration,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T: Send + Sync + Debug> Builder<T> {
+    fn layout(&self) -> Layout {
+        unsafe {
+            Layout::from_size_align_unchecked(
+                (std::mem::size_of::<T>() + self.supplementary_size).max(1),
+                std::mem::align_of::<T>(),
+            )
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<T> {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            has_ownership: true,
+            storage_name: *storage_name,
+            supplementary_size: 0,
+            config: Configuration::default(),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorageBuilder<T, Storage<T>> for Builder<T> {
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn supplementary_size(mut self, value: usize) -> Self {
+        self.supplementary_size = value;
+        self
+    }
+
+    fn create_and_initialize<F: FnOnce(&mut T, &mut BumpAllocator) -> bool>(
+        self,
+        initial_value: T,
+        initializer: F,
+    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic_storage::SharedMemoryDirectory";
+
+        if !self.config.access_rights.is_empty() {
+            fail!(from self, with DynamicStorageCreateError::Creation,
+                "{} since access rights cannot be applied to a single storage of the directory \"{}\".",
+                msg, self.config.directory_name);
+        }
+
+        let directory = fail!(from self, when self.config.directories().acquire(&self.config.file_name(&self.storage_name)),
+                                with DynamicStorageCreateError::Creation,
+                                "{} since the directory \"{}\" is not available.", msg, self.config.directory_name);
+
+        let file_creator = fail!(from self, when unsafe { directory.new_file(self.layout()) },
+                                with DynamicStorageCreateError::Creation,
+                                "{} since the memory for the storage could not be allocated in the directory \"{}\".",
+                                msg, self.config.directory_name);
+
+        let supplementary_size = self.supplementary_size;
+        let file = match file_creator.is_persistent(true).create_and_initialize(
+            &self.config.file_name(&self.storage_name),
+            |content| {
+                let value = content.as_mut_ptr() as *mut T;
+                unsafe { value.write(initial_value) };
+
+                let mut allocator = BumpAllocator::new(
+                    unsafe {
+                        NonNull::new_unchecked(content.as_mut_ptr().add(std::mem::size_of::<T>()))
+                    },
+                    supplementary_size,
+                );
+
+                initializer(unsafe { &mut *value }, &mut allocator)
+            },
+        ) {
+            Ok(file) => file,
+            Err(SharedMemoryDirectoryCreateFileError::DoesExist)
+            | Err(SharedMemoryDirectoryCreateFileError::BeingCreated) => {
+                fail!(from self, with DynamicStorageCreateError::AlreadyExists,
+                    "{} since a storage with the name already exists.", msg);
+            }
+            Err(SharedMemoryDirectoryCreateFileError::InitializationFailed) => {
+                fail!(from self, with DynamicStorageCreateError::InitializationFailed,
+                    "{} since the initialization of the underlying construct failed.", msg);
+            }
+            Err(SharedMemoryDirectoryCreateFileError::FileLimitExceeded) => {
+                fail!(from self, with DynamicStorageCreateError::Creation,
+                    "{} since the directory \"{}\" cannot hold any more files.", msg, self.config.directory_name);
+            }
+        };
+
+        Ok(Storage {
+            file,
+            directory,
+            name: self.storage_name,
+            has_ownership: self.has_ownership,
+            config: self.config,
+            _phantom_data: PhantomData,
+        })
+    }
+
+    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open ";
+        let origin = format!("{:?}", self);
+        match self.try_open() {
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                fail!(from origin, with DynamicStorageOpenError::DoesNotExist, "{} since a storage with that name does not exists.", msg);
+            }
+            Err(e) => Err(e),
+            Ok(s) => Ok(s),
+        }
+    }
+
+    fn try_open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open ";
+
+        let file_name = self.config.file_name(&self.storage_name);
+        let directory = match fail!(from self, when self.config.directories().acquire_existing(&file_name),
+                                with DynamicStorageOpenError::Open,
+                                "{} since the directory \"{}\" is not available.", msg, self.config.directory_name)
+        {
+            Some(directory) => directory,
+            None => return Err(DynamicStorageOpenError::DoesNotExist),
+        };
+
+        // files only become visible in the directory when they are completely initialized
+        let file = match unsafe { directory.open_file(&file_name) } {
+            Some(file) => file,
+            None => return Err(DynamicStorageOpenError::DoesNotExist),
+        };
+
+        let required_layout = self.layout();
+        if file.content().len() < required_layout.size() {
+            fail!(from self, with DynamicStorageOpenError::InternalError,
+                "{} since the actual size {} does not match the required size of {}.",
+                msg, file.content().len(), required_layout.size());
+        }
+
+        Ok(Storage {
+            file,
+            directory,
+            name: self.storage_name,
+            has_ownership: false,
+            config: self.config,
+            _phantom_data: PhantomData,
+        })
+    }
+}
+
+/// Implements [`DynamicStorage`] as file inside a
+/// [`crate::shared_memory_directory::SharedMemoryDirectory`]. It is built by [`Builder`].
+#[derive(Debug)]
+pub struct Storage<T> {
+    // declared before the directory so that it is dropped first
+    file: File<'static>,
+    directory: DirectoryHandle,
+    name: FileName,
+    has_ownership: bool,
+    config: Configuration,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T> Drop for Storage<T> {
+    fn drop(&mut self) {
+        if self.has_ownership {
+            self.directory
+                .remove_file(&self.config.file_name(&self.name));
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = "Unable to check if dynamic_storage::shared_memory_directory exists";
+        let origin = "dynamic_storage::shared_memory_directory::Storage::does_exist_cfg()";
+
+        let file_name = cfg.file_name(name);
+        match cfg.directories().acquire_existing(&file_name) {
+            Ok(Some(directory)) => Ok(directory.does_file_exist(&file_name)),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                fail!(from origin, with NamedConceptDoesExistError::InternalError,
+                    "{} since the directory \"{}\" is not available ({:?}).", msg, cfg.directory_name, e);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all dynamic_storage::shared_memory_directory";
+        let origin = "dynamic_storage::shared_memory_directory::Storage::list_cfg()";
+
+        let directories = match config.directories().list_existing() {
+            Ok(directories) => directories,
+            Err(e) => {
+                fail!(from origin, with NamedConceptListError::InternalError,
+                    "{} since the directories \"{}\" are not available ({:?}).", msg, config.directory_name, e);
+            }
+        };
+
+        Ok(directories
+            .iter()
+            .flat_map(|directory| directory.list_files())
+            .filter_map(|file| config.extract_name_from_file(&file.name()))
+            .collect())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove dynamic_storage::shared_memory_directory";
+        let origin = "dynamic_storage::shared_memory_directory::Storage::remove_cfg()";
+
+        let file_name = cfg.file_name(name);
+        match cfg.directories().acquire_existing(&file_name) {
+            Ok(Some(directory)) => Ok(directory.remove_file(&file_name)),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} \"{}\" since the directory \"{}\" is not available ({:?}).", msg, name, cfg.directory_name, e);
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
+    type Builder = Builder<T>;
+
+    fn does_support_persistency() -> bool {
+        true
+    }
+
+    fn acquire_ownership(&mut self) {
+        self.has_ownership = true;
+    }
+
+    fn get(&self) -> &T {
+        unsafe { &*(self.file.content().as_ptr() as *const T) }
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.has_ownership
+    }
+
+    fn release_ownership(&mut self) {
+        self.has_ownership = false;
+    }
+}
@@ -49,7 +49,7 @@ impl std::fmt::Display for NotifierCreateError {
 
 impl std::error::Error for NotifierCreateError {}
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum ListenerWaitError {
     ContractViolation,
     InternalFailure,
@@ -13,10 +13,13 @@
 use std::{marker::PhantomData, mem::MaybeUninit};
 
 pub use crate::event::*;
+use crate::named_concept::AccessRights;
 use crate::static_storage::file::NamedConceptConfiguration;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::{
-    file_descriptor::FileDescriptorBased, file_descriptor_set::SynchronousMultiplexing,
+    access_control_list::{AccessControlListApplyError, AclPermission},
+    file_descriptor::{FileDescriptorBased, FileDescriptorManagement},
+    file_descriptor_set::SynchronousMultiplexing,
     unix_datagram_socket::*,
 };
 pub use iceoryx2_bb_system_types::file_name::FileName;
@@ -26,6 +29,7 @@ pub struct Configuration {
     suffix: FileName,
     prefix: FileName,
     path: Path,
+    access_rights: AccessRights,
 }
 
 impl Default for Configuration {
@@ -34,6 +38,7 @@ impl Default for Configuration {
             path: DEFAULT_PATH_HINT,
             suffix: DEFAULT_SUFFIX,
             prefix: DEFAULT_PREFIX,
+            access_rights: AccessRights::default(),
         }
     }
 }
@@ -65,6 +70,11 @@ impl NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
 }
 
 impl From<Configuration> for crate::communication_channel::unix_datagram::Configuration {
@@ -304,11 +314,23 @@ impl<Id: crate::event::TriggerId + Copy> crate::event::ListenerBuilder<Id, Event
             .creation_mode(CreationMode::CreateExclusive)
             .create()
         {
-            Ok(r) => Ok(Listener {
-                receiver: r,
-                name: self.name,
-                _data: PhantomData,
-            }),
+            Ok(r) => {
+                if !self.config.access_rights.is_empty() {
+                    let acl = fail!(from self, when self.config.access_rights.access_control_list(AclPermission::ReadWriteExecute),
+                        with ListenerCreateError::InternalFailure,
+                        "{} since the access control list could not be created.", msg);
+                    fail!(from self, when r.set_access_control_list(&acl),
+                        map AccessControlListApplyError::InsufficientPermissions => ListenerCreateError::InsufficientPermissions,
+                        unmatched ListenerCreateError::InternalFailure,
+                        "{} since the access control list could not be applied.", msg);
+                }
+
+                Ok(Listener {
+                    receiver: r,
+                    name: self.name,
+                    _data: PhantomData,
+                })
+            }
             Err(UnixDatagramReceiverCreationError::SocketFileAlreadyExists) => {
                 fail!(from self, with ListenerCreateError::AlreadyExists,
                             "{} since the underlying socket does not exist.", msg);
@@ -15,6 +15,9 @@ use std::fmt::Debug;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_container::semantic_string::SemanticStringAccessor;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::access_control_list::{
+    AccessControlList, AccessControlListCreationError, Acl, AclPermission,
+};
 pub use iceoryx2_bb_system_types::file_name::FileName;
 pub use iceoryx2_bb_system_types::file_path::FilePath;
 pub use iceoryx2_bb_system_types::path::Path;
@@ -39,6 +42,132 @@ pub enum NamedConceptListError {
     InternalError,
 }
 
+/// The maximum number of users and the maximum number of groups that can be stored in
+/// [`AccessRights`].
+pub const MAX_NUMBER_OF_ACCESS_RIGHTS: usize = 16;
+
+/// Users and groups that are granted access to a [`NamedConcept`] in addition to its owner.
+/// Every user and group has its own [`AclPermission`]. Concepts that are backed by files or
+/// shared memory apply them as [`AccessControlList`] when they are created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct AccessRights {
+    users: [(u32, AclPermission); MAX_NUMBER_OF_ACCESS_RIGHTS],
+    number_of_users: usize,
+    groups: [(u32, AclPermission); MAX_NUMBER_OF_ACCESS_RIGHTS],
+    number_of_groups: usize,
+}
+
+impl Default for AccessRights {
+    fn default() -> Self {
+        Self {
+            users: [(0, AclPermission::None); MAX_NUMBER_OF_ACCESS_RIGHTS],
+            number_of_users: 0,
+            groups: [(0, AclPermission::None); MAX_NUMBER_OF_ACCESS_RIGHTS],
+            number_of_groups: 0,
+        }
+    }
+}
+
+impl AccessRights {
+    /// Grants the user with the given uid the [`AclPermission`]. If the user was already added
+    /// the permissions are combined. Returns false when the maximum number of users,
+    /// [`MAX_NUMBER_OF_ACCESS_RIGHTS`], was already added.
+    pub fn add_user(&mut self, uid: u32, permission: AclPermission) -> bool {
+        Self::add(&mut self.users, &mut self.number_of_users, uid, permission)
+    }
+
+    /// Grants the group with the given gid the [`AclPermission`]. If the group was already
+    /// added the permissions are combined. Returns false when the maximum number of groups,
+    /// [`MAX_NUMBER_OF_ACCESS_RIGHTS`], was already added.
+    pub fn add_group(&mut self, gid: u32, permission: AclPermission) -> bool {
+        Self::add(
+            &mut self.groups,
+            &mut self.number_of_groups,
+            gid,
+            permission,
+        )
+    }
+
+    /// Returns the uids and permissions of all users that are granted access
+    pub fn users(&self) -> &[(u32, AclPermission)] {
+        &self.users[..self.number_of_users]
+    }
+
+    /// Returns the gids and permissions of all groups that are granted access
+    pub fn groups(&self) -> &[(u32, AclPermission)] {
+        &self.groups[..self.number_of_groups]
+    }
+
+    /// Returns true when no additional user or group is granted access
+    pub fn is_empty(&self) -> bool {
+        self.number_of_users == 0 && self.number_of_groups == 0
+    }
+
+    /// Creates an [`AccessControlList`] that grants the owner the `owner_permission` and all
+    /// users and groups of the [`AccessRights`] their permission. Everyone else has no access.
+    pub fn access_control_list(
+        &self,
+        owner_permission: AclPermission,
+    ) -> Result<AccessControlList, AccessControlListCreationError> {
+        let mut acl = AccessControlList::new()?;
+        acl.set(Acl::OwningUser, owner_permission)?;
+        acl.set(Acl::OwningGroup, AclPermission::None)?;
+        acl.set(Acl::Other, AclPermission::None)?;
+
+        let mut max_permission = AclPermission::None;
+        for (uid, permission) in self.users() {
+            acl.add_user(*uid, *permission)?;
+            max_permission = combine(max_permission, *permission);
+        }
+
+        for (gid, permission) in self.groups() {
+            acl.add_group(*gid, *permission)?;
+            max_permission = combine(max_permission, *permission);
+        }
+        acl.set(Acl::MaxAccessRightsForNonOwners, max_permission)?;
+
+        Ok(acl)
+    }
+
+    fn add(
+        entries: &mut [(u32, AclPermission); MAX_NUMBER_OF_ACCESS_RIGHTS],
+        len: &mut usize,
+        id: u32,
+        permission: AclPermission,
+    ) -> bool {
+        if let Some(entry) = entries[..*len].iter_mut().find(|(e, _)| *e == id) {
+            entry.1 = combine(entry.1, permission);
+            return true;
+        }
+
+        if *len == MAX_NUMBER_OF_ACCESS_RIGHTS {
+            return false;
+        }
+
+        entries[*len] = (id, permission);
+        *len += 1;
+        true
+    }
+}
+
+fn combine(lhs: AclPermission, rhs: AclPermission) -> AclPermission {
+    const READ: u32 = AclPermission::Read as u32;
+    const WRITE: u32 = AclPermission::Write as u32;
+    const EXECUTE: u32 = AclPermission::Execute as u32;
+
+    let value = lhs as u32 | rhs as u32;
+    match (value & READ != 0, value & WRITE != 0, value & EXECUTE != 0) {
+        (false, false, false) => AclPermission::None,
+        (true, false, false) => AclPermission::Read,
+        (false, true, false) => AclPermission::Write,
+        (false, false, true) => AclPermission::Execute,
+        (true, true, false) => AclPermission::ReadWrite,
+        (true, false, true) => AclPermission::ReadExecute,
+        (false, true, true) => AclPermission::WriteExecute,
+        (true, true, true) => AclPermission::ReadWriteExecute,
+    }
+}
+
 /// Every [`NamedConcept`] must have a custom configuration that at least allows the user to define
 /// a custom [`NamedConceptConfiguration::suffix()`] for all file names that are transparent during
 /// usage as well as a [`NamedConceptConfiguration::path_hint()`] that can be ignored if the
@@ -64,6 +193,20 @@ pub trait NamedConceptConfiguration: Default + Clone + Debug {
     /// Returns the configurations path hint.
     fn get_path_hint(&self) -> &Path;
 
+    /// Defines the [`AccessRights`] of the concept. Concepts that cannot be accessed by other
+    /// users, like process local ones, ignore them.
+    fn access_rights(self, _value: AccessRights) -> Self {
+        self
+    }
+
+    /// Returns false when the concept is accessible by other users but cannot restrict the
+    /// access to its underlying resources with [`AccessRights`], for instance since the
+    /// resources are shared with other concepts. Those concepts fail to create a resource with
+    /// non-empty [`AccessRights`].
+    fn does_support_access_rights() -> bool {
+        true
+    }
+
     /// Returns the full path for a given value under the given configuration.
     fn path_for(&self, value: &FileName) -> FilePath {
         let mut path = *self.get_path_hint();
@@ -56,6 +56,7 @@
 
 pub mod posix;
 pub mod process_local;
+pub mod shared_memory_directory;
 
 use std::fmt::Debug;
 
@@ -120,6 +121,14 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
 
     /// Opens already existing [`SharedMemory`]. If it does not exist the method will fail.
     fn open(self) -> Result<Shm, SharedMemoryOpenError>;
+
+    /// Opens already existing [`SharedMemory`] with read-only access. If it does not exist the
+    /// method will fail. The memory must not be modified, e.g. with
+    /// [`SharedMemory::allocate()`]. Implementations that do not support read-only access open
+    /// the memory with read-write access.
+    fn open_read_only(self) -> Result<Shm, SharedMemoryOpenError> {
+        self.open()
+    }
 }
 
 /// Abstract concept of a memory shared between multiple processes. Can be created with the
@@ -19,6 +19,8 @@ pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::DeallocationError;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::access_control_list::{AccessControlListApplyError, AclPermission};
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
 use iceoryx2_bb_posix::shared_memory::{AccessMode, Permission};
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_posix::unix_datagram_socket::CreationMode;
@@ -26,7 +28,7 @@ use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
 
 use crate::static_storage::file::{
-    NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+    AccessRights, NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
     NamedConceptRemoveError,
 };
 
@@ -40,6 +42,7 @@ pub struct Configuration {
     path: Path,
     suffix: FileName,
     prefix: FileName,
+    access_rights: AccessRights,
 }
 
 impl Default for Configuration {
@@ -51,6 +54,7 @@ impl Default for Configuration {
             path: DEFAULT_PATH_HINT,
             suffix: DEFAULT_SUFFIX,
             prefix: DEFAULT_PREFIX,
+            access_rights: AccessRights::default(),
         }
     }
 }
@@ -82,6 +86,11 @@ impl NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -102,6 +111,67 @@ impl<Allocator: ShmAllocator + Debug> Builder<Allocator> {
     fn allocator_size(&self, allocator_config: &Allocator::Configuration) -> usize {
         Self::allocator_details_size() + Allocator::management_size(self.size, allocator_config)
     }
+
+    fn open_with_access_mode(
+        self,
+        access_mode: AccessMode,
+    ) -> Result<Memory<Allocator>, SharedMemoryOpenError> {
+        let msg = "Unable to open shared memory";
+
+        let shm = match iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder::new(unsafe {
+            &FileName::new_unchecked(self.config.path_for(&self.name).file_name())
+        })
+        .is_memory_locked(self.config.is_memory_locked)
+        .open_existing(access_mode)
+        {
+            Ok(s) => s,
+            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::DoesNotExist) => {
+                fail!(from self, with SharedMemoryOpenError::DoesNotExist,
+                        "{} since a shared memory with that name does not exist.", msg);
+            }
+            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::SizeDoesNotFit) => {
+                fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
+                        "{} since the requested size is not equal the actual size of the shared memory.", msg);
+            }
+            Err(
+                iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::InsufficientPermissions,
+            ) => {
+                fail!(from self, with SharedMemoryOpenError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with SharedMemoryOpenError::InternalError,
+                        "{} since an unknown error has occurred ({:?}).", msg, v);
+            }
+        };
+
+        let allocator_addr = shm.base_address().as_ptr() as *mut AllocatorDetails<Allocator>;
+
+        if unsafe { &*allocator_addr }.state.load(Ordering::Relaxed) != IS_INITIALIZED_STATE_VALUE {
+            fail!(from self, with SharedMemoryOpenError::InternalError,
+                    "{} since the creation of the shared memory is not yet finished.", msg);
+        }
+
+        const SPACE_FOR_ALLOCATOR_ID: usize = 1;
+
+        if shm.size() <= SPACE_FOR_ALLOCATOR_ID {
+            fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
+                "{} since the shared memories size {} is smaller than the minimum required size of {}.",
+                msg, shm.size(), SPACE_FOR_ALLOCATOR_ID);
+        }
+
+        if unsafe { &*allocator_addr }.allocator_id != Allocator::unique_id() {
+            fail!(from self, with SharedMemoryOpenError::WrongAllocatorSelected,
+                "{} since the shared memory contains an allocator with unique id {} but the selected allocator has the unique id {}.",
+                msg, unsafe{&*allocator_addr}.allocator_id, Allocator::unique_id());
+        }
+
+        Ok(Memory::<Allocator> {
+            shared_memory: shm,
+            name: self.name,
+            allocator: unsafe { NonNull::new_unchecked(allocator_addr) },
+        })
+    }
 }
 
 impl<Allocator: ShmAllocator + Debug> NamedConceptBuilder<Memory<Allocator>>
@@ -170,6 +240,16 @@ impl<Allocator: ShmAllocator + Debug>
             }
         };
 
+        if !self.config.access_rights.is_empty() {
+            let acl = fail!(from self, when self.config.access_rights.access_control_list(AclPermission::ReadWriteExecute),
+                with SharedMemoryCreateError::InternalError,
+                "{} since the access control list could not be created.", msg);
+            fail!(from self, when shm.set_access_control_list(&acl),
+                map AccessControlListApplyError::InsufficientPermissions => SharedMemoryCreateError::InsufficientPermissions,
+                unmatched SharedMemoryCreateError::InternalError,
+                "{} since the access control list could not be applied.", msg);
+        }
+
         let allocator_addr = shm.base_address().as_ptr() as *mut AllocatorDetails<Allocator>;
         let slice = unsafe {
             std::slice::from_raw_parts_mut(
@@ -219,61 +299,11 @@ impl<Allocator: ShmAllocator + Debug>
     }
 
     fn open(self) -> Result<Memory<Allocator>, SharedMemoryOpenError> {
-        let msg = "Unable to open shared memory";
-
-        let shm = match iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder::new(unsafe {
-            &FileName::new_unchecked(self.config.path_for(&self.name).file_name())
-        })
-        .is_memory_locked(self.config.is_memory_locked)
-        .open_existing(AccessMode::ReadWrite)
-        {
-            Ok(s) => s,
-            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::DoesNotExist) => {
-                fail!(from self, with SharedMemoryOpenError::DoesNotExist,
-                        "{} since a shared memory with that name does not exist.", msg);
-            }
-            Err(iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::SizeDoesNotFit) => {
-                fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
-                        "{} since the requested size is not equal the actual size of the shared memory.", msg);
-            }
-            Err(
-                iceoryx2_bb_posix::shared_memory::SharedMemoryCreationError::InsufficientPermissions,
-            ) => {
-                fail!(from self, with SharedMemoryOpenError::InsufficientPermissions,
-                        "{} due to insufficient permissions.", msg);
-            }
-            Err(v) => {
-                fail!(from self, with SharedMemoryOpenError::InternalError,
-                        "{} since an unknown error has occurred ({:?}).", msg, v);
-            }
-        };
-
-        let allocator_addr = shm.base_address().as_ptr() as *mut AllocatorDetails<Allocator>;
-
-        if unsafe { &*allocator_addr }.state.load(Ordering::Relaxed) != IS_INITIALIZED_STATE_VALUE {
-            fail!(from self, with SharedMemoryOpenError::InternalError,
-                    "{} since the creation of the shared memory is not yet finished.", msg);
-        }
-
-        const SPACE_FOR_ALLOCATOR_ID: usize = 1;
-
-        if shm.size() <= SPACE_FOR_ALLOCATOR_ID {
-            fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
-                "{} since the shared memories size {} is smaller than the minimum required size of {}.",
-                msg, shm.size(), SPACE_FOR_ALLOCATOR_ID);
-        }
-
-        if unsafe { &*allocator_addr }.allocator_id != Allocator::unique_id() {
-            fail!(from self, with SharedMemoryOpenError::WrongAllocatorSelected,
-                "{} since the shared memory contains an allocator with unique id {} but the selected allocator has the unique id {}.",
-                msg, unsafe{&*allocator_addr}.allocator_id, Allocator::unique_id());
-        }
+        self.open_with_access_mode(AccessMode::ReadWrite)
+    }
 
-        Ok(Memory::<Allocator> {
-            shared_memory: shm,
-            name: self.name,
-            allocator: unsafe { NonNull::new_unchecked(allocator_addr) },
-        })
+    fn open_read_only(self) -> Result<Memory<Allocator>, SharedMemoryOpenError> {
+        self.open_with_access_mode(AccessMode::Read)
     }
 }
 
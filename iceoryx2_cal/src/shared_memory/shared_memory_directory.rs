@@ -0,0 +1,460 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`SharedMemory`] that is stored as file inside a
+//! [`crate::shared_memory_directory::SharedMemoryDirectory`]. The shared memories are
+//! distributed by the hash of their name over [`Configuration::number_of_directories`]
+//! directories whose names are derived from the prefix and the
+//! [`Configuration::directory_name`]. A directory can hold at most 512 shared memories. It
+//! starts with a data segment of [`Configuration::directory_size`], defined by the first process
+//! that creates it, and grows on demand when a shared memory does not fit anymore. The max
+//! supported alignment is the alignment of the files inside the directory.
+//!
+//! The data segments of a directory stay reserved as long as the directory contains a shared
+//! memory or is used by a process. With the defaults, every directory in use reserves at least
+//! 4 MiB and the 16 directories reserve at least 64 MiB when all of them are in use.
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::named_concept::{
+    AccessRights, NamedConceptConfiguration, NamedConceptDoesExistError, NamedConceptListError,
+    NamedConceptRemoveError,
+};
+pub use crate::shared_memory::*;
+use crate::shared_memory_directory::file::File;
+use crate::shared_memory_directory::registry::{DirectoryHandle, DirectorySet};
+use crate::shared_memory_directory::{
+    SharedMemoryDirectoryCreateFileError, DEFAULT_NUMBER_OF_DIRECTORIES,
+};
+use iceoryx2_bb_elementary::allocator::DeallocationError;
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
+
+/// The name of the directory that is used when no other name is configured.
+pub const DEFAULT_DIRECTORY_NAME: FileName =
+    unsafe { FileName::new_unchecked(b"shared_memory_directory") };
+
+/// The size of the first data segment of a directory that is used when no other size is
+/// configured.
+pub const DEFAULT_DIRECTORY_SIZE: usize = 4 * 1024 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    /// The name of the directory in which the shared memory is stored.
+    pub directory_name: FileName,
+    /// The size of the first data segment of the directory when it does not exist and has to
+    /// be created.
+    pub directory_size: usize,
+    /// The number of directories over which the shared memory objects are distributed. Every
+    /// directory can hold at most 512 shared memory objects.
+    pub number_of_directories: usize,
+    pub zero_memory: bool,
+    path: Path,
+    suffix: FileName,
+    prefix: FileName,
+    access_rights: AccessRights,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            directory_name: DEFAULT_DIRECTORY_NAME,
+            directory_size: DEFAULT_DIRECTORY_SIZE,
+            number_of_directories: DEFAULT_NUMBER_OF_DIRECTORIES,
+            zero_memory: true,
+            path: DEFAULT_PATH_HINT,
+            suffix: DEFAULT_SUFFIX,
+            prefix: DEFAULT_PREFIX,
+            access_rights: AccessRights::default(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
+
+    fn does_support_access_rights() -> bool {
+        false
+    }
+}
+
+impl Configuration {
+    fn file_name(&self, name: &FileName) -> FileName {
+        unsafe { FileName::new_unchecked(self.path_for(name).file_name()) }
+    }
+
+    fn directories(&self) -> DirectorySet {
+        DirectorySet::new(
+            &self.prefix,
+            &self.directory_name,
+            self.directory_size,
+            self.number_of_directories,
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder<Allocator: ShmAllocator + Debug> {
+    name: FileName,
+    size: usize,
+    config: Configuration,
+    _phantom_allocator: PhantomData<Allocator>,
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConceptBuilder<Memory<Allocator>>
+    for Builder<Allocator>
+{
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+            size: 0,
+            _phantom_allocator: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug>
+    crate::shared_memory::SharedMemoryBuilder<Allocator, Memory<Allocator>> for Builder<Allocator>
+{
+    fn size(mut self, value: usize) -> Self {
+        self.size = value;
+        self
+    }
+
+    fn create(
+        self,
+        allocator_config: &Allocator::Configuration,
+    ) -> Result<Memory<Allocator>, SharedMemoryCreateError> {
+        let msg = "Unable to create shared memory";
+
+        if self.size == 0 {
+            fail!(from self, with SharedMemoryCreateError::SizeIsZero,
+                    "{} since the size is zero.", msg);
+        }
+
+        if !self.config.access_rights.is_empty() {
+            fail!(from self, with SharedMemoryCreateError::InternalError,
+                "{} since access rights cannot be applied to a single shared memory of the directory \"{}\".",
+                msg, self.config.directory_name);
+        }
+
+        let directory = fail!(from self, when self.config.directories().acquire(&self.config.file_name(&self.name)),
+                                with SharedMemoryCreateError::InternalError,
+                                "{} since the directory \"{}\" is not available.", msg, self.config.directory_name);
+
+        // the file consists of the allocator details, the management memory of the allocator
+        // and the payload, which is aligned to the max alignment of the directory
+        let max_alignment = directory.max_alignment();
+        let mgmt_size = Allocator::management_size(self.size, allocator_config);
+        let data_offset = align(
+            std::mem::size_of::<AllocatorDetails<Allocator>>() + mgmt_size,
+            max_alignment,
+        );
+        let layout = unsafe {
+            Layout::from_size_align_unchecked(
+                data_offset + self.size,
+                max_alignment.max(std::mem::align_of::<AllocatorDetails<Allocator>>()),
+            )
+        };
+
+        let file_creator = fail!(from self, when unsafe { directory.new_file(layout) },
+                                with SharedMemoryCreateError::InternalError,
+                                "{} since the memory could not be allocated in the directory \"{}\".",
+                                msg, self.config.directory_name);
+
+        let zero_memory = self.config.zero_memory;
+        let file = match file_creator.is_persistent(true).create_and_initialize(
+            &self.config.file_name(&self.name),
+            |content| {
+                if zero_memory {
+                    content.fill(0);
+                }
+
+                let base_address = content.as_mut_ptr();
+                let details = base_address as *mut AllocatorDetails<Allocator>;
+                let data = unsafe {
+                    NonNull::new_unchecked(core::slice::from_raw_parts_mut(
+                        base_address.add(data_offset),
+                        content.len() - data_offset,
+                    ))
+                };
+
+                unsafe {
+                    details.write(AllocatorDetails {
+                        allocator_id: Allocator::unique_id(),
+                        allocator: Allocator::new_uninit(max_alignment, data, allocator_config),
+                        data_offset,
+                    })
+                };
+
+                let bump_allocator = BumpAllocator::new(
+                    unsafe {
+                        NonNull::new_unchecked(
+                            base_address.add(std::mem::size_of::<AllocatorDetails<Allocator>>()),
+                        )
+                    },
+                    mgmt_size,
+                );
+
+                unsafe { (*details).allocator.init(&bump_allocator) }.is_ok()
+            },
+        ) {
+            Ok(file) => file,
+            Err(SharedMemoryDirectoryCreateFileError::DoesExist)
+            | Err(SharedMemoryDirectoryCreateFileError::BeingCreated) => {
+                fail!(from self, with SharedMemoryCreateError::AlreadyExists,
+                    "{} since a shared memory with that name already exists.", msg);
+            }
+            Err(SharedMemoryDirectoryCreateFileError::InitializationFailed) => {
+                fail!(from self, with SharedMemoryCreateError::InternalError,
+                    "{} since the management memory for the allocator could not be initialized.", msg);
+            }
+            Err(SharedMemoryDirectoryCreateFileError::FileLimitExceeded) => {
+                fail!(from self, with SharedMemoryCreateError::InternalError,
+                    "{} since the directory \"{}\" cannot hold any more files.", msg, self.config.directory_name);
+            }
+        };
+
+        Ok(Memory::<Allocator> {
+            file,
+            directory,
+            name: self.name,
+            has_ownership: true,
+            config: self.config,
+            _phantom_allocator: PhantomData,
+        })
+    }
+
+    fn open(self) -> Result<Memory<Allocator>, SharedMemoryOpenError> {
+        let msg = "Unable to open shared memory";
+
+        let file_name = self.config.file_name(&self.name);
+        let directory = fail!(from self, when self.config.directories().acquire_existing(&file_name),
+                                "{} since the directory \"{}\" is not available.", msg, self.config.directory_name);
+
+        // files only become visible in the directory when they are completely initialized
+        let (directory, file) = match directory.and_then(|directory| {
+            let file = unsafe { directory.open_file(&file_name) }?;
+            Some((directory, file))
+        }) {
+            Some(v) => v,
+            None => {
+                fail!(from self, with SharedMemoryOpenError::DoesNotExist,
+                        "{} since a shared memory with that name does not exist.", msg);
+            }
+        };
+
+        if file.content().len() < std::mem::size_of::<AllocatorDetails<Allocator>>() {
+            fail!(from self, with SharedMemoryOpenError::SizeDoesNotFit,
+                "{} since the shared memories size {} is smaller than the minimum required size of {}.",
+                msg, file.content().len(), std::mem::size_of::<AllocatorDetails<Allocator>>());
+        }
+
+        let details = unsafe { &*(file.content().as_ptr() as *const AllocatorDetails<Allocator>) };
+        if details.allocator_id != Allocator::unique_id() {
+            fail!(from self, with SharedMemoryOpenError::WrongAllocatorSelected,
+                "{} since the shared memory contains an allocator with unique id {} but the selected allocator has the unique id {}.",
+                msg, details.allocator_id, Allocator::unique_id());
+        }
+
+        Ok(Memory::<Allocator> {
+            file,
+            directory,
+            name: self.name,
+            has_ownership: false,
+            config: self.config,
+            _phantom_allocator: PhantomData,
+        })
+    }
+}
+
+#[repr(C)]
+struct AllocatorDetails<Allocator: ShmAllocator> {
+    allocator_id: u8,
+    allocator: Allocator,
+    data_offset: usize,
+}
+
+#[derive(Debug)]
+pub struct Memory<Allocator: ShmAllocator + Debug> {
+    // declared before the directory so that it is dropped first
+    file: File<'static>,
+    directory: DirectoryHandle,
+    name: FileName,
+    has_ownership: bool,
+    config: Configuration,
+    _phantom_allocator: PhantomData<Allocator>,
+}
+
+impl<Allocator: ShmAllocator + Debug> Drop for Memory<Allocator> {
+    fn drop(&mut self) {
+        if self.has_ownership {
+            self.directory
+                .remove_file(&self.config.file_name(&self.name));
+        }
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> Memory<Allocator> {
+    fn details(&self) -> &AllocatorDetails<Allocator> {
+        unsafe { &*(self.file.content().as_ptr() as *const AllocatorDetails<Allocator>) }
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConcept for Memory<Allocator> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConceptMgmt for Memory<Allocator> {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = "Unable to check if shared_memory::shared_memory_directory exists";
+        let origin = "shared_memory::shared_memory_directory::Memory::does_exist_cfg()";
+
+        let file_name = cfg.file_name(name);
+        match cfg.directories().acquire_existing(&file_name) {
+            Ok(Some(directory)) => Ok(directory.does_file_exist(&file_name)),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                fail!(from origin, with NamedConceptDoesExistError::InternalError,
+                    "{} since the directory \"{}\" is not available ({:?}).", msg, cfg.directory_name, e);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all shared_memory::shared_memory_directory";
+        let origin = "shared_memory::shared_memory_directory::Memory::list_cfg()";
+
+        let directories = match config.directories().list_existing() {
+            Ok(directories) => directories,
+            Err(e) => {
+                fail!(from origin, with NamedConceptListError::InternalError,
+                    "{} since the directories \"{}\" are not available ({:?}).", msg, config.directory_name, e);
+            }
+        };
+
+        Ok(directories
+            .iter()
+            .flat_map(|directory| directory.list_files())
+            .filter_map(|file| config.extract_name_from_file(&file.name()))
+            .collect())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove shared_memory::shared_memory_directory";
+        let origin = "shared_memory::shared_memory_directory::Memory::remove_cfg()";
+
+        let file_name = cfg.file_name(name);
+        match cfg.directories().acquire_existing(&file_name) {
+            Ok(Some(directory)) => Ok(directory.remove_file(&file_name)),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} \"{}\" since the directory \"{}\" is not available ({:?}).", msg, name, cfg.directory_name, e);
+            }
+        }
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> crate::shared_memory::SharedMemory<Allocator>
+    for Memory<Allocator>
+{
+    type Builder = Builder<Allocator>;
+
+    fn size(&self) -> usize {
+        self.file.content().len() - self.details().data_offset
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.details().allocator.max_alignment()
+    }
+
+    fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
+        let offset = fail!(from self, when unsafe { self.details().allocator.allocate(layout) },
+            "Failed to allocate shared memory due to an internal allocator failure.");
+
+        Ok(ShmPointer {
+            offset,
+            data_ptr: (offset.value() + self.allocator_data_start_address()) as *mut u8,
+        })
+    }
+
+    unsafe fn deallocate(
+        &self,
+        offset: PointerOffset,
+        layout: std::alloc::Layout,
+    ) -> Result<(), DeallocationError> {
+        fail!(from self, when self.details().allocator.deallocate(offset, layout),
+            "Failed to deallocate shared memory chunk due to an internal allocator failure.");
+        Ok(())
+    }
+
+    fn release_ownership(&mut self) {
+        self.has_ownership = false;
+    }
+
+    fn allocator_data_start_address(&self) -> usize {
+        self.file.content().as_ptr() as usize + self.details().data_offset
+    }
+}
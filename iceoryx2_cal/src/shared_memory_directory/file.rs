@@ -19,10 +19,23 @@ use crate::shared_memory_directory::SharedMemoryDirectoryCreateFileError;
 
 use super::file_reference_set::{FileReferenceSet, FileReferenceSetId};
 
+/// Provides access to the data segments of the
+/// [`crate::shared_memory_directory::SharedMemoryDirectory`] in which the file contents are
+/// stored.
+pub(crate) trait FileDataSegments: Send + Sync {
+    /// Returns the base address of the data segment or [`None`] when the data segment is not
+    /// available.
+    fn base_address(&self, segment: usize) -> Option<usize>;
+
+    /// Returns the memory of a removed file back to its data segment.
+    fn release(&self, segment: usize, offset: usize, layout: Layout);
+}
+
 pub struct File<'a> {
     pub(crate) set: &'a FileReferenceSet,
     pub(crate) id: FileReferenceSetId,
     pub(crate) base_address: usize,
+    pub(crate) segments: &'a dyn FileDataSegments,
 }
 
 impl Debug for File<'_> {
@@ -57,32 +70,47 @@ impl<'a> File<'a> {
 
 impl<'a> Drop for File<'a> {
     fn drop(&mut self) {
-        self.set.release(self.id)
+        self.set.release(self.id, self.segments)
     }
 }
 
-#[derive(Debug)]
 pub struct FileCreator<'a> {
     set: &'a FileReferenceSet,
     layout: Layout,
     is_persistent: bool,
+    segment: usize,
     memory: ShmPointer,
     base_address: usize,
+    segments: &'a dyn FileDataSegments,
+}
+
+impl Debug for FileCreator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FileCreator {{ layout: {:?}, is_persistent: {}, segment: {}, memory: {:?}, base_address: {} }}",
+            self.layout, self.is_persistent, self.segment, self.memory, self.base_address
+        )
+    }
 }
 
 impl<'a> FileCreator<'a> {
     pub(crate) fn new(
         set: &'a FileReferenceSet,
+        segment: usize,
         memory: ShmPointer,
         layout: Layout,
         base_address: usize,
+        segments: &'a dyn FileDataSegments,
     ) -> Self {
         Self {
             set,
             layout,
             is_persistent: false,
+            segment,
             memory,
             base_address,
+            segments,
         }
     }
 
@@ -96,24 +124,54 @@ impl<'a> FileCreator<'a> {
         name: &FileName,
         mut initializer: F,
     ) -> Result<File<'a>, SharedMemoryDirectoryCreateFileError> {
-        let id = fail!(from self, when self.set.insert(
+        self.create_and_initialize(name, |content| {
+            initializer(content);
+            true
+        })
+    }
+
+    /// Creates the file and calls the initializer before the file becomes visible to others.
+    /// When the initializer returns false the file is discarded and
+    /// [`SharedMemoryDirectoryCreateFileError::InitializationFailed`] is returned.
+    pub fn create_and_initialize<F: FnOnce(&mut [u8]) -> bool>(
+        self,
+        name: &FileName,
+        initializer: F,
+    ) -> Result<File<'a>, SharedMemoryDirectoryCreateFileError> {
+        // from here on the set owns the memory and returns it when the entry is removed
+        let this = core::mem::ManuallyDrop::new(self);
+        let id = fail!(from this, when this.set.insert(
                                         name,
-                                        self.memory.offset.value(),
-                                        self.layout.size(),
-                                        self.is_persistent,
+                                        this.segment,
+                                        this.memory.offset.value(),
+                                        this.layout,
+                                        this.is_persistent,
+                                        this.segments,
                                     ),
                             "Failed to create new file {}.", *name);
 
-        initializer(unsafe {
-            core::slice::from_raw_parts_mut(self.memory.data_ptr, self.layout.size())
-        });
+        if !initializer(unsafe {
+            core::slice::from_raw_parts_mut(this.memory.data_ptr, this.layout.size())
+        }) {
+            this.set.discard(id, this.segments);
+            fail!(from this, with SharedMemoryDirectoryCreateFileError::InitializationFailed,
+                "Failed to create new file {} since the initialization failed.", *name);
+        }
 
-        self.set.finalize_initialization(id);
+        this.set.finalize_initialization(id);
 
         Ok(File {
-            set: self.set,
+            set: this.set,
             id,
-            base_address: self.base_address,
+            base_address: this.base_address,
+            segments: this.segments,
         })
     }
 }
+
+impl<'a> Drop for FileCreator<'a> {
+    fn drop(&mut self) {
+        self.segments
+            .release(self.segment, self.memory.offset.value(), self.layout)
+    }
+}
@@ -11,13 +11,14 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::shared_memory_directory::decision_counter::DecisionCounter;
-use crate::shared_memory_directory::file::File;
+use crate::shared_memory_directory::file::{File, FileDataSegments};
 use crate::shared_memory_directory::reference_counter::ReferenceCounter;
 use crate::shared_memory_directory::SharedMemoryDirectoryCreateFileError;
 use crate::shared_memory_directory::MAX_NUMBER_OF_ENTRIES;
 use iceoryx2_bb_lock_free::mpmc::unique_index_set::FixedSizeUniqueIndexSet;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_system_types::file_name::FileName;
+use std::alloc::Layout;
 use std::cell::UnsafeCell;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -28,16 +29,20 @@ pub(crate) struct FileReferenceSetId(usize);
 #[repr(C)]
 struct Entry {
     name: FileName,
+    segment: usize,
     offset: usize,
     len: usize,
+    align: usize,
 }
 
 impl Entry {
     const fn default() -> Self {
         Self {
             name: unsafe { FileName::new_unchecked(b"empty") },
+            segment: 0,
             offset: 0,
             len: 0,
+            align: 1,
         }
     }
 }
@@ -75,17 +80,21 @@ impl Default for FileReferenceSet {
 }
 
 impl FileReferenceSet {
+    // takes the ownership of the memory, when the insertion fails the memory is released
     pub(crate) fn insert(
         &self,
         name: &FileName,
+        segment: usize,
         offset: usize,
-        len: usize,
+        layout: Layout,
         is_persistent: bool,
+        segments: &dyn FileDataSegments,
     ) -> Result<FileReferenceSetId, SharedMemoryDirectoryCreateFileError> {
         let msg = "Unable to insert file";
         let id = match unsafe { self.ids.acquire_raw_index() } {
             Some(id) => id as usize,
             None => {
+                segments.release(segment, offset, layout);
                 fail!(from self,
                            with SharedMemoryDirectoryCreateFileError::FileLimitExceeded,
                            "{} \"{}\" into the set since there are no more entries available.", msg, *name);
@@ -95,8 +104,10 @@ impl FileReferenceSet {
         unsafe {
             self.entries[id].get().write(Entry {
                 name: *name,
+                segment,
                 offset,
-                len,
+                len: layout.size(),
+                align: layout.align(),
             })
         };
 
@@ -104,6 +115,7 @@ impl FileReferenceSet {
         self.counter[id].increment_ref_counter();
         let current_decision_count = self.global_decision_counter.fetch_add(1, Ordering::Relaxed);
         if !self.decision_counter[id].set(current_decision_count) {
+            self.discard(FileReferenceSetId(id), segments);
             fail!(from self, with SharedMemoryDirectoryCreateFileError::DoesExist,
                     "{} \"{}\" since the file already exists.", msg, *name);
         }
@@ -118,7 +130,11 @@ impl FileReferenceSet {
                 if unsafe { &*self.entries[i].get() }.name == *name
                     && !self.decision_counter[i].does_value_win(current_decision_count)
                 {
-                    if self.counter[i].is_initialized() {
+                    let is_initialized = self.counter[i].is_initialized();
+                    self.decrement_ref_counter(FileReferenceSetId(i), segments);
+                    self.discard(FileReferenceSetId(id), segments);
+
+                    if is_initialized {
                         fail!(from self, with SharedMemoryDirectoryCreateFileError::DoesExist,
                         "{} \"{}\" since the file already exists.", msg, *name);
                     } else {
@@ -127,7 +143,7 @@ impl FileReferenceSet {
                     }
                 }
 
-                self.decrement_ref_counter(FileReferenceSetId(i));
+                self.decrement_ref_counter(FileReferenceSetId(i), segments);
             }
         }
 
@@ -135,11 +151,11 @@ impl FileReferenceSet {
     }
 
     // can only be called when the ownership is acquired
-    pub(crate) fn to_be_removed(&self, name: &FileName) -> bool {
+    pub(crate) fn to_be_removed(&self, name: &FileName, segments: &dyn FileDataSegments) -> bool {
         if let Some(id) = self.find_entry(name) {
             self.counter[id.0].set_persistency_bit(false);
             self.counter[id.0].to_be_removed();
-            self.decrement_ref_counter(id);
+            self.decrement_ref_counter(id, segments);
             return true;
         }
 
@@ -151,26 +167,34 @@ impl FileReferenceSet {
         self.counter[id.0].set_initialized_bit(true);
     }
 
-    pub(crate) fn does_exist(&self, name: &FileName) -> bool {
+    pub(crate) fn does_exist(&self, name: &FileName, segments: &dyn FileDataSegments) -> bool {
         if let Some(id) = self.find_entry(name) {
-            self.decrement_ref_counter(id);
+            self.decrement_ref_counter(id, segments);
             return true;
         }
 
         false
     }
 
-    pub(crate) fn borrow(&self, name: &FileName, base_address: usize) -> Option<File> {
-        self.find_entry(name).map(|id| File {
-            set: self,
-            id,
-            base_address,
-        })
+    pub(crate) fn borrow<'a>(
+        &'a self,
+        name: &FileName,
+        segments: &'a dyn FileDataSegments,
+    ) -> Option<File<'a>> {
+        self.find_entry(name)
+            .and_then(|id| self.to_file(id, segments))
     }
 
     // can only be called when the ownership is acquired
-    pub(crate) fn release(&self, id: FileReferenceSetId) {
-        self.decrement_ref_counter(id)
+    pub(crate) fn release(&self, id: FileReferenceSetId, segments: &dyn FileDataSegments) {
+        self.decrement_ref_counter(id, segments)
+    }
+
+    // can only be called when the ownership is acquired and the initialization was not
+    // finalized, removes the entry
+    pub(crate) fn discard(&self, id: FileReferenceSetId, segments: &dyn FileDataSegments) {
+        self.counter[id.0].set_persistency_bit(false);
+        self.decrement_ref_counter(id, segments)
     }
 
     // can only be called when the ownership is acquired
@@ -178,15 +202,13 @@ impl FileReferenceSet {
         self.counter[id.0].is_persistent()
     }
 
-    pub(crate) fn list(&self, base_address: usize) -> Vec<File> {
+    pub(crate) fn list<'a>(&'a self, segments: &'a dyn FileDataSegments) -> Vec<File<'a>> {
         let mut ret_val = vec![];
         for id in 0..self.ids.capacity() as usize {
             if self.counter[id].increment_ref_counter_when_initialized() {
-                ret_val.push(File {
-                    set: self,
-                    id: FileReferenceSetId(id),
-                    base_address,
-                });
+                if let Some(file) = self.to_file(FileReferenceSetId(id), segments) {
+                    ret_val.push(file);
+                }
             }
         }
 
@@ -234,9 +256,36 @@ impl FileReferenceSet {
         None
     }
 
-    fn decrement_ref_counter(&self, id: FileReferenceSetId) {
+    // requires the read-only ownership of the entry, it is released when the data segment of
+    // the file is not available
+    fn to_file<'a>(
+        &'a self,
+        id: FileReferenceSetId,
+        segments: &'a dyn FileDataSegments,
+    ) -> Option<File<'a>> {
+        let segment = unsafe { &*self.entries[id.0].get() }.segment;
+        match segments.base_address(segment) {
+            Some(base_address) => Some(File {
+                set: self,
+                id,
+                base_address,
+                segments,
+            }),
+            None => {
+                self.decrement_ref_counter(id, segments);
+                None
+            }
+        }
+    }
+
+    fn decrement_ref_counter(&self, id: FileReferenceSetId, segments: &dyn FileDataSegments) {
         if self.counter[id.0].decrement_ref_counter() {
-            // remove entry
+            // remove entry and return its memory
+            let entry = unsafe { *self.entries[id.0].get() };
+            segments.release(entry.segment, entry.offset, unsafe {
+                Layout::from_size_align_unchecked(entry.len, entry.align)
+            });
+
             self.counter[id.0].reset();
             self.decision_counter[id.0].set_to_undecided();
             unsafe { self.ids.release_raw_index(id.0 as u32) };
@@ -14,31 +14,80 @@ mod decision_counter;
 pub mod file;
 mod file_reference_set;
 mod reference_counter;
+pub(crate) mod registry;
 
 use crate::shared_memory_directory::file_reference_set::FileReferenceSet;
 use crate::shm_allocator::bump_allocator::BumpAllocator;
 use crate::{named_concept::*, shared_memory::*, shm_allocator::ShmAllocator};
+use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::math::align_to;
-use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_system_types::file_name::FileName;
+use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use std::{alloc::Layout, fmt::Debug, marker::PhantomData};
 
-use crate::shared_memory_directory::file::{File, FileCreator};
+use crate::shared_memory_directory::file::{File, FileCreator, FileDataSegments};
 
 const MAX_NUMBER_OF_ENTRIES: usize = 512;
+
+/// The maximum number of data segments of a directory. When the memory of all existing data
+/// segments is exhausted, a new data segment is created that is twice as large as its
+/// predecessor.
+pub const MAX_NUMBER_OF_DATA_SEGMENTS: usize = 8;
+
+/// The number of directories over which the files of a concept are distributed when no other
+/// number is configured.
+pub const DEFAULT_NUMBER_OF_DIRECTORIES: usize = 16;
 const MGMT_SHM_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".dm") };
 const DATA_SHM_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".dd") };
+const DATA_SHM_SUFFIXES: [FileName; MAX_NUMBER_OF_DATA_SEGMENTS] = unsafe {
+    [
+        DATA_SHM_SUFFIX,
+        FileName::new_unchecked(b".dd1"),
+        FileName::new_unchecked(b".dd2"),
+        FileName::new_unchecked(b".dd3"),
+        FileName::new_unchecked(b".dd4"),
+        FileName::new_unchecked(b".dd5"),
+        FileName::new_unchecked(b".dd6"),
+        FileName::new_unchecked(b".dd7"),
+    ]
+};
+const MAX_DATA_SEGMENT_CREATION_DURATION: Duration = Duration::from_millis(100);
+const IS_REMOVED: u64 = 1 << 63;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum SharedMemoryDirectoryCreateFileError {
     FileLimitExceeded,
     BeingCreated,
     DoesExist,
+    InitializationFailed,
+}
+
+fn shm_config<T: NamedConceptConfiguration>(prefix: Option<FileName>, suffix: FileName) -> T {
+    let config = T::default().suffix(suffix);
+    match prefix {
+        Some(prefix) => config.prefix(prefix),
+        None => config,
+    }
+}
+
+#[repr(C)]
+struct Management<AllocatorConfig> {
+    files: FileReferenceSet,
+    // number of registered users, the IS_REMOVED bit is set when the last user removes the
+    // directory
+    users: AtomicU64,
+    data_segment_size: usize,
+    allocator_config: AllocatorConfig,
 }
 
 #[derive(Debug)]
 pub struct SharedMemoryDirectoryCreator {
     name: FileName,
+    prefix: Option<FileName>,
     size: usize,
     is_persistent: bool,
 }
@@ -47,16 +96,24 @@ impl SharedMemoryDirectoryCreator {
     pub fn new(name: &FileName) -> Self {
         Self {
             name: *name,
+            prefix: None,
             size: 0,
             is_persistent: false,
         }
     }
 
+    pub fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = Some(value);
+        self
+    }
+
     pub fn is_persistent(mut self, value: bool) -> Self {
         self.is_persistent = value;
         self
     }
 
+    /// The size of the first data segment. Every further data segment is twice as large as
+    /// its predecessor.
     pub fn size(mut self, value: usize) -> Self {
         self.size = value;
         self
@@ -73,27 +130,30 @@ impl SharedMemoryDirectoryCreator {
         let msg = "Unable to create shared memory directory";
         let mut mgmt_shm = fail!(from self,
         when MgmtShm::Builder::new(&self.name)
-            .config(
-                &MgmtShm::Configuration::default()
-                    .suffix(MGMT_SHM_SUFFIX),
-            )
-            .size(core::mem::size_of::<FileReferenceSet>() + core::mem::align_of::<FileReferenceSet>() - 1)
+            .config(&shm_config(self.prefix, MGMT_SHM_SUFFIX))
+            .size(core::mem::size_of::<Management<Allocator::Configuration>>() + core::mem::align_of::<Management<Allocator::Configuration>>() - 1)
             .create(&<BumpAllocator as ShmAllocator>::Configuration::default()),
         "{} since the management segment could not be created.", msg);
 
         let shm_ptr = fatal_panic!(from self,
-                                when mgmt_shm.allocate(std::alloc::Layout::new::<FileReferenceSet>()),
+                                when mgmt_shm.allocate(std::alloc::Layout::new::<Management<Allocator::Configuration>>()),
                                 "This should never happen! {} since the allocation of the management segment failed.",
                                 msg);
 
-        let files = shm_ptr.data_ptr as *mut FileReferenceSet;
-        unsafe { files.write(FileReferenceSet::default()) };
+        let mgmt = shm_ptr.data_ptr as *mut Management<Allocator::Configuration>;
+        unsafe {
+            mgmt.write(Management {
+                files: FileReferenceSet::default(),
+                users: AtomicU64::new(0),
+                data_segment_size: self.size,
+                allocator_config: *allocator_config,
+            })
+        };
 
         let mut data_shm = fail!(from self,
-            when DataShm::Builder::new(&self.name).config(
-                &DataShm::Configuration::default()
-                    .suffix(DATA_SHM_SUFFIX),
-                ).size(self.size).create(allocator_config),
+            when DataShm::Builder::new(&self.name)
+                .config(&shm_config(self.prefix, DATA_SHM_SUFFIX))
+                .size(self.size).create(allocator_config),
             "{} since the data segment could not be created.", msg);
 
         if self.is_persistent {
@@ -102,9 +162,13 @@ impl SharedMemoryDirectoryCreator {
         }
 
         Ok(SharedMemoryDirectory {
+            name: self.name,
+            prefix: self.prefix,
+            has_ownership: !self.is_persistent,
             _mgmt_shm: mgmt_shm,
             data_shm,
-            files,
+            further_data_segments: core::array::from_fn(|_| OnceCell::new()),
+            mgmt,
             _allocator: PhantomData,
         })
     }
@@ -118,28 +182,27 @@ impl SharedMemoryDirectoryCreator {
     ) -> Result<SharedMemoryDirectory<MgmtShm, Allocator, DataShm>, SharedMemoryOpenError> {
         let msg = "Unable to open shared memory directory";
         let data_shm = fail!(from self, when DataShm::Builder::new(&self.name)
-                                .config(
-                                    &DataShm::Configuration::default()
-                                        .suffix(DATA_SHM_SUFFIX),
-                                    )
+                                .config(&shm_config(self.prefix, DATA_SHM_SUFFIX))
                                 .open(),
                                 "{} since the data segment could not be opened.", msg);
 
         let mgmt_shm = fail!(from self, when MgmtShm::Builder::new(&self.name)
-                                .config(
-                                    &MgmtShm::Configuration::default()
-                                        .suffix(MGMT_SHM_SUFFIX),
-                                )
+                                .config(&shm_config(self.prefix, MGMT_SHM_SUFFIX))
                                 .open(),
                                 "{} since the management segment could not be opened.", msg);
 
-        let files = align_to::<FileReferenceSet>(mgmt_shm.allocator_data_start_address())
-            as *mut FileReferenceSet;
+        let mgmt = align_to::<Management<Allocator::Configuration>>(
+            mgmt_shm.allocator_data_start_address(),
+        ) as *mut Management<Allocator::Configuration>;
 
         Ok(SharedMemoryDirectory {
+            name: self.name,
+            prefix: self.prefix,
+            has_ownership: false,
             _mgmt_shm: mgmt_shm,
             data_shm,
-            files,
+            further_data_segments: core::array::from_fn(|_| OnceCell::new()),
+            mgmt,
             _allocator: PhantomData,
         })
     }
@@ -150,12 +213,86 @@ pub struct SharedMemoryDirectory<
     Allocator: ShmAllocator,
     DataShm: SharedMemory<Allocator>,
 > {
+    name: FileName,
+    prefix: Option<FileName>,
+    has_ownership: bool,
     _mgmt_shm: MgmtShm,
     data_shm: DataShm,
-    files: *mut FileReferenceSet,
+    // the data segments that are created when the memory of the first one is exhausted, they
+    // are mapped on first use
+    further_data_segments: [OnceCell<DataShm>; MAX_NUMBER_OF_DATA_SEGMENTS - 1],
+    mgmt: *mut Management<Allocator::Configuration>,
     _allocator: PhantomData<Allocator>,
 }
 
+unsafe impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > Send for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+}
+
+unsafe impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > Sync for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+}
+
+impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > Drop for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+    fn drop(&mut self) {
+        // the first data segment and the management segment are removed by their owning
+        // shared memory, the further data segments are never owned by a shared memory
+        if !self.has_ownership {
+            return;
+        }
+
+        for suffix in &DATA_SHM_SUFFIXES[1..] {
+            if unsafe { DataShm::remove_cfg(&self.name, &shm_config(self.prefix, *suffix)) }
+                .is_err()
+            {
+                warn!(from self, "Unable to remove the data segment \"{}{}\" of the directory.",
+                    self.name, suffix);
+            }
+        }
+    }
+}
+
+impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > FileDataSegments for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+    fn base_address(&self, segment: usize) -> Option<usize> {
+        self.data_segment(segment)
+            .map(|data_segment| data_segment.allocator_data_start_address())
+    }
+
+    fn release(&self, segment: usize, offset: usize, layout: Layout) {
+        let data_segment = match self.data_segment(segment) {
+            Some(data_segment) => data_segment,
+            None => {
+                warn!(from self, "Unable to release the memory of a removed file at offset {} with {:?} since the data segment {} is not available. The memory is lost.",
+                    offset, layout, segment);
+                return;
+            }
+        };
+
+        if unsafe { data_segment.deallocate(PointerOffset::new(offset), layout) }.is_err() {
+            warn!(from self, "Unable to release the memory of a removed file at offset {} with {:?}. The memory is lost.",
+                offset, layout);
+        }
+    }
+}
+
 impl<
         MgmtShm: SharedMemory<BumpAllocator>,
         Allocator: ShmAllocator,
@@ -163,7 +300,7 @@ impl<
     > Debug for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "SharedMemoryDirectory {{  }}")
+        write!(f, "SharedMemoryDirectory {{ name: {} }}", self.name)
     }
 }
 
@@ -173,42 +310,127 @@ impl<
         DataShm: SharedMemory<Allocator>,
     > SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
 {
+    /// Allocates the memory of a new file. When the memory of all existing data segments is
+    /// exhausted a further data segment is created.
     pub fn new_file(&self, layout: Layout) -> Result<FileCreator, ShmAllocationError> {
-        let memory = fail!(from self, when self.data_shm.allocate(layout),
-            "Unable to create file since the allocation of {:?} failed.", layout);
-
-        Ok(FileCreator::new(
-            self.files(),
-            memory,
-            layout,
-            self.data_shm.allocator_data_start_address(),
-        ))
+        let msg = "Unable to create file";
+        // leaves room for the block overhead of the allocator when the file is the first one
+        // in a new data segment
+        let min_data_segment_size = 2 * (layout.size() + layout.align());
+
+        for index in 0..MAX_NUMBER_OF_DATA_SEGMENTS {
+            let data_segment = fail!(from self, when self.acquire_data_segment(index, min_data_segment_size),
+                with ShmAllocationError::AllocationError(AllocationError::InternalError),
+                "{} since the data segment {} is not available.", msg, index);
+
+            match data_segment.allocate(layout) {
+                Ok(memory) => {
+                    return Ok(FileCreator::new(
+                        self.files(),
+                        index,
+                        memory,
+                        layout,
+                        data_segment.allocator_data_start_address(),
+                        self,
+                    ))
+                }
+                Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => (),
+                // the file does not fit into the data segment but into a larger one
+                Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+                    if min_data_segment_size > data_segment.size() => {}
+                Err(e) => {
+                    fail!(from self, with e,
+                        "{} since the allocation of {:?} failed ({:?}).", msg, layout, e);
+                }
+            }
+        }
+
+        fail!(from self, with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
+            "{} since the allocation of {:?} failed in all {} data segments.",
+            msg, layout, MAX_NUMBER_OF_DATA_SEGMENTS);
     }
 
     pub fn open_file(&self, name: &FileName) -> Option<File> {
-        self.files()
-            .borrow(name, self.data_shm.allocator_data_start_address())
+        self.files().borrow(name, self)
     }
 
     pub fn list_files(&self) -> Vec<File> {
-        self.files()
-            .list(self.data_shm.allocator_data_start_address())
+        self.files().list(self)
     }
 
     pub fn does_file_exist(&self, name: &FileName) -> bool {
-        self.files().does_exist(name)
+        self.files().does_exist(name, self)
     }
 
     pub fn remove_file(&self, name: &FileName) -> bool {
-        self.files().to_be_removed(name)
+        self.files().to_be_removed(name, self)
+    }
+
+    pub fn max_alignment(&self) -> usize {
+        self.data_shm.max_alignment()
     }
 
     pub fn file_capacity(&self) -> usize {
         MAX_NUMBER_OF_ENTRIES
     }
 
+    /// Returns the size of all data segments when the directory has grown to its maximum.
     pub fn memory_capacity(&self) -> usize {
-        self.data_shm.size()
+        (0..MAX_NUMBER_OF_DATA_SEGMENTS)
+            .map(|index| self.mgmt().data_segment_size << index)
+            .sum()
+    }
+
+    /// Registers a user of the directory. Returns false when the directory was removed by its
+    /// last user and must not be used anymore.
+    pub fn register_user(&self) -> bool {
+        let users = &self.mgmt().users;
+        let mut current_value = users.load(Ordering::Relaxed);
+
+        loop {
+            if current_value & IS_REMOVED != 0 {
+                return false;
+            }
+
+            match users.compare_exchange(
+                current_value,
+                current_value + 1,
+                Ordering::AcqRel,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(v) => current_value = v,
+            }
+        }
+    }
+
+    /// Deregisters a user that was registered with [`SharedMemoryDirectory::register_user()`].
+    /// When it was the last user and the directory does not contain any files, the directory
+    /// is removed.
+    pub fn deregister_user(&self) {
+        let users = &self.mgmt().users;
+        if users.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return;
+        }
+
+        // no user can register while the directory is marked as removed
+        if users
+            .compare_exchange(0, IS_REMOVED, Ordering::AcqRel, Ordering::Relaxed)
+            .is_err()
+        {
+            return;
+        }
+
+        // files of processes that terminated abnormally keep the directory alive
+        if !self.list_files().is_empty() {
+            users.store(0, Ordering::Release);
+            return;
+        }
+
+        if unsafe { Self::remove_cfg(&self.name, self.prefix) }.is_err() {
+            warn!(from self, "Unable to remove the directory \"{}\" after its last user was deregistered.",
+                self.name);
+        }
     }
 
     pub fn does_exist(name: &FileName) -> Result<bool, NamedConceptDoesExistError> {
@@ -245,30 +467,136 @@ impl<
     ///   * The [`SharedMemoryDirectory`] shall not be used by any other process otherwise
     ///     other instances are working on a stale [`SharedMemoryDirectory`] instance
     pub unsafe fn remove(name: &FileName) -> Result<bool, NamedConceptRemoveError> {
+        Self::remove_cfg(name, None)
+    }
+
+    /// Returns the size of all data segments that are mapped by this instance.
+    pub fn size(&self) -> usize {
+        self.data_shm.size()
+            + self
+                .further_data_segments
+                .iter()
+                .filter_map(|data_segment| data_segment.get())
+                .map(|data_segment| data_segment.size())
+                .sum::<usize>()
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        prefix: Option<FileName>,
+    ) -> Result<bool, NamedConceptRemoveError> {
         let msg = "Unable to remove SharedMemoryDirectory";
         let origin = "SharedMemoryDirectory::remove()";
 
         if !fail!(from origin, when DataShm::remove_cfg(
             name,
-            &DataShm::Configuration::default().suffix(DATA_SHM_SUFFIX)),
+            &shm_config(prefix, DATA_SHM_SUFFIX)),
             "{} \"{}\" since the data segment could not be removed.", msg, name)
         {
             return Ok(false);
         }
 
+        for suffix in &DATA_SHM_SUFFIXES[1..] {
+            fail!(from origin, when DataShm::remove_cfg(name, &shm_config(prefix, *suffix)),
+                "{} \"{}\" since the further data segment \"{}\" could not be removed.", msg, name, suffix);
+        }
+
         let mgmt_result = fail!(from origin,
-            when MgmtShm::remove_cfg(name, &MgmtShm::Configuration::default().suffix(MGMT_SHM_SUFFIX)),
+            when MgmtShm::remove_cfg(name, &shm_config(prefix, MGMT_SHM_SUFFIX)),
             "{} \"{}\" since the management segment could not be removed.", msg, name
         );
 
         Ok(mgmt_result)
     }
 
-    pub fn size(&self) -> usize {
-        self.data_shm.size()
+    // maps an existing data segment
+    fn data_segment(&self, index: usize) -> Option<&DataShm> {
+        if index == 0 {
+            return Some(&self.data_shm);
+        }
+
+        self.further_data_segments
+            .get(index - 1)?
+            .get_or_try_init(|| {
+                DataShm::Builder::new(&self.name)
+                    .config(&shm_config(self.prefix, DATA_SHM_SUFFIXES[index]))
+                    .open()
+            })
+            .ok()
+    }
+
+    // maps the data segment and creates it when it does not exist
+    fn acquire_data_segment(
+        &self,
+        index: usize,
+        min_size: usize,
+    ) -> Result<&DataShm, SharedMemoryOpenError> {
+        if index == 0 {
+            return Ok(&self.data_shm);
+        }
+
+        self.further_data_segments[index - 1]
+            .get_or_try_init(|| self.open_or_create_data_segment(index, min_size))
+    }
+
+    fn open_or_create_data_segment(
+        &self,
+        index: usize,
+        min_size: usize,
+    ) -> Result<DataShm, SharedMemoryOpenError> {
+        let msg = "Unable to open or create data segment";
+        let config = shm_config(self.prefix, DATA_SHM_SUFFIXES[index]);
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                with SharedMemoryOpenError::InternalError,
+                                "{} {} since the adaptive wait could not be created.", msg, index);
+
+        loop {
+            // an InternalError signals that another process is currently creating the segment
+            match DataShm::Builder::new(&self.name).config(&config).open() {
+                Ok(data_segment) => return Ok(data_segment),
+                Err(SharedMemoryOpenError::DoesNotExist) => {
+                    let size = (self.mgmt().data_segment_size << index).max(min_size);
+                    match DataShm::Builder::new(&self.name)
+                        .config(&config)
+                        .size(size)
+                        .create(&self.mgmt().allocator_config)
+                    {
+                        Ok(mut data_segment) => {
+                            // removed together with the directory
+                            data_segment.release_ownership();
+                            return Ok(data_segment);
+                        }
+                        Err(SharedMemoryCreateError::AlreadyExists) => (),
+                        Err(e) => {
+                            fail!(from self, with SharedMemoryOpenError::InternalError,
+                                "{} {} since the segment could not be created ({:?}).", msg, index, e);
+                        }
+                    }
+                }
+                Err(SharedMemoryOpenError::InternalError) => (),
+                Err(e) => {
+                    fail!(from self, with e,
+                        "{} {} since the segment could not be opened ({:?}).", msg, index, e);
+                }
+            }
+
+            let elapsed = fail!(from self, when adaptive_wait.wait(),
+                                with SharedMemoryOpenError::InternalError,
+                                "{} {} since a failure while waiting for the creation finalization occurred.", msg, index);
+            if elapsed >= MAX_DATA_SEGMENT_CREATION_DURATION {
+                fail!(from self, with SharedMemoryOpenError::InternalError,
+                    "{} {} since the segment is not available after {:?}.",
+                    msg, index, MAX_DATA_SEGMENT_CREATION_DURATION);
+            }
+        }
+    }
+
+    fn mgmt(&self) -> &Management<Allocator::Configuration> {
+        unsafe { &*self.mgmt }
     }
 
     fn files(&self) -> &FileReferenceSet {
-        unsafe { &*self.files }
+        &self.mgmt().files
     }
 }
@@ -0,0 +1,350 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Process wide registry of the [`SharedMemoryDirectory`]s that are used by the concept
+//! implementations which store their resources as files inside a directory, like
+//! [`crate::dynamic_storage::shared_memory_directory`]. Every directory is opened (or created)
+//! once per process and stays mapped as long as a [`DirectoryHandle`] to it exists. Every
+//! process that maps a directory is registered as its user. The last user removes the
+//! directory when it does not contain any files anymore. A process that terminates abnormally
+//! is never deregistered, its directories are kept until they are removed manually.
+//!
+//! The files of a concept are distributed over a [`DirectorySet`] so that the number of files is
+//! not restricted by the capacity of a single directory.
+
+use std::{
+    alloc::Layout,
+    collections::HashMap,
+    ops::Deref,
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::{
+    adaptive_wait::AdaptiveWaitBuilder,
+    mutex::{Mutex, MutexBuilder, MutexHandle},
+};
+use iceoryx2_bb_system_types::file_name::FileName;
+use once_cell::sync::Lazy;
+
+use crate::shared_memory::{posix, SharedMemoryCreateError, SharedMemoryOpenError};
+use crate::shared_memory_directory::file::{File, FileCreator};
+use crate::shm_allocator::ShmAllocationError;
+use crate::shm_allocator::{
+    bump_allocator::BumpAllocator, tlsf_allocator, tlsf_allocator::TlsfAllocator,
+};
+
+use super::{SharedMemoryDirectory, SharedMemoryDirectoryCreator};
+
+/// The [`SharedMemoryDirectory`] that is used by all concepts that are stored inside a
+/// directory.
+pub(crate) type DefaultSharedMemoryDirectory = SharedMemoryDirectory<
+    posix::Memory<BumpAllocator>,
+    TlsfAllocator,
+    posix::Memory<TlsfAllocator>,
+>;
+
+/// Every file inside a directory has at least this size and at most this alignment.
+const MIN_FILE_LAYOUT: Layout = unsafe { Layout::from_size_align_unchecked(1024, 64) };
+const MAX_CREATION_DURATION: Duration = Duration::from_millis(100);
+
+type DirectoryMap = HashMap<(FileName, FileName), Weak<RegisteredDirectory>>;
+
+static DIRECTORY_MTX_HANDLE: Lazy<MutexHandle<DirectoryMap>> = Lazy::new(MutexHandle::new);
+static DIRECTORIES: Lazy<Mutex<DirectoryMap>> = Lazy::new(|| {
+    let result = MutexBuilder::new()
+        .is_interprocess_capable(false)
+        .create(HashMap::new(), &DIRECTORY_MTX_HANDLE);
+
+    if result.is_err() {
+        fatal_panic!(from "SHARED_MEMORY_DIRECTORIES", "Failed to create global shared memory directory registry");
+    }
+
+    result.unwrap()
+});
+
+#[derive(Debug)]
+struct RegisteredDirectory(DefaultSharedMemoryDirectory);
+
+impl Drop for RegisteredDirectory {
+    fn drop(&mut self) {
+        self.0.deregister_user();
+    }
+}
+
+/// Shared handle to a [`DefaultSharedMemoryDirectory`] whose process is registered as user of
+/// the directory. The process is deregistered when the last handle is dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct DirectoryHandle(Arc<RegisteredDirectory>);
+
+impl Deref for DirectoryHandle {
+    type Target = DefaultSharedMemoryDirectory;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0 .0
+    }
+}
+
+impl DirectoryHandle {
+    /// See [`SharedMemoryDirectory::new_file()`].
+    ///
+    /// # Safety
+    ///
+    ///  * the [`FileCreator`] and the created [`File`] must be dropped before the handle
+    pub(crate) unsafe fn new_file(
+        &self,
+        layout: Layout,
+    ) -> Result<FileCreator<'static>, ShmAllocationError> {
+        let directory: &DefaultSharedMemoryDirectory = self;
+        let file_creator = directory.new_file(layout)?;
+        Ok(core::mem::transmute::<FileCreator<'_>, FileCreator<'static>>(file_creator))
+    }
+
+    /// See [`SharedMemoryDirectory::open_file()`].
+    ///
+    /// # Safety
+    ///
+    ///  * the [`File`] must be dropped before the handle
+    pub(crate) unsafe fn open_file(&self, name: &FileName) -> Option<File<'static>> {
+        let directory: &DefaultSharedMemoryDirectory = self;
+        directory
+            .open_file(name)
+            .map(|file| core::mem::transmute::<File<'_>, File<'static>>(file))
+    }
+}
+
+/// A set of [`SharedMemoryDirectory`]s over which the files of a concept are distributed by
+/// the hash of their name. The directory of a file is therefore always known and the
+/// uniqueness of the file name is guaranteed by the directory. A directory is only created
+/// when the first file is stored in it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DirectorySet {
+    prefix: FileName,
+    name: FileName,
+    directory_size: usize,
+    number_of_directories: usize,
+}
+
+impl DirectorySet {
+    /// Creates a new [`DirectorySet`]. The names of the directories consist of the `prefix`,
+    /// the `name` and the index of the directory.
+    pub(crate) fn new(
+        prefix: &FileName,
+        name: &FileName,
+        directory_size: usize,
+        number_of_directories: usize,
+    ) -> Self {
+        Self {
+            prefix: *prefix,
+            name: *name,
+            directory_size,
+            number_of_directories: number_of_directories.max(1),
+        }
+    }
+
+    /// Returns the [`SharedMemoryDirectory`] in which the file with the given name is stored.
+    /// If the directory does not exist it is created.
+    pub(crate) fn acquire(
+        &self,
+        file_name: &FileName,
+    ) -> Result<DirectoryHandle, SharedMemoryOpenError> {
+        acquire(
+            &self.prefix,
+            &self.directory_name(self.index_of(file_name))?,
+            self.directory_size,
+        )
+    }
+
+    /// Returns the [`SharedMemoryDirectory`] in which the file with the given name is stored
+    /// or [`None`] when the directory does not exist.
+    pub(crate) fn acquire_existing(
+        &self,
+        file_name: &FileName,
+    ) -> Result<Option<DirectoryHandle>, SharedMemoryOpenError> {
+        acquire_existing(
+            &self.prefix,
+            &self.directory_name(self.index_of(file_name))?,
+            self.directory_size,
+        )
+    }
+
+    /// Returns all existing [`SharedMemoryDirectory`]s of the set.
+    pub(crate) fn list_existing(&self) -> Result<Vec<DirectoryHandle>, SharedMemoryOpenError> {
+        let mut directories = vec![];
+        for index in 0..self.number_of_directories {
+            if let Some(directory) = acquire_existing(
+                &self.prefix,
+                &self.directory_name(index)?,
+                self.directory_size,
+            )? {
+                directories.push(directory);
+            }
+        }
+
+        Ok(directories)
+    }
+
+    fn directory_name(&self, index: usize) -> Result<FileName, SharedMemoryOpenError> {
+        let mut name = self.name;
+        fail!(from self, when name.push_bytes(format!("_{}", index).as_bytes()),
+            with SharedMemoryOpenError::InternalError,
+            "Unable to generate the name of the directory {} since the resulting name would be invalid.", index);
+        Ok(name)
+    }
+
+    fn index_of(&self, file_name: &FileName) -> usize {
+        // FNV-1a, the index must be identical in every process independent of the used
+        // compiler version
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in file_name.as_bytes() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        (hash % self.number_of_directories as u64) as usize
+    }
+}
+
+/// Returns the [`SharedMemoryDirectory`] with the given prefix and name. If the directory is
+/// not yet used by the process it is opened or, when it does not exist, created with the
+/// provided size.
+fn acquire(
+    prefix: &FileName,
+    name: &FileName,
+    size: usize,
+) -> Result<DirectoryHandle, SharedMemoryOpenError> {
+    let msg = "Unable to acquire shared memory directory";
+    let origin = "shared_memory_directory::registry::acquire()";
+
+    let mut guard = fail!(from origin, when DIRECTORIES.lock(),
+                            with SharedMemoryOpenError::InternalError,
+                            "{} \"{}\" since the lock could not be acquired.", msg, name);
+
+    if let Some(directory) = guard.get(&(*prefix, *name)).and_then(Weak::upgrade) {
+        return Ok(DirectoryHandle(directory));
+    }
+
+    let directory = Arc::new(open_or_create(prefix, name, size)?);
+    guard.insert((*prefix, *name), Arc::downgrade(&directory));
+
+    Ok(DirectoryHandle(directory))
+}
+
+/// Returns the [`SharedMemoryDirectory`] with the given prefix and name when it exists,
+/// otherwise [`None`].
+fn acquire_existing(
+    prefix: &FileName,
+    name: &FileName,
+    size: usize,
+) -> Result<Option<DirectoryHandle>, SharedMemoryOpenError> {
+    let msg = "Unable to acquire existing shared memory directory";
+    let origin = "shared_memory_directory::registry::acquire_existing()";
+
+    let mut guard = fail!(from origin, when DIRECTORIES.lock(),
+                            with SharedMemoryOpenError::InternalError,
+                            "{} \"{}\" since the lock could not be acquired.", msg, name);
+
+    if let Some(directory) = guard.get(&(*prefix, *name)).and_then(Weak::upgrade) {
+        return Ok(Some(DirectoryHandle(directory)));
+    }
+
+    let directory = match SharedMemoryDirectoryCreator::new(name)
+        .prefix(*prefix)
+        .open()
+    {
+        Ok(directory) => {
+            // the directory is removed by its last user since it does not contain any files
+            if !directory.register_user() {
+                return Ok(None);
+            }
+            RegisteredDirectory(directory)
+        }
+        Err(SharedMemoryOpenError::DoesNotExist) => return Ok(None),
+        // the directory is currently being created by another process
+        Err(_) => open_or_create(prefix, name, size)?,
+    };
+
+    let directory = Arc::new(directory);
+    guard.insert((*prefix, *name), Arc::downgrade(&directory));
+
+    Ok(Some(DirectoryHandle(directory)))
+}
+
+fn open_or_create(
+    prefix: &FileName,
+    name: &FileName,
+    size: usize,
+) -> Result<RegisteredDirectory, SharedMemoryOpenError> {
+    let msg = "Unable to open or create shared memory directory";
+    let origin = "shared_memory_directory::registry::open_or_create()";
+
+    let mut adaptive_wait = fail!(from origin, when AdaptiveWaitBuilder::new().create(),
+                                with SharedMemoryOpenError::InternalError,
+                                "{} \"{}\" since the adaptive wait could not be created.", msg, name);
+
+    loop {
+        // an InternalError signals that another process is currently creating or removing the
+        // directory
+        let open_error = match SharedMemoryDirectoryCreator::new(name)
+            .prefix(*prefix)
+            .open()
+        {
+            Ok(directory) => {
+                if directory.register_user() {
+                    return Ok(RegisteredDirectory(directory));
+                }
+                SharedMemoryOpenError::InternalError
+            }
+            Err(e @ SharedMemoryOpenError::DoesNotExist)
+            | Err(e @ SharedMemoryOpenError::InternalError) => e,
+            Err(e) => {
+                fail!(from origin, with e,
+                    "{} \"{}\" since the directory could not be opened ({:?}).", msg, name, e);
+            }
+        };
+
+        if open_error == SharedMemoryOpenError::DoesNotExist {
+            match SharedMemoryDirectoryCreator::new(name)
+                .prefix(*prefix)
+                .size(size)
+                .is_persistent(true)
+                .create(&tlsf_allocator::Config {
+                    min_block_layout: MIN_FILE_LAYOUT,
+                }) {
+                Ok(directory) => {
+                    if directory.register_user() {
+                        return Ok(RegisteredDirectory(directory));
+                    }
+                }
+                Err(SharedMemoryCreateError::AlreadyExists) => (),
+                Err(SharedMemoryCreateError::InsufficientPermissions) => {
+                    fail!(from origin, with SharedMemoryOpenError::InsufficientPermissions,
+                        "{} \"{}\" due to insufficient permissions to create the directory.", msg, name);
+                }
+                Err(e) => {
+                    fail!(from origin, with SharedMemoryOpenError::InternalError,
+                        "{} \"{}\" since the directory could not be created ({:?}).", msg, name, e);
+                }
+            }
+        }
+
+        let elapsed = fail!(from origin, when adaptive_wait.wait(),
+                            with SharedMemoryOpenError::InternalError,
+                            "{} \"{}\" since a failure while waiting for the creation finalization occurred.", msg, name);
+        if elapsed >= MAX_CREATION_DURATION {
+            fail!(from origin, with open_error,
+                "{} \"{}\" since the directory is not available after {:?}.", msg, name, MAX_CREATION_DURATION);
+        }
+    }
+}
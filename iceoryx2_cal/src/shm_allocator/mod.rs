@@ -12,6 +12,7 @@
 
 pub mod bump_allocator;
 pub mod pool_allocator;
+pub mod tlsf_allocator;
 
 use std::{alloc::Layout, ptr::NonNull};
 
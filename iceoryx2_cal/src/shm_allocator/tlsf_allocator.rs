@@ -0,0 +1,549 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe** two-level segregated fit (TLSF) [`ShmAllocator`] which can provide chunks
+//! of arbitrary size from one memory segment. Allocation and deallocation have a constant time
+//! complexity.
+//!
+//! The memory is partitioned into blocks which are a multiple of
+//! [`Config::min_block_layout`]. Free blocks are stored in segregated free lists which are
+//! indexed by a first level (power of two) and a second level (linear subdivision) bitmap.
+//! Released blocks are merged with their free physical neighbours.
+//!
+//! The block headers are not stored inside the payload memory but in a separate management
+//! memory which is provided in [`ShmAllocator::init()`]. All block references are indices,
+//! the management data is therefore relocatable and can be used from every process that has
+//! mapped it.
+//!
+//! The management data is protected by a lock which tracks the process that owns it. When a
+//! process dies while it holds the lock the management data may be inconsistent. The lock is
+//! then recovered by the next process that tries to acquire it but the allocator is poisoned
+//! and every further allocation and deallocation fails. The memory segment has to be
+//! recreated in this case. The owner is detected as dead via its process id, a reused process
+//! id keeps the lock blocked.
+
+use std::{
+    alloc::Layout,
+    cell::UnsafeCell,
+    ptr::NonNull,
+    sync::atomic::{AtomicBool, AtomicI32, Ordering},
+};
+
+use crate::shm_allocator::{ShmAllocator, ShmAllocatorConfig};
+use iceoryx2_bb_elementary::{
+    allocator::{AllocationError, BaseAllocator, DeallocationError},
+    math::align,
+    relocatable_ptr::{PointerTrait, RelocatablePointer},
+};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::process::{Process, ProcessId};
+
+use super::{PointerOffset, ShmAllocationError, ShmAllocatorInitError};
+
+const SECOND_LEVEL_LOG2: u32 = 4;
+const SECOND_LEVEL_COUNT: u32 = 1 << SECOND_LEVEL_LOG2;
+const NO_BLOCK: u32 = u32::MAX;
+const FREE_FLAG: u32 = 1 << 31;
+// the size of a block must not collide with the FREE_FLAG
+const MAX_NUMBER_OF_BLOCKS: usize = FREE_FLAG as usize - 1;
+const UNLOCKED: i32 = 0;
+const SPINS_BETWEEN_OWNER_CHECKS: u32 = 1024;
+const ENTRIES_PER_BLOCK: usize = 4;
+
+const BLOCK_SIZE: usize = 0;
+const BLOCK_PREV_PHYSICAL: usize = 1;
+const BLOCK_NEXT_FREE: usize = 2;
+const BLOCK_PREV_FREE: usize = 3;
+
+#[derive(Clone, Copy)]
+pub struct Config {
+    /// The size of the smallest block and the alignment of every block. Every allocation
+    /// is rounded up to a multiple of the size.
+    pub min_block_layout: Layout,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_block_layout: unsafe { Layout::from_size_align_unchecked(64, 8) },
+        }
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+/// The management memory consists of `u32` entries with the layout
+///
+///  * second level bitmaps: `[u32; first_level_count]`
+///  * free list heads: `[u32; first_level_count * SECOND_LEVEL_COUNT]`
+///  * blocks: `[[u32; ENTRIES_PER_BLOCK]; number_of_blocks]`
+///
+/// A block is identified by the index of its first minimal block. Only entries of blocks that
+/// start at this index are valid, all other entries have a size of zero.
+#[derive(Debug)]
+#[repr(C)]
+pub struct TlsfAllocator {
+    mgmt_ptr: RelocatablePointer<UnsafeCell<u32>>,
+    first_level_bitmap: UnsafeCell<u32>,
+    first_level_count: u32,
+    number_of_blocks: u32,
+    block_size: usize,
+    block_alignment: usize,
+    start_offset: usize,
+    max_supported_alignment_by_memory: usize,
+    lock_owner: AtomicI32,
+    is_poisoned: AtomicBool,
+    is_memory_initialized: AtomicBool,
+}
+
+unsafe impl Send for TlsfAllocator {}
+unsafe impl Sync for TlsfAllocator {}
+
+struct LockGuard<'a> {
+    lock_owner: &'a AtomicI32,
+}
+
+impl Drop for LockGuard<'_> {
+    fn drop(&mut self) {
+        self.lock_owner.store(UNLOCKED, Ordering::Release);
+    }
+}
+
+impl TlsfAllocator {
+    /// Returns the size of the smallest block. Every allocation is a multiple of it.
+    pub fn min_block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the number of smallest blocks the memory is partitioned into.
+    pub fn number_of_blocks(&self) -> u32 {
+        self.number_of_blocks
+    }
+
+    fn block_size_of(config: &Config) -> usize {
+        align(
+            config.min_block_layout.size().max(1),
+            config.min_block_layout.align(),
+        )
+    }
+
+    fn first_level_count_of(number_of_blocks: u32) -> u32 {
+        Self::mapping(number_of_blocks.max(1)).0 + 1
+    }
+
+    fn number_of_mgmt_entries(number_of_blocks: u32) -> usize {
+        let first_level_count = Self::first_level_count_of(number_of_blocks) as usize;
+        first_level_count
+            + first_level_count * SECOND_LEVEL_COUNT as usize
+            + number_of_blocks as usize * ENTRIES_PER_BLOCK
+    }
+
+    /// Returns the first and second level index of the free list which contains blocks of
+    /// the given size.
+    fn mapping(size: u32) -> (u32, u32) {
+        if size < SECOND_LEVEL_COUNT {
+            return (0, size);
+        }
+
+        let msb = u32::BITS - 1 - size.leading_zeros();
+        (
+            msb - SECOND_LEVEL_LOG2 + 1,
+            (size >> (msb - SECOND_LEVEL_LOG2)) - SECOND_LEVEL_COUNT,
+        )
+    }
+
+    /// Returns the first and second level index of the smallest free list whose blocks are
+    /// all at least of the given size.
+    fn mapping_search(size: u32) -> (u32, u32) {
+        if size < SECOND_LEVEL_COUNT {
+            return (0, size);
+        }
+
+        let msb = u32::BITS - 1 - size.leading_zeros();
+        Self::mapping(size.saturating_add((1 << (msb - SECOND_LEVEL_LOG2)) - 1))
+    }
+
+    fn verify_init(&self, source: &str) {
+        if !self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(from self, "Undefined behavior when calling \"{}\" and the object is not initialized.", source);
+        }
+    }
+
+    /// Acquires the lock of the management data. Returns [`None`] when the allocator is
+    /// poisoned since a process died while it was holding the lock.
+    fn lock(&self) -> Option<LockGuard<'_>> {
+        let pid = Process::from_self().id().value();
+        let mut spins: u32 = 0;
+
+        loop {
+            match self.lock_owner.compare_exchange_weak(
+                UNLOCKED,
+                pid,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(owner) => {
+                    spins = spins.wrapping_add(1);
+                    if owner != UNLOCKED
+                        && spins % SPINS_BETWEEN_OWNER_CHECKS == 0
+                        && !Process::from_pid(ProcessId::new(owner)).does_exist()
+                        && self
+                            .lock_owner
+                            .compare_exchange(owner, pid, Ordering::Acquire, Ordering::Relaxed)
+                            .is_ok()
+                    {
+                        warn!(from self, "The process {} died while it was modifying the allocator. The allocator is poisoned.", owner);
+                        self.is_poisoned.store(true, Ordering::Relaxed);
+                        break;
+                    }
+                    std::hint::spin_loop();
+                }
+            }
+        }
+
+        let guard = LockGuard {
+            lock_owner: &self.lock_owner,
+        };
+
+        match self.is_poisoned.load(Ordering::Relaxed) {
+            true => None,
+            false => Some(guard),
+        }
+    }
+
+    unsafe fn entry(&self, index: usize) -> *mut u32 {
+        (*self.mgmt_ptr.as_ptr().add(index)).get()
+    }
+
+    unsafe fn second_level_bitmap(&self, first_level: u32) -> *mut u32 {
+        self.entry(first_level as usize)
+    }
+
+    unsafe fn free_list_head(&self, first_level: u32, second_level: u32) -> *mut u32 {
+        self.entry(
+            self.first_level_count as usize
+                + (first_level * SECOND_LEVEL_COUNT + second_level) as usize,
+        )
+    }
+
+    unsafe fn block(&self, block: u32, field: usize) -> *mut u32 {
+        self.entry(
+            self.first_level_count as usize * (1 + SECOND_LEVEL_COUNT as usize)
+                + block as usize * ENTRIES_PER_BLOCK
+                + field,
+        )
+    }
+
+    unsafe fn size_of_block(&self, block: u32) -> u32 {
+        *self.block(block, BLOCK_SIZE) & !FREE_FLAG
+    }
+
+    unsafe fn is_free(&self, block: u32) -> bool {
+        *self.block(block, BLOCK_SIZE) & FREE_FLAG != 0
+    }
+
+    unsafe fn insert_free_block(&self, block: u32, size: u32) {
+        let (first_level, second_level) = Self::mapping(size);
+        let head = *self.free_list_head(first_level, second_level);
+
+        *self.block(block, BLOCK_SIZE) = size | FREE_FLAG;
+        *self.block(block, BLOCK_NEXT_FREE) = head;
+        *self.block(block, BLOCK_PREV_FREE) = NO_BLOCK;
+        if head != NO_BLOCK {
+            *self.block(head, BLOCK_PREV_FREE) = block;
+        }
+
+        *self.free_list_head(first_level, second_level) = block;
+        *self.second_level_bitmap(first_level) |= 1 << second_level;
+        *self.first_level_bitmap.get() |= 1 << first_level;
+    }
+
+    unsafe fn remove_free_block(&self, block: u32) {
+        let (first_level, second_level) = Self::mapping(self.size_of_block(block));
+        let next = *self.block(block, BLOCK_NEXT_FREE);
+        let prev = *self.block(block, BLOCK_PREV_FREE);
+
+        if next != NO_BLOCK {
+            *self.block(next, BLOCK_PREV_FREE) = prev;
+        }
+
+        if prev != NO_BLOCK {
+            *self.block(prev, BLOCK_NEXT_FREE) = next;
+        } else {
+            *self.free_list_head(first_level, second_level) = next;
+            if next == NO_BLOCK {
+                *self.second_level_bitmap(first_level) &= !(1 << second_level);
+                if *self.second_level_bitmap(first_level) == 0 {
+                    *self.first_level_bitmap.get() &= !(1 << first_level);
+                }
+            }
+        }
+
+        *self.block(block, BLOCK_SIZE) &= !FREE_FLAG;
+    }
+
+    unsafe fn find_free_block(&self, size: u32) -> Option<u32> {
+        let (first_level, second_level) = Self::mapping_search(size);
+        if let Some(block) = self.find_free_block_in_or_above(first_level, second_level) {
+            return Some(block);
+        }
+
+        // the blocks of the free list which contains the requested size are not guaranteed
+        // to be large enough, but its head may still fit
+        let (first_level, second_level) = Self::mapping(size);
+        let head = *self.free_list_head(first_level, second_level);
+        if head != NO_BLOCK && self.size_of_block(head) >= size {
+            return Some(head);
+        }
+
+        None
+    }
+
+    unsafe fn find_free_block_in_or_above(
+        &self,
+        mut first_level: u32,
+        second_level: u32,
+    ) -> Option<u32> {
+        if first_level >= self.first_level_count {
+            return None;
+        }
+
+        let mut second_level_map = *self.second_level_bitmap(first_level) & (!0u32 << second_level);
+        if second_level_map == 0 {
+            let first_level_map = *self.first_level_bitmap.get() & (!0u32 << (first_level + 1));
+            if first_level_map == 0 {
+                return None;
+            }
+
+            first_level = first_level_map.trailing_zeros();
+            second_level_map = *self.second_level_bitmap(first_level);
+        }
+
+        Some(*self.free_list_head(first_level, second_level_map.trailing_zeros()))
+    }
+
+    unsafe fn set_prev_physical_of_next(&self, block: u32, size: u32) {
+        let next = block + size;
+        if next < self.number_of_blocks {
+            *self.block(next, BLOCK_PREV_PHYSICAL) = block;
+        }
+    }
+}
+
+impl ShmAllocator for TlsfAllocator {
+    type Configuration = Config;
+
+    fn management_size(memory_size: usize, config: &Self::Configuration) -> usize {
+        let number_of_blocks =
+            (memory_size / Self::block_size_of(config)).min(MAX_NUMBER_OF_BLOCKS) as u32;
+
+        std::mem::size_of::<u32>() * Self::number_of_mgmt_entries(number_of_blocks)
+            + std::mem::align_of::<u32>()
+            - 1
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        base_address: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        let block_size = Self::block_size_of(config);
+        let block_alignment = config.min_block_layout.align();
+        let base_address_value = (base_address.as_ptr() as *mut u8) as usize;
+        let start_offset = align(base_address_value, block_alignment) - base_address_value;
+        let mut number_of_blocks = base_address.len().saturating_sub(start_offset) / block_size;
+        if number_of_blocks > MAX_NUMBER_OF_BLOCKS {
+            warn!(from "TlsfAllocator::new_uninit()",
+                "The memory consists of {} blocks but only {} blocks are supported. The remaining memory is not used.",
+                number_of_blocks, MAX_NUMBER_OF_BLOCKS);
+            number_of_blocks = MAX_NUMBER_OF_BLOCKS;
+        }
+        let number_of_blocks = number_of_blocks as u32;
+
+        Self {
+            mgmt_ptr: RelocatablePointer::new_uninit(),
+            first_level_bitmap: UnsafeCell::new(0),
+            first_level_count: Self::first_level_count_of(number_of_blocks),
+            number_of_blocks,
+            block_size,
+            block_alignment,
+            start_offset,
+            max_supported_alignment_by_memory,
+            lock_owner: AtomicI32::new(UNLOCKED),
+            is_poisoned: AtomicBool::new(false),
+            is_memory_initialized: AtomicBool::new(false),
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.block_alignment
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        let msg = "Unable to initialize allocator";
+        if self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(
+                from self,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior."
+            );
+        }
+
+        if self.max_supported_alignment_by_memory < self.max_alignment() {
+            fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
+                "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
+                msg, self.max_alignment(), self.max_supported_alignment_by_memory);
+        }
+
+        let number_of_entries = Self::number_of_mgmt_entries(self.number_of_blocks);
+        let mgmt_memory = fail!(from self, when allocator.allocate(Layout::from_size_align_unchecked(
+                    std::mem::size_of::<u32>() * number_of_entries,
+                    std::mem::align_of::<u32>())),
+            with ShmAllocatorInitError::AllocationFailed,
+            "{} since the allocation of the allocator managment memory failed.", msg);
+        self.mgmt_ptr.init(mgmt_memory);
+
+        let blocks_start = self.first_level_count as usize * (1 + SECOND_LEVEL_COUNT as usize);
+        for i in 0..number_of_entries {
+            let value = if i < self.first_level_count as usize || i >= blocks_start {
+                0
+            } else {
+                NO_BLOCK
+            };
+            (self.mgmt_ptr.as_ptr() as *mut UnsafeCell<u32>)
+                .add(i)
+                .write(UnsafeCell::new(value));
+        }
+
+        if self.number_of_blocks != 0 {
+            *self.block(0, BLOCK_PREV_PHYSICAL) = NO_BLOCK;
+            self.insert_free_block(0, self.number_of_blocks);
+        }
+
+        self.is_memory_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn unique_id() -> u8 {
+        2
+    }
+
+    unsafe fn allocate(&self, layout: Layout) -> Result<PointerOffset, ShmAllocationError> {
+        self.verify_init("allocate");
+
+        let msg = "Unable to allocate memory";
+        if layout.align() > self.max_alignment() {
+            fail!(from self, with ShmAllocationError::ExceedsMaxSupportedAlignment,
+                "{} since an alignment of {} exceeds the maximum supported alignment of {}.",
+                msg, layout.align(), self.max_alignment());
+        }
+
+        if layout.size() == 0 {
+            fail!(from self, with ShmAllocationError::AllocationError(AllocationError::SizeIsZero),
+                "{} since the requested size was zero.", msg);
+        }
+
+        let required_blocks = (layout.size() + self.block_size - 1) / self.block_size;
+        if required_blocks > self.number_of_blocks as usize {
+            fail!(from self, with ShmAllocationError::AllocationError(AllocationError::SizeTooLarge),
+                "{} since the requested size of {} exceeds the size of the managed memory of {}.",
+                msg, layout.size(), self.number_of_blocks as usize * self.block_size);
+        }
+        let required_blocks = required_blocks as u32;
+
+        let _guard = match self.lock() {
+            Some(guard) => guard,
+            None => {
+                fail!(from self, with ShmAllocationError::AllocationError(AllocationError::InternalError),
+                    "{} since the allocator is poisoned.", msg);
+            }
+        };
+        let block = match self.find_free_block(required_blocks) {
+            Some(block) => block,
+            None => {
+                fail!(from self, with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
+                    "{} since no free block with a size of at least {} is available.",
+                    msg, layout.size());
+            }
+        };
+
+        self.remove_free_block(block);
+        let size = self.size_of_block(block);
+        if size > required_blocks {
+            let remainder = block + required_blocks;
+            *self.block(remainder, BLOCK_PREV_PHYSICAL) = block;
+            self.set_prev_physical_of_next(remainder, size - required_blocks);
+            self.insert_free_block(remainder, size - required_blocks);
+            *self.block(block, BLOCK_SIZE) = required_blocks;
+        }
+
+        Ok(PointerOffset::new(
+            self.start_offset + block as usize * self.block_size,
+        ))
+    }
+
+    unsafe fn deallocate(
+        &self,
+        offset: PointerOffset,
+        _layout: Layout,
+    ) -> Result<(), DeallocationError> {
+        self.verify_init("deallocate");
+
+        let msg = "Unable to release shared memory chunk";
+        let distance = offset.value().wrapping_sub(self.start_offset);
+        let block = distance / self.block_size;
+        if offset.value() < self.start_offset
+            || distance % self.block_size != 0
+            || block >= self.number_of_blocks as usize
+        {
+            fail!(from self, with DeallocationError::ProvidedPointerNotContainedInAllocator,
+                "{} since the offset {} does not belong to this allocator.", msg, offset.value());
+        }
+        let mut block = block as u32;
+
+        let _guard = match self.lock() {
+            Some(guard) => guard,
+            None => {
+                fail!(from self, with DeallocationError::InternalError,
+                    "{} since the allocator is poisoned.", msg);
+            }
+        };
+        if self.size_of_block(block) == 0 || self.is_free(block) {
+            fail!(from self, with DeallocationError::ProvidedPointerNotContainedInAllocator,
+                "{} since the offset {} does not point to an allocated chunk.", msg, offset.value());
+        }
+
+        let mut size = self.size_of_block(block);
+        let next = block + size;
+        if next < self.number_of_blocks && self.is_free(next) {
+            self.remove_free_block(next);
+            size += self.size_of_block(next);
+            *self.block(next, BLOCK_SIZE) = 0;
+        }
+
+        let prev = *self.block(block, BLOCK_PREV_PHYSICAL);
+        if prev != NO_BLOCK && self.is_free(prev) {
+            self.remove_free_block(prev);
+            size += self.size_of_block(prev);
+            *self.block(block, BLOCK_SIZE) = 0;
+            block = prev;
+        }
+
+        self.set_prev_physical_of_next(block, size);
+        self.insert_free_block(block, size);
+
+        Ok(())
+    }
+}
@@ -48,17 +48,28 @@ pub use crate::static_storage::*;
 
 use iceoryx2_bb_log::{fail, trace, warn};
 use iceoryx2_bb_posix::{
-    directory::*, file::*, file_descriptor::FileDescriptorManagement, file_type::FileType,
+    access_control_list::{AccessControlListApplyError, AclPermission},
+    directory::*,
+    file::*,
+    file_descriptor::FileDescriptorManagement,
+    file_type::FileType,
 };
 
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
 
+// the group bits of a finalized storage can be set by the access control list, therefore only
+// the owner bits define whether the storage is finalized
+fn is_finalized(permission: Permission) -> bool {
+    permission & Permission::OWNER_ALL == FINAL_PERMISSIONS
+}
+
 /// The custom configuration of the [`Storage`].
 #[derive(Clone, Debug)]
 pub struct Configuration {
     path: Path,
     suffix: FileName,
     prefix: FileName,
+    access_rights: AccessRights,
 }
 
 impl Default for Configuration {
@@ -67,6 +78,7 @@ impl Default for Configuration {
             path: DEFAULT_PATH_HINT,
             suffix: DEFAULT_SUFFIX,
             prefix: DEFAULT_PREFIX,
+            access_rights: AccessRights::default(),
         }
     }
 }
@@ -98,6 +110,11 @@ impl crate::named_concept::NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path
     }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
 }
 
 impl crate::static_storage::StaticStorageConfiguration for Configuration {}
@@ -133,6 +150,17 @@ impl StaticStorageLocked<Storage> for Locked {
                 unmatched StaticStorageUnlockError::InternalError,
                 "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
 
+        let access_rights = self.static_storage.config.access_rights;
+        if !access_rights.is_empty() {
+            let acl = fail!(from self, when access_rights.access_control_list(AclPermission::Read),
+                    with StaticStorageUnlockError::InternalError,
+                    "{} since the access control list could not be created.", msg);
+            fail!(from self, when self.static_storage.file.set_access_control_list(&acl),
+                    map AccessControlListApplyError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+                    unmatched StaticStorageUnlockError::InternalError,
+                    "{} due to a failure while applying the access control list.", msg);
+        }
+
         self.static_storage.len = contents.len() as u64;
 
         Ok(self.static_storage)
@@ -227,8 +255,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         let mut result = vec![];
         for entry in &entries {
             let metadata = entry.metadata();
-            if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS
-            {
+            if metadata.file_type() == FileType::File && is_finalized(metadata.permission()) {
                 if let Some(entry_name) = config.extract_name_from_file(entry.name()) {
                     result.push(entry_name);
                 }
@@ -271,7 +298,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         }
         let metadata = metadata.unwrap();
 
-        if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS {
+        if metadata.file_type() == FileType::File && is_finalized(metadata.permission()) {
             return Ok(true);
         }
 
@@ -403,7 +430,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             when file.metadata(), with StaticStorageOpenError::Read,
             "{} due to a failure while reading the files metadata.", msg);
 
-        if metadata.permission() != FINAL_PERMISSIONS {
+        if !is_finalized(metadata.permission()) {
             fail!(from origin, with StaticStorageOpenError::IsLocked,
                 "{} since the static storage is still being created (in locked state), try later.", msg);
         }
@@ -0,0 +1,448 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The channel logic of all [`ZeroCopyConnection`]s whose [`SharedManagementData`] is stored in
+//! a memory that is shared between processes. An implementation only has to provide the
+//! [`SharedChannelMemory`] and to set up the [`SharedManagementData`] inside of it.
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+use crate::zero_copy_connection::*;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::spsc::{
+    index_queue::RelocatableIndexQueue,
+    safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
+};
+use iceoryx2_bb_log::{error, fail};
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+
+/// The memory in which the [`SharedManagementData`] of a connection is stored.
+pub trait SharedChannelMemory: Debug {
+    /// Returns the address of the [`SharedManagementData`].
+    fn base_address(&self) -> usize;
+
+    /// Removes the memory when neither a [`Sender`] nor a [`Receiver`] is connected anymore.
+    /// Returns false when the memory could not be removed.
+    fn remove(&self) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum State {
+    None = 0b00000000,
+    Sender = 0b00000001,
+    Receiver = 0b00000010,
+    MarkedForDestruction = 0b10000000,
+}
+
+impl State {
+    fn value(&self) -> u8 {
+        *self as u8
+    }
+}
+
+#[repr(C)]
+pub(crate) struct SharedManagementData {
+    receive_channel: RelocatableSafelyOverflowingIndexQueue,
+    retrieve_channel: RelocatableIndexQueue,
+    max_borrowed_samples: usize,
+    state: AtomicU8,
+    pub(crate) init_state: AtomicU64,
+    enable_safe_overflow: bool,
+}
+
+impl SharedManagementData {
+    fn new(
+        receive_channel_buffer_size: usize,
+        retrieve_channel_buffer_size: usize,
+        enable_safe_overflow: bool,
+        max_borrowed_samples: usize,
+    ) -> Self {
+        Self {
+            receive_channel: unsafe {
+                RelocatableSafelyOverflowingIndexQueue::new_uninit(receive_channel_buffer_size)
+            },
+            retrieve_channel: unsafe {
+                RelocatableIndexQueue::new_uninit(retrieve_channel_buffer_size)
+            },
+            state: AtomicU8::new(State::None.value()),
+            init_state: AtomicU64::new(0),
+            enable_safe_overflow,
+            max_borrowed_samples,
+        }
+    }
+
+    /// Returns the size of the memory that is required for the [`SharedManagementData`] and
+    /// its channels. The memory must be aligned to the alignment of [`SharedManagementData`].
+    pub(crate) const fn const_memory_size(
+        receive_channel_buffer_size: usize,
+        retrieve_channel_buffer_size: usize,
+    ) -> usize {
+        std::mem::size_of::<Self>()
+            + RelocatableIndexQueue::const_memory_size(retrieve_channel_buffer_size)
+            + RelocatableSafelyOverflowingIndexQueue::const_memory_size(receive_channel_buffer_size)
+    }
+
+    /// Writes the [`SharedManagementData`] to `ptr` and initializes its channels in the memory
+    /// that follows directly. Returns false when the channels could not be initialized.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must point to a memory of `memory_size` bytes which is at least
+    ///    [`SharedManagementData::const_memory_size()`]
+    ///  * `ptr` must be aligned to the alignment of [`SharedManagementData`]
+    pub(crate) unsafe fn init(
+        ptr: *mut Self,
+        memory_size: usize,
+        receive_channel_buffer_size: usize,
+        retrieve_channel_buffer_size: usize,
+        enable_safe_overflow: bool,
+        max_borrowed_samples: usize,
+    ) -> bool {
+        ptr.write(Self::new(
+            receive_channel_buffer_size,
+            retrieve_channel_buffer_size,
+            enable_safe_overflow,
+            max_borrowed_samples,
+        ));
+
+        let allocator = BumpAllocator::new(
+            NonNull::new_unchecked((ptr as *mut u8).add(std::mem::size_of::<Self>())),
+            memory_size - std::mem::size_of::<Self>(),
+        );
+
+        (*ptr).receive_channel.init(&allocator).is_ok()
+            && (*ptr).retrieve_channel.init(&allocator).is_ok()
+    }
+
+    /// Verifies that the settings of an existing connection are equal to the required
+    /// settings.
+    pub(crate) fn verify_settings<T: Debug>(
+        &self,
+        origin: &T,
+        receive_channel_buffer_size: usize,
+        retrieve_channel_buffer_size: usize,
+        enable_safe_overflow: bool,
+        max_borrowed_samples: usize,
+    ) -> Result<(), ZeroCopyCreationError> {
+        let msg = "Failed to open existing connection";
+
+        if self.receive_channel.capacity() != receive_channel_buffer_size {
+            fail!(from origin, with ZeroCopyCreationError::IncompatibleBufferSize,
+                "{} since the connection has a buffer size of {} but a buffer size of {} is required.",
+                msg, self.receive_channel.capacity(), receive_channel_buffer_size);
+        }
+
+        if self.retrieve_channel.capacity() != retrieve_channel_buffer_size {
+            fail!(from origin, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
+                "{} since the max borrowed sample setting is set to {} but a value of {} is required.",
+                msg, self.retrieve_channel.capacity() - self.receive_channel.capacity(), max_borrowed_samples);
+        }
+
+        if self.enable_safe_overflow != enable_safe_overflow {
+            fail!(from origin, with ZeroCopyCreationError::IncompatibleOverflowSetting,
+                "{} since the safe overflow is set to {} but should be set to {}.",
+                msg, self.enable_safe_overflow, enable_safe_overflow);
+        }
+
+        Ok(())
+    }
+
+    /// Registers the port with the provided `state` at the connection.
+    pub(crate) fn reserve_port<T: Debug>(
+        &self,
+        origin: &T,
+        state: State,
+        msg: &str,
+    ) -> Result<(), ZeroCopyCreationError> {
+        let new_state = state.value();
+        let mut current_state = State::None.value();
+
+        loop {
+            match self.state.compare_exchange(
+                current_state,
+                current_state | new_state,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => {
+                    current_state = v;
+                    if current_state & new_state != 0 {
+                        fail!(from origin, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
+                            "{} since an instance is already connected.", msg);
+                    } else if current_state & State::MarkedForDestruction.value() != 0 {
+                        fail!(from origin, with ZeroCopyCreationError::InternalError,
+                            "{} since the connection is currently being cleaned up.", msg);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
+    }
+}
+
+fn mgmt<Memory: SharedChannelMemory>(memory: &Memory) -> &SharedManagementData {
+    unsafe { &*(memory.base_address() as *const SharedManagementData) }
+}
+
+fn cleanup_memory<T: Debug, Memory: SharedChannelMemory>(
+    origin: &T,
+    memory: &Memory,
+    state_to_remove: State,
+) {
+    let mgmt_ref = mgmt(memory);
+
+    let mut current_state = mgmt_ref.state.load(Ordering::Relaxed);
+    loop {
+        let new_state = if current_state == state_to_remove.value() {
+            State::MarkedForDestruction.value()
+        } else {
+            current_state & !state_to_remove.value()
+        };
+
+        match mgmt_ref.state.compare_exchange(
+            current_state,
+            new_state,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => {
+                current_state = new_state;
+                break;
+            }
+            Err(s) => {
+                current_state = s;
+            }
+        }
+    }
+
+    if current_state == State::MarkedForDestruction.value() && !memory.remove() {
+        error!(from origin, "Failed to remove the underlying memory when cleaning up the connection.");
+    }
+}
+
+#[derive(Debug)]
+pub struct Sender<Memory: SharedChannelMemory> {
+    memory: Memory,
+    used_chunk_list: UsedChunkList,
+    name: FileName,
+}
+
+impl<Memory: SharedChannelMemory> Drop for Sender<Memory> {
+    fn drop(&mut self) {
+        cleanup_memory(self, &self.memory, State::Sender);
+    }
+}
+
+impl<Memory: SharedChannelMemory> Sender<Memory> {
+    /// Creates a new [`Sender`]. The sender port must be already reserved with
+    /// [`SharedManagementData::reserve_port()`].
+    pub(crate) fn new(memory: Memory, used_chunk_list: UsedChunkList, name: FileName) -> Self {
+        Self {
+            memory,
+            used_chunk_list,
+            name,
+        }
+    }
+
+    fn mgmt(&self) -> &SharedManagementData {
+        mgmt(&self.memory)
+    }
+}
+
+impl<Memory: SharedChannelMemory> NamedConcept for Sender<Memory> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<Memory: SharedChannelMemory> ZeroCopyPortDetails for Sender<Memory> {
+    fn buffer_size(&self) -> usize {
+        self.mgmt().receive_channel.capacity()
+    }
+
+    fn max_borrowed_samples(&self) -> usize {
+        self.mgmt().max_borrowed_samples
+    }
+
+    fn has_enabled_safe_overflow(&self) -> bool {
+        self.mgmt().enable_safe_overflow
+    }
+
+    fn is_connected(&self) -> bool {
+        self.mgmt().is_connected()
+    }
+}
+
+impl<Memory: SharedChannelMemory> ZeroCopySender for Sender<Memory> {
+    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        let msg = "Unable to send sample";
+        let space_in_retrieve_channel =
+            self.mgmt().retrieve_channel.capacity() - self.mgmt().retrieve_channel.len();
+
+        if space_in_retrieve_channel
+            <= self.mgmt().max_borrowed_samples + self.mgmt().receive_channel.len()
+        {
+            fail!(from self, with ZeroCopySendError::ClearRetrieveChannelBeforeSend,
+                "{} since sufficient space for every sample in the retrieve channel cannot be guaranteed. Samples have to be retrieved before a new sample can be send.", msg);
+        }
+
+        if !self.mgmt().enable_safe_overflow && self.mgmt().receive_channel.is_full() {
+            fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
+                             "{} since the receive buffer is full.", msg);
+        }
+
+        self.used_chunk_list.insert(ptr.value());
+        match unsafe { self.mgmt().receive_channel.push(ptr.value()) } {
+            Some(v) => {
+                self.used_chunk_list.remove(v);
+                Ok(Some(PointerOffset::new(v)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        if !self.mgmt().enable_safe_overflow {
+            AdaptiveWaitBuilder::new()
+                .create()
+                .unwrap()
+                .wait_while(|| self.mgmt().receive_channel.is_full())
+                .unwrap();
+        }
+
+        self.try_send(ptr)
+    }
+
+    fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+        match unsafe { self.mgmt().retrieve_channel.pop() } {
+            None => Ok(None),
+            Some(v) => {
+                self.used_chunk_list.remove(v);
+                Ok(Some(PointerOffset::new(v)))
+            }
+        }
+    }
+
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+        // the returned samples are tracked in the used chunk list as well
+        while self.mgmt().retrieve_channel.pop().is_some() {}
+        self.used_chunk_list
+            .drain(|offset| callback(PointerOffset::new(offset)));
+    }
+}
+
+#[derive(Debug)]
+pub struct Receiver<Memory: SharedChannelMemory> {
+    memory: Memory,
+    borrow_counter: UnsafeCell<usize>,
+    name: FileName,
+}
+
+impl<Memory: SharedChannelMemory> Drop for Receiver<Memory> {
+    fn drop(&mut self) {
+        cleanup_memory(self, &self.memory, State::Receiver);
+    }
+}
+
+impl<Memory: SharedChannelMemory> Receiver<Memory> {
+    /// Creates a new [`Receiver`]. The receiver port must be already reserved with
+    /// [`SharedManagementData::reserve_port()`].
+    pub(crate) fn new(memory: Memory, name: FileName) -> Self {
+        Self {
+            memory,
+            borrow_counter: UnsafeCell::new(0),
+            name,
+        }
+    }
+
+    fn mgmt(&self) -> &SharedManagementData {
+        mgmt(&self.memory)
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    // convenience to access internal mutable object
+    fn borrow_counter(&self) -> &mut usize {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.borrow_counter.get()
+        }
+    }
+}
+
+impl<Memory: SharedChannelMemory> NamedConcept for Receiver<Memory> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<Memory: SharedChannelMemory> ZeroCopyPortDetails for Receiver<Memory> {
+    fn buffer_size(&self) -> usize {
+        self.mgmt().receive_channel.capacity()
+    }
+
+    fn max_borrowed_samples(&self) -> usize {
+        self.mgmt().max_borrowed_samples
+    }
+
+    fn has_enabled_safe_overflow(&self) -> bool {
+        self.mgmt().enable_safe_overflow
+    }
+
+    fn is_connected(&self) -> bool {
+        self.mgmt().is_connected()
+    }
+}
+
+impl<Memory: SharedChannelMemory> ZeroCopyReceiver for Receiver<Memory> {
+    fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+        if *self.borrow_counter() >= self.mgmt().max_borrowed_samples {
+            fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
+                "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
+                    self.borrow_counter(), self.max_borrowed_samples());
+        }
+
+        match unsafe { self.mgmt().receive_channel.pop() } {
+            None => Ok(None),
+            Some(v) => {
+                *self.borrow_counter() += 1;
+                Ok(Some(PointerOffset::new(v)))
+            }
+        }
+    }
+
+    fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
+        match unsafe { self.mgmt().retrieve_channel.push(ptr.value()) } {
+            true => {
+                *self.borrow_counter() -= 1;
+                Ok(())
+            }
+            false => {
+                fail!(from self, with ZeroCopyReleaseError::RetrieveBufferFull,
+                    "Unable to release pointer since the retrieve buffer is full.");
+            }
+        }
+    }
+}
@@ -10,13 +10,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub(crate) mod common;
 pub mod posix_shared_memory;
 pub mod process_local;
+pub mod shared_memory_directory;
 
+use std::cell::{Cell, UnsafeCell};
 use std::fmt::Debug;
 
 pub use crate::shared_memory::PointerOffset;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_posix::config::TEMP_DIRECTORY;
 pub use iceoryx2_bb_system_types::file_name::FileName;
 pub use iceoryx2_bb_system_types::path::Path;
@@ -93,6 +97,8 @@ impl std::error::Error for ZeroCopyReleaseError {}
 pub const DEFAULT_BUFFER_SIZE: usize = 4;
 pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
 pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
+pub const DEFAULT_NUMBER_OF_SAMPLES: usize = 8;
+pub const DEFAULT_SAMPLE_SIZE: usize = 1;
 
 /// The default suffix of every zero copy connection
 pub const DEFAULT_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".rx") };
@@ -108,6 +114,14 @@ pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<
     fn enable_safe_overflow(self, value: bool) -> Self;
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
 
+    /// Defines the number of samples of the data segment of the [`ZeroCopySender`]. Every
+    /// offset that is sent must belong to one of these samples.
+    fn number_of_samples(self, value: usize) -> Self;
+
+    /// Defines the size of a sample of the data segment of the [`ZeroCopySender`]. The samples
+    /// are stored consecutively in the data segment.
+    fn sample_size(self, value: usize) -> Self;
+
     fn create_sender(self) -> Result<C::Sender, ZeroCopyCreationError>;
     fn create_receiver(self) -> Result<C::Receiver, ZeroCopyCreationError>;
 }
@@ -126,6 +140,18 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
         -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
+
+    /// Calls the provided callback for every [`PointerOffset`] that was sent to the
+    /// [`ZeroCopyReceiver`] and was not yet returned via [`ZeroCopySender::reclaim()`].
+    /// Afterwards the [`ZeroCopySender`] no longer tracks any [`PointerOffset`] and the
+    /// connection shall no longer be used.
+    ///
+    /// # Safety
+    ///
+    ///  * the corresponding [`ZeroCopyReceiver`] must not use any received [`PointerOffset`]
+    ///    anymore, for instance since the owning process died
+    ///
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F);
 }
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
@@ -133,6 +159,82 @@ pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
 }
 
+/// Tracks all offsets that a [`ZeroCopySender`] has sent and which were not yet reclaimed.
+/// Required to recover the samples of a [`ZeroCopyReceiver`] that died. Every sample of the
+/// senders data segment is represented by one bit. The samples are stored consecutively,
+/// therefore all offsets have the same distance to a multiple of the sample size.
+#[derive(Debug)]
+pub(crate) struct UsedChunkList {
+    bits: UnsafeCell<Vec<u64>>,
+    number_of_samples: usize,
+    sample_size: usize,
+    start_offset: Cell<usize>,
+}
+
+impl UsedChunkList {
+    const BITS_PER_ENTRY: usize = u64::BITS as usize;
+
+    pub(crate) fn new(number_of_samples: usize, sample_size: usize) -> Self {
+        Self {
+            bits: UnsafeCell::new(vec![
+                0;
+                (number_of_samples + Self::BITS_PER_ENTRY - 1)
+                    / Self::BITS_PER_ENTRY
+            ]),
+            number_of_samples,
+            sample_size: sample_size.max(1),
+            start_offset: Cell::new(0),
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    // convenience to access internal mutable object
+    fn bits(&self) -> &mut Vec<u64> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.bits.get()
+        }
+    }
+
+    fn index(&self, value: usize) -> usize {
+        let index = value / self.sample_size;
+        if index >= self.number_of_samples {
+            fatal_panic!(from self,
+                "This should never happen! The offset {} does not belong to the data segment with {} samples of size {}.",
+                value, self.number_of_samples, self.sample_size);
+        }
+
+        index
+    }
+
+    pub(crate) fn insert(&self, value: usize) {
+        let index = self.index(value);
+        self.start_offset.set(value % self.sample_size);
+        self.bits()[index / Self::BITS_PER_ENTRY] |= 1 << (index % Self::BITS_PER_ENTRY);
+    }
+
+    pub(crate) fn remove(&self, value: usize) -> bool {
+        let index = self.index(value);
+        let entry = &mut self.bits()[index / Self::BITS_PER_ENTRY];
+        let mask = 1 << (index % Self::BITS_PER_ENTRY);
+        let was_used = *entry & mask != 0;
+        *entry &= !mask;
+        was_used
+    }
+
+    pub(crate) fn drain<F: FnMut(usize)>(&self, mut callback: F) {
+        for (n, entry) in self.bits().iter_mut().enumerate() {
+            while *entry != 0 {
+                let bit = entry.trailing_zeros() as usize;
+                *entry &= !(1 << bit);
+                callback(
+                    (n * Self::BITS_PER_ENTRY + bit) * self.sample_size + self.start_offset.get(),
+                );
+            }
+        }
+    }
+}
+
 pub trait ZeroCopyConnection: Sized + NamedConceptMgmt {
     type Sender: ZeroCopySender;
     type Receiver: ZeroCopyReceiver;
@@ -10,23 +10,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::cell::UnsafeCell;
-use std::fmt::Debug;
-use std::ptr::NonNull;
-use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use crate::named_concept::*;
+use crate::zero_copy_connection::common::{self, SharedChannelMemory, SharedManagementData, State};
 pub use crate::zero_copy_connection::*;
-use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
-use iceoryx2_bb_lock_free::spsc::{
-    index_queue::RelocatableIndexQueue,
-    safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
-};
-use iceoryx2_bb_log::{error, fail, fatal_panic};
-use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::access_control_list::AclPermission;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
 use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_posix::shared_memory::{SharedMemory, SharedMemoryBuilder};
 
@@ -38,6 +32,7 @@ pub struct Configuration {
     suffix: FileName,
     prefix: FileName,
     path_hint: Path,
+    access_rights: AccessRights,
 }
 
 impl Default for Configuration {
@@ -46,6 +41,7 @@ impl Default for Configuration {
             suffix: DEFAULT_SUFFIX,
             prefix: DEFAULT_PREFIX,
             path_hint: DEFAULT_PATH_HINT,
+            access_rights: AccessRights::default(),
         }
     }
 }
@@ -77,102 +73,20 @@ impl NamedConceptConfiguration for Configuration {
     fn get_path_hint(&self) -> &Path {
         &self.path_hint
     }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
-enum State {
-    None = 0b00000000,
-    Sender = 0b00000001,
-    Receiver = 0b00000010,
-    MarkedForDestruction = 0b10000000,
-}
-
-impl State {
-    fn value(&self) -> u8 {
-        *self as u8
-    }
-}
 
-fn cleanup_shared_memory<T: Debug>(
-    origin: &T,
-    shared_memory: &SharedMemory,
-    state_to_remove: State,
-) {
-    let mgmt_ref =
-        unsafe { &*(shared_memory.base_address().as_ptr() as *const SharedManagementData) };
-
-    let mut current_state = mgmt_ref.state.load(Ordering::Relaxed);
-    loop {
-        let new_state = if current_state == state_to_remove.value() {
-            State::MarkedForDestruction.value()
-        } else {
-            current_state & !state_to_remove.value()
-        };
-
-        match mgmt_ref.state.compare_exchange(
-            current_state,
-            new_state,
-            Ordering::Relaxed,
-            Ordering::Relaxed,
-        ) {
-            Ok(_) => {
-                current_state = new_state;
-                break;
-            }
-            Err(s) => {
-                current_state = s;
-            }
-        }
-    }
-
-    if current_state == State::MarkedForDestruction.value()
-        && SharedMemory::remove(shared_memory.name()).is_err()
-    {
-        error!(from origin, "Failed to remove shared memory when cleaning up the connection.");
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
     }
 }
 
-#[repr(C)]
-struct SharedManagementData {
-    receive_channel: RelocatableSafelyOverflowingIndexQueue,
-    retrieve_channel: RelocatableIndexQueue,
-    max_borrowed_samples: usize,
-    state: AtomicU8,
-    init_state: AtomicU64,
-    enable_safe_overflow: bool,
-}
-
-impl SharedManagementData {
-    fn new(
-        receive_channel_buffer_size: usize,
-        retrieve_channel_buffer_size: usize,
-        enable_safe_overflow: bool,
-        max_borrowed_samples: usize,
-    ) -> Self {
-        Self {
-            receive_channel: unsafe {
-                RelocatableSafelyOverflowingIndexQueue::new_uninit(receive_channel_buffer_size)
-            },
-            retrieve_channel: unsafe {
-                RelocatableIndexQueue::new_uninit(retrieve_channel_buffer_size)
-            },
-            state: AtomicU8::new(State::None.value()),
-            init_state: AtomicU64::new(0),
-            enable_safe_overflow,
-            max_borrowed_samples,
-        }
+impl SharedChannelMemory for SharedMemory {
+    fn base_address(&self) -> usize {
+        SharedMemory::base_address(self).as_ptr() as usize
     }
 
-    const fn const_memory_size(
-        receive_channel_buffer_size: usize,
-        retrieve_channel_buffer_size: usize,
-    ) -> usize {
-        // we do not have to consider the alignment of Self since posix shared memory is always
-        // page size aligned
-        std::mem::size_of::<Self>()
-            + RelocatableIndexQueue::const_memory_size(retrieve_channel_buffer_size)
-            + RelocatableSafelyOverflowingIndexQueue::const_memory_size(receive_channel_buffer_size)
+    fn remove(&self) -> bool {
+        SharedMemory::remove(self.name()).is_ok()
     }
 }
 
@@ -182,6 +96,8 @@ pub struct Builder {
     buffer_size: usize,
     enable_safe_overflow: bool,
     max_borrowed_samples: usize,
+    number_of_samples: usize,
+    sample_size: usize,
     config: Configuration,
 }
 
@@ -215,28 +131,29 @@ impl Builder {
         match shm.has_ownership() {
             true => {
                 let msg = "Failed to set up newly created connection";
-                unsafe {
-                    mgmt_ptr.write(SharedManagementData::new(
+                if !self.config.access_rights.is_empty() {
+                    let acl = fail!(from self, when self.config.access_rights.access_control_list(AclPermission::ReadWriteExecute),
+                        with ZeroCopyCreationError::InternalError,
+                        "{} since the access control list could not be created.", msg);
+                    fail!(from self, when shm.set_access_control_list(&acl),
+                        with ZeroCopyCreationError::InternalError,
+                        "{} since the access control list could not be applied.", msg);
+                }
+
+                // posix shared memory is always page size aligned
+                if !unsafe {
+                    SharedManagementData::init(
+                        mgmt_ptr,
+                        shm_size,
                         self.receive_channel_size(),
                         self.retrieve_channel_size(),
                         self.enable_safe_overflow,
                         self.max_borrowed_samples,
-                    ))
-                };
-
-                let supplementary_ptr =
-                    (mgmt_ptr as usize + std::mem::size_of::<SharedManagementData>()) as *mut u8;
-                let supplementary_len = shm_size - std::mem::size_of::<SharedManagementData>();
-
-                let allocator = BumpAllocator::new(
-                    unsafe { NonNull::new_unchecked(supplementary_ptr) },
-                    supplementary_len,
-                );
-
-                fatal_panic!(from self, when unsafe { (*mgmt_ptr).receive_channel.init(&allocator) },
-                            "{} since the receive channel allocation failed. - This is an implementation bug!", msg);
-                fatal_panic!(from self, when unsafe { (*mgmt_ptr).retrieve_channel.init(&allocator) },
-                            "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
+                    )
+                } {
+                    fatal_panic!(from self,
+                        "{} since the channel allocation failed. - This is an implementation bug!", msg);
+                }
 
                 unsafe {
                     (*mgmt_ptr)
@@ -251,7 +168,7 @@ impl Builder {
                 let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                             with ZeroCopyCreationError::InternalError, "{} since the adaptive wait could not be created.", msg);
 
-                let mgmt_ref = unsafe { &mut *mgmt_ptr };
+                let mgmt_ref = unsafe { &*mgmt_ptr };
                 while mgmt_ref.init_state.load(Ordering::Relaxed) != IS_INITIALIZED_STATE_VALUE {
                     if fail!(from self, when adaptive_wait.wait(), with ZeroCopyCreationError::InternalError,
                             "{} since a failure while waiting for creation finalization occurred.", msg)
@@ -261,60 +178,18 @@ impl Builder {
                     }
                 }
 
-                if mgmt_ref.receive_channel.capacity() != self.receive_channel_size() {
-                    fail!(from self, with ZeroCopyCreationError::IncompatibleBufferSize,
-                        "{} since the connection has a buffer size of {} but a buffer size of {} is required.",
-                        msg, mgmt_ref.receive_channel.capacity(), self.receive_channel_size());
-                }
-
-                if mgmt_ref.retrieve_channel.capacity() != self.retrieve_channel_size() {
-                    fail!(from self, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
-                        "{} since the max borrowed sample setting is set to {} but a value of {} is required.",
-                        msg, mgmt_ref.retrieve_channel.capacity() - mgmt_ref.receive_channel.capacity(), self.max_borrowed_samples);
-                }
-
-                if mgmt_ref.enable_safe_overflow != self.enable_safe_overflow {
-                    fail!(from self, with ZeroCopyCreationError::IncompatibleOverflowSetting,
-                        "{} since the safe overflow is set to {} but should be set to {}.",
-                        msg, mgmt_ref.enable_safe_overflow, self.enable_safe_overflow);
-                }
+                mgmt_ref.verify_settings(
+                    self,
+                    self.receive_channel_size(),
+                    self.retrieve_channel_size(),
+                    self.enable_safe_overflow,
+                    self.max_borrowed_samples,
+                )?;
             }
         }
 
         Ok(shm)
     }
-
-    fn reserve_port(
-        &self,
-        mgmt_ref: &mut SharedManagementData,
-        new_state: u8,
-        msg: &str,
-    ) -> Result<(), ZeroCopyCreationError> {
-        let mut current_state = State::None.value();
-
-        loop {
-            match mgmt_ref.state.compare_exchange(
-                current_state,
-                current_state | new_state,
-                Ordering::Relaxed,
-                Ordering::Relaxed,
-            ) {
-                Ok(_) => break,
-                Err(v) => {
-                    current_state = v;
-                    if current_state & new_state != 0 {
-                        fail!(from self, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
-                            "{} since an instance is already connected.", msg);
-                    } else if current_state & State::MarkedForDestruction.value() != 0 {
-                        fail!(from self, with ZeroCopyCreationError::InternalError,
-                            "{} since the connection is currently being cleaned up.", msg);
-                    }
-                }
-            }
-        }
-
-        Ok(())
-    }
 }
 
 impl NamedConceptBuilder<Connection> for Builder {
@@ -324,6 +199,8 @@ impl NamedConceptBuilder<Connection> for Builder {
             buffer_size: DEFAULT_BUFFER_SIZE,
             enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
             max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            number_of_samples: DEFAULT_NUMBER_OF_SAMPLES,
+            sample_size: DEFAULT_SAMPLE_SIZE,
             config: Configuration::default(),
         }
     }
@@ -350,18 +227,29 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         self
     }
 
+    fn number_of_samples(mut self, value: usize) -> Self {
+        self.number_of_samples = value;
+        self
+    }
+
+    fn sample_size(mut self, value: usize) -> Self {
+        self.sample_size = value;
+        self
+    }
+
     fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
         let msg = "Unable to create sender";
         let shm = fail!(from self, when self.create_or_open_shm(),
             "{} since the corresponding connection could not be created or opened", msg);
 
-        let mgmt_ref = unsafe { &mut *(shm.base_address().as_ptr() as *mut SharedManagementData) };
-        self.reserve_port(mgmt_ref, State::Sender.value(), msg)?;
+        let mgmt_ref = unsafe { &*(shm.base_address().as_ptr() as *const SharedManagementData) };
+        mgmt_ref.reserve_port(&self, State::Sender, msg)?;
 
-        Ok(Sender {
-            shared_memory: shm,
-            name: self.name,
-        })
+        Ok(Sender::new(
+            shm,
+            UsedChunkList::new(self.number_of_samples, self.sample_size),
+            self.name,
+        ))
     }
 
     fn create_receiver(
@@ -371,188 +259,16 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         let shm = fail!(from self, when self.create_or_open_shm(),
             "{} since the corresponding connection could not be created or opened", msg);
 
-        let mgmt_ref = unsafe { &mut *(shm.base_address().as_ptr() as *mut SharedManagementData) };
-        self.reserve_port(mgmt_ref, State::Receiver.value(), msg)?;
-
-        Ok(Receiver {
-            shared_memory: shm,
-            borrow_counter: UnsafeCell::new(0),
-            name: self.name,
-        })
-    }
-}
-
-#[derive(Debug)]
-pub struct Sender {
-    shared_memory: SharedMemory,
-    name: FileName,
-}
-
-impl Drop for Sender {
-    fn drop(&mut self) {
-        cleanup_shared_memory(self, &self.shared_memory, State::Sender);
-    }
-}
-
-impl Sender {
-    fn mgmt(&self) -> &SharedManagementData {
-        unsafe { &*(self.shared_memory.base_address().as_ptr() as *const SharedManagementData) }
-    }
-}
-
-impl NamedConcept for Sender {
-    fn name(&self) -> &FileName {
-        &self.name
-    }
-}
-
-impl ZeroCopyPortDetails for Sender {
-    fn buffer_size(&self) -> usize {
-        self.mgmt().receive_channel.capacity()
-    }
-
-    fn max_borrowed_samples(&self) -> usize {
-        self.mgmt().max_borrowed_samples
-    }
-
-    fn has_enabled_safe_overflow(&self) -> bool {
-        self.mgmt().enable_safe_overflow
-    }
-
-    fn is_connected(&self) -> bool {
-        self.mgmt().state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
-    }
-}
-
-impl ZeroCopySender for Sender {
-    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
-        let msg = "Unable to send sample";
-        let space_in_retrieve_channel =
-            self.mgmt().retrieve_channel.capacity() - self.mgmt().retrieve_channel.len();
-
-        if space_in_retrieve_channel
-            <= self.mgmt().max_borrowed_samples + self.mgmt().receive_channel.len()
-        {
-            fail!(from self, with ZeroCopySendError::ClearRetrieveChannelBeforeSend,
-                "{} since sufficient space for every sample in the retrieve channel cannot be guaranteed. Samples have to be retrieved before a new sample can be send.", msg);
-        }
-
-        if !self.mgmt().enable_safe_overflow && self.mgmt().receive_channel.is_full() {
-            fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
-                             "{} since the receive buffer is full.", msg);
-        }
-
-        match unsafe { self.mgmt().receive_channel.push(ptr.value()) } {
-            Some(v) => Ok(Some(PointerOffset::new(v))),
-            None => Ok(None),
-        }
-    }
-
-    fn blocking_send(
-        &self,
-        ptr: PointerOffset,
-    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
-        if !self.mgmt().enable_safe_overflow {
-            AdaptiveWaitBuilder::new()
-                .create()
-                .unwrap()
-                .wait_while(|| self.mgmt().receive_channel.is_full())
-                .unwrap();
-        }
+        let mgmt_ref = unsafe { &*(shm.base_address().as_ptr() as *const SharedManagementData) };
+        mgmt_ref.reserve_port(&self, State::Receiver, msg)?;
 
-        self.try_send(ptr)
-    }
-
-    fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
-        match unsafe { self.mgmt().retrieve_channel.pop() } {
-            None => Ok(None),
-            Some(v) => Ok(Some(PointerOffset::new(v))),
-        }
+        Ok(Receiver::new(shm, self.name))
     }
 }
 
-#[derive(Debug)]
-pub struct Receiver {
-    shared_memory: SharedMemory,
-    borrow_counter: UnsafeCell<usize>,
-    name: FileName,
-}
-
-impl Drop for Receiver {
-    fn drop(&mut self) {
-        cleanup_shared_memory(self, &self.shared_memory, State::Receiver);
-    }
-}
-
-impl Receiver {
-    fn mgmt(&self) -> &SharedManagementData {
-        unsafe { &*(self.shared_memory.base_address().as_ptr() as *const SharedManagementData) }
-    }
+pub type Sender = common::Sender<SharedMemory>;
 
-    #[allow(clippy::mut_from_ref)]
-    // convenience to access internal mutable object
-    fn borrow_counter(&self) -> &mut usize {
-        #[deny(clippy::mut_from_ref)]
-        unsafe {
-            &mut *self.borrow_counter.get()
-        }
-    }
-}
-
-impl NamedConcept for Receiver {
-    fn name(&self) -> &FileName {
-        &self.name
-    }
-}
-
-impl ZeroCopyPortDetails for Receiver {
-    fn buffer_size(&self) -> usize {
-        self.mgmt().receive_channel.capacity()
-    }
-
-    fn max_borrowed_samples(&self) -> usize {
-        self.mgmt().max_borrowed_samples
-    }
-
-    fn has_enabled_safe_overflow(&self) -> bool {
-        self.mgmt().enable_safe_overflow
-    }
-
-    fn is_connected(&self) -> bool {
-        self.mgmt().state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
-    }
-}
-
-impl ZeroCopyReceiver for Receiver {
-    fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
-        if *self.borrow_counter() >= self.mgmt().max_borrowed_samples {
-            fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
-                "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
-                    self.borrow_counter(), self.max_borrowed_samples());
-        }
-
-        match unsafe { self.mgmt().receive_channel.pop() } {
-            None => Ok(None),
-            Some(v) => {
-                *self.borrow_counter() += 1;
-                Ok(Some(PointerOffset::new(v)))
-            }
-        }
-    }
-
-    fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
-        match unsafe { self.mgmt().retrieve_channel.push(ptr.value()) } {
-            true => {
-                *self.borrow_counter() -= 1;
-                Ok(())
-            }
-            false => {
-                fail!(from self, with ZeroCopyReleaseError::RetrieveBufferFull,
-                    "Unable to release pointer since the retrieve buffer is full.");
-            }
-        }
-    }
-}
+pub type Receiver = common::Receiver<SharedMemory>;
 
 pub struct Connection {}
 
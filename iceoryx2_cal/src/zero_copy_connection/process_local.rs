@@ -113,6 +113,8 @@ pub struct Builder {
     buffer_size: usize,
     enable_safe_overflow: bool,
     max_borrowed_samples: usize,
+    number_of_samples: usize,
+    sample_size: usize,
     config: Configuration,
 }
 
@@ -152,6 +154,8 @@ impl NamedConceptBuilder<Connection> for Builder {
             buffer_size: DEFAULT_BUFFER_SIZE,
             enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
             max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            number_of_samples: DEFAULT_NUMBER_OF_SAMPLES,
+            sample_size: DEFAULT_SAMPLE_SIZE,
             config: Configuration::default(),
         }
     }
@@ -178,6 +182,16 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         self
     }
 
+    fn number_of_samples(mut self, value: usize) -> Self {
+        self.number_of_samples = value;
+        self
+    }
+
+    fn sample_size(mut self, value: usize) -> Self {
+        self.sample_size = value;
+        self
+    }
+
     fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
         let msg = "Unable to create sender";
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
@@ -201,6 +215,7 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 
                 Ok(Sender {
                     mgmt: entry.clone(),
+                    used_chunk_list: UsedChunkList::new(self.number_of_samples, self.sample_size),
                     config: self.config,
                 })
             }
@@ -217,6 +232,7 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 
                 Ok(Sender {
                     mgmt: entry,
+                    used_chunk_list: UsedChunkList::new(self.number_of_samples, self.sample_size),
                     config: self.config,
                 })
             }
@@ -274,6 +290,7 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 #[derive(Debug)]
 pub struct Sender {
     mgmt: Arc<Management>,
+    used_chunk_list: UsedChunkList,
     config: Configuration,
 }
 
@@ -326,8 +343,12 @@ impl ZeroCopySender for Sender {
                         "{} since the receive buffer is full.", msg);
         }
 
+        self.used_chunk_list.insert(ptr.value());
         match unsafe { self.mgmt.receive_channel.push(ptr.value()) } {
-            Some(v) => Ok(Some(PointerOffset::new(v))),
+            Some(v) => {
+                self.used_chunk_list.remove(v);
+                Ok(Some(PointerOffset::new(v)))
+            }
             None => Ok(None),
         }
     }
@@ -352,9 +373,19 @@ impl ZeroCopySender for Sender {
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
         match unsafe { self.mgmt.retrieve_channel.pop() } {
             None => Ok(None),
-            Some(v) => Ok(Some(PointerOffset::new(v))),
+            Some(v) => {
+                self.used_chunk_list.remove(v);
+                Ok(Some(PointerOffset::new(v)))
+            }
         }
     }
+
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+        // the returned samples are tracked in the used chunk list as well
+        while self.mgmt.retrieve_channel.pop().is_some() {}
+        self.used_chunk_list
+            .drain(|offset| callback(PointerOffset::new(offset)));
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,403 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`ZeroCopyConnection`] whose management data is stored as file inside a
+//! [`crate::shared_memory_directory::SharedMemoryDirectory`]. The connections are distributed
+//! by the hash of their name over [`Configuration::number_of_directories`] directories whose
+//! names are derived from the prefix and the [`Configuration::directory_name`]. A directory can
+//! hold at most 512 connections. The first process that creates a directory defines the size of
+//! its first data segment with [`Configuration::directory_size`], the directory grows when the
+//! memory is exhausted. A directory reserves its data segments as long as it contains a
+//! connection or is used by a process, with the defaults at least 256 KiB per directory.
+
+use std::alloc::Layout;
+use std::time::Duration;
+
+use crate::named_concept::*;
+use crate::shared_memory_directory::file::File;
+use crate::shared_memory_directory::registry::{DirectoryHandle, DirectorySet};
+use crate::shared_memory_directory::{
+    SharedMemoryDirectoryCreateFileError, DEFAULT_NUMBER_OF_DIRECTORIES,
+};
+use crate::zero_copy_connection::common::{self, SharedChannelMemory, SharedManagementData, State};
+pub use crate::zero_copy_connection::*;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+
+const MAX_CREATION_DURATION: Duration = Duration::from_millis(10);
+
+/// The name of the directory that is used when no other name is configured.
+pub const DEFAULT_DIRECTORY_NAME: FileName =
+    unsafe { FileName::new_unchecked(b"zero_copy_connection_directory") };
+
+/// The size of the first data segment of a directory that is used when no other size is
+/// configured.
+pub const DEFAULT_DIRECTORY_SIZE: usize = 256 * 1024;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Configuration {
+    /// The name of the directory in which the connection is stored.
+    pub directory_name: FileName,
+    /// The size of the first data segment of the directory when it does not exist and has to
+    /// be created.
+    pub directory_size: usize,
+    /// The number of directories that share the connection objects, at most 512 connection
+    /// objects fit into one directory.
+    pub number_of_directories: usize,
+    suffix: FileName,
+    prefix: FileName,
+    path_hint: Path,
+    access_rights: AccessRights,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            directory_name: DEFAULT_DIRECTORY_NAME,
+            directory_size: DEFAULT_DIRECTORY_SIZE,
+            number_of_directories: DEFAULT_NUMBER_OF_DIRECTORIES,
+            suffix: DEFAULT_SUFFIX,
+            prefix: DEFAULT_PREFIX,
+            path_hint: DEFAULT_PATH_HINT,
+            access_rights: AccessRights::default(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path_hint = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path_hint
+    }
+
+    fn access_rights(mut self, value: AccessRights) -> Self {
+        self.access_rights = value;
+        self
+    }
+
+    fn does_support_access_rights() -> bool {
+        false
+    }
+}
+
+impl Configuration {
+    fn file_name(&self, name: &FileName) -> FileName {
+        unsafe { FileName::new_unchecked(self.path_for(name).file_name()) }
+    }
+
+    fn directories(&self) -> DirectorySet {
+        DirectorySet::new(
+            &self.prefix,
+            &self.directory_name,
+            self.directory_size,
+            self.number_of_directories,
+        )
+    }
+}
+
+/// The file inside the [`crate::shared_memory_directory::SharedMemoryDirectory`] in which the
+/// management data of a connection is stored.
+#[derive(Debug)]
+pub struct ConnectionFile {
+    // declared before the directory so that it is dropped first
+    file: File<'static>,
+    directory: DirectoryHandle,
+}
+
+impl SharedChannelMemory for ConnectionFile {
+    fn base_address(&self) -> usize {
+        self.file.content().as_ptr() as usize
+    }
+
+    fn remove(&self) -> bool {
+        self.directory.remove_file(&self.file.name())
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    buffer_size: usize,
+    enable_safe_overflow: bool,
+    max_borrowed_samples: usize,
+    number_of_samples: usize,
+    sample_size: usize,
+    config: Configuration,
+}
+
+impl Builder {
+    fn receive_channel_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    fn retrieve_channel_size(&self) -> usize {
+        self.buffer_size + self.max_borrowed_samples + 1
+    }
+
+    fn create_or_open_file(&self) -> Result<ConnectionFile, ZeroCopyCreationError> {
+        let msg = "Failed to acquire underlying file";
+        let full_name = self.config.file_name(&self.name);
+
+        if !self.config.access_rights.is_empty() {
+            fail!(from self, with ZeroCopyCreationError::InternalError,
+                "{} since access rights cannot be applied to a single connection of the directory \"{}\".",
+                msg, self.config.directory_name);
+        }
+
+        let directory = fail!(from self, when self.config.directories().acquire(&full_name),
+                                with ZeroCopyCreationError::InternalError,
+                                "{} since the directory \"{}\" is not available.", msg, self.config.directory_name);
+        let layout = unsafe {
+            Layout::from_size_align_unchecked(
+                SharedManagementData::const_memory_size(
+                    self.receive_channel_size(),
+                    self.retrieve_channel_size(),
+                ),
+                std::mem::align_of::<SharedManagementData>(),
+            )
+        };
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                    with ZeroCopyCreationError::InternalError,
+                                    "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            if let Some(file) = unsafe { directory.open_file(&full_name) } {
+                let file = ConnectionFile { file, directory };
+                unsafe { &*(file.base_address() as *const SharedManagementData) }.verify_settings(
+                    self,
+                    self.receive_channel_size(),
+                    self.retrieve_channel_size(),
+                    self.enable_safe_overflow,
+                    self.max_borrowed_samples,
+                )?;
+                return Ok(file);
+            }
+
+            let file_creator = fail!(from self, when unsafe { directory.new_file(layout) },
+                                    with ZeroCopyCreationError::InternalError,
+                                    "{} since the memory could not be allocated in the directory \"{}\".",
+                                    msg, self.config.directory_name);
+
+            match file_creator.is_persistent(true).create_and_initialize(
+                &full_name,
+                |content| unsafe {
+                    // the file is aligned to the alignment of SharedManagementData
+                    SharedManagementData::init(
+                        content.as_mut_ptr() as *mut SharedManagementData,
+                        content.len(),
+                        self.receive_channel_size(),
+                        self.retrieve_channel_size(),
+                        self.enable_safe_overflow,
+                        self.max_borrowed_samples,
+                    )
+                },
+            ) {
+                Ok(file) => return Ok(ConnectionFile { file, directory }),
+                Err(SharedMemoryDirectoryCreateFileError::DoesExist)
+                | Err(SharedMemoryDirectoryCreateFileError::BeingCreated) => {
+                    // another instance created the connection concurrently, open it
+                    if fail!(from self, when adaptive_wait.wait(), with ZeroCopyCreationError::InternalError,
+                            "{} since a failure while waiting for creation finalization occurred.", msg)
+                        >= MAX_CREATION_DURATION
+                    {
+                        fail!(from self, with ZeroCopyCreationError::InternalError,
+                            "{} since the connection is still being created after {:?}.", msg, MAX_CREATION_DURATION);
+                    }
+                }
+                Err(SharedMemoryDirectoryCreateFileError::InitializationFailed) => {
+                    fail!(from self, with ZeroCopyCreationError::InternalError,
+                        "{} since the channel allocation failed. - This is an implementation bug!", msg);
+                }
+                Err(SharedMemoryDirectoryCreateFileError::FileLimitExceeded) => {
+                    fail!(from self, with ZeroCopyCreationError::InternalError,
+                        "{} since the directory \"{}\" cannot hold any more files.", msg, self.config.directory_name);
+                }
+            }
+        }
+    }
+}
+
+impl NamedConceptBuilder<Connection> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
+            max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            number_of_samples: DEFAULT_NUMBER_OF_SAMPLES,
+            sample_size: DEFAULT_SAMPLE_SIZE,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl ZeroCopyConnectionBuilder<Connection> for Builder {
+    fn buffer_size(mut self, value: usize) -> Self {
+        self.buffer_size = value;
+        self
+    }
+
+    fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.enable_safe_overflow = value;
+        self
+    }
+
+    fn receiver_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.max_borrowed_samples = value;
+        self
+    }
+
+    fn number_of_samples(mut self, value: usize) -> Self {
+        self.number_of_samples = value;
+        self
+    }
+
+    fn sample_size(mut self, value: usize) -> Self {
+        self.sample_size = value;
+        self
+    }
+
+    fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
+        let msg = "Unable to create sender";
+        let file = fail!(from self, when self.create_or_open_file(),
+            "{} since the corresponding connection could not be created or opened", msg);
+
+        let mgmt_ref = unsafe { &*(file.base_address() as *const SharedManagementData) };
+        mgmt_ref.reserve_port(&self, State::Sender, msg)?;
+
+        Ok(Sender::new(
+            file,
+            UsedChunkList::new(self.number_of_samples, self.sample_size),
+            self.name,
+        ))
+    }
+
+    fn create_receiver(
+        self,
+    ) -> Result<<Connection as ZeroCopyConnection>::Receiver, ZeroCopyCreationError> {
+        let msg = "Unable to create receiver";
+        let file = fail!(from self, when self.create_or_open_file(),
+            "{} since the corresponding connection could not be created or opened", msg);
+
+        let mgmt_ref = unsafe { &*(file.base_address() as *const SharedManagementData) };
+        mgmt_ref.reserve_port(&self, State::Receiver, msg)?;
+
+        Ok(Receiver::new(file, self.name))
+    }
+}
+
+pub type Sender = common::Sender<ConnectionFile>;
+
+pub type Receiver = common::Receiver<ConnectionFile>;
+
+pub struct Connection {}
+
+impl NamedConceptMgmt for Connection {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = "Unable to check if zero_copy_connection::shared_memory_directory exists";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::does_exist_cfg()";
+
+        let file_name = cfg.file_name(name);
+        match cfg.directories().acquire_existing(&file_name) {
+            Ok(Some(directory)) => Ok(directory.does_file_exist(&file_name)),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                fail!(from origin, with NamedConceptDoesExistError::InternalError,
+                    "{} since the directory \"{}\" is not available ({:?}).", msg, cfg.directory_name, e);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all zero_copy_connection::shared_memory_directory";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::list_cfg()";
+
+        let directories = match config.directories().list_existing() {
+            Ok(directories) => directories,
+            Err(e) => {
+                fail!(from origin, with NamedConceptListError::InternalError,
+                    "{} since the directories \"{}\" are not available ({:?}).", msg, config.directory_name, e);
+            }
+        };
+
+        Ok(directories
+            .iter()
+            .flat_map(|directory| directory.list_files())
+            .filter_map(|file| config.extract_name_from_file(&file.name()))
+            .collect())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove zero_copy_connection::shared_memory_directory";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::remove_cfg()";
+
+        let file_name = cfg.file_name(name);
+        match cfg.directories().acquire_existing(&file_name) {
+            Ok(Some(directory)) => Ok(directory.remove_file(&file_name)),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} \"{}\" since the directory \"{}\" is not available ({:?}).", msg, name, cfg.directory_name, e);
+            }
+        }
+    }
+}
+
+impl ZeroCopyConnection for Connection {
+    type Sender = Sender;
+    type Builder = Builder;
+    type Receiver = Receiver;
+
+    fn does_support_safe_overflow() -> bool {
+        true
+    }
+
+    fn has_configurable_buffer_size() -> bool {
+        true
+    }
+}
@@ -15,7 +15,9 @@ mod dynamic_storage {
     use iceoryx2_bb_container::semantic_string::*;
     use iceoryx2_bb_elementary::allocator::*;
     use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_posix::access_control_list::AclPermission;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::dynamic_storage::*;
@@ -442,9 +444,31 @@ mod dynamic_storage {
         assert_that!(*config.get_path_hint(), eq DEFAULT_PATH_HINT);
     }
 
+    #[test]
+    fn create_with_access_rights_fails_when_not_supported<Sut: DynamicStorage<TestData>>() {
+        if <Sut as NamedConceptMgmt>::Configuration::does_support_access_rights() {
+            return;
+        }
+
+        let storage_name = generate_name();
+        let mut access_rights = AccessRights::default();
+        access_rights.add_user(User::from_self().unwrap().uid(), AclPermission::ReadWrite);
+        let config =
+            <Sut as NamedConceptMgmt>::Configuration::default().access_rights(access_rights);
+
+        let sut = Sut::Builder::new(&storage_name)
+            .config(&config)
+            .create(TestData::new(123));
+        assert_that!(sut, is_err);
+        assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(false));
+    }
+
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<TestData>>)]
     mod posix_shared_memory {}
 
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::dynamic_storage::shared_memory_directory::Storage<TestData>>)]
+    mod shared_memory_directory {}
 }
@@ -394,3 +394,61 @@ mod event {
     #[instantiate_tests(<iceoryx2_cal::event::process_local::Event<u64>>)]
     mod process_local {}
 }
+
+#[cfg(any(target_os = "linux", target_os = "freebsd"))]
+mod event_unix_datagram_socket {
+    use std::os::unix::fs::PermissionsExt;
+
+    use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::access_control_list::AclPermission;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::unix_datagram_socket::Configuration;
+    use iceoryx2_cal::event::{Event, Listener, ListenerBuilder, Notifier, NotifierBuilder};
+    use iceoryx2_cal::named_concept::*;
+
+    type Sut = iceoryx2_cal::event::unix_datagram_socket::Event<u64>;
+
+    fn generate_name() -> FileName {
+        let mut file = FileName::new(b"event_tests_").unwrap();
+        file.push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+        file
+    }
+
+    #[test]
+    fn listener_with_access_rights_denies_notifiers_that_are_not_granted_access() {
+        let name = generate_name();
+        let mut access_rights = AccessRights::default();
+        access_rights.add_user(User::from_self().unwrap().uid(), AclPermission::Write);
+        let config = Configuration::default().access_rights(access_rights);
+
+        let sut_listener = <Sut as Event<u64>>::ListenerBuilder::new(&name)
+            .config(&config)
+            .create()
+            .unwrap();
+
+        let mode = std::fs::metadata(config.path_for(&name).to_string())
+            .unwrap()
+            .permissions()
+            .mode();
+        // the group permission bits contain the maximum access rights of all granted users
+        assert_that!(mode & 0o070, eq 0o020);
+        assert_that!(mode & 0o007, eq 0);
+
+        let sut_notifier = <Sut as Event<u64>>::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+            .unwrap();
+        assert_that!(sut_notifier.notify(1), is_ok);
+        assert_that!(sut_listener.try_wait().unwrap(), eq Some(1));
+    }
+}
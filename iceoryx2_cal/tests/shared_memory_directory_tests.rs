@@ -385,6 +385,104 @@ mod shared_memory_directory {
         }
     }
 
+    #[test]
+    fn directory_grows_when_memory_is_exhausted() {
+        const NUMBER_OF_FILES: usize = 40;
+        const INITIAL_SIZE: usize = 8 * 1024;
+        let name = generate_name();
+
+        let sut = SharedMemoryDirectoryCreator::new(&name)
+            .size(INITIAL_SIZE)
+            .create::<MgmtShm, Allocator, DataShm>(
+                &<Allocator as ShmAllocator>::Configuration::default(),
+            )
+            .unwrap();
+
+        let mut files = vec![];
+        for i in 0..NUMBER_OF_FILES {
+            let file_name = generate_name();
+            let file = sut
+                .new_file(Layout::new::<u64>())
+                .unwrap()
+                .is_persistent(true)
+                .create(&file_name, |data_ptr| data_ptr[0] = i as u8);
+            assert_that!(file, is_ok);
+            files.push(file_name);
+        }
+
+        assert_that!(sut.size(), gt INITIAL_SIZE);
+        assert_that!(sut.memory_capacity(), gt sut.size());
+
+        let sut_2 = SharedMemoryDirectoryCreator::new(&name)
+            .open::<MgmtShm, Allocator, DataShm>()
+            .unwrap();
+
+        for (i, file_name) in files.iter().enumerate() {
+            let file = sut_2.open_file(file_name);
+            assert_that!(file, is_some);
+            assert_that!(file.unwrap().content()[0], eq i as u8);
+        }
+
+        for file_name in files {
+            assert_that!(sut_2.remove_file(&file_name), eq true);
+        }
+    }
+
+    #[test]
+    fn directory_is_removed_by_last_user_when_it_is_empty() {
+        let name = generate_name();
+
+        let sut = SharedMemoryDirectoryCreator::new(&name)
+            .size(1024 * 1024)
+            .is_persistent(true)
+            .create::<MgmtShm, Allocator, DataShm>(
+                &<Allocator as ShmAllocator>::Configuration::default(),
+            )
+            .unwrap();
+        let sut_2 = SharedMemoryDirectoryCreator::new(&name)
+            .open::<MgmtShm, Allocator, DataShm>()
+            .unwrap();
+
+        assert_that!(sut.register_user(), eq true);
+        assert_that!(sut_2.register_user(), eq true);
+
+        sut.deregister_user();
+        assert_that!(SharedMemoryDirectory::<MgmtShm, Allocator, DataShm>::does_exist(&name), eq Ok(true));
+
+        sut_2.deregister_user();
+        assert_that!(SharedMemoryDirectory::<MgmtShm, Allocator, DataShm>::does_exist(&name), eq Ok(false));
+        assert_that!(sut.register_user(), eq false);
+    }
+
+    #[test]
+    fn directory_with_files_is_not_removed_by_last_user() {
+        let name = generate_name();
+
+        let sut = SharedMemoryDirectoryCreator::new(&name)
+            .size(1024 * 1024)
+            .is_persistent(true)
+            .create::<MgmtShm, Allocator, DataShm>(
+                &<Allocator as ShmAllocator>::Configuration::default(),
+            )
+            .unwrap();
+
+        let file_name = generate_name();
+        sut.new_file(Layout::new::<u8>())
+            .unwrap()
+            .is_persistent(true)
+            .create(&file_name, |_| {})
+            .unwrap();
+
+        assert_that!(sut.register_user(), eq true);
+        sut.deregister_user();
+        assert_that!(SharedMemoryDirectory::<MgmtShm, Allocator, DataShm>::does_exist(&name), eq Ok(true));
+
+        assert_that!(sut.register_user(), eq true);
+        assert_that!(sut.remove_file(&file_name), eq true);
+        sut.deregister_user();
+        assert_that!(SharedMemoryDirectory::<MgmtShm, Allocator, DataShm>::does_exist(&name), eq Ok(false));
+    }
+
     #[test]
     fn remove_makes_file_available_even_when_opened() {
         let name = generate_name();
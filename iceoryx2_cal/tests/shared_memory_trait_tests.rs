@@ -16,7 +16,9 @@ mod shared_memory {
 
     use iceoryx2_bb_container::semantic_string::*;
     use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_posix::access_control_list::AclPermission;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_cal::named_concept::*;
@@ -199,6 +201,25 @@ mod shared_memory {
         assert_that!(chunk, is_ok);
     }
 
+    #[test]
+    fn read_only_client_sees_data_of_creator<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { chunk.data_ptr.write(123) };
+
+        let sut_open = Sut::Builder::new(&name).open_read_only().unwrap();
+        assert_that!(sut_open.size(), ge DEFAULT_SIZE);
+
+        let data_ptr =
+            (sut_open.allocator_data_start_address() + chunk.offset.value()) as *const u8;
+        assert_that!(unsafe { *data_ptr }, eq 123);
+    }
+
     #[test]
     fn list_shm_works<Sut: SharedMemory<DefaultAllocator>>() {
         let mut storage_names = vec![];
@@ -303,9 +324,32 @@ mod shared_memory {
         assert_that!(*config.get_path_hint(), eq DEFAULT_PATH_HINT);
     }
 
+    #[test]
+    fn create_with_access_rights_fails_when_not_supported<Sut: SharedMemory<DefaultAllocator>>() {
+        if <Sut as NamedConceptMgmt>::Configuration::does_support_access_rights() {
+            return;
+        }
+
+        let name = generate_name();
+        let mut access_rights = AccessRights::default();
+        access_rights.add_user(User::from_self().unwrap().uid(), AclPermission::ReadWrite);
+        let config =
+            <Sut as NamedConceptMgmt>::Configuration::default().access_rights(access_rights);
+
+        let sut = Sut::Builder::new(&name)
+            .config(&config)
+            .size(DEFAULT_SIZE)
+            .create(&SHM_CONFIG);
+        assert_that!(sut, is_err);
+        assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
+    }
+
     #[instantiate_tests(<iceoryx2_cal::shared_memory::posix::Memory<DefaultAllocator>>)]
     mod posix {}
 
     #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::shared_memory_directory::Memory<DefaultAllocator>>)]
+    mod shared_memory_directory {}
 }
@@ -173,4 +173,7 @@ mod shm_allocator {
 
     #[instantiate_tests(<iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator>)]
     mod bump_allocator {}
+
+    #[instantiate_tests(<iceoryx2_cal::shm_allocator::tlsf_allocator::TlsfAllocator>)]
+    mod tlsf_allocator {}
 }
@@ -0,0 +1,208 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod tlsf_allocator {
+    use iceoryx2_bb_elementary::allocator::{AllocationError, DeallocationError};
+    use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::shm_allocator::{tlsf_allocator::*, *};
+    use std::{alloc::Layout, ptr::NonNull};
+
+    const MEMORY_SIZE: usize = 8192;
+    const MGMT_SIZE: usize = 8192;
+    const MAX_ALIGNMENT: usize = 512;
+
+    struct TestFixture {
+        memory: Box<[u8; MEMORY_SIZE]>,
+        mgmt_memory: Box<[u8; MGMT_SIZE]>,
+        sut: Option<TlsfAllocator>,
+    }
+
+    impl TestFixture {
+        fn new() -> Self {
+            Self {
+                memory: Box::new([0u8; MEMORY_SIZE]),
+                mgmt_memory: Box::new([0u8; MGMT_SIZE]),
+                sut: None,
+            }
+        }
+
+        fn init(&mut self, config: &Config) -> &TlsfAllocator {
+            assert_that!(TlsfAllocator::management_size(MEMORY_SIZE, config) <= MGMT_SIZE, eq true);
+            let bump_allocator = BumpAllocator::new(
+                NonNull::new(self.mgmt_memory.as_mut_ptr()).unwrap(),
+                MGMT_SIZE,
+            );
+
+            self.sut = Some(unsafe {
+                TlsfAllocator::new_uninit(
+                    MAX_ALIGNMENT,
+                    NonNull::new_unchecked(self.memory.as_mut_slice()),
+                    config,
+                )
+            });
+
+            let sut = self.sut.as_ref().unwrap();
+            assert_that!(unsafe { sut.init(&bump_allocator) }, is_ok);
+            sut
+        }
+    }
+
+    fn layout(size: usize) -> Layout {
+        unsafe { Layout::from_size_align_unchecked(size, 8) }
+    }
+
+    #[test]
+    fn tlsf_allocator_allocates_chunks_of_different_sizes() {
+        let mut test = TestFixture::new();
+        let sut = test.init(&Config::default());
+
+        let sizes = [1, 64, 65, 200, 1000, 31];
+        let mut chunks = vec![];
+        for size in sizes {
+            let offset = unsafe { sut.allocate(layout(size)) };
+            assert_that!(offset, is_ok);
+            chunks.push((offset.unwrap().value(), size));
+        }
+
+        chunks.sort();
+        for pair in chunks.windows(2) {
+            assert_that!(pair[0].0 + pair[0].1 <= pair[1].0, eq true);
+        }
+
+        for (offset, size) in chunks {
+            assert_that!(
+                unsafe { sut.deallocate(PointerOffset::new(offset), layout(size)) },
+                is_ok
+            );
+        }
+    }
+
+    #[test]
+    fn tlsf_allocator_fails_when_out_of_memory() {
+        let mut test = TestFixture::new();
+        let sut = test.init(&Config::default());
+        let chunk_size = sut.min_block_size() * 4;
+
+        let mut chunks = vec![];
+        while let Ok(offset) = unsafe { sut.allocate(layout(chunk_size)) } {
+            chunks.push(offset);
+        }
+
+        assert_that!(chunks.len() >= MEMORY_SIZE / chunk_size - 1, eq true);
+        let result = unsafe { sut.allocate(layout(chunk_size)) };
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ShmAllocationError::AllocationError(AllocationError::OutOfMemory));
+    }
+
+    #[test]
+    fn tlsf_allocator_merges_released_neighbours() {
+        let mut test = TestFixture::new();
+        let sut = test.init(&Config::default());
+        let total_size = sut.number_of_blocks() as usize * sut.min_block_size();
+        let chunk_size = sut.min_block_size() * 2;
+
+        let mut chunks = vec![];
+        while let Ok(offset) = unsafe { sut.allocate(layout(chunk_size)) } {
+            chunks.push(offset);
+        }
+        assert_that!(unsafe { sut.allocate(layout(total_size)) }, is_err);
+
+        // release in an interleaved order so that merges with the previous and the next
+        // neighbour are both exercised
+        for offset in chunks.iter().step_by(2) {
+            assert_that!(
+                unsafe { sut.deallocate(*offset, layout(chunk_size)) },
+                is_ok
+            );
+        }
+        for offset in chunks.iter().skip(1).step_by(2) {
+            assert_that!(
+                unsafe { sut.deallocate(*offset, layout(chunk_size)) },
+                is_ok
+            );
+        }
+
+        let offset = unsafe { sut.allocate(layout(total_size)) };
+        assert_that!(offset, is_ok);
+    }
+
+    #[test]
+    fn tlsf_allocator_allocation_exceeding_memory_fails() {
+        let mut test = TestFixture::new();
+        let sut = test.init(&Config::default());
+
+        let result = unsafe { sut.allocate(layout(MEMORY_SIZE + 1)) };
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ShmAllocationError::AllocationError(AllocationError::SizeTooLarge));
+    }
+
+    #[test]
+    fn tlsf_allocator_releasing_unknown_offset_fails() {
+        let mut test = TestFixture::new();
+        let sut = test.init(&Config::default());
+
+        let offset = unsafe { sut.allocate(layout(128)) }.unwrap();
+        let result = unsafe { sut.deallocate(PointerOffset::new(offset.value() + 1), layout(128)) };
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq DeallocationError::ProvidedPointerNotContainedInAllocator);
+
+        assert_that!(unsafe { sut.deallocate(offset, layout(128)) }, is_ok);
+        let result = unsafe { sut.deallocate(offset, layout(128)) };
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq DeallocationError::ProvidedPointerNotContainedInAllocator);
+    }
+
+    #[test]
+    fn tlsf_allocator_respects_configured_block_layout() {
+        let mut test = TestFixture::new();
+        let sut = test.init(&Config {
+            min_block_layout: unsafe { Layout::from_size_align_unchecked(96, 32) },
+        });
+
+        assert_that!(sut.max_alignment(), eq 32);
+        assert_that!(sut.min_block_size(), eq 96);
+
+        let base = test_base_address(&test);
+        let sut = test.sut.as_ref().unwrap();
+        for _ in 0..8 {
+            let offset = unsafe {
+                sut.allocate(Layout::from_size_align_unchecked(50, 32))
+                    .unwrap()
+            };
+            assert_that!((base + offset.value()) % 32, eq 0);
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn tlsf_allocator_limits_the_number_of_blocks() {
+        const MAX_NUMBER_OF_BLOCKS: u32 = (1 << 31) - 1;
+        let config = Config {
+            min_block_layout: unsafe { Layout::from_size_align_unchecked(1, 1) },
+        };
+
+        // the memory is never accessed by new_uninit
+        let memory = NonNull::slice_from_raw_parts(NonNull::<u8>::dangling(), 1 << 32);
+        let sut = unsafe { TlsfAllocator::new_uninit(MAX_ALIGNMENT, memory, &config) };
+
+        assert_that!(sut.number_of_blocks(), eq MAX_NUMBER_OF_BLOCKS);
+        assert_that!(
+            TlsfAllocator::management_size(1 << 32, &config),
+            eq TlsfAllocator::management_size(MAX_NUMBER_OF_BLOCKS as usize, &config)
+        );
+    }
+
+    fn test_base_address(test: &TestFixture) -> usize {
+        test.memory.as_ptr() as usize
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_cal::named_concept::*;
+use iceoryx2_cal::zero_copy_connection::shared_memory_directory::*;
+
+// exceeds the capacity of a single directory
+const NUMBER_OF_CONNECTIONS: usize = 600;
+
+fn generate_name() -> FileName {
+    let mut file = FileName::new(b"test_").unwrap();
+    file.push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+        .unwrap();
+    file
+}
+
+#[test]
+fn zero_copy_connection_shared_memory_directory_supports_more_connections_than_a_directory_can_hold(
+) {
+    let mut names = vec![];
+    let mut senders = vec![];
+
+    for _ in 0..NUMBER_OF_CONNECTIONS {
+        let name = generate_name();
+        let sender = Builder::new(&name).create_sender();
+        assert_that!(sender, is_ok);
+
+        names.push(name);
+        senders.push(sender.unwrap());
+    }
+
+    let list = Connection::list().unwrap();
+    for name in &names {
+        assert_that!(list, contains * name);
+        assert_that!(Connection::does_exist(name), eq Ok(true));
+    }
+
+    drop(senders);
+
+    for name in &names {
+        assert_that!(Connection::does_exist(name), eq Ok(false));
+    }
+}
+
+#[test]
+fn zero_copy_connection_shared_memory_directory_with_different_prefixes_are_separated() {
+    let name = generate_name();
+    let config_1 = Configuration::default().prefix(FileName::new(b"test_prefix_1_").unwrap());
+    let config_2 = Configuration::default().prefix(FileName::new(b"test_prefix_2_").unwrap());
+
+    let sender = Builder::new(&name).config(&config_1).create_sender();
+    assert_that!(sender, is_ok);
+
+    assert_that!(Connection::does_exist_cfg(&name, &config_1), eq Ok(true));
+    assert_that!(Connection::does_exist_cfg(&name, &config_2), eq Ok(false));
+    assert_that!(Builder::new(&name).config(&config_2).create_sender(), is_ok);
+}
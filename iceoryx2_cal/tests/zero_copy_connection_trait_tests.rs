@@ -16,8 +16,10 @@ mod zero_copy_connection {
 
     use iceoryx2_bb_container::semantic_string::*;
     use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_posix::access_control_list::AclPermission;
     use iceoryx2_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_cal::named_concept::*;
@@ -27,6 +29,7 @@ mod zero_copy_connection {
     use iceoryx2_cal::zero_copy_connection::*;
 
     const TIMEOUT: Duration = Duration::from_millis(25);
+    const SAMPLE_SIZE: usize = 7789;
 
     fn generate_name() -> FileName {
         let mut file = FileName::new(b"test_").unwrap();
@@ -196,23 +199,73 @@ mod zero_copy_connection {
     fn send_receive_and_retrieval_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
 
-        let sut_sender = Sut::Builder::new(&name).create_sender().unwrap();
+        let sut_sender = Sut::Builder::new(&name)
+            .sample_size(SAMPLE_SIZE)
+            .create_sender()
+            .unwrap();
         let sut_receiver = Sut::Builder::new(&name).create_receiver().unwrap();
 
-        assert_that!(sut_sender.try_send(PointerOffset::new(1237789)), is_ok);
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(5 * SAMPLE_SIZE)),
+            is_ok
+        );
         let sample = sut_receiver.receive().unwrap();
         assert_that!(sample, is_some);
-        assert_that!(sample.as_ref().unwrap().value(), eq 1237789);
+        assert_that!(sample.as_ref().unwrap().value(), eq 5 * SAMPLE_SIZE);
 
         assert_that!(sut_receiver.release(sample.unwrap()), is_ok);
         let retrieval = sut_sender.reclaim().unwrap();
         assert_that!(retrieval, is_some);
-        assert_that!(retrieval.as_ref().unwrap().value(), eq 1237789);
+        assert_that!(retrieval.as_ref().unwrap().value(), eq 5 * SAMPLE_SIZE);
 
         let retrieval = sut_sender.reclaim().unwrap();
         assert_that!(retrieval, is_none);
     }
 
+    #[test]
+    fn acquire_used_offsets_returns_all_offsets_which_were_not_reclaimed<
+        Sut: ZeroCopyConnection,
+    >() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(4)
+            .receiver_max_borrowed_samples(2)
+            .sample_size(SAMPLE_SIZE)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(4)
+            .receiver_max_borrowed_samples(2)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..4 {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(i * SAMPLE_SIZE)),
+                is_ok
+            );
+        }
+
+        // 0 is reclaimed, 1 is released but not reclaimed, 2 is borrowed, 3 is not received
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_receiver.release(sample), is_ok);
+        assert_that!(sut_sender.reclaim().unwrap().unwrap().value(), eq 0);
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_receiver.release(sample), is_ok);
+        let _borrowed_sample = sut_receiver.receive().unwrap().unwrap();
+
+        let mut used_offsets = vec![];
+        unsafe { sut_sender.acquire_used_offsets(|offset| used_offsets.push(offset.value())) };
+        used_offsets.sort();
+        assert_that!(used_offsets, eq vec![SAMPLE_SIZE, 2 * SAMPLE_SIZE, 3 * SAMPLE_SIZE]);
+
+        used_offsets.clear();
+        unsafe { sut_sender.acquire_used_offsets(|offset| used_offsets.push(offset.value())) };
+        assert_that!(used_offsets, is_empty);
+        assert_that!(sut_sender.reclaim().unwrap(), is_none);
+    }
+
     #[test]
     fn send_until_buffer_is_full_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -220,6 +273,7 @@ mod zero_copy_connection {
 
         let sut_sender = Sut::Builder::new(&name)
             .buffer_size(BUFFER_SIZE)
+            .number_of_samples(BUFFER_SIZE)
             .create_sender()
             .unwrap();
         for i in 0..BUFFER_SIZE {
@@ -238,6 +292,7 @@ mod zero_copy_connection {
         let sut_sender = Sut::Builder::new(&name)
             .buffer_size(BUFFER_SIZE)
             .enable_safe_overflow(true)
+            .number_of_samples(BUFFER_SIZE)
             .create_sender()
             .unwrap();
         for i in 0..BUFFER_SIZE {
@@ -259,6 +314,7 @@ mod zero_copy_connection {
         let sut_sender = Sut::Builder::new(&name)
             .buffer_size(BUFFER_SIZE)
             .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .number_of_samples(BUFFER_SIZE)
             .create_sender()
             .unwrap();
         for i in 0..BUFFER_SIZE {
@@ -296,6 +352,7 @@ mod zero_copy_connection {
         let sut_sender = Sut::Builder::new(&name)
             .buffer_size(BUFFER_SIZE)
             .receiver_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .number_of_samples(BUFFER_SIZE)
             .create_sender()
             .unwrap();
 
@@ -334,6 +391,7 @@ mod zero_copy_connection {
             .buffer_size(BUFFER_SIZE)
             .receiver_max_borrowed_samples(MAX_BORROW)
             .enable_safe_overflow(true)
+            .number_of_samples(BUFFER_SIZE)
             .create_sender()
             .unwrap();
         let sut_receiver = Sut::Builder::new(&name)
@@ -375,6 +433,7 @@ mod zero_copy_connection {
 
         let sut_sender = Sut::Builder::new(&name)
             .buffer_size(1)
+            .sample_size(SAMPLE_SIZE)
             .create_sender()
             .unwrap();
 
@@ -394,15 +453,21 @@ mod zero_copy_connection {
                 std::thread::sleep(TIMEOUT);
                 let sample_2 = sut_receiver.receive();
 
-                assert_that!(sample_1.unwrap().unwrap().value(), eq 7789);
-                assert_that!(sample_2.unwrap().unwrap().value(), eq 227789);
+                assert_that!(sample_1.unwrap().unwrap().value(), eq SAMPLE_SIZE);
+                assert_that!(sample_2.unwrap().unwrap().value(), eq 3 * SAMPLE_SIZE);
             });
 
             barrier.wait();
             let now = Instant::now();
 
-            assert_that!(sut_sender.blocking_send(PointerOffset::new(7789)), is_ok);
-            assert_that!(sut_sender.blocking_send(PointerOffset::new(227789)), is_ok);
+            assert_that!(
+                sut_sender.blocking_send(PointerOffset::new(SAMPLE_SIZE)),
+                is_ok
+            );
+            assert_that!(
+                sut_sender.blocking_send(PointerOffset::new(3 * SAMPLE_SIZE)),
+                is_ok
+            );
             assert_that!(now.elapsed(), time_at_least TIMEOUT);
         });
     }
@@ -501,9 +566,35 @@ mod zero_copy_connection {
         assert_that!(*config.get_path_hint(), eq DEFAULT_PATH_HINT);
     }
 
+    #[test]
+    fn create_with_access_rights_fails_when_not_supported<Sut: ZeroCopyConnection>() {
+        if <Sut as NamedConceptMgmt>::Configuration::does_support_access_rights() {
+            return;
+        }
+
+        let name = generate_name();
+        let mut access_rights = AccessRights::default();
+        access_rights.add_user(User::from_self().unwrap().uid(), AclPermission::ReadWrite);
+        let config =
+            <Sut as NamedConceptMgmt>::Configuration::default().access_rights(access_rights);
+
+        assert_that!(
+            Sut::Builder::new(&name).config(&config).create_sender(),
+            is_err
+        );
+        assert_that!(
+            Sut::Builder::new(&name).config(&config).create_receiver(),
+            is_err
+        );
+        assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
+    }
+
     #[instantiate_tests(<zero_copy_connection::posix_shared_memory::Connection>)]
     mod posix_shared_memory {}
 
     #[instantiate_tests(<zero_copy_connection::process_local::Connection>)]
     mod process_local {}
+
+    #[instantiate_tests(<zero_copy_connection::shared_memory_directory::Connection>)]
+    mod shared_memory_directory {}
 }
@@ -0,0 +1,174 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use clap::{ArgEnum, ArgGroup, Args, Parser, Subcommand};
+
+/// Command line tool to introspect a running iceoryx2 system.
+#[derive(Parser, Debug)]
+#[clap(name = "iox2", version)]
+pub struct Cli {
+    /// Use the iceoryx2 config from the given file instead of the default config
+    #[clap(long, global = true)]
+    pub config: Option<String>,
+
+    /// Print the output as JSON so that it can be processed by scripts
+    #[clap(long, global = true)]
+    pub json: bool,
+
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspect the services of the system
+    #[clap(subcommand)]
+    Service(ServiceCommand),
+
+    /// Inspect the iceoryx2 config
+    #[clap(subcommand)]
+    Config(ConfigCommand),
+
+    /// Print the samples of a publish-subscribe service without knowing its payload type
+    Echo(EchoOptions),
+
+    /// Send raw bytes to a publish-subscribe service without knowing its payload type
+    Pub(PubOptions),
+
+    /// Record the samples of publish-subscribe services into a file
+    Record(RecordOptions),
+
+    /// Publish the samples of a recording again
+    Replay(ReplayOptions),
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ServiceCommand {
+    /// List all services
+    List,
+
+    /// Show the static settings and the connected ports of a service
+    Show {
+        /// The name of the service
+        name: String,
+    },
+
+    /// Check if a service exists, the exit code is non-zero when it does not exist
+    Exists {
+        /// The name of the service
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Show the config that is used by the tool
+    Show,
+}
+
+/// Since the type-erased commands cannot verify the payload type of a service, the user has to
+/// either name the expected type or explicitly opt out of the check.
+#[derive(Args, Debug)]
+#[clap(group(
+    ArgGroup::new("type-check")
+        .required(true)
+        .args(&["type-name", "ignore-type-name"]),
+))]
+pub struct TypeCheck {
+    /// The type name the service must have, the command fails when it differs
+    #[clap(long)]
+    pub type_name: Option<String>,
+
+    /// Attach to the service regardless of its type name
+    #[clap(long)]
+    pub ignore_type_name: bool,
+}
+
+/// Defines how the payload of a received sample is printed.
+#[derive(ArgEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadFormat {
+    Hex,
+    Utf8,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+#[derive(Args, Debug)]
+pub struct EchoOptions {
+    /// The name of the service
+    pub name: String,
+
+    #[clap(flatten)]
+    pub type_check: TypeCheck,
+
+    /// How the payload is decoded, numbers are read in native endianness
+    #[clap(long, arg_enum, default_value = "hex")]
+    pub format: PayloadFormat,
+
+    /// Exit after the given number of samples were received
+    #[clap(long)]
+    pub count: Option<usize>,
+}
+
+#[derive(Args, Debug)]
+pub struct PubOptions {
+    /// The name of the service
+    pub name: String,
+
+    #[clap(flatten)]
+    pub type_check: TypeCheck,
+
+    /// The payload as hex encoded bytes, whitespaces are ignored, e.g. "2a 00 00 00"
+    pub payload: String,
+
+    /// How often the payload is sent
+    #[clap(long, default_value = "1")]
+    pub repeat: usize,
+
+    /// Time in milliseconds between two sends
+    #[clap(long, default_value = "0")]
+    pub interval: u64,
+}
+
+#[derive(Args, Debug)]
+pub struct RecordOptions {
+    /// The file the recording is written to, an existing file is overridden
+    pub file: String,
+
+    /// The names of the recorded services
+    #[clap(required = true)]
+    pub names: Vec<String>,
+
+    /// Stop the recording after the given number of seconds, otherwise it records until the
+    /// process is terminated
+    #[clap(long)]
+    pub duration: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReplayOptions {
+    /// The recording that is replayed
+    pub file: String,
+
+    /// How fast the recording is replayed compared to the recorded timing, e.g. 2.0 is twice
+    /// as fast
+    #[clap(long, default_value = "1.0")]
+    pub speed: f64,
+}
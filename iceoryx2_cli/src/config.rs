@@ -0,0 +1,31 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+
+use iceoryx2::config::Config;
+
+use crate::output::{print_json, Format};
+
+pub fn show(config: &Config, format: Format) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    match format {
+        // converting into a toml::Value first ensures that all plain values are emitted before
+        // the nested tables, otherwise the serialization fails
+        Format::Human => print!(
+            "{}",
+            toml::to_string_pretty(&toml::Value::try_from(config)?)?
+        ),
+        Format::Json => print_json(config)?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+use std::time::Duration;
+
+use iceoryx2::config::Config;
+use serde::Serialize;
+
+use crate::cli::{EchoOptions, PayloadFormat};
+use crate::output::Format;
+use crate::type_erased;
+
+const CYCLE_TIME: Duration = Duration::from_millis(10);
+
+#[derive(Serialize)]
+struct ReceivedSample {
+    number_of_elements: u64,
+    payload: String,
+}
+
+fn decode<const N: usize, T: ToString>(payload: &[u8], from_ne_bytes: fn([u8; N]) -> T) -> String {
+    let mut elements: Vec<String> = payload
+        .chunks_exact(N)
+        .map(|chunk| from_ne_bytes(chunk.try_into().unwrap()).to_string())
+        .collect();
+
+    let remainder = payload.chunks_exact(N).remainder();
+    if !remainder.is_empty() {
+        elements.push(format!("<trailing bytes: {}>", to_hex(remainder)));
+    }
+
+    elements.join(" ")
+}
+
+fn to_hex(payload: &[u8]) -> String {
+    payload
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_payload(payload: &[u8], format: PayloadFormat) -> String {
+    match format {
+        PayloadFormat::Hex => to_hex(payload),
+        PayloadFormat::Utf8 => String::from_utf8_lossy(payload).into_owned(),
+        PayloadFormat::U8 => decode(payload, u8::from_ne_bytes),
+        PayloadFormat::U16 => decode(payload, u16::from_ne_bytes),
+        PayloadFormat::U32 => decode(payload, u32::from_ne_bytes),
+        PayloadFormat::U64 => decode(payload, u64::from_ne_bytes),
+        PayloadFormat::I8 => decode(payload, i8::from_ne_bytes),
+        PayloadFormat::I16 => decode(payload, i16::from_ne_bytes),
+        PayloadFormat::I32 => decode(payload, i32::from_ne_bytes),
+        PayloadFormat::I64 => decode(payload, i64::from_ne_bytes),
+        PayloadFormat::F32 => decode(payload, f32::from_ne_bytes),
+        PayloadFormat::F64 => decode(payload, f64::from_ne_bytes),
+    }
+}
+
+pub fn echo(
+    options: &EchoOptions,
+    config: &Config,
+    format: Format,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let service = type_erased::open(&options.name, &options.type_check, config)?;
+    let has_notifications = service.static_config().has_notifications();
+    let subscriber = service.subscriber().create()?;
+
+    let mut number_of_received_samples = 0;
+    while options.count != Some(number_of_received_samples) {
+        let sample = match has_notifications {
+            true => subscriber.timed_receive(CYCLE_TIME)?,
+            false => subscriber.receive()?,
+        };
+
+        let sample = match sample {
+            Some(sample) => sample,
+            None => {
+                if !has_notifications {
+                    std::thread::sleep(CYCLE_TIME);
+                }
+                continue;
+            }
+        };
+
+        let payload = format_payload(sample.payload(), options.format);
+        match format {
+            Format::Human => println!("{}", payload),
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string(&ReceivedSample {
+                    number_of_elements: sample.header().number_of_elements(),
+                    payload,
+                })?
+            ),
+        }
+        number_of_received_samples += 1;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
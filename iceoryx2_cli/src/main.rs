@@ -0,0 +1,62 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli;
+mod config;
+mod echo;
+mod output;
+mod publish;
+mod record;
+mod service;
+mod type_erased;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+use cli::{Cli, Command, ConfigCommand, ServiceCommand};
+use iceoryx2::config::Config;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use output::Format;
+
+fn load_config(config_file: &Option<String>) -> Result<Config, Box<dyn std::error::Error>> {
+    Ok(match config_file {
+        Some(config_file) => Config::from_file(&FilePath::new(config_file.as_bytes())?)?,
+        None => Config::get_global_config().clone(),
+    })
+}
+
+fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
+    set_log_level(LogLevel::Error);
+
+    let cli = Cli::parse();
+    let config = load_config(&cli.config)?;
+    let format = if cli.json {
+        Format::Json
+    } else {
+        Format::Human
+    };
+
+    match cli.command {
+        Command::Service(ServiceCommand::List) => service::list(&config, format),
+        Command::Service(ServiceCommand::Show { name }) => service::show(&name, &config, format),
+        Command::Service(ServiceCommand::Exists { name }) => {
+            service::exists(&name, &config, format)
+        }
+        Command::Config(ConfigCommand::Show) => config::show(&config, format),
+        Command::Echo(options) => echo::echo(&options, &config, format),
+        Command::Pub(options) => publish::publish(&options, &config, format),
+        Command::Record(options) => record::record(&options, config, format),
+        Command::Replay(options) => record::replay(&options, config, format),
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use serde::Serialize;
+
+/// Defines how the results of a command are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable, aligned key-value pairs and tables
+    Human,
+    /// Single JSON document for scripting
+    Json,
+}
+
+pub fn print_json<T: Serialize>(value: &T) -> Result<(), Box<dyn std::error::Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Prints the entries as aligned "key: value" lines.
+pub fn print_key_values(entries: &[(&str, String)]) {
+    let width = entries.iter().map(|(key, _)| key.len()).max().unwrap_or(0) + 1;
+    for (key, value) in entries {
+        println!("{:<width$} {}", format!("{}:", key), value, width = width);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+use std::time::Duration;
+
+use iceoryx2::config::Config;
+use serde::Serialize;
+
+use crate::cli::PubOptions;
+use crate::output::{print_json, Format};
+use crate::type_erased;
+
+#[derive(Serialize)]
+struct SentSamples {
+    number_of_sent_samples: usize,
+    number_of_deliveries: usize,
+}
+
+fn parse_hex(payload: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let digits: Vec<char> = payload.chars().filter(|c| !c.is_whitespace()).collect();
+    if digits.len() % 2 != 0 {
+        return Err(format!(
+            "The payload \"{}\" contains an odd number of hex digits.",
+            payload
+        )
+        .into());
+    }
+
+    Ok(digits
+        .chunks(2)
+        .map(|byte| u8::from_str_radix(&byte.iter().collect::<String>(), 16))
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+pub fn publish(
+    options: &PubOptions,
+    config: &Config,
+    format: Format,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let payload = parse_hex(&options.payload)?;
+    let service = type_erased::open(&options.name, &options.type_check, config)?;
+    let publisher = service.publisher().create()?;
+
+    let mut number_of_deliveries = 0;
+    for n in 0..options.repeat {
+        if n != 0 {
+            std::thread::sleep(Duration::from_millis(options.interval));
+        }
+
+        // SAFETY: the user either confirmed the payload type of the service with --type-name or
+        // explicitly accepted with --ignore-type-name that the bytes may not represent it
+        number_of_deliveries += unsafe { publisher.send_bytes(&payload)? };
+    }
+
+    match format {
+        Format::Human => println!(
+            "sent {} sample(s) with {} deliveries",
+            options.repeat, number_of_deliveries
+        ),
+        Format::Json => print_json(&SentSamples {
+            number_of_sent_samples: options.repeat,
+            number_of_deliveries,
+        })?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
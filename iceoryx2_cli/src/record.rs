@@ -0,0 +1,91 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use iceoryx2::config::Config;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_record::recorder::RecorderBuilder;
+use iceoryx2_record::replayer::ReplayerBuilder;
+use serde::Serialize;
+
+use crate::cli::{RecordOptions, ReplayOptions};
+use crate::output::{print_json, Format};
+
+const CYCLE_TIME: Duration = Duration::from_millis(10);
+
+#[derive(Serialize)]
+struct RecordSummary {
+    number_of_records: u64,
+}
+
+#[derive(Serialize)]
+struct ReplaySummary {
+    number_of_replayed_samples: usize,
+}
+
+pub fn record(
+    options: &RecordOptions,
+    config: Config,
+    format: Format,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    // the recorder keeps its ports until the process terminates
+    let config = Box::leak(Box::new(config));
+    let mut builder = RecorderBuilder::new(&FilePath::new(options.file.as_bytes())?).config(config);
+    for name in &options.names {
+        builder = builder.record(ServiceName::new(name)?);
+    }
+    let mut recorder = builder.create()?;
+
+    let duration = options.duration.map(Duration::from_secs);
+    let start = Instant::now();
+    while duration.map_or(true, |duration| start.elapsed() < duration) {
+        recorder.record()?;
+        std::thread::sleep(CYCLE_TIME);
+    }
+    recorder.record()?;
+
+    match format {
+        Format::Human => println!("recorded {} sample(s)", recorder.number_of_records()),
+        Format::Json => print_json(&RecordSummary {
+            number_of_records: recorder.number_of_records(),
+        })?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+pub fn replay(
+    options: &ReplayOptions,
+    config: Config,
+    format: Format,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let config = Box::leak(Box::new(config));
+    let replayer = ReplayerBuilder::new(&FilePath::new(options.file.as_bytes())?)
+        .config(config)
+        .speed_factor(options.speed)
+        .create()?;
+
+    let number_of_replayed_samples = replayer.replay()?;
+
+    match format {
+        Format::Human => println!("replayed {} sample(s)", number_of_replayed_samples),
+        Format::Json => print_json(&ReplaySummary {
+            number_of_replayed_samples,
+        })?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
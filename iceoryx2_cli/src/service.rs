@@ -0,0 +1,253 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+
+use iceoryx2::config::Config;
+use iceoryx2::service::dynamic_config::NumberOfPorts;
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::static_config::StaticConfig;
+use iceoryx2::service::{zero_copy, Details, ServiceNumberOfPortsError};
+use serde::Serialize;
+
+use crate::output::{print_json, print_key_values, Format};
+
+#[derive(Serialize)]
+struct ServiceDetails<'a> {
+    static_config: &'a StaticConfig,
+    number_of_ports: NumberOfPorts,
+}
+
+#[derive(Serialize)]
+struct ServiceExists<'a> {
+    service_name: &'a str,
+    exists: bool,
+}
+
+fn messaging_pattern_name(messaging_pattern: &MessagingPattern) -> &'static str {
+    match messaging_pattern {
+        MessagingPattern::PublishSubscribe(_) => "PublishSubscribe",
+        MessagingPattern::Event(_) => "Event",
+        MessagingPattern::RequestResponse(_) => "RequestResponse",
+        MessagingPattern::Blackboard(_) => "Blackboard",
+        MessagingPattern::Pipeline(_) => "Pipeline",
+        _ => "Unknown",
+    }
+}
+
+fn static_config_entries(static_config: &StaticConfig) -> Vec<(&'static str, String)> {
+    let mut entries = vec![
+        ("service name", static_config.service_name().to_string()),
+        ("uuid", static_config.uuid().to_string()),
+        (
+            "messaging pattern",
+            messaging_pattern_name(static_config.messaging_pattern()).to_string(),
+        ),
+    ];
+
+    match static_config.messaging_pattern() {
+        MessagingPattern::PublishSubscribe(c) => entries.extend([
+            ("type name", c.type_name().to_string()),
+            ("payload size", c.payload_size().to_string()),
+            ("payload alignment", c.payload_alignment().to_string()),
+            (
+                "type hash",
+                c.type_hash()
+                    .map_or("-".to_string(), |hash| format!("{:#018x}", hash)),
+            ),
+            ("max publishers", c.max_supported_publishers().to_string()),
+            ("max subscribers", c.max_supported_subscribers().to_string()),
+            ("history size", c.history_size().to_string()),
+            (
+                "subscriber max buffer size",
+                c.subscriber_max_buffer_size().to_string(),
+            ),
+            (
+                "subscriber max borrowed samples",
+                c.subscriber_max_borrowed_samples().to_string(),
+            ),
+            ("safe overflow", c.has_safe_overflow().to_string()),
+            ("max slice length", c.max_slice_len().to_string()),
+            ("notifications", c.has_notifications().to_string()),
+            ("copy transfer", c.has_copy_transfer().to_string()),
+        ]),
+        MessagingPattern::Event(c) => entries.extend([
+            ("max notifiers", c.max_supported_notifiers().to_string()),
+            ("max listeners", c.max_supported_listeners().to_string()),
+        ]),
+        MessagingPattern::RequestResponse(c) => entries.extend([
+            ("request type name", c.request_type_name().to_string()),
+            ("response type name", c.response_type_name().to_string()),
+            ("max clients", c.max_supported_clients().to_string()),
+            ("max servers", c.max_supported_servers().to_string()),
+            (
+                "server max buffer size",
+                c.server_max_buffer_size().to_string(),
+            ),
+            (
+                "server max borrowed requests",
+                c.server_max_borrowed_requests().to_string(),
+            ),
+            (
+                "client max buffer size",
+                c.client_max_buffer_size().to_string(),
+            ),
+            (
+                "client max borrowed responses",
+                c.client_max_borrowed_responses().to_string(),
+            ),
+        ]),
+        MessagingPattern::Blackboard(c) => entries.extend([
+            ("type name", c.type_name().to_string()),
+            ("max readers", c.max_supported_readers().to_string()),
+            ("max writers", c.max_supported_writers().to_string()),
+            ("keys", format!("{:?}", c.keys())),
+        ]),
+        MessagingPattern::Pipeline(c) => entries.extend([
+            ("type name", c.type_name().to_string()),
+            ("max producers", c.max_supported_producers().to_string()),
+            ("max consumers", c.max_supported_consumers().to_string()),
+            (
+                "consumer max buffer size",
+                c.consumer_max_buffer_size().to_string(),
+            ),
+            (
+                "consumer max borrowed samples",
+                c.consumer_max_borrowed_samples().to_string(),
+            ),
+        ]),
+        _ => (),
+    }
+
+    entries
+}
+
+fn number_of_ports_entries(number_of_ports: &NumberOfPorts) -> Vec<(&'static str, String)> {
+    match *number_of_ports {
+        NumberOfPorts::PublishSubscribe {
+            publishers,
+            subscribers,
+        } => vec![
+            ("publishers", publishers.to_string()),
+            ("subscribers", subscribers.to_string()),
+        ],
+        NumberOfPorts::Event {
+            notifiers,
+            listeners,
+        } => vec![
+            ("notifiers", notifiers.to_string()),
+            ("listeners", listeners.to_string()),
+        ],
+        NumberOfPorts::RequestResponse { clients, servers } => vec![
+            ("clients", clients.to_string()),
+            ("servers", servers.to_string()),
+        ],
+        NumberOfPorts::Blackboard { readers, writers } => vec![
+            ("readers", readers.to_string()),
+            ("writers", writers.to_string()),
+        ],
+        NumberOfPorts::Pipeline {
+            producers,
+            consumers,
+        } => vec![
+            ("producers", producers.to_string()),
+            ("consumers", consumers.to_string()),
+        ],
+        _ => vec![],
+    }
+}
+
+pub fn list(config: &Config, format: Format) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let mut services = zero_copy::Service::list_with_custom_config(config)?;
+    services.sort_by_key(|s| s.service_name().to_string());
+
+    match format {
+        Format::Human => {
+            for service in &services {
+                println!(
+                    "{:<18} {}",
+                    messaging_pattern_name(service.messaging_pattern()),
+                    service.service_name()
+                );
+            }
+        }
+        Format::Json => print_json(&services)?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+pub fn show(
+    name: &str,
+    config: &Config,
+    format: Format,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(name)?;
+    let static_config = match zero_copy::Service::list_with_custom_config(config)?
+        .into_iter()
+        .find(|s| *s.service_name() == service_name)
+    {
+        Some(static_config) => static_config,
+        None => {
+            eprintln!("The service \"{}\" does not exist.", name);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    let number_of_ports =
+        match zero_copy::Service::number_of_ports_with_custom_config(&static_config, config) {
+            Ok(number_of_ports) => number_of_ports,
+            Err(ServiceNumberOfPortsError::DoesNotExist) => {
+                eprintln!("The service \"{}\" was removed in the meantime.", name);
+                return Ok(ExitCode::FAILURE);
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+    match format {
+        Format::Human => {
+            let mut entries = static_config_entries(&static_config);
+            entries.extend(number_of_ports_entries(&number_of_ports));
+            print_key_values(&entries);
+        }
+        Format::Json => print_json(&ServiceDetails {
+            static_config: &static_config,
+            number_of_ports,
+        })?,
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+pub fn exists(
+    name: &str,
+    config: &Config,
+    format: Format,
+) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let exists =
+        zero_copy::Service::does_exist_with_custom_config(&ServiceName::new(name)?, config)?;
+
+    match format {
+        Format::Human => println!("{}", exists),
+        Format::Json => print_json(&ServiceExists {
+            service_name: name,
+            exists,
+        })?,
+    }
+
+    Ok(if exists {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::config::Config;
+use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+use iceoryx2::service::port_factory::type_erased::PortFactory;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::{zero_copy, Service};
+
+use crate::cli::TypeCheck;
+
+/// Opens the publish-subscribe service without knowing its payload type. When the user
+/// provided a type name it must match the one of the service, otherwise
+/// [`PublishSubscribeOpenError::IncompatibleTypes`] is returned.
+pub fn open<'config>(
+    name: &str,
+    type_check: &TypeCheck,
+    config: &'config Config,
+) -> Result<PortFactory<'config, zero_copy::Service<'config>>, Box<dyn std::error::Error>> {
+    let service = zero_copy::Service::new(&ServiceName::new(name)?)
+        .publish_subscribe_with_custom_config(config)
+        .open_type_erased()?;
+
+    if let Some(type_name) = &type_check.type_name {
+        if service.static_config().type_name() != type_name {
+            eprintln!(
+                "The service \"{}\" uses the type \"{}\" but \"{}\" was expected.",
+                name,
+                service.static_config().type_name(),
+                type_name
+            );
+            return Err(PublishSubscribeOpenError::IncompatibleTypes.into());
+        }
+    }
+
+    Ok(service)
+}
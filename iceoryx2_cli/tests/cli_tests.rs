@@ -0,0 +1,365 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli {
+    use std::process::{Child, Command, Output, Stdio};
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const CYCLE_TIME: Duration = Duration::from_millis(1);
+
+    fn generate_name() -> String {
+        format!("cli_tests_{}", UniqueSystemId::new().unwrap().value())
+    }
+
+    fn generate_file() -> String {
+        std::env::temp_dir()
+            .join(format!("{}.iox2rec", generate_name()))
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    fn wait_for<F: Fn() -> bool>(condition: F) {
+        let start = Instant::now();
+        while !condition() {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            std::thread::sleep(CYCLE_TIME);
+        }
+    }
+
+    fn iox2(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_iox2"))
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    fn spawn_iox2(args: &[&str]) -> Child {
+        Command::new(env!("CARGO_BIN_EXE_iox2"))
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap()
+    }
+
+    fn stdout(output: &Output) -> String {
+        String::from_utf8(output.stdout.clone()).unwrap()
+    }
+
+    fn json(output: &Output) -> serde_json::Value {
+        serde_json::from_slice(&output.stdout).unwrap()
+    }
+
+    #[test]
+    fn missing_subcommand_is_rejected() {
+        let output = iox2(&[]);
+
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn unknown_subcommand_is_rejected() {
+        let output = iox2(&["does-not-exist"]);
+
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn echo_without_type_check_is_rejected() {
+        let output = iox2(&["echo", &generate_name()]);
+
+        assert_that!(output.status.success(), eq false);
+        assert_that!(String::from_utf8_lossy(&output.stderr).contains("--type-name"), eq true);
+    }
+
+    #[test]
+    fn pub_with_type_name_and_ignore_type_name_is_rejected() {
+        let output = iox2(&[
+            "pub",
+            &generate_name(),
+            "--type-name",
+            "u32",
+            "--ignore-type-name",
+            "2a000000",
+        ]);
+
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn echo_with_unknown_payload_format_is_rejected() {
+        let output = iox2(&[
+            "echo",
+            &generate_name(),
+            "--ignore-type-name",
+            "--format",
+            "u128",
+        ]);
+
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn record_without_service_names_is_rejected() {
+        let output = iox2(&["record", &generate_file()]);
+
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn service_list_prints_messaging_pattern_and_service_name() {
+        let service_name = generate_name();
+        let event_service_name = generate_name();
+        let _service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let _event_service =
+            zero_copy::Service::new(&ServiceName::new(&event_service_name).unwrap())
+                .event()
+                .create()
+                .unwrap();
+
+        let output = iox2(&["service", "list"]);
+
+        assert_that!(output.status.success(), eq true);
+        let lines: Vec<String> = stdout(&output)
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+        assert_that!(lines, contains format!("PublishSubscribe {}", service_name));
+        assert_that!(lines, contains format!("Event {}", event_service_name));
+    }
+
+    #[test]
+    fn service_list_as_json_contains_static_configs() {
+        let service_name = generate_name();
+        let _service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let output = iox2(&["service", "list", "--json"]);
+
+        assert_that!(output.status.success(), eq true);
+        let services = json(&output);
+        let services = services.as_array().unwrap();
+        assert_that!(
+            services
+                .iter()
+                .any(|s| s["service_name"] == serde_json::json!(service_name)),
+            eq true
+        );
+    }
+
+    #[test]
+    fn service_exists_sets_exit_code() {
+        let service_name = generate_name();
+
+        let output = iox2(&["service", "exists", &service_name]);
+        assert_that!(output.status.success(), eq false);
+        assert_that!(stdout(&output).trim(), eq "false");
+
+        let _service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let output = iox2(&["service", "exists", &service_name, "--json"]);
+        assert_that!(output.status.success(), eq true);
+        assert_that!(json(&output), eq serde_json::json!({
+            "service_name": service_name,
+            "exists": true
+        }));
+    }
+
+    #[test]
+    fn echo_prints_received_payload_in_requested_format() {
+        let service_name = generate_name();
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u32>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let child = spawn_iox2(&[
+            "echo",
+            &service_name,
+            "--type-name",
+            "u32",
+            "--format",
+            "u32",
+            "--count",
+            "2",
+        ]);
+        wait_for(|| service.dynamic_config().number_of_subscribers() == 1);
+
+        publisher.send_copy(42).unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        let output = child.wait_with_output().unwrap();
+        assert_that!(output.status.success(), eq true);
+        assert_that!(stdout(&output), eq "42\n1234\n");
+    }
+
+    #[test]
+    fn echo_prints_received_payload_as_json() {
+        let service_name = generate_name();
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u32>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let child = spawn_iox2(&[
+            "echo",
+            &service_name,
+            "--ignore-type-name",
+            "--count",
+            "1",
+            "--json",
+        ]);
+        wait_for(|| service.dynamic_config().number_of_subscribers() == 1);
+
+        publisher
+            .send_copy(u32::from_ne_bytes([1, 2, 3, 4]))
+            .unwrap();
+
+        let output = child.wait_with_output().unwrap();
+        assert_that!(output.status.success(), eq true);
+        assert_that!(json(&output), eq serde_json::json!({
+            "number_of_elements": 1,
+            "payload": "01 02 03 04"
+        }));
+    }
+
+    #[test]
+    fn echo_with_different_type_name_fails() {
+        let service_name = generate_name();
+        let _service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u32>()
+            .unwrap();
+
+        let output = iox2(&["echo", &service_name, "--type-name", "u64", "--count", "1"]);
+
+        assert_that!(output.status.success(), eq false);
+    }
+
+    #[test]
+    fn pub_sends_hex_payload_to_subscribers() {
+        let service_name = generate_name();
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            // the copied samples stay with the subscriber when the publisher process exits
+            .enable_copy_transfer(true)
+            .create::<u32>()
+            .unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let output = iox2(&[
+            "pub",
+            &service_name,
+            "--type-name",
+            "u32",
+            "--repeat",
+            "2",
+            "2a 00 00 00",
+        ]);
+
+        assert_that!(output.status.success(), eq true);
+        assert_that!(stdout(&output).trim(), eq "sent 2 sample(s) with 2 deliveries");
+        for _ in 0..2 {
+            let sample = subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq u32::from_le_bytes([0x2a, 0, 0, 0]));
+        }
+    }
+
+    #[test]
+    fn pub_prints_summary_as_json() {
+        let service_name = generate_name();
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u32>()
+            .unwrap();
+        let _subscriber = service.subscriber().create().unwrap();
+
+        let output = iox2(&[
+            "pub",
+            &service_name,
+            "--ignore-type-name",
+            "--json",
+            "2a000000",
+        ]);
+
+        assert_that!(output.status.success(), eq true);
+        assert_that!(json(&output), eq serde_json::json!({
+            "number_of_sent_samples": 1,
+            "number_of_deliveries": 1
+        }));
+    }
+
+    #[test]
+    fn pub_with_odd_number_of_hex_digits_fails() {
+        let service_name = generate_name();
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u32>()
+            .unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let output = iox2(&["pub", &service_name, "--ignore-type-name", "2a000"]);
+
+        assert_that!(output.status.success(), eq false);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn record_and_replay_print_number_of_samples() {
+        let service_name = generate_name();
+        let file = generate_file();
+        let service = zero_copy::Service::new(&ServiceName::new(&service_name).unwrap())
+            .publish_subscribe()
+            .create::<u32>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+
+        let child = spawn_iox2(&["record", &file, &service_name, "--duration", "1"]);
+        wait_for(|| service.dynamic_config().number_of_subscribers() == 1);
+
+        publisher.send_copy(42).unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        let output = child.wait_with_output().unwrap();
+        assert_that!(output.status.success(), eq true);
+        assert_that!(stdout(&output).trim(), eq "recorded 2 sample(s)");
+
+        // the replayer creates the recorded service again
+        drop(publisher);
+        drop(service);
+
+        let output = iox2(&["replay", &file, "--speed", "100.0", "--json"]);
+        std::fs::remove_file(&file).unwrap();
+
+        assert_that!(output.status.success(), eq true);
+        assert_that!(json(&output), eq serde_json::json!({
+            "number_of_replayed_samples": 2
+        }));
+    }
+}
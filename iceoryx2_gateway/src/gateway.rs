@@ -0,0 +1,469 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+//! use iceoryx2_bb_system_types::port::Port;
+//! use iceoryx2_gateway::gateway::GatewayBuilder;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut gateway = GatewayBuilder::new()
+//!     .listen_on(Ipv4Address::new(0, 0, 0, 0), Port::new(9988))
+//!     .peer(Ipv4Address::new(192, 168, 1, 20), Port::new(9988))
+//!     .forward(ServiceName::new("My/Funk/ServiceName")?)
+//!     .announce_interval(Duration::from_millis(500))
+//!     .max_datagram_size(1472)
+//!     .create()?;
+//!
+//! loop {
+//!     gateway.process(Duration::from_millis(10))?;
+//!     println!("lost samples: {}", gateway.number_of_lost_samples());
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use iceoryx2::config::Config;
+use iceoryx2::port::port_identifiers::UniquePublisherId;
+use iceoryx2::service::access_control::{Operation, Subject};
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::udp_socket::{UdpClient, UdpClientBuilder, UdpServer, UdpServerBuilder};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+
+use crate::inbound::InboundService;
+use crate::outbound::OutboundService;
+use crate::protocol::{self, Datagram, FRAGMENT_HEADER_SIZE, MAX_DATAGRAM_SIZE};
+
+const DEFAULT_ANNOUNCE_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_INBOUND_SERVICE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Fits into a single ethernet frame: 1500 bytes MTU minus the IPv4 and UDP header
+const DEFAULT_MAX_DATAGRAM_SIZE: usize = 1472;
+
+/// Failures that can occur when a [`Gateway`] is created with [`GatewayBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayCreateError {
+    InvalidMaxDatagramSize,
+    UnableToCreateGatewayId,
+    UnableToListen,
+    UnableToConnectToPeer,
+}
+
+impl std::fmt::Display for GatewayCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for GatewayCreateError {}
+
+/// Failures that can occur in [`Gateway::process()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayProcessError {
+    UnableToReceiveSample,
+    UnableToReceiveDatagram,
+}
+
+impl std::fmt::Display for GatewayProcessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for GatewayProcessError {}
+
+/// Identifies a service of a specific peer. The peer is identified by the id of its gateway
+/// and not by its address, since a restarted peer may send from another port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct InboundKey {
+    gateway_id: u128,
+    service_id: u32,
+}
+
+/// Creates a [`Gateway`]. A gateway that only listens republishes the services of its peers,
+/// a gateway with a peer forwards the configured services to it.
+#[derive(Debug)]
+pub struct GatewayBuilder {
+    config: &'static Config,
+    listen_on: Option<(Ipv4Address, Port)>,
+    peer: Option<(Ipv4Address, Port)>,
+    services: Vec<ServiceName>,
+    access_rules: Vec<(Subject, u32, Operation)>,
+    announce_interval: Duration,
+    inbound_service_timeout: Duration,
+    max_datagram_size: usize,
+}
+
+impl Default for GatewayBuilder {
+    fn default() -> Self {
+        Self {
+            config: Config::get_global_config(),
+            listen_on: None,
+            peer: None,
+            services: vec![],
+            access_rules: vec![],
+            announce_interval: DEFAULT_ANNOUNCE_INTERVAL,
+            inbound_service_timeout: DEFAULT_INBOUND_SERVICE_TIMEOUT,
+            max_datagram_size: DEFAULT_MAX_DATAGRAM_SIZE,
+        }
+    }
+}
+
+impl GatewayBuilder {
+    /// Creates a new [`GatewayBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uses a custom [`Config`] for the local services instead of the global config.
+    pub fn config(mut self, config: &'static Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Receives the services of the peers on the given address and port. When
+    /// [`iceoryx2_bb_system_types::port::UNSPECIFIED`] is used, the operating system chooses a
+    /// free port, see [`Gateway::listen_port()`].
+    pub fn listen_on(mut self, address: Ipv4Address, port: Port) -> Self {
+        self.listen_on = Some((address, port));
+        self
+    }
+
+    /// Defines the gateway to which the services are forwarded.
+    pub fn peer(mut self, address: Ipv4Address, port: Port) -> Self {
+        self.peer = Some((address, port));
+        self
+    }
+
+    /// Adds a local service that is forwarded to the peer. The service does not need to exist
+    /// yet, the gateway forwards it as soon as it was created.
+    pub fn forward(mut self, service_name: ServiceName) -> Self {
+        self.services.push(service_name);
+        self
+    }
+
+    /// Permits the user with the given uid to perform the [`Operation`] on the services of the
+    /// peers that are created by the gateway, see
+    /// [`iceoryx2::service::access_control::AccessControl`]. The access control of a service is
+    /// not announced to the peers since users and groups are specific to a host, the services
+    /// of the peers are only restricted by the users and groups that are permitted here. The
+    /// user of the gateway must be permitted to publish. It has no effect on services that
+    /// already exist locally.
+    pub fn permit_user(mut self, uid: u32, operation: Operation) -> Self {
+        self.access_rules.push((Subject::User, uid, operation));
+        self
+    }
+
+    /// Permits all members of the group with the given gid to perform the [`Operation`] on the
+    /// services of the peers that are created by the gateway, see
+    /// [`GatewayBuilder::permit_user()`].
+    pub fn permit_group(mut self, gid: u32, operation: Operation) -> Self {
+        self.access_rules.push((Subject::Group, gid, operation));
+        self
+    }
+
+    /// Defines how often the forwarded services are announced to the peer. A peer that starts
+    /// later republishes the samples after it received the next announcement.
+    pub fn announce_interval(mut self, value: Duration) -> Self {
+        self.announce_interval = value;
+        self
+    }
+
+    /// Defines how long a service of a peer is republished without receiving its announcement.
+    /// Afterwards its local publisher is removed, e.g. when the peer was restarted or shut
+    /// down. It must be larger than the announce interval of the peers.
+    pub fn inbound_service_timeout(mut self, value: Duration) -> Self {
+        self.inbound_service_timeout = value;
+        self
+    }
+
+    /// Defines the maximum size of a datagram. Larger samples are split into multiple
+    /// fragments.
+    pub fn max_datagram_size(mut self, value: usize) -> Self {
+        self.max_datagram_size = value;
+        self
+    }
+
+    /// Creates the [`Gateway`] or returns a [`GatewayCreateError`] on failure.
+    pub fn create(self) -> Result<Gateway, GatewayCreateError> {
+        let msg = "Unable to create gateway";
+
+        if self.max_datagram_size <= FRAGMENT_HEADER_SIZE
+            || self.max_datagram_size > MAX_DATAGRAM_SIZE
+        {
+            fail!(from self, with GatewayCreateError::InvalidMaxDatagramSize,
+                "{} since the max datagram size of {} bytes is not in the range ({}, {}].",
+                msg, self.max_datagram_size, FRAGMENT_HEADER_SIZE, MAX_DATAGRAM_SIZE);
+        }
+
+        let id = fail!(from self, when UniqueSystemId::new(),
+                with GatewayCreateError::UnableToCreateGatewayId,
+                "{} since the unique id of the gateway could not be created.", msg);
+
+        let server = match self.listen_on {
+            Some((address, port)) => Some(
+                fail!(from self, when UdpServerBuilder::new().address(address).port(port).listen(),
+                    with GatewayCreateError::UnableToListen,
+                    "{} since it is unable to listen on {}:{}.", msg, address, port),
+            ),
+            None => None,
+        };
+
+        let client = match self.peer {
+            Some((address, port)) => Some(
+                fail!(from self, when UdpClientBuilder::new(address).connect_to(port),
+                    with GatewayCreateError::UnableToConnectToPeer,
+                    "{} since it is unable to connect to the peer {}:{}.", msg, address, port),
+            ),
+            None => None,
+        };
+
+        Ok(Gateway {
+            config: self.config,
+            server,
+            client,
+            outbound: self
+                .services
+                .iter()
+                .enumerate()
+                .map(|(service_id, service_name)| {
+                    OutboundService::new(id.value(), service_id as u32, *service_name)
+                })
+                .collect(),
+            inbound: HashMap::new(),
+            inbound_publishers: vec![],
+            access_rules: self.access_rules,
+            announce_interval: self.announce_interval,
+            inbound_service_timeout: self.inbound_service_timeout,
+            last_announcement: None,
+            max_fragment_size: self.max_datagram_size - FRAGMENT_HEADER_SIZE,
+            receive_buffer: vec![0; MAX_DATAGRAM_SIZE],
+            send_buffer: Vec::with_capacity(self.max_datagram_size),
+            number_of_forwarded_samples: 0,
+            number_of_lost_samples: 0,
+        })
+    }
+}
+
+/// Bridges publish-subscribe services between hosts, see the crate documentation. All work is
+/// done in [`Gateway::process()`] which must be called cyclically.
+pub struct Gateway {
+    config: &'static Config,
+    server: Option<UdpServer>,
+    client: Option<UdpClient>,
+    outbound: Vec<OutboundService>,
+    inbound: HashMap<InboundKey, InboundService>,
+    inbound_publishers: Vec<UniquePublisherId>,
+    access_rules: Vec<(Subject, u32, Operation)>,
+    announce_interval: Duration,
+    inbound_service_timeout: Duration,
+    last_announcement: Option<Instant>,
+    max_fragment_size: usize,
+    receive_buffer: Vec<u8>,
+    send_buffer: Vec<u8>,
+    number_of_forwarded_samples: u64,
+    number_of_lost_samples: u64,
+}
+
+impl std::fmt::Debug for Gateway {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Gateway {{ server: {:?}, client: {:?}, number_of_outbound_services: {}, number_of_inbound_services: {} }}",
+            self.server,
+            self.client,
+            self.outbound.len(),
+            self.inbound.len()
+        )
+    }
+}
+
+impl Gateway {
+    /// Returns the port on which the gateway receives the services of its peers, if it listens.
+    pub fn listen_port(&self) -> Option<Port> {
+        self.server.as_ref().map(|server| server.port())
+    }
+
+    /// Returns the number of local services that are forwarded to the peer. Services that do
+    /// not exist yet are not counted.
+    pub fn number_of_outbound_services(&self) -> usize {
+        self.outbound
+            .iter()
+            .filter(|service| service.is_connected())
+            .count()
+    }
+
+    /// Returns the number of services of peers that are republished locally. A service is
+    /// republished until its peer did not announce it for the
+    /// [`GatewayBuilder::inbound_service_timeout()`].
+    pub fn number_of_inbound_services(&self) -> usize {
+        self.inbound.len()
+    }
+
+    /// Returns the number of samples that were forwarded to the peer.
+    pub fn number_of_forwarded_samples(&self) -> u64 {
+        self.number_of_forwarded_samples
+    }
+
+    /// Returns the number of samples of peers that were lost in transmission, detected by gaps
+    /// in the sequence numbers.
+    pub fn number_of_lost_samples(&self) -> u64 {
+        self.number_of_lost_samples
+    }
+
+    /// Announces the forwarded services when the announce interval has passed, forwards all
+    /// received local samples to the peer and republishes the samples of the peers. It waits
+    /// at most `timeout` for incoming datagrams.
+    pub fn process(&mut self, timeout: Duration) -> Result<(), GatewayProcessError> {
+        if self
+            .last_announcement
+            .map_or(true, |last| last.elapsed() >= self.announce_interval)
+        {
+            self.announce();
+            self.last_announcement = Some(Instant::now());
+        }
+
+        self.forward()?;
+        self.receive(timeout)?;
+        self.remove_expired_inbound_services();
+        Ok(())
+    }
+
+    fn announce(&mut self) {
+        let client = match &self.client {
+            Some(client) => client,
+            None => return,
+        };
+
+        for service in &mut self.outbound {
+            service.connect(self.config);
+
+            let announcement = match service.announcement() {
+                Some(announcement) => announcement,
+                None => continue,
+            };
+
+            match protocol::encode_announcement(&announcement) {
+                Ok(datagram) => {
+                    if let Err(e) = client.send(&datagram) {
+                        warn!(from "Gateway::announce()", "Unable to announce the service \"{}\" ({:?}).",
+                            announcement.service_name, e);
+                    }
+                }
+                Err(e) => {
+                    warn!(from "Gateway::announce()", "Unable to announce the service \"{}\" since it could not be serialized ({:?}).",
+                        announcement.service_name, e);
+                }
+            }
+        }
+    }
+
+    fn forward(&mut self) -> Result<(), GatewayProcessError> {
+        let client = match &self.client {
+            Some(client) => client,
+            None => return Ok(()),
+        };
+
+        for service in &mut self.outbound {
+            self.number_of_forwarded_samples += service.forward(
+                client,
+                &self.inbound_publishers,
+                self.max_fragment_size,
+                &mut self.send_buffer,
+            )? as u64;
+        }
+
+        Ok(())
+    }
+
+    fn receive(&mut self, timeout: Duration) -> Result<(), GatewayProcessError> {
+        let server = match &self.server {
+            Some(server) => server,
+            None => {
+                std::thread::sleep(timeout);
+                return Ok(());
+            }
+        };
+
+        let msg = "Unable to receive datagrams";
+        let mut received_datagram = fail!(from "Gateway::process()", when server.timed_receive_from(&mut self.receive_buffer, timeout),
+                with GatewayProcessError::UnableToReceiveDatagram, "{} since the timed receive failed.", msg);
+
+        while let Some(details) = received_datagram {
+            match protocol::decode(&self.receive_buffer[..details.number_of_bytes]) {
+                Some(Datagram::Announcement(announcement)) => {
+                    let key = InboundKey {
+                        gateway_id: announcement.gateway_id,
+                        service_id: announcement.service_id,
+                    };
+
+                    match self.inbound.get_mut(&key) {
+                        Some(service) => service.refresh(),
+                        None => {
+                            if let Ok(service) =
+                                InboundService::new(&announcement, &self.access_rules, self.config)
+                            {
+                                debug!(from "Gateway::process()", "Republish the service \"{}\" of the peer {}:{}.",
+                                    announcement.service_name, details.source_ip, details.source_port);
+                                self.inbound_publishers.push(service.publisher_id());
+                                self.inbound.insert(key, service);
+                            }
+                        }
+                    }
+                }
+                Some(Datagram::Fragment(header, data)) => {
+                    let key = InboundKey {
+                        gateway_id: header.gateway_id,
+                        service_id: header.service_id,
+                    };
+
+                    // fragments that arrive before the announcement of their service are lost
+                    if let Some(service) = self.inbound.get_mut(&key) {
+                        self.number_of_lost_samples += service.receive(&header, data);
+                    }
+                }
+                None => {
+                    debug!(from "Gateway::process()", "Discard invalid datagram from {}:{}.",
+                        details.source_ip, details.source_port);
+                }
+            }
+
+            received_datagram = fail!(from "Gateway::process()", when server.try_receive_from(&mut self.receive_buffer),
+                with GatewayProcessError::UnableToReceiveDatagram, "{} since the receive failed.", msg);
+        }
+
+        Ok(())
+    }
+
+    fn remove_expired_inbound_services(&mut self) {
+        let timeout = self.inbound_service_timeout;
+        let inbound_publishers = &mut self.inbound_publishers;
+        self.inbound.retain(|_, service| {
+            if !service.is_expired(timeout) {
+                return true;
+            }
+
+            debug!(from "Gateway::process()", "Stop to republish the service \"{}\" since the peer did not announce it for {:?}.",
+                service.service_name(), timeout);
+            inbound_publishers.retain(|id| *id != service.publisher_id());
+            false
+        });
+    }
+}
@@ -0,0 +1,259 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::time::{Duration, Instant};
+
+use iceoryx2::config::Config;
+use iceoryx2::port::port_identifiers::UniquePublisherId;
+use iceoryx2::port::type_erased_publisher::OwningTypeErasedPublisher;
+use iceoryx2::service::access_control::{Operation, Subject};
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::{zero_copy, Service};
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::protocol::{Announcement, FragmentHeader};
+
+/// Failures that can occur when the service of an announcement cannot be provided locally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InboundServiceCreateError {
+    InvalidAnnouncement,
+    IncompatibleService,
+    UnableToCreatePublisher,
+}
+
+/// Collects the fragments of a single sample.
+struct Reassembly {
+    sequence_number: u64,
+    number_of_fragments: u16,
+    fragment_size: Option<usize>,
+    received_fragments: Vec<bool>,
+    number_of_received_fragments: u16,
+    payload: Vec<u8>,
+}
+
+impl Reassembly {
+    fn new(header: &FragmentHeader) -> Self {
+        Self {
+            sequence_number: header.sequence_number,
+            number_of_fragments: header.number_of_fragments,
+            fragment_size: None,
+            received_fragments: vec![false; header.number_of_fragments as usize],
+            number_of_received_fragments: 0,
+            payload: vec![0; header.payload_size as usize],
+        }
+    }
+
+    /// The sender splits the payload into fragments of equal size, only the last one can be
+    /// smaller. Since the header does not contain the fragment size it is derived from the
+    /// first received fragment. Returns [`None`] when the fragment does not fit to any
+    /// fragment size.
+    fn derive_fragment_size(&self, header: &FragmentHeader, data: &[u8]) -> Option<usize> {
+        let index = header.fragment_index as usize;
+        let number_of_fragments = self.number_of_fragments as usize;
+        let fragment_size = if number_of_fragments == 1 {
+            self.payload.len()
+        } else if index == 0 {
+            data.len()
+        } else if header.fragment_offset as usize % index == 0 {
+            header.fragment_offset as usize / index
+        } else {
+            return None;
+        };
+
+        // every fragment but the last one is completely filled
+        if number_of_fragments > 1
+            && (fragment_size == 0
+                || (number_of_fragments - 1) * fragment_size >= self.payload.len()
+                || number_of_fragments * fragment_size < self.payload.len())
+        {
+            return None;
+        }
+
+        Some(fragment_size)
+    }
+
+    /// Returns true when all fragments of the sample were received.
+    fn add(&mut self, header: &FragmentHeader, data: &[u8]) -> bool {
+        let start = header.fragment_offset as usize;
+        let end = start + data.len();
+        let index = header.fragment_index as usize;
+
+        // discard corrupted or duplicated fragments
+        if header.number_of_fragments != self.number_of_fragments
+            || header.payload_size as usize != self.payload.len()
+            || index >= self.received_fragments.len()
+            || end > self.payload.len()
+            || self.received_fragments[index]
+        {
+            return false;
+        }
+
+        let fragment_size = match self.fragment_size {
+            Some(fragment_size) => fragment_size,
+            None => match self.derive_fragment_size(header, data) {
+                Some(fragment_size) => fragment_size,
+                None => return false,
+            },
+        };
+
+        // discard fragments that would overlap or leave gaps in the payload
+        let is_last_fragment = index + 1 == self.received_fragments.len();
+        if start != index * fragment_size
+            || (!is_last_fragment && data.len() != fragment_size)
+            || (is_last_fragment && end != self.payload.len())
+        {
+            return false;
+        }
+
+        self.fragment_size = Some(fragment_size);
+        self.payload[start..end].copy_from_slice(data);
+        self.received_fragments[index] = true;
+        self.number_of_received_fragments += 1;
+        self.number_of_received_fragments == self.number_of_fragments
+    }
+}
+
+/// A service of the peer that is republished locally.
+pub(crate) struct InboundService {
+    service_name: ServiceName,
+    publisher: OwningTypeErasedPublisher<zero_copy::Service<'static>>,
+    publisher_id: UniquePublisherId,
+    max_payload_size: usize,
+    last_sequence_number: Option<u64>,
+    reassembly: Option<Reassembly>,
+    last_announcement: Instant,
+}
+
+impl InboundService {
+    /// Opens or creates the local service with the static configuration of the announcement.
+    /// A created service is restricted by the access rules of the gateway.
+    pub(crate) fn new(
+        announcement: &Announcement,
+        access_rules: &[(Subject, u32, Operation)],
+        config: &'static Config,
+    ) -> Result<Self, InboundServiceCreateError> {
+        let origin = "InboundService::new()";
+        let msg = "Unable to provide the announced service";
+        let static_config = &announcement.static_config;
+
+        let service_name = fail!(from origin, when ServiceName::new(&announcement.service_name),
+                with InboundServiceCreateError::InvalidAnnouncement,
+                "{} since the service name \"{}\" is invalid.", msg, announcement.service_name);
+
+        let payload_layout = fail!(from origin, when Layout::from_size_align(static_config.payload_size(), static_config.payload_alignment()),
+                with InboundServiceCreateError::InvalidAnnouncement,
+                "{} \"{}\" since the payload layout is invalid.", msg, service_name);
+
+        let builder = access_rules.iter().fold(
+            zero_copy::Service::new(&service_name)
+                .publish_subscribe_with_custom_config(config)
+                .apply_static_config(static_config, &announcement.attributes),
+            |builder, (subject, id, operation)| match subject {
+                Subject::User => builder.permit_user(*id, *operation),
+                Subject::Group => builder.permit_group(*id, *operation),
+            },
+        );
+
+        let factory = fail!(from origin, when builder.open_or_create_type_erased(static_config.type_name(), payload_layout),
+                with InboundServiceCreateError::IncompatibleService,
+                "{} \"{}\" since the local service is not compatible.", msg, service_name);
+        let max_payload_size =
+            factory.static_config().payload_size() * factory.static_config().max_slice_len();
+
+        let publisher = fail!(from origin, when OwningTypeErasedPublisher::create(factory),
+            with InboundServiceCreateError::UnableToCreatePublisher,
+            "{} \"{}\" since the publisher could not be created.", msg, service_name);
+
+        Ok(Self {
+            service_name,
+            publisher_id: publisher.with_publisher(|publisher| publisher.id()),
+            publisher,
+            max_payload_size,
+            last_sequence_number: None,
+            reassembly: None,
+            last_announcement: Instant::now(),
+        })
+    }
+
+    pub(crate) fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    pub(crate) fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Is called whenever the peer announces the service again.
+    pub(crate) fn refresh(&mut self) {
+        self.last_announcement = Instant::now();
+    }
+
+    /// Returns true when the peer did not announce the service within the timeout, e.g.
+    /// because it was restarted or shut down.
+    pub(crate) fn is_expired(&self, timeout: Duration) -> bool {
+        self.last_announcement.elapsed() > timeout
+    }
+
+    /// Adds the fragment to the current sample and publishes it when it is complete. Returns the
+    /// number of samples that were lost since the last published sample.
+    pub(crate) fn receive(&mut self, header: &FragmentHeader, data: &[u8]) -> u64 {
+        if header.payload_size as usize > self.max_payload_size {
+            warn!(from "InboundService::receive()", "Discard fragment of sample {} of the service \"{}\" since the payload size of {} bytes exceeds the maximum of {} bytes.",
+                header.sequence_number, self.service_name, header.payload_size, self.max_payload_size);
+            return 0;
+        }
+
+        // outdated fragments of already published or abandoned samples
+        if self
+            .last_sequence_number
+            .map_or(false, |last| header.sequence_number <= last)
+        {
+            return 0;
+        }
+
+        let is_current_sample = match &self.reassembly {
+            Some(reassembly) if reassembly.sequence_number > header.sequence_number => return 0,
+            Some(reassembly) => reassembly.sequence_number == header.sequence_number,
+            None => false,
+        };
+
+        // a newer sample abandons an incomplete one, it is counted as lost when the newer sample
+        // is published
+        if !is_current_sample {
+            self.reassembly = Some(Reassembly::new(header));
+        }
+
+        let reassembly = self.reassembly.as_mut().unwrap();
+        if !reassembly.add(header, data) {
+            return 0;
+        }
+
+        let reassembly = self.reassembly.take().unwrap();
+        let number_of_lost_samples = self
+            .last_sequence_number
+            .map_or(0, |last| reassembly.sequence_number - last - 1);
+        self.last_sequence_number = Some(reassembly.sequence_number);
+
+        let service_name = &self.service_name;
+        self.publisher.with_publisher(|publisher| {
+            // SAFETY: the payload stems from a sample of a service with the same type name,
+            // payload size and alignment on the peer
+            if let Err(e) = unsafe { publisher.send_bytes(&reassembly.payload) } {
+                warn!(from "InboundService::receive()", "Unable to republish sample {} of the service \"{}\" ({:?}).",
+                    reassembly.sequence_number, service_name, e);
+            }
+        });
+
+        number_of_lost_samples
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # iceoryx2 gateway
+//!
+//! The [`Gateway`](crate::gateway::Gateway) bridges
+//! [`MessagingPattern::PublishSubscribe`](iceoryx2::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+//! services between hosts. It subscribes to the configured local services and forwards every
+//! received sample as UDP datagrams to a peer gateway. The peer creates or opens the
+//! corresponding local service with the same static configuration and republishes the samples
+//! with a local publisher.
+//!
+//! The gateway does not know the payload types, it forwards the raw bytes of the samples.
+//! Therefore, both hosts must use the same payload layout and endianness.
+//!
+//! # Protocol
+//!
+//!  * **Announcement** - every forwarded service is announced periodically with its name and
+//!    static configuration so that the peer can create a matching service. The access control
+//!    of the service is not announced, the peer restricts the service with the users and
+//!    groups it permits itself, see
+//!    [`GatewayBuilder::permit_user()`](crate::gateway::GatewayBuilder::permit_user()).
+//!  * **Fragment** - a sample is split into fragments that fit into a single datagram. Every
+//!    sample carries a sequence number so that the receiving gateway detects lost samples.
+//!
+//! Every datagram carries the unique id of the sending gateway. A service of a peer that is no
+//! longer announced, e.g. since the peer was restarted, is no longer republished after the
+//! [`GatewayBuilder::inbound_service_timeout()`](crate::gateway::GatewayBuilder::inbound_service_timeout()).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+//! use iceoryx2_bb_system_types::port::Port;
+//! use iceoryx2_gateway::gateway::GatewayBuilder;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // receives the services of the peer on port 9988 and forwards "My/Funk/ServiceName" to it
+//! let mut gateway = GatewayBuilder::new()
+//!     .listen_on(Ipv4Address::new(0, 0, 0, 0), Port::new(9988))
+//!     .peer(Ipv4Address::new(192, 168, 1, 20), Port::new(9988))
+//!     .forward(ServiceName::new("My/Funk/ServiceName")?)
+//!     .create()?;
+//!
+//! loop {
+//!     gateway.process(Duration::from_millis(10))?;
+//! }
+//! # }
+//! ```
+
+pub mod gateway;
+
+mod inbound;
+mod outbound;
+mod protocol;
@@ -0,0 +1,174 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::config::Config;
+use iceoryx2::port::port_identifiers::UniquePublisherId;
+use iceoryx2::port::type_erased_subscriber::OwningTypeErasedSubscriber;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::{zero_copy, Service};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::udp_socket::UdpClient;
+
+use crate::gateway::GatewayProcessError;
+use crate::protocol::{self, Announcement, FragmentHeader};
+
+/// A local service whose samples are forwarded to the peer gateway.
+pub(crate) struct OutboundService {
+    gateway_id: u128,
+    service_id: u32,
+    service_name: ServiceName,
+    // the service may not exist yet, the gateway retries to open it periodically
+    subscriber: Option<OwningTypeErasedSubscriber<zero_copy::Service<'static>>>,
+    sequence_number: u64,
+}
+
+impl OutboundService {
+    pub(crate) fn new(gateway_id: u128, service_id: u32, service_name: ServiceName) -> Self {
+        Self {
+            gateway_id,
+            service_id,
+            service_name,
+            subscriber: None,
+            sequence_number: 0,
+        }
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        self.subscriber.is_some()
+    }
+
+    /// Tries to open the local service and to create a subscriber, when this was not yet
+    /// successful.
+    pub(crate) fn connect(&mut self, config: &'static Config) {
+        if self.subscriber.is_some() {
+            return;
+        }
+
+        let factory = match zero_copy::Service::new(&self.service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open_type_erased()
+        {
+            Ok(factory) => factory,
+            // the service may be created later, try again with the next announcement
+            Err(_) => return,
+        };
+
+        match OwningTypeErasedSubscriber::create(factory) {
+            Ok(subscriber) => self.subscriber = Some(subscriber),
+            Err(e) => {
+                warn!(from "OutboundService::connect()", "Unable to forward the service \"{}\" since the subscriber could not be created ({:?}).",
+                    self.service_name, e);
+            }
+        }
+    }
+
+    pub(crate) fn announcement(&self) -> Option<Announcement> {
+        self.subscriber.as_ref().map(|subscriber| Announcement {
+            gateway_id: self.gateway_id,
+            service_id: self.service_id,
+            service_name: self.service_name.to_string(),
+            static_config: subscriber.factory().static_config().clone(),
+            attributes: subscriber.factory().attributes().clone(),
+        })
+    }
+
+    /// Sends all received samples in fragments to the peer. Samples of the publishers in
+    /// `ignored_publishers` are discarded, they were received from the peer and forwarding them
+    /// back would create a loop. Returns the number of forwarded samples.
+    pub(crate) fn forward(
+        &mut self,
+        client: &UdpClient,
+        ignored_publishers: &[UniquePublisherId],
+        max_fragment_size: usize,
+        datagram: &mut Vec<u8>,
+    ) -> Result<usize, GatewayProcessError> {
+        let subscriber = match &self.subscriber {
+            Some(subscriber) => subscriber,
+            None => return Ok(0),
+        };
+
+        let gateway_id = self.gateway_id;
+        let service_id = self.service_id;
+        let service_name = &self.service_name;
+        let sequence_number = &mut self.sequence_number;
+        subscriber.with_subscriber(|subscriber| {
+            let mut number_of_forwarded_samples = 0;
+            while let Some(sample) = fail!(from "OutboundService::forward()", when subscriber.receive(),
+                        with GatewayProcessError::UnableToReceiveSample,
+                        "Unable to forward samples of the service \"{}\" since the receive failed.", service_name)
+            {
+                if ignored_publishers.contains(&sample.header().publisher_id()) {
+                    continue;
+                }
+
+                *sequence_number += 1;
+                send_sample(
+                    client,
+                    gateway_id,
+                    service_id,
+                    *sequence_number,
+                    sample.payload(),
+                    max_fragment_size,
+                    datagram,
+                );
+                number_of_forwarded_samples += 1;
+            }
+
+            Ok(number_of_forwarded_samples)
+        })
+    }
+}
+
+fn send_sample(
+    client: &UdpClient,
+    gateway_id: u128,
+    service_id: u32,
+    sequence_number: u64,
+    payload: &[u8],
+    max_fragment_size: usize,
+    datagram: &mut Vec<u8>,
+) {
+    let origin = "OutboundService::forward()";
+    // a sample without payload, like a zero sized type, is sent in a single empty fragment
+    let number_of_fragments = std::cmp::max(
+        1,
+        (payload.len() + max_fragment_size - 1) / max_fragment_size,
+    );
+
+    if number_of_fragments > u16::MAX as usize || payload.len() > u32::MAX as usize {
+        warn!(from origin, "Discard sample {} since its payload of {} bytes requires too many fragments.",
+            sequence_number, payload.len());
+        return;
+    }
+
+    for fragment_index in 0..number_of_fragments {
+        let start = fragment_index * max_fragment_size;
+        let end = std::cmp::min(start + max_fragment_size, payload.len());
+        let header = FragmentHeader {
+            gateway_id,
+            service_id,
+            sequence_number,
+            payload_size: payload.len() as u32,
+            fragment_offset: start as u32,
+            fragment_index: fragment_index as u16,
+            number_of_fragments: number_of_fragments as u16,
+        };
+
+        protocol::encode_fragment(&header, &payload[start..end], datagram);
+        // UDP is unreliable anyway, the peer detects the lost sample via the sequence number
+        if let Err(e) = client.send(datagram) {
+            warn!(from origin, "Unable to send fragment {} of sample {} ({:?}).",
+                fragment_index, sequence_number, e);
+            return;
+        }
+    }
+}
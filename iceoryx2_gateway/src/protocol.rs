@@ -0,0 +1,119 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::service::attribute::AttributeSet;
+use iceoryx2::service::static_config::publish_subscribe::StaticConfig;
+use iceoryx2_cal::serialize::{toml::Toml, Serialize, SerializeError};
+use serde::Deserialize;
+
+/// Datagrams with another version are discarded.
+const PROTOCOL_VERSION: u8 = 1;
+const ANNOUNCEMENT: u8 = 1;
+const FRAGMENT: u8 = 2;
+
+/// version and kind of the datagram and the id of the sending gateway
+const PREAMBLE_SIZE: usize = 2 + 16;
+
+/// service id, sequence number, payload size, fragment offset, fragment index and number of
+/// fragments
+pub(crate) const FRAGMENT_HEADER_SIZE: usize = PREAMBLE_SIZE + 4 + 8 + 4 + 4 + 2 + 2;
+
+/// The largest payload of an UDP datagram that is sent via IPv4.
+pub(crate) const MAX_DATAGRAM_SIZE: usize = 65507;
+
+/// Announces a forwarded service to the peer so that it can create a matching service. It
+/// does not contain the [`iceoryx2::service::access_control::AccessControl`] of the service
+/// since users and groups are specific to a host, the peer restricts the service with its own
+/// access rules.
+#[derive(Debug, serde::Serialize, Deserialize)]
+pub(crate) struct Announcement {
+    // is part of the preamble since the serializer does not support 128 bit integers
+    #[serde(skip)]
+    pub(crate) gateway_id: u128,
+    pub(crate) service_id: u32,
+    pub(crate) service_name: String,
+    pub(crate) static_config: StaticConfig,
+    #[serde(default)]
+    pub(crate) attributes: AttributeSet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FragmentHeader {
+    pub(crate) gateway_id: u128,
+    pub(crate) service_id: u32,
+    pub(crate) sequence_number: u64,
+    pub(crate) payload_size: u32,
+    pub(crate) fragment_offset: u32,
+    pub(crate) fragment_index: u16,
+    pub(crate) number_of_fragments: u16,
+}
+
+#[derive(Debug)]
+pub(crate) enum Datagram<'a> {
+    Announcement(Announcement),
+    Fragment(FragmentHeader, &'a [u8]),
+}
+
+pub(crate) fn encode_announcement(announcement: &Announcement) -> Result<Vec<u8>, SerializeError> {
+    let mut datagram = vec![PROTOCOL_VERSION, ANNOUNCEMENT];
+    datagram.extend_from_slice(&announcement.gateway_id.to_le_bytes());
+    datagram.extend(Toml::serialize(announcement)?);
+    Ok(datagram)
+}
+
+pub(crate) fn encode_fragment(header: &FragmentHeader, data: &[u8], datagram: &mut Vec<u8>) {
+    datagram.clear();
+    datagram.extend_from_slice(&[PROTOCOL_VERSION, FRAGMENT]);
+    datagram.extend_from_slice(&header.gateway_id.to_le_bytes());
+    datagram.extend_from_slice(&header.service_id.to_le_bytes());
+    datagram.extend_from_slice(&header.sequence_number.to_le_bytes());
+    datagram.extend_from_slice(&header.payload_size.to_le_bytes());
+    datagram.extend_from_slice(&header.fragment_offset.to_le_bytes());
+    datagram.extend_from_slice(&header.fragment_index.to_le_bytes());
+    datagram.extend_from_slice(&header.number_of_fragments.to_le_bytes());
+    datagram.extend_from_slice(data);
+}
+
+/// Returns [`None`] when the datagram is not a valid datagram of this protocol version.
+pub(crate) fn decode(datagram: &[u8]) -> Option<Datagram<'_>> {
+    if datagram.len() < PREAMBLE_SIZE || datagram[0] != PROTOCOL_VERSION {
+        return None;
+    }
+
+    let gateway_id = u128::from_le_bytes(datagram[2..18].try_into().unwrap());
+    match datagram[1] {
+        ANNOUNCEMENT => Toml::deserialize(&datagram[PREAMBLE_SIZE..])
+            .ok()
+            .map(|announcement| {
+                Datagram::Announcement(Announcement {
+                    gateway_id,
+                    ..announcement
+                })
+            }),
+        FRAGMENT if datagram.len() >= FRAGMENT_HEADER_SIZE => {
+            let header = FragmentHeader {
+                gateway_id,
+                service_id: u32::from_le_bytes(datagram[18..22].try_into().unwrap()),
+                sequence_number: u64::from_le_bytes(datagram[22..30].try_into().unwrap()),
+                payload_size: u32::from_le_bytes(datagram[30..34].try_into().unwrap()),
+                fragment_offset: u32::from_le_bytes(datagram[34..38].try_into().unwrap()),
+                fragment_index: u16::from_le_bytes(datagram[38..40].try_into().unwrap()),
+                number_of_fragments: u16::from_le_bytes(datagram[40..42].try_into().unwrap()),
+            };
+            Some(Datagram::Fragment(
+                header,
+                &datagram[FRAGMENT_HEADER_SIZE..],
+            ))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,471 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod gateway {
+    use std::net::UdpSocket;
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::access_control::{Operation, Subject};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_system_types::ipv4_address::LOCALHOST;
+    use iceoryx2_bb_system_types::port;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_gateway::gateway::{Gateway, GatewayBuilder, GatewayCreateError};
+
+    type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+    const CYCLE_TIME: Duration = Duration::from_millis(1);
+    const ANNOUNCE_INTERVAL: Duration = Duration::from_millis(10);
+
+    fn generate_name() -> TestResult<ServiceName> {
+        Ok(ServiceName::new(&format!(
+            "gateway_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))?)
+    }
+
+    /// The services of the remote host use another prefix so that both hosts can be simulated
+    /// in one process without interfering with each other.
+    fn remote_config() -> &'static Config {
+        let mut config = Config::get_global_config().clone();
+        config.global.prefix = "iox2_gateway_tests_remote_".to_string();
+        Box::leak(Box::new(config))
+    }
+
+    fn create_gateways(
+        service_name: ServiceName,
+        config: &'static Config,
+        max_datagram_size: usize,
+    ) -> TestResult<(Gateway, Gateway)> {
+        let remote = GatewayBuilder::new()
+            .config(config)
+            .listen_on(LOCALHOST, port::UNSPECIFIED)
+            .create()?;
+
+        let local = GatewayBuilder::new()
+            .peer(LOCALHOST, remote.listen_port().unwrap())
+            .forward(service_name)
+            .announce_interval(ANNOUNCE_INTERVAL)
+            .max_datagram_size(max_datagram_size)
+            .create()?;
+
+        Ok((local, remote))
+    }
+
+    fn process_until<F: FnMut(&Gateway) -> bool>(
+        local: &mut Gateway,
+        remote: &mut Gateway,
+        mut condition: F,
+    ) -> TestResult<()> {
+        let start = Instant::now();
+        while !condition(remote) {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            local.process(Duration::ZERO)?;
+            remote.process(CYCLE_TIME)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn create_fails_with_invalid_max_datagram_size() {
+        let sut = GatewayBuilder::new()
+            .peer(LOCALHOST, port::Port::new(9988))
+            .max_datagram_size(8)
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq GatewayCreateError::InvalidMaxDatagramSize);
+    }
+
+    #[test]
+    fn remote_service_is_created_with_the_same_static_config() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(3)
+            .max_subscribers(4)
+            .history_size(2)
+            .subscriber_max_buffer_size(5)
+            .type_hash(0x1234)
+            .attribute("unit", "m")
+            .create::<u64>()?;
+
+        let (mut local, mut remote) = create_gateways(service_name, config, 1472)?;
+        assert_that!(local.number_of_outbound_services(), eq 0);
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+        assert_that!(local.number_of_outbound_services(), eq 1);
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        assert_that!(remote_service.static_config(), eq service.static_config());
+        assert_that!(remote_service.attributes(), eq service.attributes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_service_is_restricted_by_the_access_rules_of_the_remote_gateway() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let user = User::from_self().unwrap();
+        let _service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .permit_user(user.uid(), Operation::Publish)
+            .permit_user(user.uid(), Operation::Subscribe)
+            .create::<u64>()?;
+
+        let mut remote = GatewayBuilder::new()
+            .config(config)
+            .listen_on(LOCALHOST, port::UNSPECIFIED)
+            .permit_user(user.uid(), Operation::Publish)
+            .permit_group(user.gid(), Operation::Subscribe)
+            .create()?;
+        let mut local = GatewayBuilder::new()
+            .peer(LOCALHOST, remote.listen_port().unwrap())
+            .forward(service_name)
+            .announce_interval(ANNOUNCE_INTERVAL)
+            .create()?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        let rules = remote_service.access_control().rules();
+        assert_that!(rules, len 2);
+        assert_that!(rules[0].subject(), eq Subject::User);
+        assert_that!(rules[0].operation(), eq Operation::Publish);
+        assert_that!(rules[1].subject(), eq Subject::Group);
+        assert_that!(rules[1].id(), eq user.gid());
+        assert_that!(rules[1].operation(), eq Operation::Subscribe);
+
+        Ok(())
+    }
+
+    #[test]
+    fn access_control_of_the_local_service_is_not_announced() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let user = User::from_self().unwrap();
+        let _service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .permit_user(user.uid(), Operation::Publish)
+            .permit_user(user.uid(), Operation::Subscribe)
+            .create::<u64>()?;
+
+        let (mut local, mut remote) = create_gateways(service_name, config, 1472)?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        assert_that!(remote_service.access_control().is_restricted(), eq false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn samples_are_forwarded_to_the_remote_host() -> TestResult<()> {
+        const NUMBER_OF_SAMPLES: u64 = 32;
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create::<u64>()?;
+        let publisher = service.publisher().create()?;
+
+        let (mut local, mut remote) = create_gateways(service_name, config, 1472)?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        let subscriber = remote_service.subscriber().create()?;
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(value * 3)?;
+        }
+
+        let mut received_values = vec![];
+        process_until(&mut local, &mut remote, |_| {
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received_values.push(*sample);
+            }
+            received_values.len() == NUMBER_OF_SAMPLES as usize
+        })?;
+
+        for (n, value) in received_values.iter().enumerate() {
+            assert_that!(*value, eq n as u64 * 3);
+        }
+        assert_that!(local.number_of_forwarded_samples(), eq NUMBER_OF_SAMPLES);
+        assert_that!(remote.number_of_lost_samples(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn samples_larger_than_a_datagram_are_fragmented() -> TestResult<()> {
+        const MAX_SLICE_LEN: usize = 4096;
+        const MAX_DATAGRAM_SIZE: usize = 64;
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u8]>()?;
+        let publisher = service.publisher().create()?;
+
+        let (mut local, mut remote) = create_gateways(service_name, config, MAX_DATAGRAM_SIZE)?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<[u8]>()?;
+        let subscriber = remote_service.subscriber().create()?;
+
+        for len in [1, MAX_DATAGRAM_SIZE, 1000, MAX_SLICE_LEN] {
+            let sample = publisher
+                .loan_uninit_slice(len)?
+                .write_from_fn(|i| (i % 251) as u8);
+            publisher.send(sample)?;
+
+            let mut received_payload = None;
+            process_until(&mut local, &mut remote, |_| {
+                received_payload = subscriber
+                    .receive()
+                    .unwrap()
+                    .map(|sample| sample.payload().to_vec());
+                received_payload.is_some()
+            })?;
+
+            let received_payload = received_payload.unwrap();
+            assert_that!(received_payload, len len);
+            for (i, byte) in received_payload.iter().enumerate() {
+                assert_that!(*byte, eq(i % 251) as u8);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fragments_that_do_not_fit_into_the_payload_are_discarded() -> TestResult<()> {
+        // the fields of a fragment datagram that are modified
+        const KIND: usize = 1;
+        const FRAGMENT_OFFSET: std::ops::Range<usize> = 34..38;
+        const FRAGMENT_HEADER_SIZE: usize = 42;
+        const FRAGMENT_SIZE: usize = 8;
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(32)
+            .create::<[u8]>()?;
+        let publisher = service.publisher().create()?;
+
+        // the datagrams of the local gateway are relayed by the test so that they can be
+        // modified before they reach the remote gateway
+        let relay = UdpSocket::bind("127.0.0.1:0")?;
+        relay.set_read_timeout(Some(CYCLE_TIME))?;
+        let mut remote = GatewayBuilder::new()
+            .config(config)
+            .listen_on(LOCALHOST, port::UNSPECIFIED)
+            .create()?;
+        let remote_address = format!("127.0.0.1:{}", remote.listen_port().unwrap());
+        let mut local = GatewayBuilder::new()
+            .peer(LOCALHOST, port::Port::new(relay.local_addr()?.port()))
+            .forward(service_name)
+            .announce_interval(ANNOUNCE_INTERVAL)
+            .max_datagram_size(FRAGMENT_HEADER_SIZE + FRAGMENT_SIZE)
+            .create()?;
+
+        let mut fragments = vec![];
+        let relay_datagrams = |local: &mut Gateway, fragments: &mut Vec<Vec<u8>>| {
+            local.process(Duration::ZERO).unwrap();
+            let mut buffer = [0u8; 65536];
+            while let Ok(len) = relay.recv(&mut buffer) {
+                match buffer[KIND] {
+                    2 => fragments.push(buffer[..len].to_vec()),
+                    _ => {
+                        relay.send_to(&buffer[..len], &remote_address).unwrap();
+                    }
+                }
+            }
+        };
+
+        let start = Instant::now();
+        while remote.number_of_inbound_services() != 1 {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            relay_datagrams(&mut local, &mut fragments);
+            remote.process(CYCLE_TIME)?;
+        }
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<[u8]>()?;
+        let subscriber = remote_service.subscriber().create()?;
+
+        let sample = publisher.loan_uninit_slice(20)?.write_from_fn(|i| i as u8);
+        publisher.send(sample)?;
+
+        let start = Instant::now();
+        while fragments.len() != 3 {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            relay_datagrams(&mut local, &mut fragments);
+        }
+
+        // a fragment that overlaps the previous one and a fragment that leaves a gap since it
+        // is not completely filled although it is not the last one
+        let mut overlapping_fragment = fragments[1].clone();
+        overlapping_fragment[FRAGMENT_OFFSET].copy_from_slice(&4u32.to_le_bytes());
+        let mut short_fragment = fragments[1].clone();
+        short_fragment.truncate(FRAGMENT_HEADER_SIZE + FRAGMENT_SIZE / 2);
+
+        let client = UdpSocket::bind("127.0.0.1:0")?;
+        for datagram in [
+            &fragments[0],
+            &overlapping_fragment,
+            &short_fragment,
+            &fragments[1],
+            &fragments[2],
+        ] {
+            client.send_to(datagram, &remote_address)?;
+        }
+
+        let mut received_payload = None;
+        let start = Instant::now();
+        while received_payload.is_none() {
+            assert_that!(start.elapsed(), lt TIMEOUT);
+            relay_datagrams(&mut local, &mut fragments);
+            remote.process(CYCLE_TIME)?;
+            received_payload = subscriber
+                .receive()?
+                .map(|sample| sample.payload().to_vec());
+        }
+
+        let received_payload = received_payload.unwrap();
+        assert_that!(received_payload, len 20);
+        for (i, byte) in received_payload.iter().enumerate() {
+            assert_that!(*byte, eq i as u8);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn lost_samples_are_detected() -> TestResult<()> {
+        // exceeds the receive buffer of the socket, the remote gateway does not process in the
+        // meantime
+        const NUMBER_OF_SAMPLES: u64 = 20000;
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()?;
+        let publisher = service.publisher().create()?;
+
+        let (mut local, mut remote) = create_gateways(service_name, config, 1472)?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        let subscriber = remote_service.subscriber().create()?;
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(value)?;
+            local.process(Duration::ZERO)?;
+        }
+        assert_that!(local.number_of_forwarded_samples(), eq NUMBER_OF_SAMPLES);
+
+        // the last sample is delivered after the receive buffer was drained
+        remote.process(CYCLE_TIME)?;
+        publisher.send_copy(NUMBER_OF_SAMPLES)?;
+        process_until(&mut local, &mut remote, |_| {
+            let mut received_last_sample = false;
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received_last_sample |= *sample == NUMBER_OF_SAMPLES;
+            }
+            received_last_sample
+        })?;
+
+        assert_that!(remote.number_of_lost_samples(), gt 0);
+        assert_that!(remote.number_of_lost_samples(), lt NUMBER_OF_SAMPLES);
+
+        Ok(())
+    }
+
+    #[test]
+    fn services_of_a_restarted_peer_expire() -> TestResult<()> {
+        const INBOUND_SERVICE_TIMEOUT: Duration = Duration::from_millis(200);
+        let service_name = generate_name()?;
+        let config = remote_config();
+        let _service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()?;
+
+        let mut remote = GatewayBuilder::new()
+            .config(config)
+            .listen_on(LOCALHOST, port::UNSPECIFIED)
+            .inbound_service_timeout(INBOUND_SERVICE_TIMEOUT)
+            .create()?;
+        let create_local = |remote: &Gateway| {
+            GatewayBuilder::new()
+                .peer(LOCALHOST, remote.listen_port().unwrap())
+                .forward(service_name)
+                .announce_interval(ANNOUNCE_INTERVAL)
+                .create()
+        };
+
+        let mut local = create_local(&remote)?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        // the restarted peer has a new gateway id and sends from another port
+        drop(local);
+        let mut local = create_local(&remote)?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 2
+        })?;
+        process_until(&mut local, &mut remote, |remote| {
+            remote.number_of_inbound_services() == 1
+        })?;
+
+        let remote_service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        assert_that!(remote_service.dynamic_config().number_of_publishers(), eq 1);
+
+        Ok(())
+    }
+}
@@ -31,6 +31,8 @@ pub const ACL_GROUP: acl_tag_t = crate::internal::ACL_GROUP as _;
 pub const ACL_MASK: acl_tag_t = crate::internal::ACL_MASK as _;
 pub const ACL_OTHER: acl_tag_t = crate::internal::ACL_OTHER as _;
 
+pub const ACL_TYPE_ACCESS: acl_type_t = crate::internal::ACL_TYPE_ACCESS as _;
+
 pub const ACL_FIRST_ENTRY: int = crate::internal::ACL_FIRST_ENTRY as _;
 pub const ACL_NEXT_ENTRY: int = crate::internal::ACL_NEXT_ENTRY as _;
 
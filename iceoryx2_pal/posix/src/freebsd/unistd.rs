@@ -67,6 +67,10 @@ pub unsafe fn getgid() -> gid_t {
     crate::internal::getgid()
 }
 
+pub unsafe fn getgroups(size: int, list: *mut gid_t) -> int {
+    crate::internal::getgroups(size, list)
+}
+
 pub unsafe fn rmdir(pathname: *const char) -> int {
     crate::internal::rmdir(pathname)
 }
@@ -79,6 +79,10 @@ pub unsafe fn acl_set_fd(fd: int, acl: acl_t) -> int {
     crate::internal::acl_set_fd(fd, acl)
 }
 
+pub unsafe fn acl_set_file(path_name: *const char, type_: acl_type_t, acl: acl_t) -> int {
+    crate::internal::acl_set_file(path_name, type_, acl)
+}
+
 pub unsafe fn acl_to_text(acl: acl_t, len_p: *mut ssize_t) -> *const char {
     crate::internal::acl_to_text(acl, len_p)
 }
@@ -79,6 +79,10 @@ pub unsafe fn acl_set_fd(_fd: int, _acl: acl_t) -> int {
     -1
 }
 
+pub unsafe fn acl_set_file(_path_name: *const char, _type_: acl_type_t, _acl: acl_t) -> int {
+    -1
+}
+
 pub unsafe fn acl_to_text(_acl: acl_t, _len_p: *mut ssize_t) -> *const char {
     core::ptr::null::<char>()
 }
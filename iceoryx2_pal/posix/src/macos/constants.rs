@@ -33,6 +33,8 @@ pub const ACL_GROUP: acl_tag_t = 16;
 pub const ACL_MASK: acl_tag_t = 32;
 pub const ACL_OTHER: acl_tag_t = 64;
 
+pub const ACL_TYPE_ACCESS: acl_type_t = 0;
+
 pub const ACL_FIRST_ENTRY: int = 128;
 pub const ACL_NEXT_ENTRY: int = 256;
 
@@ -80,6 +80,10 @@ pub unsafe fn acl_set_fd(fd: int, acl: acl_t) -> int {
     -1
 }
 
+pub unsafe fn acl_set_file(path_name: *const char, type_: acl_type_t, acl: acl_t) -> int {
+    -1
+}
+
 pub unsafe fn acl_to_text(acl: acl_t, len_p: *mut ssize_t) -> *const char {
     core::ptr::null::<char>()
 }
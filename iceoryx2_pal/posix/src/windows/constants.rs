@@ -32,6 +32,8 @@ pub const ACL_GROUP: acl_tag_t = 8;
 pub const ACL_MASK: acl_tag_t = 16;
 pub const ACL_OTHER: acl_tag_t = 32;
 
+pub const ACL_TYPE_ACCESS: acl_type_t = acl_type_t {};
+
 pub const ACL_FIRST_ENTRY: int = 0;
 pub const ACL_NEXT_ENTRY: int = 1;
 
@@ -262,6 +262,10 @@ pub unsafe fn getgid() -> gid_t {
     gid_t::MAX
 }
 
+pub unsafe fn getgroups(_size: int, _list: *mut gid_t) -> int {
+    0
+}
+
 pub unsafe fn rmdir(pathname: *const char) -> int {
     if win32call! {RemoveDirectoryA(pathname as*const u8), ignore ERROR_FILE_NOT_FOUND} == 0 {
         return -1;
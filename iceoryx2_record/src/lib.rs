@@ -0,0 +1,59 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # iceoryx2 record
+//!
+//! Captures the traffic of
+//! [`MessagingPattern::PublishSubscribe`](iceoryx2::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+//! services for offline debugging.
+//!
+//!  * [`Recorder`](crate::recorder::Recorder) - attaches a subscriber to every given service and
+//!    writes the received samples into a file. Every record contains the publisher id and time
+//!    stamp of the sample [`Header`](iceoryx2::service::header::publish_subscribe::Header), the
+//!    service name, the type name and the raw payload bytes.
+//!  * [`Recording`](crate::recording::Recording) - loads a recording file.
+//!  * [`Replayer`](crate::replayer::Replayer) - recreates the recorded services and publishes
+//!    the samples again with the original timing or at a configurable speed factor.
+//!
+//! Like the gateway, the recorder does not know the payload types. Therefore, a recording can
+//! only be replayed on a host with the same payload layout and endianness.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_record::recorder::RecorderBuilder;
+//! use iceoryx2_record::replayer::ReplayerBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let file = FilePath::new(b"/tmp/funk.iox2rec")?;
+//! let mut recorder = RecorderBuilder::new(&file)
+//!     .record(ServiceName::new("My/Funk/ServiceName")?)
+//!     .create()?;
+//!
+//! for _ in 0..100 {
+//!     recorder.record()?;
+//!     std::thread::sleep(std::time::Duration::from_millis(10));
+//! }
+//! drop(recorder);
+//!
+//! let replayer = ReplayerBuilder::new(&file).create()?;
+//! replayer.replay()?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod recorder;
+pub mod recording;
+pub mod replayer;
@@ -0,0 +1,234 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_record::recorder::RecorderBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut recorder = RecorderBuilder::new(&FilePath::new(b"/tmp/funk.iox2rec")?)
+//!     .record(ServiceName::new("My/Funk/ServiceName")?)
+//!     .record(ServiceName::new("My/Other/ServiceName")?)
+//!     .create()?;
+//!
+//! loop {
+//!     recorder.record()?;
+//!     std::thread::sleep(std::time::Duration::from_millis(10));
+//! }
+//! # }
+//! ```
+
+use iceoryx2::config::Config;
+use iceoryx2::port::type_erased_subscriber::OwningTypeErasedSubscriber;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::{zero_copy, Service};
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+use crate::recording::RecordingWriter;
+
+/// Failures that can occur when a [`Recorder`] is created with [`RecorderBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecorderCreateError {
+    UnableToOpenService,
+    UnableToCreateSubscriber,
+    UnableToCreateFile,
+    UnableToWriteFile,
+}
+
+impl std::fmt::Display for RecorderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RecorderCreateError {}
+
+/// Failures that can occur in [`Recorder::record()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecorderError {
+    UnableToReceiveSample,
+    UnableToWriteFile,
+}
+
+impl std::fmt::Display for RecorderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RecorderError {}
+
+/// Creates a [`Recorder`] that records the samples of the given services into a file.
+#[derive(Debug)]
+pub struct RecorderBuilder {
+    file_path: FilePath,
+    config: &'static Config,
+    services: Vec<ServiceName>,
+}
+
+impl RecorderBuilder {
+    /// Creates a new [`RecorderBuilder`] that writes the recording into the given file. An
+    /// existing file is overridden.
+    pub fn new(file_path: &FilePath) -> Self {
+        Self {
+            file_path: *file_path,
+            config: Config::get_global_config(),
+            services: vec![],
+        }
+    }
+
+    /// Uses a custom [`Config`] for the recorded services instead of the global config.
+    pub fn config(mut self, config: &'static Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Adds a publish-subscribe service that shall be recorded.
+    pub fn record(mut self, service_name: ServiceName) -> Self {
+        self.services.push(service_name);
+        self
+    }
+
+    /// Opens all services, attaches a subscriber to each of them and creates the recording
+    /// file. Returns a [`RecorderCreateError`] on failure.
+    pub fn create(self) -> Result<Recorder, RecorderCreateError> {
+        let msg = "Unable to create recorder";
+
+        let mut services = vec![];
+        for service_name in &self.services {
+            let factory = fail!(from self, when zero_copy::Service::new(service_name)
+                    .publish_subscribe_with_custom_config(self.config)
+                    .open_type_erased(),
+                with RecorderCreateError::UnableToOpenService,
+                "{} since the service \"{}\" could not be opened.", msg, service_name);
+
+            let subscriber = fail!(from self, when OwningTypeErasedSubscriber::create(factory),
+                with RecorderCreateError::UnableToCreateSubscriber,
+                "{} since no subscriber could be attached to the service \"{}\".", msg, service_name);
+
+            services.push((*service_name, subscriber));
+        }
+
+        let mut writer = match RecordingWriter::create(&self.file_path) {
+            Some(writer) => writer,
+            None => {
+                fail!(from self, with RecorderCreateError::UnableToCreateFile,
+                    "{} since the file \"{}\" could not be created.", msg, self.file_path);
+            }
+        };
+
+        for (service_id, (service_name, subscriber)) in services.iter().enumerate() {
+            if writer
+                .write_service(
+                    service_id as u32,
+                    service_name,
+                    subscriber.factory().static_config(),
+                    subscriber.factory().attributes(),
+                )
+                .is_none()
+            {
+                fail!(from self, with RecorderCreateError::UnableToWriteFile,
+                    "{} since the service \"{}\" could not be written into the file \"{}\".",
+                    msg, service_name, self.file_path);
+            }
+        }
+
+        Ok(Recorder {
+            file_path: self.file_path,
+            writer,
+            services,
+            number_of_records: 0,
+        })
+    }
+}
+
+/// Records the samples of publish-subscribe services into a file that can be loaded with
+/// [`Recording::load()`](crate::recording::Recording::load()) or replayed with the
+/// [`Replayer`](crate::replayer::Replayer).
+///
+/// The recorder does not know the payload types, it stores the raw payload bytes together with
+/// the [`Header`](iceoryx2::service::header::publish_subscribe::Header) information of every
+/// sample.
+pub struct Recorder {
+    file_path: FilePath,
+    writer: RecordingWriter,
+    services: Vec<(
+        ServiceName,
+        OwningTypeErasedSubscriber<zero_copy::Service<'static>>,
+    )>,
+    number_of_records: u64,
+}
+
+impl std::fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Recorder {{ file_path: {}, number_of_services: {}, number_of_records: {} }}",
+            self.file_path,
+            self.services.len(),
+            self.number_of_records
+        )
+    }
+}
+
+impl Recorder {
+    /// Writes all samples that were received since the last call into the file and returns
+    /// the number of new records. It must be called frequently enough so that the subscriber
+    /// buffers do not overflow, otherwise samples are lost.
+    pub fn record(&mut self) -> Result<usize, RecorderError> {
+        let msg = "Unable to record samples";
+        let writer = &mut self.writer;
+        let file_path = &self.file_path;
+
+        let mut number_of_new_records = 0;
+        for (service_id, (service_name, subscriber)) in self.services.iter().enumerate() {
+            number_of_new_records += subscriber.with_subscriber(|subscriber| {
+                let mut number_of_records = 0;
+                while let Some(sample) = fail!(from "Recorder::record()", when subscriber.receive(),
+                        with RecorderError::UnableToReceiveSample,
+                        "{} of the service \"{}\" since the receive failed.", msg, service_name)
+                {
+                    let header = sample.header();
+                    if writer
+                        .write_sample(
+                            service_id as u32,
+                            header.publisher_id().value(),
+                            header.time_stamp(),
+                            sample.payload(),
+                        )
+                        .is_none()
+                    {
+                        fail!(from "Recorder::record()", with RecorderError::UnableToWriteFile,
+                            "{} of the service \"{}\" since the file \"{}\" could not be written.",
+                            msg, service_name, file_path);
+                    }
+                    number_of_records += 1;
+                }
+
+                Ok(number_of_records)
+            })?;
+        }
+
+        self.number_of_records += number_of_new_records as u64;
+        Ok(number_of_new_records)
+    }
+
+    /// Returns the number of samples that were recorded so far
+    pub fn number_of_records(&self) -> u64 {
+        self.number_of_records
+    }
+}
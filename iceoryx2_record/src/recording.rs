@@ -0,0 +1,365 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # File Format
+//!
+//! A recording starts with the magic bytes `IOX2REC` followed by the format version. The rest of
+//! the file is a sequence of entries, each consisting of its kind (`u8`), the size of its body
+//! (`u32`) and the body. All numbers are stored in little endian.
+//!
+//!  * **Service** - the service id (`u32`) followed by the service name, the
+//!    [`StaticConfig`] and the [`AttributeSet`] serialized as toml. Every service is stored before its first sample.
+//!  * **Sample** - the service id (`u32`), the publisher id (`u128`), the seconds (`u64`) and
+//!    nanoseconds (`u32`) of the time stamp followed by the raw payload bytes.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_record::recording::Recording;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let recording = Recording::load(&FilePath::new(b"/tmp/funk.iox2rec")?)?;
+//!
+//! for record in recording.records() {
+//!     println!("{} from {}: {:?}", record.service_name(), record.publisher_id(),
+//!         record.payload());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2::service::attribute::AttributeSet;
+use iceoryx2::service::service_name::ServiceName;
+use iceoryx2::service::static_config::publish_subscribe::StaticConfig;
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::clock::{Time, TimeBuilder};
+use iceoryx2_bb_posix::file::{AccessMode, CreationMode, File, FileBuilder, Permission};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_cal::serialize::{toml::Toml, Serialize};
+use serde::Deserialize;
+
+const MAGIC: &[u8] = b"IOX2REC";
+/// Recordings with another version cannot be loaded.
+const FORMAT_VERSION: u8 = 1;
+const SERVICE: u8 = 1;
+const SAMPLE: u8 = 2;
+
+/// kind and size of the body
+const ENTRY_HEADER_SIZE: usize = 1 + 4;
+/// service id, publisher id, seconds and nanoseconds of the time stamp
+const SAMPLE_HEADER_SIZE: usize = 4 + 16 + 8 + 4;
+
+/// Failures that can occur when a [`Recording`] is loaded with [`Recording::load()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingLoadError {
+    UnableToOpenFile,
+    UnableToReadFile,
+    UnsupportedVersion,
+    InvalidFormat,
+}
+
+impl std::fmt::Display for RecordingLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RecordingLoadError {}
+
+#[derive(Debug, serde::Serialize, Deserialize)]
+struct ServiceEntry {
+    service_name: String,
+    static_config: StaticConfig,
+    #[serde(default)]
+    attributes: AttributeSet,
+}
+
+/// A recorded service with the [`StaticConfig`] and [`AttributeSet`] it had when the
+/// recording was created.
+#[derive(Debug, Clone)]
+pub struct RecordedService {
+    service_name: ServiceName,
+    static_config: StaticConfig,
+    attributes: AttributeSet,
+}
+
+impl RecordedService {
+    /// Returns the name of the service
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the [`StaticConfig`] of the service
+    pub fn static_config(&self) -> &StaticConfig {
+        &self.static_config
+    }
+
+    /// Returns the [`AttributeSet`] of the service
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+}
+
+/// A single recorded sample.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub(crate) service_index: usize,
+    service_name: ServiceName,
+    type_name: String,
+    publisher_id: u128,
+    time_stamp: Time,
+    payload: Vec<u8>,
+}
+
+impl Record {
+    /// Returns the name of the service the sample was received from
+    pub fn service_name(&self) -> &ServiceName {
+        &self.service_name
+    }
+
+    /// Returns the type name of the payload
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the value of the
+    /// [`UniquePublisherId`](iceoryx2::port::port_identifiers::UniquePublisherId) of the
+    /// publisher that sent the sample.
+    pub fn publisher_id(&self) -> u128 {
+        self.publisher_id
+    }
+
+    /// Returns the [`Time`] when the sample was delivered by the publisher
+    pub fn time_stamp(&self) -> Time {
+        self.time_stamp
+    }
+
+    /// Returns the raw payload of the sample
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+/// The content of a recording file that was created by the
+/// [`Recorder`](crate::recorder::Recorder).
+#[derive(Debug)]
+pub struct Recording {
+    services: Vec<RecordedService>,
+    records: Vec<Record>,
+}
+
+impl Recording {
+    /// Loads the recording from the given file. The records are ordered by their time stamps.
+    /// An incomplete last entry, e.g. of a recorder that was terminated while writing, is
+    /// ignored.
+    pub fn load(file_path: &FilePath) -> Result<Self, RecordingLoadError> {
+        let origin = format!("Recording::load({})", file_path);
+        let msg = "Unable to load recording";
+
+        let file = fail!(from origin, when FileBuilder::new(file_path).open_existing(AccessMode::Read),
+                with RecordingLoadError::UnableToOpenFile,
+                "{} since the file could not be opened.", msg);
+
+        let mut content = vec![];
+        fail!(from origin, when file.read_to_vector(&mut content),
+                with RecordingLoadError::UnableToReadFile,
+                "{} since the file could not be read.", msg);
+
+        if content.len() <= MAGIC.len() || &content[..MAGIC.len()] != MAGIC {
+            fail!(from origin, with RecordingLoadError::InvalidFormat,
+                "{} since the file is not a recording.", msg);
+        }
+
+        if content[MAGIC.len()] != FORMAT_VERSION {
+            fail!(from origin, with RecordingLoadError::UnsupportedVersion,
+                "{} since the format version {} is not supported, expected version {}.",
+                msg, content[MAGIC.len()], FORMAT_VERSION);
+        }
+
+        let mut recording = Self {
+            services: vec![],
+            records: vec![],
+        };
+        let mut service_ids = vec![];
+        let mut position = MAGIC.len() + 1;
+
+        while position < content.len() {
+            // a recorder that was terminated abruptly leaves an incomplete last entry, the
+            // entries before it are still loaded
+            let truncated_msg = "The recording is truncated, the incomplete entry at position";
+            if content.len() - position < ENTRY_HEADER_SIZE {
+                warn!(from origin, "{} {} is ignored.", truncated_msg, position);
+                break;
+            }
+
+            let kind = content[position];
+            let size = u32::from_le_bytes(content[position + 1..position + 5].try_into().unwrap())
+                as usize;
+            let start = position + ENTRY_HEADER_SIZE;
+            if content.len() - start < size {
+                warn!(from origin, "{} {} is ignored.", truncated_msg, position);
+                break;
+            }
+
+            if size < 4 {
+                fail!(from origin, with RecordingLoadError::InvalidFormat,
+                    "{} since the entry at position {} is corrupted.", msg, position);
+            }
+
+            let body = &content[start..start + size];
+            let service_id = u32::from_le_bytes(body[0..4].try_into().unwrap());
+            match kind {
+                SERVICE => {
+                    let entry: ServiceEntry = fail!(from origin, when Toml::deserialize(&body[4..]),
+                        with RecordingLoadError::InvalidFormat,
+                        "{} since the service entry at position {} is corrupted.", msg, position);
+                    let service_name = fail!(from origin, when ServiceName::new(&entry.service_name),
+                        with RecordingLoadError::InvalidFormat,
+                        "{} since the service name \"{}\" is invalid.", msg, entry.service_name);
+
+                    service_ids.push(service_id);
+                    recording.services.push(RecordedService {
+                        service_name,
+                        static_config: entry.static_config,
+                        attributes: entry.attributes,
+                    });
+                }
+                SAMPLE => {
+                    let service_index = match service_ids.iter().position(|id| *id == service_id) {
+                        Some(index) => index,
+                        None => {
+                            fail!(from origin, with RecordingLoadError::InvalidFormat,
+                                "{} since the sample at position {} belongs to the unknown service {}.",
+                                msg, position, service_id);
+                        }
+                    };
+
+                    if size < SAMPLE_HEADER_SIZE {
+                        fail!(from origin, with RecordingLoadError::InvalidFormat,
+                            "{} since the sample at position {} is truncated.", msg, position);
+                    }
+
+                    let service = &recording.services[service_index];
+                    recording.records.push(Record {
+                        service_index,
+                        service_name: service.service_name.clone(),
+                        type_name: service.static_config.type_name().to_string(),
+                        publisher_id: u128::from_le_bytes(body[4..20].try_into().unwrap()),
+                        time_stamp: TimeBuilder::new()
+                            .seconds(u64::from_le_bytes(body[20..28].try_into().unwrap()))
+                            .nanoseconds(u32::from_le_bytes(body[28..32].try_into().unwrap()))
+                            .create(),
+                        payload: body[SAMPLE_HEADER_SIZE..].to_vec(),
+                    });
+                }
+                _ => {
+                    fail!(from origin, with RecordingLoadError::InvalidFormat,
+                        "{} since the entry at position {} has the unknown kind {}.",
+                        msg, position, kind);
+                }
+            }
+
+            position = start + size;
+        }
+
+        // the recorder collects the samples service by service, therefore samples of different
+        // services may be stored out of order
+        recording
+            .records
+            .sort_by_key(|record| record.time_stamp.as_duration());
+
+        Ok(recording)
+    }
+
+    /// Returns all recorded services
+    pub fn services(&self) -> &[RecordedService] {
+        &self.services
+    }
+
+    /// Returns all records ordered by their time stamps
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+}
+
+/// Writes the entries of a recording into a file.
+pub(crate) struct RecordingWriter {
+    file: File,
+    entry: Vec<u8>,
+}
+
+impl RecordingWriter {
+    pub(crate) fn create(file_path: &FilePath) -> Option<Self> {
+        let mut file = FileBuilder::new(file_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .permission(Permission::OWNER_READ | Permission::OWNER_WRITE)
+            .create()
+            .ok()?;
+
+        let mut preamble = MAGIC.to_vec();
+        preamble.push(FORMAT_VERSION);
+        file.write(&preamble).ok()?;
+
+        Some(Self {
+            file,
+            entry: vec![],
+        })
+    }
+
+    pub(crate) fn write_service(
+        &mut self,
+        service_id: u32,
+        service_name: &ServiceName,
+        static_config: &StaticConfig,
+        attributes: &AttributeSet,
+    ) -> Option<()> {
+        let body = Toml::serialize(&ServiceEntry {
+            service_name: service_name.to_string(),
+            static_config: static_config.clone(),
+            attributes: attributes.clone(),
+        })
+        .ok()?;
+
+        self.begin_entry(SERVICE, 4 + body.len())?;
+        self.entry.extend_from_slice(&service_id.to_le_bytes());
+        self.entry.extend_from_slice(&body);
+        self.file.write(&self.entry).ok().map(|_| ())
+    }
+
+    pub(crate) fn write_sample(
+        &mut self,
+        service_id: u32,
+        publisher_id: u128,
+        time_stamp: Time,
+        payload: &[u8],
+    ) -> Option<()> {
+        self.begin_entry(SAMPLE, SAMPLE_HEADER_SIZE + payload.len())?;
+        self.entry.extend_from_slice(&service_id.to_le_bytes());
+        self.entry.extend_from_slice(&publisher_id.to_le_bytes());
+        self.entry
+            .extend_from_slice(&time_stamp.seconds().to_le_bytes());
+        self.entry
+            .extend_from_slice(&time_stamp.nanoseconds().to_le_bytes());
+        self.entry.extend_from_slice(payload);
+        self.file.write(&self.entry).ok().map(|_| ())
+    }
+
+    fn begin_entry(&mut self, kind: u8, size: usize) -> Option<()> {
+        let size: u32 = size.try_into().ok()?;
+        self.entry.clear();
+        self.entry.push(kind);
+        self.entry.extend_from_slice(&size.to_le_bytes());
+        Some(())
+    }
+}
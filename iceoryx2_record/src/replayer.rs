@@ -0,0 +1,229 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_record::replayer::ReplayerBuilder;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // replays the recording twice as fast as it was recorded
+//! let replayer = ReplayerBuilder::new(&FilePath::new(b"/tmp/funk.iox2rec")?)
+//!     .speed_factor(2.0)
+//!     .create()?;
+//!
+//! let number_of_replayed_samples = replayer.replay()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::alloc::Layout;
+use std::time::{Duration, Instant};
+
+use iceoryx2::config::Config;
+use iceoryx2::port::type_erased_publisher::OwningTypeErasedPublisher;
+use iceoryx2::service::{zero_copy, Service};
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_path::FilePath;
+
+use crate::recording::{RecordedService, Recording, RecordingLoadError};
+
+/// Failures that can occur when a [`Replayer`] is created with [`ReplayerBuilder::create()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayerCreateError {
+    InvalidSpeedFactor,
+    UnableToLoadRecording(RecordingLoadError),
+    InvalidPayloadLayout,
+    IncompatibleService,
+    UnableToCreatePublisher,
+}
+
+impl std::fmt::Display for ReplayerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReplayerCreateError {}
+
+/// Failures that can occur in [`Replayer::replay()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayError {
+    UnableToSendSample,
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Creates a [`Replayer`] for a recording file that was created by the
+/// [`Recorder`](crate::recorder::Recorder).
+#[derive(Debug)]
+pub struct ReplayerBuilder {
+    file_path: FilePath,
+    config: &'static Config,
+    speed_factor: f64,
+}
+
+impl ReplayerBuilder {
+    /// Creates a new [`ReplayerBuilder`] for the given recording file.
+    pub fn new(file_path: &FilePath) -> Self {
+        Self {
+            file_path: *file_path,
+            config: Config::get_global_config(),
+            speed_factor: 1.0,
+        }
+    }
+
+    /// Uses a custom [`Config`] for the replayed services instead of the global config.
+    pub fn config(mut self, config: &'static Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Defines how fast the recording is replayed compared to the original timing, e.g. `2.0`
+    /// replays it twice as fast. [`f64::INFINITY`] publishes all samples without waiting.
+    /// By default, the original timing is used.
+    pub fn speed_factor(mut self, value: f64) -> Self {
+        self.speed_factor = value;
+        self
+    }
+
+    /// Loads the recording and opens or creates all recorded services with the recorded
+    /// [`StaticConfig`](iceoryx2::service::static_config::publish_subscribe::StaticConfig).
+    /// When a service already exists, its type name and payload layout must match the
+    /// recorded ones, otherwise [`ReplayerCreateError::IncompatibleService`] is returned.
+    pub fn create(self) -> Result<Replayer, ReplayerCreateError> {
+        let msg = "Unable to create replayer";
+
+        if self.speed_factor.is_nan() || self.speed_factor <= 0.0 {
+            fail!(from self, with ReplayerCreateError::InvalidSpeedFactor,
+                "{} since the speed factor {} is not greater than zero.", msg, self.speed_factor);
+        }
+
+        let recording = match Recording::load(&self.file_path) {
+            Ok(recording) => recording,
+            Err(e) => {
+                fail!(from self, with ReplayerCreateError::UnableToLoadRecording(e),
+                    "{} since the recording \"{}\" could not be loaded ({:?}).", msg, self.file_path, e);
+            }
+        };
+
+        let mut services = vec![];
+        for service in recording.services() {
+            services.push(self.open_or_create_service(service)?);
+        }
+
+        Ok(Replayer {
+            recording,
+            services,
+            speed_factor: self.speed_factor,
+        })
+    }
+
+    fn open_or_create_service(
+        &self,
+        service: &RecordedService,
+    ) -> Result<OwningTypeErasedPublisher<zero_copy::Service<'static>>, ReplayerCreateError> {
+        let msg = "Unable to create replayer";
+        let service_name = service.service_name();
+        let static_config = service.static_config();
+
+        let payload_layout = fail!(from self, when Layout::from_size_align(static_config.payload_size(), static_config.payload_alignment()),
+                with ReplayerCreateError::InvalidPayloadLayout,
+                "{} since the recorded payload layout of the service \"{}\" is invalid.", msg, service_name);
+
+        let builder = zero_copy::Service::new(service_name)
+            .publish_subscribe_with_custom_config(self.config)
+            .apply_static_config(static_config, service.attributes());
+
+        let factory = fail!(from self, when builder.open_or_create_type_erased(static_config.type_name(), payload_layout),
+                with ReplayerCreateError::IncompatibleService,
+                "{} since the existing service \"{}\" is not compatible with the recorded type \"{}\".",
+                msg, service_name, static_config.type_name());
+
+        Ok(
+            fail!(from self, when OwningTypeErasedPublisher::create(factory),
+            with ReplayerCreateError::UnableToCreatePublisher,
+            "{} since no publisher could be created for the service \"{}\".", msg, service_name),
+        )
+    }
+}
+
+/// Publishes the samples of a [`Recording`] again. Every recorded service gets its own
+/// publisher, the samples are sent in the order of their time stamps.
+pub struct Replayer {
+    recording: Recording,
+    services: Vec<OwningTypeErasedPublisher<zero_copy::Service<'static>>>,
+    speed_factor: f64,
+}
+
+impl std::fmt::Debug for Replayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Replayer {{ number_of_services: {}, number_of_records: {}, speed_factor: {} }}",
+            self.services.len(),
+            self.recording.records().len(),
+            self.speed_factor
+        )
+    }
+}
+
+impl Replayer {
+    /// Returns the loaded [`Recording`]
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Publishes all records with the recorded timing scaled by the speed factor and returns
+    /// the number of replayed samples. The call blocks until the last sample was sent.
+    pub fn replay(&self) -> Result<usize, ReplayError> {
+        let records = self.recording.records();
+        let first_time_stamp = match records.first() {
+            Some(record) => record.time_stamp().as_duration(),
+            None => return Ok(0),
+        };
+
+        let start = Instant::now();
+        for record in records {
+            let offset = record
+                .time_stamp()
+                .as_duration()
+                .saturating_sub(first_time_stamp)
+                .as_secs_f64()
+                / self.speed_factor;
+            let elapsed = start.elapsed();
+            let offset = Duration::from_secs_f64(offset);
+            if offset > elapsed {
+                std::thread::sleep(offset - elapsed);
+            }
+
+            self.services[record.service_index].with_publisher(|publisher| {
+                // SAFETY: the payload was recorded from a service with the same type name,
+                // payload size and alignment
+                fail!(from "Replayer::replay()", when unsafe { publisher.send_bytes(record.payload()) },
+                    with ReplayError::UnableToSendSample,
+                    "Unable to replay a sample of the service \"{}\".", record.service_name());
+                Ok(())
+            })?;
+        }
+
+        Ok(records.len())
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod record_replay {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::config::TEMP_DIRECTORY;
+    use iceoryx2_bb_posix::file::{AccessMode, CreationMode, File, FileBuilder};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_record::recorder::{RecorderBuilder, RecorderCreateError};
+    use iceoryx2_record::recording::{Recording, RecordingLoadError};
+    use iceoryx2_record::replayer::{ReplayerBuilder, ReplayerCreateError};
+
+    type TestResult<T> = core::result::Result<T, Box<dyn std::error::Error>>;
+
+    const NUMBER_OF_SAMPLES: u64 = 16;
+
+    fn generate_name() -> TestResult<ServiceName> {
+        Ok(ServiceName::new(&format!(
+            "record_replay_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))?)
+    }
+
+    fn generate_file_path() -> TestResult<FilePath> {
+        let file = FileName::new(
+            format!(
+                "record_replay_tests_{}.iox2rec",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )?;
+        Ok(FilePath::from_path_and_file(&TEMP_DIRECTORY, &file)?)
+    }
+
+    /// The replayed services use another prefix so that they do not exist yet when the
+    /// recorded services are still alive.
+    fn replay_config() -> &'static Config {
+        let mut config = Config::get_global_config().clone();
+        config.global.prefix = "iox2_record_replay_tests_".to_string();
+        Box::leak(Box::new(config))
+    }
+
+    /// Records the values `0..NUMBER_OF_SAMPLES` with a pause of `interval` between two samples.
+    fn record_u64_samples(
+        service_name: &ServiceName,
+        file_path: &FilePath,
+        interval: Duration,
+    ) -> TestResult<()> {
+        let service = zero_copy::Service::new(service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .attribute("unit", "m")
+            .create::<u64>()?;
+        let publisher = service.publisher().create()?;
+
+        let mut recorder = RecorderBuilder::new(file_path)
+            .record(*service_name)
+            .create()?;
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(value)?;
+            std::thread::sleep(interval);
+        }
+
+        assert_that!(recorder.record()?, eq NUMBER_OF_SAMPLES as usize);
+        assert_that!(recorder.number_of_records(), eq NUMBER_OF_SAMPLES);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recorder_fails_when_service_does_not_exist() -> TestResult<()> {
+        let sut = RecorderBuilder::new(&generate_file_path()?)
+            .record(generate_name()?)
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RecorderCreateError::UnableToOpenService);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recording_contains_header_service_and_payload_of_every_sample() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let file_path = generate_file_path()?;
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .attribute("unit", "m")
+            .create::<u64>()?;
+        let publisher = service.publisher().create()?;
+        let subscriber = service.subscriber().create()?;
+
+        let mut recorder = RecorderBuilder::new(&file_path)
+            .record(service_name)
+            .create()?;
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(value * 7)?;
+        }
+        assert_that!(recorder.record()?, eq NUMBER_OF_SAMPLES as usize);
+        drop(recorder);
+
+        let sut = Recording::load(&file_path)?;
+        assert_that!(sut.services(), len 1);
+        assert_that!(sut.services()[0].service_name(), eq & service_name);
+        assert_that!(sut.services()[0].static_config(), eq service.static_config());
+        assert_that!(sut.services()[0].attributes(), eq service.attributes());
+        assert_that!(sut.records(), len NUMBER_OF_SAMPLES as usize);
+
+        for (n, record) in sut.records().iter().enumerate() {
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(record.service_name(), eq & service_name);
+            assert_that!(record.type_name(), eq service.static_config().type_name());
+            assert_that!(record.publisher_id(), eq sample.header().publisher_id().value());
+            assert_that!(record.time_stamp(), eq sample.header().time_stamp());
+            assert_that!(record.payload(), eq(n as u64 * 7).to_ne_bytes());
+        }
+
+        File::remove(&file_path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn loading_a_file_that_is_not_a_recording_fails() -> TestResult<()> {
+        let file_path = generate_file_path()?;
+        let mut file = FileBuilder::new(&file_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()?;
+        file.write(b"this is not a recording")?;
+
+        let sut = Recording::load(&file_path);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RecordingLoadError::InvalidFormat);
+
+        file.remove_self()?;
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_recording_replays_all_complete_records() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let file_path = generate_file_path()?;
+        let config = replay_config();
+        record_u64_samples(&service_name, &file_path, Duration::ZERO)?;
+
+        // simulates a recorder that was terminated while writing the last sample
+        let mut content = vec![];
+        FileBuilder::new(&file_path)
+            .open_existing(AccessMode::Read)?
+            .read_to_vector(&mut content)?;
+        let mut file = FileBuilder::new(&file_path)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .create()?;
+        file.write(&content[..content.len() - 3])?;
+
+        let recording = Recording::load(&file_path)?;
+        assert_that!(recording.services(), len 1);
+        assert_that!(recording.records(), len NUMBER_OF_SAMPLES as usize - 1);
+
+        let sut = ReplayerBuilder::new(&file_path)
+            .config(config)
+            .speed_factor(f64::INFINITY)
+            .create()?;
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.replay()?, eq NUMBER_OF_SAMPLES as usize - 1);
+
+        for value in 0..NUMBER_OF_SAMPLES - 1 {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        file.remove_self()?;
+        Ok(())
+    }
+
+    #[test]
+    fn replayer_recreates_services_and_publishes_all_samples() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let file_path = generate_file_path()?;
+        let config = replay_config();
+        record_u64_samples(&service_name, &file_path, Duration::ZERO)?;
+
+        let sut = ReplayerBuilder::new(&file_path)
+            .config(config)
+            .speed_factor(f64::INFINITY)
+            .create()?;
+
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .open::<u64>()?;
+        assert_that!(service.static_config(), eq sut.recording().services()[0].static_config());
+        assert_that!(service.attributes().get("unit"), eq Some("m"));
+        let subscriber = service.subscriber().create()?;
+
+        assert_that!(sut.replay()?, eq NUMBER_OF_SAMPLES as usize);
+
+        for value in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq value);
+        }
+        assert_that!(subscriber.receive()?, is_none);
+
+        File::remove(&file_path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn replayer_respects_the_speed_factor() -> TestResult<()> {
+        const INTERVAL: Duration = Duration::from_millis(10);
+        let service_name = generate_name()?;
+        let file_path = generate_file_path()?;
+        record_u64_samples(&service_name, &file_path, INTERVAL)?;
+
+        let recording = Recording::load(&file_path)?;
+        let records = recording.records();
+        let recorded_duration = records[records.len() - 1].time_stamp().as_duration()
+            - records[0].time_stamp().as_duration();
+
+        let sut = ReplayerBuilder::new(&file_path)
+            .config(replay_config())
+            .speed_factor(0.5)
+            .create()?;
+
+        let start = Instant::now();
+        sut.replay()?;
+        assert_that!(start.elapsed(), ge recorded_duration * 2);
+
+        File::remove(&file_path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn replayer_fails_with_invalid_speed_factor() -> TestResult<()> {
+        for speed_factor in [0.0, -1.0, f64::NAN] {
+            let sut = ReplayerBuilder::new(&generate_file_path()?)
+                .speed_factor(speed_factor)
+                .create();
+
+            assert_that!(sut, is_err);
+            assert_that!(sut.err().unwrap(), eq ReplayerCreateError::InvalidSpeedFactor);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn replayer_fails_when_existing_service_has_another_type() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let file_path = generate_file_path()?;
+        let config = replay_config();
+        record_u64_samples(&service_name, &file_path, Duration::ZERO)?;
+
+        let _service = zero_copy::Service::new(&service_name)
+            .publish_subscribe_with_custom_config(config)
+            .create::<u32>()?;
+
+        let sut = ReplayerBuilder::new(&file_path).config(config).create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq ReplayerCreateError::IncompatibleService);
+
+        File::remove(&file_path)?;
+        Ok(())
+    }
+}